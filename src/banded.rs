@@ -0,0 +1,449 @@
+use crate::core::NdArray;
+
+/// Check that the vector `b` has `n` elements
+///
+/// ## Panics
+/// If the length differs, panics; message shown is **Shape(\[...\]) does not match Size(`n`)**
+fn check_len(b: &NdArray<f64, 1>, n: usize) {
+    if b.view_shape()[0] != n {
+        panic!("Shape({:?}) does not match Size({})", b.view_shape(), n);
+    }
+}
+
+/// Tridiagonal matrix of size `n x n`, storing only the sub-diagonal, diagonal and super-diagonal
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::banded::Tridiagonal;
+/// #
+/// # fn main() {
+/// // 1-D Poisson equation: -u[i-1] + 2u[i] - u[i+1] = f[i]
+/// let n = 4;
+/// let (off, diag) = (vec![-1.0; n - 1], vec![2.0; n]);
+/// let (off, diag) = (NdArray::<f64, 1>::from(&off, [n - 1]), NdArray::<f64, 1>::from(&diag, [n]));
+/// let a = Tridiagonal::new(&off, &diag, &off);
+/// let f = NdArray::<f64, 1>::from(&[1.0, 0.0, 0.0, 1.0], [4]);
+/// let u = a.solve(&f).unwrap();
+/// assert!(u.as_slice().iter().all(|x| (x - 1.0).abs() < 1e-12));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal {
+    lower: Vec<f64>,
+    diag: Vec<f64>,
+    upper: Vec<f64>,
+}
+
+impl Tridiagonal {
+    /// Create a tridiagonal matrix from its sub-diagonal `lower` (`n - 1` elements), `diag` (`n` elements) and super-diagonal `upper` (`n - 1` elements)
+    ///
+    /// ## Panics
+    /// If `lower` or `upper` does not have one element less than `diag`, panics; message shown is **Shape(\[...\]) does not match Size(`n - 1`)**
+    pub fn new(lower: &NdArray<f64, 1>, diag: &NdArray<f64, 1>, upper: &NdArray<f64, 1>) -> Self {
+        let n: usize = diag.view_shape()[0];
+        check_len(lower, n.saturating_sub(1));
+        check_len(upper, n.saturating_sub(1));
+
+        Tridiagonal {
+            lower: lower.as_slice().to_vec(),
+            diag: diag.as_slice().to_vec(),
+            upper: upper.as_slice().to_vec(),
+        }
+    }
+
+    /// Return the number of rows (and columns) of the matrix
+    pub fn len(&self) -> usize {
+        self.diag.len()
+    }
+
+    /// Return true if the matrix has no rows
+    pub fn is_empty(&self) -> bool {
+        self.diag.is_empty()
+    }
+
+    /// Return the product of the matrix and the vector `x`
+    ///
+    /// ## Panics
+    /// If `x` does not have `n` elements, panics; message shown is **Shape(\[...\]) does not match Size(`n`)**
+    pub fn matvec(&self, x: &NdArray<f64, 1>) -> NdArray<f64, 1> {
+        let n: usize = self.len();
+        check_len(x, n);
+        let x: &[f64] = x.as_slice();
+
+        let values: Vec<f64> = (0..n)
+            .map(|i| {
+                let mut sum: f64 = self.diag[i] * x[i];
+                if i > 0 {
+                    sum += self.lower[i - 1] * x[i - 1];
+                }
+                if i + 1 < n {
+                    sum += self.upper[i] * x[i + 1];
+                }
+                sum
+            })
+            .collect();
+        NdArray::from_owned_vec(values, [n])
+    }
+
+    /// Solve `a x = b` using the Thomas algorithm in `O(n)` time and memory
+    ///
+    /// Returns `None` if a zero pivot is encountered
+    ///
+    /// ## Note
+    /// The Thomas algorithm does not pivot, and hence is stable for diagonally dominant or symmetric positive definite matrices. Use [`Banded::solve(...)`](Banded::solve) for other matrices
+    ///
+    /// ## Panics
+    /// If `b` does not have `n` elements, panics; message shown is **Shape(\[...\]) does not match Size(`n`)**
+    pub fn solve(&self, b: &NdArray<f64, 1>) -> Option<NdArray<f64, 1>> {
+        let n: usize = self.len();
+        check_len(b, n);
+
+        let mut upper: Vec<f64> = vec![0.0; n];
+        let mut x: Vec<f64> = b.as_slice().to_vec();
+        for i in 0..n {
+            let mut pivot: f64 = self.diag[i];
+            if i > 0 {
+                pivot -= self.lower[i - 1] * upper[i - 1];
+                x[i] -= self.lower[i - 1] * x[i - 1];
+            }
+            if pivot == 0.0 || !pivot.is_finite() {
+                return None;
+            }
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] /= pivot;
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            x[i] -= upper[i] * x[i + 1];
+        }
+
+        Some(NdArray::from_owned_vec(x, [n]))
+    }
+
+    /// Return the matrix as a dense 2-D NdArray
+    pub fn to_dense(&self) -> NdArray<f64, 2> {
+        let n: usize = self.len();
+        let mut values: Vec<f64> = vec![0.0; n * n];
+        for i in 0..n {
+            values[i * n + i] = self.diag[i];
+            if i + 1 < n {
+                values[i * n + i + 1] = self.upper[i];
+                values[(i + 1) * n + i] = self.lower[i];
+            }
+        }
+
+        NdArray::from_owned_vec(values, [n, n])
+    }
+}
+
+/// Banded matrix of size `n x n` with `lower` sub-diagonals and `upper` super-diagonals. Only the `lower + upper + 1` diagonals are stored, row by row
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::banded::Banded;
+/// #
+/// # fn main() {
+/// let mut a = Banded::zeros(3, 1, 0); // lower bidiagonal
+/// a.set(0, 0, 2.0);
+/// a.set(1, 0, 1.0);
+/// a.set(1, 1, 1.0);
+/// a.set(2, 1, 1.0);
+/// a.set(2, 2, 4.0);
+/// let b = NdArray::<f64, 1>::from(&[2.0, 3.0, 10.0], [3]);
+/// let x = a.solve(&b).unwrap();
+/// assert_eq!(x.as_slice(), &[1.0, 2.0, 2.0]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<f64>,
+}
+
+impl Banded {
+    /// Create a banded matrix of size `n x n` with `lower` sub-diagonals and `upper` super-diagonals, filled with zeros
+    ///
+    /// ## Panics
+    /// If the number of stored elements `n * (lower + upper + 1)` overflows, panics; message shown is **Band(`lower`, `upper`) of Size(`n`) overflows**
+    pub fn zeros(n: usize, lower: usize, upper: usize) -> Self {
+        let size: usize = lower
+            .checked_add(upper)
+            .and_then(|width| width.checked_add(1))
+            .and_then(|width| width.checked_mul(n))
+            .unwrap_or_else(|| panic!("Band({}, {}) of Size({}) overflows", lower, upper, n));
+        Banded {
+            n,
+            lower,
+            upper,
+            data: vec![0.0; size],
+        }
+    }
+
+    /// Create a banded matrix from the square matrix `a`. Elements outside the band are ignored
+    ///
+    /// ## Panics
+    /// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+    pub fn from_dense(a: &NdArray<f64, 2>, lower: usize, upper: usize) -> Self {
+        let [n, cols] = a.view_shape();
+        if n != cols {
+            panic!("Matrix of Shape({:?}) is not square", a.view_shape());
+        }
+
+        let mut band: Banded = Banded::zeros(n, lower, upper);
+        for i in 0..n {
+            for j in i.saturating_sub(lower)..(i + upper + 1).min(n) {
+                band.set(i, j, a[[i, j]]);
+            }
+        }
+        band
+    }
+
+    /// Return the number of rows (and columns) of the matrix
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Return true if the matrix has no rows
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Return the number of sub-diagonals and super-diagonals
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /// Position of `(row, col)` in the row-wise band storage, if it lies within the band
+    fn position(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.n || col >= self.n || col + self.lower < row || row + self.upper < col {
+            return None;
+        }
+
+        Some(row * (self.lower + self.upper + 1) + col + self.lower - row)
+    }
+
+    /// Return the element at `(row, col)`. Elements outside the band are zero
+    ///
+    /// ## Panics
+    /// If `row` or `col` is not less than `n`, panics; message shown is **Index out of bounds**
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        if row >= self.n || col >= self.n {
+            panic!("Index out of bounds");
+        }
+
+        self.position(row, col).map_or(0.0, |pos| self.data[pos])
+    }
+
+    /// Set the element at `(row, col)` to `value`
+    ///
+    /// ## Panics
+    /// If `(row, col)` is not within the band, panics; message shown is **Index(\[`row`, `col`\]) outside Band(`lower`, `upper`)**
+    pub fn set(&mut self, row: usize, col: usize, value: f64) {
+        match self.position(row, col) {
+            Some(pos) => self.data[pos] = value,
+            None => panic!(
+                "Index([{}, {}]) outside Band({}, {})",
+                row, col, self.lower, self.upper
+            ),
+        }
+    }
+
+    /// Return the product of the matrix and the vector `x`
+    ///
+    /// ## Panics
+    /// If `x` does not have `n` elements, panics; message shown is **Shape(\[...\]) does not match Size(`n`)**
+    pub fn matvec(&self, x: &NdArray<f64, 1>) -> NdArray<f64, 1> {
+        check_len(x, self.n);
+        let x: &[f64] = x.as_slice();
+
+        let values: Vec<f64> = (0..self.n)
+            .map(|i| {
+                (i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.n))
+                    .map(|j| self.get(i, j) * x[j])
+                    .sum()
+            })
+            .collect();
+        NdArray::from_owned_vec(values, [self.n])
+    }
+
+    /// Solve `a x = b` using Gaussian elimination with partial pivoting within the band, in `O(n * lower * (lower + upper))` time and `O(n * (2 * lower + upper))` memory
+    ///
+    /// Returns `None` if the matrix is singular
+    ///
+    /// ## Panics
+    /// If `b` does not have `n` elements, panics; message shown is **Shape(\[...\]) does not match Size(`n`)**
+    pub fn solve(&self, b: &NdArray<f64, 1>) -> Option<NdArray<f64, 1>> {
+        let (n, lower, upper) = (self.n, self.lower, self.upper);
+        check_len(b, n);
+
+        // row pivoting widens the upper band by `lower`: row `i` holds columns `i - lower..=i + lower + upper`
+        let width: usize = 2 * lower + upper + 1;
+        let at = |row: usize, col: usize| row * width + col + lower - row;
+        let mut work: Vec<f64> = vec![0.0; n * width];
+        for i in 0..n {
+            for j in i.saturating_sub(lower)..(i + upper + 1).min(n) {
+                work[at(i, j)] = self.get(i, j);
+            }
+        }
+        let mut x: Vec<f64> = b.as_slice().to_vec();
+
+        for col in 0..n {
+            let last_row: usize = (col + lower + 1).min(n);
+            let last_col: usize = (col + lower + upper + 1).min(n);
+            let pivot: usize = (col..last_row)
+                .max_by(|p, q| work[at(*p, col)].abs().total_cmp(&work[at(*q, col)].abs()))
+                .unwrap();
+            let diag: f64 = work[at(pivot, col)];
+            if diag == 0.0 || !diag.is_finite() {
+                return None;
+            }
+            if pivot != col {
+                for j in col..last_col {
+                    work.swap(at(pivot, j), at(col, j));
+                }
+                x.swap(pivot, col);
+            }
+
+            for row in col + 1..last_row {
+                let factor: f64 = work[at(row, col)] / diag;
+                if factor == 0.0 {
+                    continue;
+                }
+                for j in col..last_col {
+                    work[at(row, j)] -= factor * work[at(col, j)];
+                }
+                x[row] -= factor * x[col];
+            }
+        }
+
+        for i in (0..n).rev() {
+            let last_col: usize = (i + lower + upper + 1).min(n);
+            let sum: f64 = (i + 1..last_col).map(|j| work[at(i, j)] * x[j]).sum();
+            x[i] = (x[i] - sum) / work[at(i, i)];
+        }
+
+        Some(NdArray::from_owned_vec(x, [n]))
+    }
+
+    /// Return the matrix as a dense 2-D NdArray
+    pub fn to_dense(&self) -> NdArray<f64, 2> {
+        let n: usize = self.n;
+        let mut values: Vec<f64> = vec![0.0; n * n];
+        for i in 0..n {
+            for j in i.saturating_sub(self.lower)..(i + self.upper + 1).min(n) {
+                values[i * n + j] = self.get(i, j);
+            }
+        }
+
+        NdArray::from_owned_vec(values, [n, n])
+    }
+}
+
+#[cfg(test)]
+mod banded_t {
+    use crate::{
+        banded::{Banded, Tridiagonal},
+        core::{Array, Array2},
+        linalg::solve,
+    };
+
+    // Test the Thomas algorithm on a diagonally dominant system
+    // Check if the solution reproduces the right-hand side
+    #[test]
+    fn tridiagonal_solve_t() {
+        let lower = Array::<f64>::from(&[1.0, -2.0, 0.5, 1.0], [4]);
+        let diag = Array::<f64>::from(&[4.0, 5.0, 6.0, 3.0, 4.0], [5]);
+        let upper = Array::<f64>::from(&[-1.0, 2.0, 1.0, 0.5], [4]);
+        let a = Tridiagonal::new(&lower, &diag, &upper);
+        let b = Array::<f64>::from(&[1.0, 2.0, 3.0, 4.0, 5.0], [5]);
+
+        let x = a.solve(&b).unwrap();
+        let ax = a.matvec(&x);
+        for i in 0..5 {
+            assert!((ax[[i]] - b[[i]]).abs() < 1e-12);
+        }
+        assert_eq!(a.to_dense()[[2, 1]], -2.0);
+    }
+
+    // Test the banded solver on a system which requires pivoting
+    // Check if the solution matches the dense solver
+    #[test]
+    fn banded_solve_pivoting_t() {
+        let dense = Array2::<f64>::from(
+            &[
+                0.0, 1.0, 2.0, 0.0, 0.0, //
+                3.0, 1.0, 0.0, 1.0, 0.0, //
+                0.0, 2.0, 0.0, 1.0, 4.0, //
+                0.0, 0.0, 1.0, 1.0, 1.0, //
+                0.0, 0.0, 0.0, 5.0, 2.0,
+            ],
+            [5, 5],
+        );
+        let band = Banded::from_dense(&dense, 1, 2);
+        assert_eq!(band.to_dense().as_slice(), dense.as_slice());
+
+        let b = Array::<f64>::from(&[1.0, -1.0, 2.0, 0.0, 3.0], [5]);
+        let x = band.solve(&b).unwrap();
+        let expected = solve(&dense, &b).unwrap();
+        for i in 0..5 {
+            assert!((x[[i]] - expected[[i]]).abs() < 1e-12);
+        }
+    }
+
+    // Test the solvers with singular matrices
+    #[test]
+    fn singular_t() {
+        let ones = Array::<f64>::ones([2]);
+        let a = Tridiagonal::new(&ones, &Array::<f64>::zeros([3]), &ones);
+        assert!(a.solve(&Array::<f64>::ones([3])).is_none());
+        assert!(Banded::zeros(3, 1, 1)
+            .solve(&Array::<f64>::ones([3]))
+            .is_none());
+    }
+
+    // Test setting an element outside the band
+    #[test]
+    #[should_panic(expected = "Index([0, 2]) outside Band(1, 1)")]
+    fn banded_set_outside_t() {
+        Banded::zeros(3, 1, 1).set(0, 2, 1.0);
+    }
+
+    // Test empty matrices and vectors created by NdArray::new()
+    #[test]
+    fn empty_t() {
+        let band = Banded::from_dense(&Array2::<f64>::new(), 0, 0);
+        assert!(band.is_empty());
+        assert!(band.solve(&Array::<f64>::new()).unwrap().is_empty());
+
+        let a = Tridiagonal::new(
+            &Array::<f64>::new(),
+            &Array::<f64>::new(),
+            &Array::<f64>::new(),
+        );
+        assert!(a.is_empty());
+        assert_eq!(a.matvec(&Array::<f64>::zeros([0])).shape(), &[0]);
+    }
+
+    // Test solving a 1 x 1 system with an empty right-hand side
+    #[test]
+    #[should_panic(expected = "Shape([0]) does not match Size(1)")]
+    fn tridiagonal_solve_empty_t() {
+        let one = Array::<f64>::ones([1]);
+        let _ = Tridiagonal::new(&Array::<f64>::zeros([0]), &one, &Array::<f64>::zeros([0]))
+            .solve(&Array::<f64>::new());
+    }
+
+    // Test a band whose storage size overflows
+    #[test]
+    #[should_panic(expected = "Band(1, 1) of Size(18446744073709551615) overflows")]
+    fn banded_zeros_overflow_t() {
+        Banded::zeros(usize::MAX, 1, 1);
+    }
+}
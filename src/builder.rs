@@ -0,0 +1,182 @@
+use crate::core::{NdArray, ShapeError};
+
+/// Builder of a two or three dimensional NdArray from rows pushed one at a time, e.g. by a streaming parser. Every row must have the length of the first row
+///
+/// ## Example
+///
+/// ```
+/// use ndim::builder::NdArrayBuilder;
+///
+/// # fn main() {
+/// let csv = "1,2,3\n4,5,6\n";
+/// let mut builder = NdArrayBuilder::<i32>::new();
+/// for line in csv.lines() {
+///     let row: Vec<i32> = line.split(',').map(|x| x.parse().unwrap()).collect();
+///     builder.push_row(&row).unwrap();
+/// }
+/// let arr = builder.build();
+/// assert_eq!(*arr.shape(), [2, 3]);
+/// assert_eq!(arr[[1, 2]], 6);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NdArrayBuilder<T> {
+    values: Vec<T>,
+    rows: usize,
+    cols: Option<usize>,
+}
+
+impl<T> NdArrayBuilder<T> {
+    /// Create a builder without any rows
+    pub fn new() -> Self {
+        NdArrayBuilder {
+            values: Vec::new(),
+            rows: 0,
+            cols: None,
+        }
+    }
+
+    /// Create a builder without any rows, with memory reserved for `rows` rows of `cols` elements
+    pub fn with_capacity(rows: usize, cols: usize) -> Self {
+        NdArrayBuilder {
+            values: Vec::with_capacity(rows.saturating_mul(cols)),
+            rows: 0,
+            cols: None,
+        }
+    }
+
+    /// Return the number of rows pushed
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the length of the rows, or `None` if no row is pushed
+    pub fn cols(&self) -> Option<usize> {
+        self.cols
+    }
+
+    /// Append a row by copying its elements. Returns [`ShapeError::RaggedRows`] if the length of the row differs from the first row, and the row is not appended
+    pub fn push_row(&mut self, row: &[T]) -> Result<&mut Self, ShapeError>
+    where
+        T: Clone,
+    {
+        self.check_row(row.len())?;
+        self.values.extend_from_slice(row);
+        self.rows += 1;
+        Ok(self)
+    }
+
+    /// Append a row, taking the ownership of its elements. Returns [`ShapeError::RaggedRows`] if the length of the row differs from the first row, and the row is not appended
+    pub fn push_row_vec(&mut self, row: Vec<T>) -> Result<&mut Self, ShapeError> {
+        self.check_row(row.len())?;
+        self.values.extend(row);
+        self.rows += 1;
+        Ok(self)
+    }
+
+    /// Return a [`ShapeError::RaggedRows`] if a row of length `len` cannot be appended
+    fn check_row(&mut self, len: usize) -> Result<(), ShapeError> {
+        match self.cols {
+            Some(expected) if expected != len => Err(ShapeError::RaggedRows {
+                row: self.rows,
+                len,
+                expected,
+            }),
+            Some(_) => Ok(()),
+            None => {
+                self.cols = Some(len);
+                Ok(())
+            }
+        }
+    }
+
+    /// Finish the builder as a two dimensional (2-D) array of shape `[rows, cols]`. The shape is `[0, 0]` if no row is pushed
+    pub fn build(self) -> NdArray<T, 2> {
+        let shape: [usize; 2] = [self.rows, self.cols.unwrap_or(0)];
+        NdArray::from_owned_vec(self.values, shape)
+    }
+
+    /// Finish the builder as a three dimensional (3-D) array of `depth` consecutive matrices of equal height, i.e., of shape `[depth, rows / depth, cols]`
+    ///
+    /// ## Note
+    /// Returns [`ShapeError::SizeMismatch`] if the rows cannot be split into `depth` matrices of equal height
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::builder::NdArrayBuilder;
+    ///
+    /// # fn main() {
+    /// let mut builder = NdArrayBuilder::<u8>::with_capacity(4, 2);
+    /// for row in [[0, 1], [2, 3], [4, 5], [6, 7]] {
+    ///     builder.push_row(&row).unwrap();
+    /// }
+    /// let arr = builder.clone().build3(2).unwrap();
+    /// assert_eq!(*arr.shape(), [2, 2, 2]);
+    /// assert_eq!(arr[[1, 0, 1]], 5);
+    /// assert!(builder.build3(3).is_err());
+    /// # }
+    /// ```
+    pub fn build3(self, depth: usize) -> Result<NdArray<T, 3>, ShapeError> {
+        let cols: usize = self.cols.unwrap_or(0);
+        let height: usize = self.rows.checked_div(depth).unwrap_or(0);
+        let shape: [usize; 3] = [depth, height, cols];
+        if depth * height != self.rows {
+            return Err(ShapeError::SizeMismatch {
+                shape: shape.to_vec(),
+                size: self.values.len(),
+            });
+        }
+
+        Ok(NdArray::from_owned_vec(self.values, shape))
+    }
+}
+
+#[cfg(test)]
+mod builder_t {
+    use crate::{builder::NdArrayBuilder, core::ShapeError};
+
+    // Test building 2-D and 3-D arrays from rows, for a type which is not Copy
+    #[test]
+    fn build_t() {
+        let mut builder = NdArrayBuilder::<String>::new();
+        assert_eq!(builder.cols(), None);
+        for row in 0..6 {
+            builder
+                .push_row_vec(vec![format!("{}a", row), format!("{}b", row)])
+                .unwrap();
+        }
+        assert_eq!((builder.rows(), builder.cols()), (6, Some(2)));
+
+        let data = builder.clone().build();
+        assert_eq!(*data.shape(), [6, 2]);
+        assert_eq!(data[[4, 1]], "4b");
+        let data = builder.build3(3).unwrap();
+        assert_eq!(*data.shape(), [3, 2, 2]);
+        assert_eq!(data[[2, 1, 0]], "5a");
+
+        assert_eq!(*NdArrayBuilder::<u8>::new().build().shape(), [0, 0]);
+        assert!(NdArrayBuilder::<u8>::new().build3(0).unwrap().is_empty());
+    }
+
+    // Test pushing a row which is longer than the first row
+    #[test]
+    fn ragged_row_t() {
+        let mut builder = NdArrayBuilder::<f32>::new();
+        builder
+            .push_row(&[1.0, 2.0])
+            .unwrap()
+            .push_row(&[3.0, 4.0])
+            .unwrap();
+        assert_eq!(
+            builder.push_row(&[5.0, 6.0, 7.0]).unwrap_err(),
+            ShapeError::RaggedRows {
+                row: 2,
+                len: 3,
+                expected: 2
+            }
+        );
+        assert_eq!(builder.rows(), 2); // the ragged row is not appended
+        assert_eq!(builder.build().as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+    }
+}
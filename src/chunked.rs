@@ -0,0 +1,816 @@
+use std::{
+    fmt, fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder};
+use num_traits::{NumCast, ToPrimitive};
+use serde_json::{json, Value};
+
+use crate::{
+    core::{unravel_index, NdArray, Order, SizedArray},
+    slice::SliceInfo,
+};
+
+/// Error while creating, opening, reading or writing a chunked array
+#[derive(Debug)]
+pub enum ZarrError {
+    /// A file of the array could not be read or written
+    Io(io::Error),
+    /// The metadata or a chunk is not valid, or uses an unsupported Zarr feature
+    Format(String),
+    /// The dtype of the array does not match the element type
+    Dtype {
+        expected: &'static str,
+        found: String,
+    },
+    /// The dimension of the array does not match the dimension of the ChunkedArray
+    Dimension { expected: usize, found: usize },
+}
+
+impl fmt::Display for ZarrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZarrError::Io(err) => write!(f, "{}", err),
+            ZarrError::Format(msg) => write!(f, "{}", msg),
+            ZarrError::Dtype { expected, found } => {
+                write!(
+                    f,
+                    "Array has Dtype({}), expected Dtype({})",
+                    found, expected
+                )
+            }
+            ZarrError::Dimension { expected, found } => write!(
+                f,
+                "Array has Dimension({}), expected Dimension({})",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ZarrError {}
+
+impl From<io::Error> for ZarrError {
+    fn from(err: io::Error) -> Self {
+        ZarrError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ZarrError {
+    fn from(err: serde_json::Error) -> Self {
+        ZarrError::Format(err.to_string())
+    }
+}
+
+/// Compression of the chunks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Chunks are stored as raw bytes
+    #[default]
+    None,
+    /// Chunks are compressed by gzip with the level (0 to 9), same as the `gzip` codec of numcodecs
+    Gzip(u32),
+}
+
+/// Element types which can be stored in a chunked array. Values are stored in little-endian byte order
+pub trait ZarrElement: Copy + Default + NumCast + ToPrimitive {
+    /// The Zarr (NumPy) dtype of the element type, e.g. `<f8`
+    const DTYPE: &'static str;
+
+    /// Append the little-endian bytes of the value to `bytes`
+    fn write_le(self, bytes: &mut Vec<u8>);
+
+    /// Read a value from its little-endian `bytes`
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+/// Implement `ZarrElement` for every primitive `$t` with the dtype `$dtype`
+macro_rules! impl_zarr_element {
+    ($($t:ty => $dtype:literal),*) => {
+        $(
+            impl ZarrElement for $t {
+                const DTYPE: &'static str = $dtype;
+
+                fn write_le(self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$t>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_zarr_element!(
+    u8 => "|u1", i8 => "|i1", u16 => "<u2", i16 => "<i2", u32 => "<u4", i32 => "<i4",
+    u64 => "<u8", i64 => "<i8", f32 => "<f4", f64 => "<f8"
+);
+
+/// Copy the box of shape `extent` starting at `src_start` of `src` (of shape `src_shape`) to the box starting at `dst_start` of `dst` (of shape `dst_shape`)
+fn copy_box<T: Copy, const N: usize>(
+    src: &[T],
+    src_shape: &SizedArray<N>,
+    src_start: &SizedArray<N>,
+    dst: &mut [T],
+    dst_shape: &SizedArray<N>,
+    dst_start: &SizedArray<N>,
+    extent: &SizedArray<N>,
+) {
+    let flat = |index: &SizedArray<N>, start: &SizedArray<N>, shape: &SizedArray<N>| -> usize {
+        (0..N).fold(0, |pos, dim| pos * shape[dim] + start[dim] + index[dim])
+    };
+    for pos in 0..extent.iter().product() {
+        let index: SizedArray<N> = unravel_index(pos, extent, Order::C);
+        dst[flat(&index, dst_start, dst_shape)] = src[flat(&index, src_start, src_shape)];
+    }
+}
+
+/// N-dimensional array stored on disk as fixed-size chunks, using the directory layout of Zarr v2. Chunks are read and written on demand, hence arrays larger than memory can be processed chunk by chunk
+///
+/// The directory holds the metadata in `.zarray` and every chunk in a file named by its chunk index (e.g. `1.0.2`), holding the little-endian elements of the whole chunk in row-major order, optionally compressed. Chunks never written read as the fill value
+///
+/// ## Note
+/// Only the row-major (C) order, no filters, and no compression or the gzip compressor are supported
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::chunked::{ChunkedArray, Compression};
+/// #
+/// # fn main() {
+/// let path = std::env::temp_dir().join("ndim_chunked_doc.zarr");
+/// let array = ChunkedArray::<f32, 2>::create(&path, [1000, 1000], [100, 250], Compression::Gzip(5)).unwrap();
+/// assert_eq!(array.grid(), [10, 4]);
+///
+/// let tile = NdArray::<f32, 2>::ones([3, 300]);
+/// array.write_region([99, 0], &tile).unwrap(); // spans 2 x 2 chunks
+/// let region = array.read_region([100, 290], [2, 20]).unwrap();
+/// assert_eq!(&region.as_slice()[..10], &[1.0; 10]);
+/// assert_eq!(&region.as_slice()[10..20], &[0.0; 10]); // fill value
+/// # std::fs::remove_dir_all(&path).unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChunkedArray<T, const N: usize> {
+    path: PathBuf,
+    shape: SizedArray<N>,
+    chunks: SizedArray<N>,
+    compression: Compression,
+    separator: char,
+    fill: T,
+}
+
+impl<T: ZarrElement, const N: usize> ChunkedArray<T, N> {
+    /// Create an empty chunked array of `shape` with chunks of shape `chunks` in the directory `path`, which is created if missing. Any existing metadata is overwritten
+    ///
+    /// ## Panics
+    /// If an axis of `chunks` is zero, panics; message shown is **Chunk Shape(`chunks`) cannot have empty axes**
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        shape: SizedArray<N>,
+        chunks: SizedArray<N>,
+        compression: Compression,
+    ) -> Result<Self, ZarrError> {
+        if chunks.contains(&0) {
+            panic!("Chunk Shape({:?}) cannot have empty axes", chunks);
+        }
+
+        let compressor: Value = match compression {
+            Compression::None => Value::Null,
+            Compression::Gzip(level) => json!({ "id": "gzip", "level": level }),
+        };
+        let metadata: Value = json!({
+            "zarr_format": 2,
+            "shape": shape.to_vec(),
+            "chunks": chunks.to_vec(),
+            "dtype": T::DTYPE,
+            "compressor": compressor,
+            "fill_value": 0,
+            "order": "C",
+            "filters": Value::Null,
+            "dimension_separator": ".",
+        });
+        let path: PathBuf = path.as_ref().to_path_buf();
+        fs::create_dir_all(&path)?;
+        fs::write(
+            path.join(".zarray"),
+            serde_json::to_string_pretty(&metadata)?,
+        )?;
+
+        Ok(ChunkedArray {
+            path,
+            shape,
+            chunks,
+            compression,
+            separator: '.',
+            fill: T::default(),
+        })
+    }
+
+    /// Open the chunked array in the directory `path`, checking its metadata against the element type and the dimension
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ZarrError> {
+        let path: PathBuf = path.as_ref().to_path_buf();
+        let metadata: Value = serde_json::from_str(&fs::read_to_string(path.join(".zarray"))?)?;
+        let field = |key: &str| -> Result<&Value, ZarrError> {
+            metadata
+                .get(key)
+                .ok_or_else(|| ZarrError::Format(format!("Metadata has no field \"{}\"", key)))
+        };
+
+        if field("zarr_format")?.as_u64() != Some(2) {
+            return Err(ZarrError::Format(
+                "Only Zarr format 2 is supported".to_string(),
+            ));
+        }
+        let dtype: &str = field("dtype")?.as_str().unwrap_or_default();
+        // the byte order of single byte types is irrelevant
+        let same_type: bool = dtype == T::DTYPE
+            || (size_of::<T>() == 1 && dtype.len() == 3 && dtype[1..] == T::DTYPE[1..]);
+        if !same_type {
+            return Err(ZarrError::Dtype {
+                expected: T::DTYPE,
+                found: dtype.to_string(),
+            });
+        }
+        if field("order")?.as_str() != Some("C") {
+            return Err(ZarrError::Format(
+                "Only the C order is supported".to_string(),
+            ));
+        }
+        if !metadata.get("filters").is_none_or(|filters| {
+            filters.is_null() || filters.as_array().is_some_and(Vec::is_empty)
+        }) {
+            return Err(ZarrError::Format("Filters are not supported".to_string()));
+        }
+
+        let compression: Compression = match field("compressor")? {
+            Value::Null => Compression::None,
+            compressor if compressor.get("id").and_then(Value::as_str) == Some("gzip") => {
+                let level: u64 = compressor.get("level").and_then(Value::as_u64).unwrap_or(6);
+                Compression::Gzip(level as u32)
+            }
+            compressor => {
+                return Err(ZarrError::Format(format!(
+                    "Compressor({}) is not supported",
+                    compressor
+                )))
+            }
+        };
+        let separator: char = match metadata.get("dimension_separator").and_then(Value::as_str) {
+            None | Some(".") => '.',
+            Some("/") => '/',
+            Some(other) => {
+                return Err(ZarrError::Format(format!(
+                    "Dimension separator \"{}\" is not supported",
+                    other
+                )))
+            }
+        };
+        let fill: T = match field("fill_value")? {
+            Value::Number(number) => number.as_f64().and_then(T::from),
+            Value::String(special) => special.parse::<f64>().ok().and_then(T::from),
+            _ => None,
+        }
+        .unwrap_or_default();
+
+        let axes = |key: &str| -> Result<SizedArray<N>, ZarrError> {
+            let values: Vec<usize> = field(key)?
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(Value::as_u64)
+                        .map(|x| x as usize)
+                        .collect()
+                })
+                .unwrap_or_default();
+            values
+                .as_slice()
+                .try_into()
+                .map_err(|_| ZarrError::Dimension {
+                    expected: N,
+                    found: values.len(),
+                })
+        };
+        let (shape, chunks) = (axes("shape")?, axes("chunks")?);
+        if chunks.contains(&0) {
+            return Err(ZarrError::Format(format!(
+                "Chunk Shape({:?}) cannot have empty axes",
+                chunks
+            )));
+        }
+
+        Ok(ChunkedArray {
+            path,
+            shape,
+            chunks,
+            compression,
+            separator,
+            fill,
+        })
+    }
+
+    /// Return the shape of the array
+    pub fn shape(&self) -> &SizedArray<N> {
+        &self.shape
+    }
+
+    /// Return the shape of the chunks. Chunks at the end of an axis may extend past the array
+    pub fn chunks(&self) -> &SizedArray<N> {
+        &self.chunks
+    }
+
+    /// Return the compression of the chunks
+    pub fn compression(&self) -> Compression {
+        self.compression
+    }
+
+    /// Return the number of chunks along every axis
+    pub fn grid(&self) -> SizedArray<N> {
+        std::array::from_fn(|dim| self.shape[dim].div_ceil(self.chunks[dim]))
+    }
+
+    /// Start and shape of the elements of the array within the chunk `index`
+    fn chunk_box(&self, index: &SizedArray<N>) -> (SizedArray<N>, SizedArray<N>) {
+        let grid: SizedArray<N> = self.grid();
+        if (0..N).any(|dim| index[dim] >= grid[dim]) {
+            panic!("Index out of bounds");
+        }
+        let (mut start, mut shape): (SizedArray<N>, SizedArray<N>) = ([0; N], [0; N]);
+        for dim in 0..N {
+            start[dim] = index[dim] * self.chunks[dim];
+            shape[dim] = self.chunks[dim].min(self.shape[dim] - start[dim]);
+        }
+        (start, shape)
+    }
+
+    /// Path of the file of the chunk `index`
+    fn chunk_path(&self, index: &SizedArray<N>) -> PathBuf {
+        let key: Vec<String> = index.iter().map(usize::to_string).collect();
+        let key: String = if N == 0 {
+            "0".to_string()
+        } else {
+            key.join(&self.separator.to_string())
+        };
+        self.path.join(key)
+    }
+
+    /// Read all elements of the chunk `index`, including the padding past the array
+    fn read_full_chunk(&self, index: &SizedArray<N>) -> Result<Vec<T>, ZarrError> {
+        let len: usize = self.chunks.iter().product();
+        let encoded: Vec<u8> = match fs::read(self.chunk_path(index)) {
+            Ok(encoded) => encoded,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![self.fill; len]),
+            Err(err) => return Err(err.into()),
+        };
+        let bytes: Vec<u8> = match self.compression {
+            Compression::None => encoded,
+            Compression::Gzip(_) => {
+                let mut bytes: Vec<u8> = Vec::new();
+                GzDecoder::new(encoded.as_slice()).read_to_end(&mut bytes)?;
+                bytes
+            }
+        };
+
+        let size: usize = size_of::<T>();
+        if bytes.len() != len * size {
+            return Err(ZarrError::Format(format!(
+                "Chunk({:?}) has {} bytes, expected {} bytes",
+                index,
+                bytes.len(),
+                len * size
+            )));
+        }
+        Ok(bytes.chunks_exact(size).map(T::read_le).collect())
+    }
+
+    /// Write all elements of the chunk `index`, including the padding past the array
+    fn write_full_chunk(&self, index: &SizedArray<N>, values: &[T]) -> Result<(), ZarrError> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(std::mem::size_of_val(values));
+        values.iter().for_each(|x| x.write_le(&mut bytes));
+        let encoded: Vec<u8> = match self.compression {
+            Compression::None => bytes,
+            Compression::Gzip(level) => {
+                let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::new(level));
+                encoder.write_all(&bytes)?;
+                encoder.finish()?
+            }
+        };
+
+        let path: PathBuf = self.chunk_path(index);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, encoded)?;
+        Ok(())
+    }
+
+    /// Read the chunk `index` as an NdArray, without the padding past the array
+    ///
+    /// ## Panics
+    /// If `index` is not within the chunk grid, panics; message shown is **Index out of bounds**
+    pub fn read_chunk(&self, index: SizedArray<N>) -> Result<NdArray<T, N>, ZarrError> {
+        let (_, shape) = self.chunk_box(&index);
+        let full: Vec<T> = self.read_full_chunk(&index)?;
+        let mut values: Vec<T> = vec![self.fill; shape.iter().product()];
+        copy_box(
+            &full,
+            &self.chunks,
+            &[0; N],
+            &mut values,
+            &shape,
+            &[0; N],
+            &shape,
+        );
+        Ok(NdArray::from_owned_vec(values, shape))
+    }
+
+    /// Write `values` to the chunk `index`, whose shape excludes the padding past the array
+    ///
+    /// ## Panics
+    /// - If `index` is not within the chunk grid, panics; message shown is **Index out of bounds**
+    /// - If the shape of `values` does not match the chunk, panics; message shown is **Shape(`values`) does not match Shape(`chunk`)**
+    pub fn write_chunk(
+        &self,
+        index: SizedArray<N>,
+        values: &NdArray<T, N>,
+    ) -> Result<(), ZarrError> {
+        let (_, shape) = self.chunk_box(&index);
+        if values.view_shape() != shape {
+            panic!(
+                "Shape({:?}) does not match Shape({:?})",
+                values.view_shape(),
+                shape
+            );
+        }
+        let mut full: Vec<T> = vec![self.fill; self.chunks.iter().product()];
+        copy_box(
+            values.as_slice(),
+            &shape,
+            &[0; N],
+            &mut full,
+            &self.chunks,
+            &[0; N],
+            &shape,
+        );
+        self.write_full_chunk(&index, &full)
+    }
+
+    /// Indices of the chunks intersecting the box of `shape` starting at `start`
+    fn chunks_of_box(&self, start: &SizedArray<N>, shape: &SizedArray<N>) -> Vec<SizedArray<N>> {
+        if (0..N).any(|dim| {
+            start[dim]
+                .checked_add(shape[dim])
+                .is_none_or(|end| end > self.shape[dim])
+        }) {
+            panic!("Index out of bounds");
+        }
+        if shape.contains(&0) {
+            return Vec::new();
+        }
+
+        let (mut first, mut count): (SizedArray<N>, SizedArray<N>) = ([0; N], [0; N]);
+        for dim in 0..N {
+            first[dim] = start[dim] / self.chunks[dim];
+            count[dim] = (start[dim] + shape[dim] - 1) / self.chunks[dim] - first[dim] + 1;
+        }
+        (0..count.iter().product())
+            .map(|pos| {
+                let mut index: SizedArray<N> = unravel_index(pos, &count, Order::C);
+                (0..N).for_each(|dim| index[dim] += first[dim]);
+                index
+            })
+            .collect()
+    }
+
+    /// Overlap of the chunk `index` with the box starting at `start` of `shape`, as `(start in the chunk, start in the box, extent)`
+    fn overlap(
+        &self,
+        index: &SizedArray<N>,
+        start: &SizedArray<N>,
+        shape: &SizedArray<N>,
+    ) -> (SizedArray<N>, SizedArray<N>, SizedArray<N>) {
+        let (mut in_chunk, mut in_box, mut extent): (SizedArray<N>, SizedArray<N>, SizedArray<N>) =
+            ([0; N], [0; N], [0; N]);
+        for dim in 0..N {
+            let chunk_start: usize = index[dim] * self.chunks[dim];
+            let from: usize = start[dim].max(chunk_start);
+            let to: usize = (start[dim] + shape[dim]).min(chunk_start + self.chunks[dim]);
+            in_chunk[dim] = from - chunk_start;
+            in_box[dim] = from - start[dim];
+            extent[dim] = to - from;
+        }
+        (in_chunk, in_box, extent)
+    }
+
+    /// Read the box of `shape` starting at `start` as an NdArray, reading only the chunks it intersects
+    ///
+    /// ## Panics
+    /// If the box is not within the array, panics; message shown is **Index out of bounds**
+    pub fn read_region(
+        &self,
+        start: SizedArray<N>,
+        shape: SizedArray<N>,
+    ) -> Result<NdArray<T, N>, ZarrError> {
+        let mut values: Vec<T> = vec![self.fill; shape.iter().product()];
+        for index in self.chunks_of_box(&start, &shape) {
+            let full: Vec<T> = self.read_full_chunk(&index)?;
+            let (in_chunk, in_box, extent) = self.overlap(&index, &start, &shape);
+            copy_box(
+                &full,
+                &self.chunks,
+                &in_chunk,
+                &mut values,
+                &shape,
+                &in_box,
+                &extent,
+            );
+        }
+        Ok(NdArray::from_owned_vec(values, shape))
+    }
+
+    /// Write `values` to the box starting at `start`, writing only the chunks it intersects. Chunks partially covered are read and merged first
+    ///
+    /// ## Panics
+    /// If the box is not within the array, panics; message shown is **Index out of bounds**
+    pub fn write_region(
+        &self,
+        start: SizedArray<N>,
+        values: &NdArray<T, N>,
+    ) -> Result<(), ZarrError> {
+        let shape: SizedArray<N> = values.view_shape();
+        for index in self.chunks_of_box(&start, &shape) {
+            let (in_chunk, in_box, extent) = self.overlap(&index, &start, &shape);
+            let (_, chunk_shape) = self.chunk_box(&index);
+            let mut full: Vec<T> = if extent == chunk_shape {
+                vec![self.fill; self.chunks.iter().product()]
+            } else {
+                self.read_full_chunk(&index)?
+            };
+            copy_box(
+                values.as_slice(),
+                &shape,
+                &in_box,
+                &mut full,
+                &self.chunks,
+                &in_chunk,
+                &extent,
+            );
+            self.write_full_chunk(&index, &full)?;
+        }
+        Ok(())
+    }
+
+    /// Read the elements selected by `info` (e.g. built by [`s!`](crate::s)) as an NdArray with `M` dimensions, reading only the chunks the selection spans. Indexed axes are dropped
+    ///
+    /// ## Panics
+    /// - If `M` is not the number of range arguments of `info`, panics; message shown is **Dimension(`M`) does not match Dimension(`out_ndim`) of the slice**
+    /// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::chunked::{ChunkedArray, Compression};
+    /// # use ndim::s;
+    /// #
+    /// # fn main() {
+    /// let path = std::env::temp_dir().join("ndim_chunked_slice_doc.zarr");
+    /// let array = ChunkedArray::<u16, 2>::create(&path, [6, 6], [4, 4], Compression::None).unwrap();
+    /// let values: Vec<u16> = (0..36).collect();
+    /// array.write_region([0, 0], &NdArray::from(&values, [6, 6])).unwrap();
+    ///
+    /// let column = array.read_slice::<1>(&s![1..;2, -1]).unwrap();
+    /// assert_eq!(column.as_slice(), &[11, 23, 35]);
+    /// # std::fs::remove_dir_all(&path).unwrap();
+    /// # }
+    /// ```
+    pub fn read_slice<const M: usize>(
+        &self,
+        info: &SliceInfo<N>,
+    ) -> Result<NdArray<T, M>, ZarrError> {
+        if info.out_ndim() != M {
+            panic!(
+                "Dimension({}) does not match Dimension({}) of the slice",
+                M,
+                info.out_ndim()
+            );
+        }
+
+        let (mut start, mut extent, mut count, mut step): (
+            SizedArray<N>,
+            SizedArray<N>,
+            SizedArray<N>,
+            SizedArray<N>,
+        ) = ([0; N], [0; N], [0; N], [0; N]);
+        for dim in 0..N {
+            let (from, selected, by) = info.args()[dim].resolve(self.shape[dim]);
+            (start[dim], count[dim], step[dim]) = (from, selected, by);
+            extent[dim] = if selected == 0 {
+                0
+            } else {
+                (selected - 1) * by + 1
+            };
+        }
+        let region: NdArray<T, N> = self.read_region(start, extent)?;
+
+        let values: Vec<T> = (0..count.iter().product())
+            .map(|pos| {
+                let mut index: SizedArray<N> = unravel_index(pos, &count, Order::C);
+                (0..N).for_each(|dim| index[dim] *= step[dim]);
+                region[index]
+            })
+            .collect();
+        let mut shape: [usize; M] = [0; M];
+        for (pos, dim) in (0..N)
+            .filter(|dim| !info.args()[*dim].is_index())
+            .enumerate()
+        {
+            shape[pos] = count[dim];
+        }
+        Ok(NdArray::from_owned_vec(values, shape))
+    }
+
+    /// Return an iterator over the chunks in row-major order of the chunk grid, yielding the start of each chunk within the array and its elements (see [`read_chunk`](ChunkedArray::read_chunk)). Only one chunk is held in memory at a time
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::chunked::{ChunkedArray, Compression};
+    /// #
+    /// # fn main() {
+    /// let path = std::env::temp_dir().join("ndim_chunked_iter_doc.zarr");
+    /// let array = ChunkedArray::<f64, 1>::create(&path, [10], [4], Compression::None).unwrap();
+    /// array.write_region([0], &NdArray::ones([10])).unwrap();
+    ///
+    /// let mut total: f64 = 0.0;
+    /// for chunk in array.iter_chunks() {
+    ///     let (_, values) = chunk.unwrap();
+    ///     total += values.as_slice().iter().sum::<f64>();
+    /// }
+    /// assert_eq!(total, 10.0);
+    /// # std::fs::remove_dir_all(&path).unwrap();
+    /// # }
+    /// ```
+    pub fn iter_chunks(
+        &self,
+    ) -> impl Iterator<Item = Result<(SizedArray<N>, NdArray<T, N>), ZarrError>> + '_ {
+        let grid: SizedArray<N> = self.grid();
+        (0..grid.iter().product()).map(move |pos| {
+            let index: SizedArray<N> = unravel_index(pos, &grid, Order::C);
+            let (start, _) = self.chunk_box(&index);
+            Ok((start, self.read_chunk(index)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod chunked_t {
+    use std::path::PathBuf;
+
+    use crate::{
+        chunked::{ChunkedArray, Compression, ZarrError},
+        core::{Array2, Array3},
+        s,
+    };
+
+    // Return an empty directory in the temporary directory
+    fn temp_dir(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&path);
+        path
+    }
+
+    // Test writing a 3-D NdArray with gzip compression and reading it back by chunks, regions, and slices
+    #[test]
+    fn round_trip_3dim_t() {
+        let path = temp_dir("ndim_round_trip_3dim_t.zarr");
+        let values: Vec<i32> = (0..105).collect();
+        let data = Array3::<i32>::from(&values, [3, 5, 7]);
+        let array =
+            ChunkedArray::<i32, 3>::create(&path, [3, 5, 7], [2, 2, 3], Compression::Gzip(1))
+                .unwrap();
+        array.write_region([0, 0, 0], &data).unwrap();
+
+        let opened = ChunkedArray::<i32, 3>::open(&path).unwrap();
+        assert_eq!(opened.grid(), [2, 3, 3]);
+        assert_eq!(opened.compression(), Compression::Gzip(1));
+        assert_eq!(std::fs::read_dir(&path).unwrap().count(), 1 + 18);
+
+        let edge = opened.read_chunk([1, 2, 2]).unwrap();
+        assert_eq!(edge.shape(), &[1, 1, 1]);
+        assert_eq!(edge.as_slice(), &[104]);
+        let region = opened.read_region([1, 1, 2], [2, 3, 4]).unwrap();
+        assert_eq!(region[[0, 0, 0]], data[[1, 1, 2]]);
+        assert_eq!(region[[1, 2, 3]], data[[2, 3, 5]]);
+        let plane = opened.read_slice::<2>(&s![.., 4, ..;3]).unwrap();
+        assert_eq!(plane.as_slice(), &[28, 31, 34, 63, 66, 69, 98, 101, 104]);
+
+        let sum: i32 = opened
+            .iter_chunks()
+            .map(|chunk| chunk.unwrap().1.as_slice().iter().sum::<i32>())
+            .sum();
+        assert_eq!(sum, values.iter().sum::<i32>());
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    // Test merging partial writes with the fill value of chunks never written
+    #[test]
+    fn partial_write_t() {
+        let path = temp_dir("ndim_partial_write_t.zarr");
+        let array =
+            ChunkedArray::<f64, 2>::create(&path, [4, 4], [3, 3], Compression::None).unwrap();
+        array
+            .write_region([1, 1], &Array2::<f64>::ones([2, 2]))
+            .unwrap();
+        array
+            .write_region([2, 2], &Array2::<f64>::from(&[5.0; 4], [2, 2]))
+            .unwrap();
+
+        let all = array.read_region([0, 0], [4, 4]).unwrap();
+        #[rustfmt::skip]
+        let expected: [f64; 16] = [
+            0.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 1.0, 0.0,
+            0.0, 1.0, 5.0, 5.0,
+            0.0, 0.0, 5.0, 5.0,
+        ];
+        assert_eq!(all.as_slice(), &expected);
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    // Test opening an array with a wrong element type or dimension, or unsupported metadata
+    #[test]
+    fn open_mismatch_t() {
+        let path = temp_dir("ndim_open_mismatch_t.zarr");
+        ChunkedArray::<u8, 2>::create(&path, [4, 4], [2, 2], Compression::None).unwrap();
+
+        assert!(ChunkedArray::<u8, 2>::open(&path).is_ok());
+        assert!(matches!(
+            ChunkedArray::<f32, 2>::open(&path),
+            Err(ZarrError::Dtype {
+                expected: "<f4",
+                ..
+            })
+        ));
+        assert!(matches!(
+            ChunkedArray::<u8, 3>::open(&path),
+            Err(ZarrError::Dimension {
+                expected: 3,
+                found: 2
+            })
+        ));
+
+        let metadata = std::fs::read_to_string(path.join(".zarray")).unwrap();
+        std::fs::write(path.join(".zarray"), metadata.replace("\"C\"", "\"F\"")).unwrap();
+        assert!(matches!(
+            ChunkedArray::<u8, 2>::open(&path),
+            Err(ZarrError::Format(_))
+        ));
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    // Test writing a chunk of a wrong shape
+    #[test]
+    #[should_panic(expected = "Shape([2, 2]) does not match Shape([1, 2])")]
+    fn write_chunk_shape_t() {
+        let path = temp_dir("ndim_write_chunk_shape_t.zarr");
+        let array =
+            ChunkedArray::<u8, 2>::create(&path, [3, 4], [2, 2], Compression::None).unwrap();
+        let _ = array.write_chunk([1, 0], &Array2::<u8>::zeros([2, 2]));
+    }
+
+    // Test writing and reading an empty region, including an empty NdArray::new()
+    #[test]
+    fn empty_region_t() {
+        let path = temp_dir("ndim_empty_region_t.zarr");
+        let array =
+            ChunkedArray::<f32, 2>::create(&path, [3, 4], [2, 2], Compression::None).unwrap();
+        array.write_region([0, 0], &Array2::<f32>::new()).unwrap();
+        array
+            .write_region([3, 1], &Array2::<f32>::zeros([0, 3]))
+            .unwrap();
+        assert_eq!(std::fs::read_dir(&path).unwrap().count(), 1);
+
+        let region = array.read_region([1, 4], [2, 0]).unwrap();
+        assert_eq!(region.shape(), &[2, 0]);
+        assert!(region.is_empty());
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    // Test a region whose end overflows usize
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn region_overflow_t() {
+        let path = temp_dir("ndim_region_overflow_t.zarr");
+        let array =
+            ChunkedArray::<u8, 2>::create(&path, [3, 4], [2, 2], Compression::None).unwrap();
+        let _ = array.write_region([usize::MAX, 0], &Array2::<u8>::zeros([1, 1]));
+    }
+}
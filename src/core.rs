@@ -1,14 +1,19 @@
 use std::{
     fmt::Debug,
+    mem::MaybeUninit,
     ops::{Index, IndexMut},
 };
 
+use crate::{error::NdimError, view::in_bounds};
+
 /// Type alias for `1usize`. Used while intializing as default values in `shape` and `strides`
 const USIZE_ONE: usize = 1;
+/// Marker for the dimension to be inferred in `NdArray::<T, N>::reshape_infer(...)`. Same as `-1` in NumPy
+pub const INFER: isize = -1;
 /// Type alias for `[usize; N]`. Typically used in `shape` and `strides` of an NdArray object
-type SizedArray<const N: usize> = [usize; N];
+pub(crate) type SizedArray<const N: usize> = [usize; N];
 
-/// Use to allocate an N-dimensional array of type T. In the memory, the sized array is stored as a 1-d contiguous array
+/// Use to allocate an N-dimensional array of type T. In the memory, the sized array is stored as a 1-d contiguous array, owned by the NdArray
 ///
 /// ## Examples
 ///
@@ -63,110 +68,215 @@ type SizedArray<const N: usize> = [usize; N];
 /// ```
 ///
 /// For more examples, view this link on [github](https://github.com/noobsiecoder/ndim/tree/main/examples)
-#[derive(Debug)]
+///
+/// #### Clone an NdArray
+///
+/// Cloning copies the elements into a new buffer, hence the clone can be mutated independently
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::zeros([2, 2]);
+/// let mut copy = arr.clone();
+/// copy[[0, 1]] = 3;
+/// assert_eq!(arr[[0, 1]], 0);
+/// # }
+/// ```
+///
+/// #### Move an NdArray to another thread
+///
+/// An NdArray is `Send` and `Sync` if type T is, as the elements are owned by a `Vec<T>`
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<f32, 1>::from(&[1.0, 2.0, 3.0], [3]);
+/// let total: f32 = std::thread::spawn(move || arr.as_slice().iter().sum()).join().unwrap();
+/// assert_eq!(total, 6.0);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
 pub struct NdArray<T, const N: usize> {
-    ptr: *mut T,
+    data: Vec<T>,
     len: usize,
     shape: SizedArray<N>,
     strides: SizedArray<N>,
 }
 
-/// Type alias for a one dimensional (1-D) array
-pub type Array<T> = NdArray<T, 1>;
-/// Type alias for a two dimensional (2-D) array
-pub type Array2<T> = NdArray<T, 2>;
-/// Type alias for a three dimensional (3-D) array
-pub type Array3<T> = NdArray<T, 3>;
-/// Type alias for a four dimensional (4-D) array
-pub type Array4<T> = NdArray<T, 4>;
+/// Logical order of the elements of an NdArray, used while reshaping and flattening it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Row-major order, where the last axis changes the fastest. Same as `order='C'` in NumPy
+    #[default]
+    C,
+    /// Column-major order, where the first axis changes the fastest. Same as `order='F'` in NumPy, and used by MATLAB, R and LAPACK
+    F,
+}
 
-impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
-    /// Calculate the stride of the array from the given `shape` and return as type `SizedArray<N>`
-    /// Helps in index navigation and the explanation is shown [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L78)
-    fn stride(shape: &SizedArray<N>) -> SizedArray<N> {
-        let mut strides: SizedArray<N> = [1usize; N];
-        strides[N - 1] = std::mem::size_of::<T>();
-        for idx in (0..N - 1).rev() {
-            // Suppose shape: [1, 2, 3]
-            // strides[0] = size_of<T>
-            // strides[1] = strides[0] * shape[0] -> size_of<T> * 3
-            // strides[2] = strides[1] * shape[1] -> size_of<T> * 3 * 2
+/// Indexing convention of the coordinate arrays returned by [`meshgrid`] and [`meshgrid3`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Indexing {
+    /// Cartesian indexing, where the first two axes are swapped, i.e., `x` changes along the columns. Same as `indexing='xy'` in NumPy
+    #[default]
+    Xy,
+    /// Matrix indexing, where the i-th coordinate array changes along the i-th axis. Same as `indexing='ij'` in NumPy
+    Ij,
+}
 
-            // For (i, j, k):
-            // Index = i * strides[0] + j * strides[1] + k * strides[2]
-            // This `Index` will be used to access the memory location of the sized array
-            strides[idx] = strides[idx + 1] * shape[idx + 1];
-        }
+/// Error returned by the fallible constructors and reshaping methods of an NdArray, when a shape does not match the number of elements
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::{NdArray, ShapeError};
+///
+/// # fn main() {
+/// let err = NdArray::<u8, 2>::try_from(&[1, 2, 3], [2, 2]).unwrap_err();
+/// assert_eq!(err, ShapeError::SizeMismatch { shape: vec![2, 2], size: 3 });
+/// assert_eq!(err.to_string(), "Shape([2, 2]) don't match with array Size(3)");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The size derived from the shape of a new NdArray does not match the length of the elements
+    SizeMismatch { shape: Vec<usize>, size: usize },
+    /// The size derived from the new shape does not match the length of the NdArray being reshaped
+    ReshapeMismatch { shape: Vec<usize>, size: usize },
+    /// A row of nested rows does not have the same length as the first row
+    RaggedRows {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// The elements of an array-like value are more than the size derived from the shape
+    TooLong {
+        shape: Vec<usize>,
+        len: usize,
+        size: usize,
+    },
+    /// The elements of an array-like value are fewer than the size derived from the shape
+    TooShort {
+        shape: Vec<usize>,
+        len: usize,
+        size: usize,
+    },
+}
 
-        strides
+impl std::fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::SizeMismatch { shape, size } => {
+                write!(
+                    f,
+                    "Shape({:?}) don't match with array Size({})",
+                    shape, size
+                )
+            }
+            ShapeError::RaggedRows { row, len, expected } => {
+                write!(
+                    f,
+                    "Row({}) has Length({}), expected Length({})",
+                    row, len, expected
+                )
+            }
+            ShapeError::ReshapeMismatch { shape, size } => {
+                write!(
+                    f,
+                    "New Shape({:?}) don't match with current Size({})",
+                    shape, size
+                )
+            }
+            ShapeError::TooLong { shape, len, size } => {
+                write!(
+                    f,
+                    "Length({}) is too long for Shape({:?}) of Size({})",
+                    len, shape, size
+                )
+            }
+            ShapeError::TooShort { shape, len, size } => {
+                write!(
+                    f,
+                    "Length({}) is too short for Shape({:?}) of Size({})",
+                    len, shape, size
+                )
+            }
+        }
     }
+}
 
-    /// Calulate the size of the array from the given `shape` and return as `usize`
-    fn size_from_shape(shape: &SizedArray<N>) -> usize {
-        shape.iter().product()
-    }
+impl std::error::Error for ShapeError {}
 
-    /// Calulate the size of the array from the given `range`, `step` and return as `usize`
-    /// Explanation behind the calculation can be viewed [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L111)
-    fn size_from_range(pos: (isize, isize), step: usize) -> usize {
-        let range: usize = (pos.1 - pos.0).abs() as usize;
-        // Avoid Zero Division Error
-        if step == 0 {
-            return range;
-        }
-
-        // Even Range and step
-        // range    = -1..5 (6ct)
-        // step     = 2
-        // values   = 4
-
-        // Even Range and an odd step
-        // range    = -1..5 (6ct)
-        // step     = 3
-        // values   = 3
-
-        // Odd Range and an even step
-        // range    = -1..6 (7ct)
-        // step     = 2
-        // values   = 4
-
-        // Odd Range and step
-        // range    = -1..6 (7ct)
-        // step     = 3
-        // values   = 3
-        if range % 2 == 0 {
-            if step % 2 == 0 {
-                return (range / step) + 1;
-            } else {
-                return range / step;
+/// Resolve the [`INFER`] dimension of `shape`, if any, from the size `len`
+///
+/// ## Panics
+/// - If more than one dimension is inferred, panics; message shown is **Only one dimension can be inferred in Shape(`shape`)**
+/// - If a dimension is negative and not [`INFER`], panics; message shown is **Invalid dimension(`dim`) in Shape(`shape`)**
+/// - If `len` is not divisible by the other dimensions, panics; message shown is **Cannot infer Shape(`shape`) from current Size(`size`)**
+fn infer_shape<const M: usize>(shape: [isize; M], len: usize) -> SizedArray<M> {
+    let mut inferred: Option<usize> = None;
+    let mut known: usize = 1;
+    for (idx, dim) in shape.iter().enumerate() {
+        if *dim == INFER {
+            if inferred.is_some() {
+                panic!("Only one dimension can be inferred in Shape({:?})", shape);
             }
+            inferred = Some(idx);
+        } else if *dim < 0 {
+            panic!("Invalid dimension({}) in Shape({:?})", dim, shape);
         } else {
-            if step % 2 == 0 {
-                return range / step;
-            } else {
-                return (range / step) + 1;
-            }
+            known = known.saturating_mul(*dim as usize);
         }
     }
 
-    /// Return the length of the NdArray object's sized array
-    pub fn len(&self) -> &usize {
-        &self.len
+    let mut new_shape: SizedArray<M> = shape.map(|dim| dim.max(0) as usize);
+    if let Some(idx) = inferred {
+        if known == 0 || !len.is_multiple_of(known) {
+            panic!("Cannot infer Shape({:?}) from current Size({})", shape, len);
+        }
+        new_shape[idx] = len / known;
     }
 
-    /// Return the shape of the NdArray object
-    pub fn shape(&self) -> &SizedArray<N> {
-        &self.shape
+    new_shape
+}
+
+/// Convert the flat position `pos` to an index of `shape`, where `pos` counts the elements in the given `order`
+pub(crate) fn unravel_index<const N: usize>(
+    pos: usize,
+    shape: &SizedArray<N>,
+    order: Order,
+) -> SizedArray<N> {
+    let mut rest: usize = pos;
+    let mut index: SizedArray<N> = [0; N];
+    let mut unravel = |dim: usize| {
+        index[dim] = rest % shape[dim];
+        rest /= shape[dim];
+    };
+    match order {
+        Order::C => (0..N).rev().for_each(&mut unravel),
+        Order::F => (0..N).for_each(&mut unravel),
     }
 
-    /// Return the stride of the NDArray object
-    pub fn strides(&self) -> &SizedArray<N> {
-        &self.strides
+    index
+}
+
+/// Type alias for a one dimensional (1-D) array
+pub type Array<T> = NdArray<T, 1>;
+/// Type alias for a two dimensional (2-D) array
+pub type Array2<T> = NdArray<T, 2>;
+/// Type alias for a three dimensional (3-D) array
+pub type Array3<T> = NdArray<T, 3>;
+/// Type alias for a four dimensional (4-D) array
+pub type Array4<T> = NdArray<T, 4>;
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Return the length of the NdArray object's sized array
+    pub fn len(&self) -> &usize {
+        &self.len
     }
 
-    /// Create an empty NdArray object. The size of the NdArray is zero, the shape is equal to `[1; N]`, and the stride is equal to `[1; N]`.
-    ///
-    /// The reason to initialize shape and stride as `[1usize; N]` is because the shape is unknown when creating an NdArray using `NdArray::<T, N>::new()`. Hence, stride also takes the same value.
+    /// Return true if the NdArray has no elements, i.e., it is created using `NdArray::<T, N>::new()` or one of the axes of its shape is zero
     ///
     /// ## Example
     ///
@@ -174,24 +284,19 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// // Creates a null pointer for the sized array
-    /// // Hence, length is zero and the shape and strides are iniialized with 1's of size `N`
-    /// let arr = NdArray::<i8, 4>::new();
+    /// assert!(NdArray::<u8, 2>::new().is_empty());
+    /// assert!(NdArray::<u8, 2>::zeros([3, 0]).is_empty());
+    /// assert!(!NdArray::<u8, 2>::zeros([1, 1]).is_empty());
     /// # }
     /// ```
-    pub fn new() -> Self {
-        NdArray {
-            ptr: std::ptr::null_mut(),
-            len: 0,
-            shape: [USIZE_ONE; N],
-            strides: [USIZE_ONE; N],
-        }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
-    /// Create an NdArray from a sized array of type T. Requires shape of size `N`
+    /// Return a reference to the element at `index`, or `None` if the index of any axis is not within the shape
     ///
-    /// ## Panics
-    /// If shape is not equivalent to current array size (or length), panics, and returns **Shape(`shape`) don't match with current Size(`size`)**
+    /// ## Note
+    /// Unlike indexing, each axis is checked against the shape. Hence, an index such as `[0, 5]` for a shape of `[3, 2]` is rejected, even if it is within the length of the sized array
     ///
     /// ## Example
     ///
@@ -199,33 +304,42 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let vec: Vec<i8> = (-2..22).collect();
-    /// let shape: [usize; 4] = [2, 2, 3, 2];
-    /// let arr = NdArray::<i8, 4>::from(&vec, shape);
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// assert_eq!(arr.get([2, 1]), Some(&5));
+    /// assert_eq!(arr.get([0, 5]), None);
+    /// assert_eq!(arr.get([3, 0]), None);
     /// # }
     /// ```
-    pub fn from(arr: &[T], shape: SizedArray<N>) -> Self {
-        let len: usize = arr.len();
-        if len != Self::size_from_shape(&shape) {
-            panic!("Shape({:?}) don't match with array Size({})", shape, len);
-        }
-
-        let slice_as_ptr: *const T = arr.as_ptr();
-        let ptr: *mut T = unsafe { std::mem::transmute(slice_as_ptr) }; // converts pointer type from *const T to *mut T by reinterpreting its bits
-        let strides: SizedArray<N> = Self::stride(&shape);
+    pub fn get(&self, index: SizedArray<N>) -> Option<&T> {
+        let idx: usize = self.checked_index(&index)?;
+        self.data.get(idx)
+    }
 
-        NdArray {
-            ptr,
-            len,
-            shape,
-            strides,
-        }
+    /// Return a mutable reference to the element at `index`, or `None` if the index of any axis is not within the shape
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// if let Some(value) = arr.get_mut([1, 0]) {
+    ///     *value = 9;
+    /// }
+    /// assert_eq!(arr[[1, 0]], 9);
+    /// assert!(arr.get_mut([1, 2]).is_none());
+    /// # }
+    /// ```
+    pub fn get_mut(&mut self, index: SizedArray<N>) -> Option<&mut T> {
+        let idx: usize = self.checked_index(&index)?;
+        self.data.get_mut(idx)
     }
 
-    /// Reshape the NdArray to a new shape. Accepts an sized array `[usize; N]`
+    /// Return a reference to the element at the signed `index`, where a negative index counts from the end of its axis, similar to NumPy. E.g., `-1` is the last position of an axis
     ///
     /// ## Panics
-    /// If new (given as an argument) shape is not equivalent to current array size (or length), panics; message shown is **New Shape(`shape`) don't match with current Size(`size`)**
+    /// If the index of any axis is not within `-len..len`, panics; message shown is **Index out of bounds**
     ///
     /// ## Example
     ///
@@ -233,37 +347,52 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let shape: [usize; 3] = [1, 1, 15];
-    /// let mut arr = NdArray::<i8, 3>::zeros(shape);
-    /// assert_eq!(*arr.shape(), shape);
-    ///
-    /// let new_shape = [1, 3, 5];
-    /// arr.reshape(new_shape);
-    /// assert_eq!(*arr.shape(), new_shape);
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// assert_eq!(*arr.at([-1, 0]), 4);
+    /// assert_eq!(*arr.at([1, -1]), 3);
     /// # }
     /// ```
-    pub fn reshape(&mut self, shape: SizedArray<N>) {
-        if Self::size_from_shape(&shape) != self.len {
-            panic!(
-                "New Shape({:?}) don't match with current Size({})",
-                shape, self.len
-            )
-        }
+    pub fn at(&self, index: [isize; N]) -> &T {
+        let index: SizedArray<N> = self.resolve_signed(&index);
+        &self[index]
+    }
 
-        self.shape = shape;
-        self.strides = Self::stride(&shape);
+    /// Return a mutable reference to the element at the signed `index`, where a negative index counts from the end of its axis
+    ///
+    /// ## Panics
+    /// If the index of any axis is not within `-len..len`, panics; message shown is **Index out of bounds**
+    pub fn at_mut(&mut self, index: [isize; N]) -> &mut T {
+        let index: SizedArray<N> = self.resolve_signed(&index);
+        &mut self[index]
     }
 
-    /// Helper function to create a sized array from a range containing `start` and an `end` value along with a `step` value
+    /// Convert a signed `index` to an index within the shape, counting negative values from the end of each axis
+    ///
+    /// ## Panics
+    /// If the index of any axis is not within `-len..len`, panics; message shown is **Index out of bounds**
+    fn resolve_signed(&self, index: &[isize; N]) -> SizedArray<N> {
+        std::array::from_fn(|dim| {
+            let len: usize = self.shape[dim];
+            let idx: isize = index[dim];
+            let pos: Option<usize> = if idx < 0 {
+                len.checked_sub(idx.unsigned_abs())
+            } else {
+                Some(idx as usize)
+            };
+            match pos {
+                Some(pos) if pos < len && self.len > 0 => pos,
+                _ => panic!("Index out of bounds"),
+            }
+        })
+    }
+
+    /// Return a reference to the element at `index`, without checking if the index is within the shape
     ///
     /// ## Note
-    /// - Accepts both positive and negative integers
-    /// - This is a private method in the implementation and cannot (and should never) be used outside this `impl` block
-    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    /// The position in the sized array is calculated from the shape, skipping the division by the strides used while indexing
     ///
-    /// ## Panics
-    /// - May panic if `start > end`; message shown is **Index out of bound**
-    /// - If `T::from(i)` conversion fails, panics; message shown is **Unable to convert to type T**
+    /// ## Safety
+    /// The index of every axis must be less than the length of the axis, i.e., `index[i] < shape[i]` for each axis `i`. Otherwise, the behavior is undefined
     ///
     /// ## Example
     ///
@@ -271,59 +400,63 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
-    /// let step: usize = 2;
-    /// let arr = NdArray::<i8, 2>::arange(range); // uses range(...) to construct a sized array
-    /// assert_eq!(*arr.len(), 5);
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let mut total: u8 = 0;
+    /// for i in 0..3 {
+    ///     for j in 0..2 {
+    ///         // SAFETY: the loops are within the shape
+    ///         total += unsafe { *arr.uget([i, j]) };
+    ///     }
+    /// }
+    /// assert_eq!(total, 15);
     /// # }
     /// ```
-    fn range(range: (isize, isize, usize)) -> Self
-    where
-        T: num_traits::NumCast + num_traits::ToPrimitive,
-    {
-        if range.0 > range.1 {
-            panic!("Index out of bound");
-        }
+    pub unsafe fn uget(&self, index: SizedArray<N>) -> &T {
+        let idx: usize = self.unchecked_index(&index);
+        self.data.get_unchecked(idx)
+    }
 
-        let end_range: usize = Self::size_from_range((range.0, range.1), range.2);
-        let mut arr: Vec<T> = Vec::<T>::with_capacity(end_range);
-        if range.2 == 0 {
-            for i in range.0..range.1 {
-                let val: T = T::from(i).expect("Unable to convert to type T"); // panics if it cannot construct to type T
-                arr.push(val);
-            }
-        } else {
-            for i in (range.0..range.1).step_by(range.2) {
-                let val: T = T::from(i).expect("Unable to convert to type T"); // panics if it cannot construct to type T
-                arr.push(val);
-            }
+    /// Return a mutable reference to the element at `index`, without checking if the index is within the shape
+    ///
+    /// ## Safety
+    /// The index of every axis must be less than the length of the axis, i.e., `index[i] < shape[i]` for each axis `i`. Otherwise, the behavior is undefined
+    pub unsafe fn uget_mut(&mut self, index: SizedArray<N>) -> &mut T {
+        let idx: usize = self.unchecked_index(&index);
+        self.data.get_unchecked_mut(idx)
+    }
+
+    /// Return the position of `index` in the row-major sized array, calculated from the shape
+    fn unchecked_index(&self, index: &SizedArray<N>) -> usize {
+        index
+            .iter()
+            .zip(self.shape.iter())
+            .fold(0, |pos, (idx, len)| pos * len + idx)
+    }
+
+    /// Return the position of `index` in the sized array if the index of every axis is within the shape
+    fn checked_index(&self, index: &SizedArray<N>) -> Option<usize> {
+        if !in_bounds(index, &self.shape) {
+            return None;
         }
+        Some(get_index::<N>(index, &self.strides))
+    }
 
-        let len: usize = arr.len();
-        let ptr: *mut T = arr[..].as_mut_ptr();
-        std::mem::forget(arr); // prevents the Vec<T> from being dropped, ensuring the buffer remains valid
+    /// Return the shape of the NdArray object
+    pub fn shape(&self) -> &SizedArray<N> {
+        &self.shape
+    }
 
-        let mut shape: SizedArray<N> = [USIZE_ONE; N];
-        shape[N - 1] = len; // [1, .., x]: row-wise contiguous storage format
-        let mut strides: SizedArray<N> = [USIZE_ONE; N];
-        strides[N - 1] = std::mem::size_of::<T>(); // [1, .., x_stride]
+    /// Return the stride of the NDArray object
+    pub fn strides(&self) -> &SizedArray<N> {
+        &self.strides
+    }
 
-        NdArray {
-            ptr,
-            len,
-            shape,
-            strides,
-        }
+    /// Return the pointer to the first element of the sized array. Dangling (but never null) if the NdArray is empty
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.data.as_ptr()
     }
 
-    /// Create a sized array with an `end` value starting from 0 within `usize` range
-    ///
-    /// ## Note
-    /// - Accepts only positive integers
-    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
-    ///
-    /// ## Panics
-    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    /// Return the sized array as a 1-d contiguous slice in the row-major (C) order
     ///
     /// ## Example
     ///
@@ -331,27 +464,16 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
-    /// let step: usize = 2;
-    /// let arr = NdArray::<i8, 2>::arange(range);
-    /// assert_eq!(*arr.len(), 5);
+    /// let mut arr = NdArray::<u8, 2>::arange(6);
+    /// arr.reshape([2, 3]);
+    /// assert_eq!(arr.as_slice(), &[0, 1, 2, 3, 4, 5]);
     /// # }
     /// ```
-    pub fn arange(range: usize) -> Self
-    where
-        T: num_traits::NumCast + num_traits::ToPrimitive,
-    {
-        Self::range((0, range as isize, 0))
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
     }
 
-    /// Create a sized array with an `end` value starting from 0 within `usize` range and a step value of range in `usize`
-    ///
-    /// ## Note
-    /// - Accepts only positive integers
-    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
-    ///
-    /// ## Panics
-    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    /// Return the sized array as a 1-d contiguous mutable slice in the row-major (C) order
     ///
     /// ## Example
     ///
@@ -359,25 +481,16 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
-    /// let step: usize = 2;
-    /// let arr = NdArray::<i8, 2>::arange_with_step(range, step);
-    /// assert_eq!(*arr.len(), 3);
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// arr.as_mut_slice()[3] = 7;
+    /// assert_eq!(arr[[1, 1]], 7);
     /// # }
     /// ```
-    pub fn arange_with_step(range: usize, step: usize) -> Self
-    where
-        T: num_traits::NumCast + num_traits::ToPrimitive + Default + Copy,
-    {
-        Self::range((0, range as isize, step))
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
     }
 
-    /// Create a sized array with `start` and `end` values within `isize` range
-    ///
-    /// ## Note
-    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
-    /// ## Panics
-    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    /// Copy the elements in row-major (C) order into a one dimensional (1-D) NdArray. See [`NdArray::<T, N>::ravel(...)`](NdArray::ravel) for a view without copying
     ///
     /// ## Example
     ///
@@ -385,24 +498,57 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let ranges: (isize, isize) = (-1, 5); // `arr` ranges from -1 to 4
-    /// let arr = NdArray::<i8, 2>::ranges(ranges);
-    /// assert_eq!(*arr.len(), 6);
+    /// let arr = NdArray::<i32, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// assert_eq!(arr.flatten().as_slice(), &[1, 2, 3, 4]);
     /// # }
     /// ```
-    pub fn ranges(ranges: (isize, isize)) -> Self
+    pub fn flatten(&self) -> NdArray<T, 1>
     where
-        T: num_traits::NumCast + num_traits::ToPrimitive + Default + Copy,
+        T: Clone,
     {
-        Self::range((ranges.0, ranges.1, 0))
+        NdArray::from_owned_vec(self.data.clone(), [self.len])
     }
 
-    /// Create a sized array with `start` and `end` values within `isize` range and a step value of range in `usize`
+    /// Consume the NdArray and return its elements in row-major (C) order as a Vec, without copying
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6], [3, 2]);
+    /// assert_eq!(arr.into_vec(), vec![1, 2, 3, 4, 5, 6]);
+    /// # }
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Overwrite every element with `value`, keeping the shape and the buffer
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<f64, 2>::ones([2, 3]);
+    /// arr.fill(0.5);
+    /// assert_eq!(arr.as_slice(), &[0.5; 6]);
+    /// # }
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Overwrite the elements with the elements of `other`, which must have the same shape. Reuses the buffer, e.g. across the iterations of a solver
     ///
-    /// ## Note
-    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
     /// ## Panics
-    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    /// If the shapes differ, panics; message shown is **Shape(`self.shape`) don't match with Shape(`other.shape`)**
     ///
     /// ## Example
     ///
@@ -410,23 +556,29 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let ranges: (isize, isize) = (-1, 5); // `arr` ranges from -1 to 4 w/o step
-    /// let step: usize = 2;
-    /// let arr = NdArray::<i8, 2>::ranges_with_step(ranges, step);
-    /// assert_eq!(*arr.len(), 3);
+    /// let mut prev = NdArray::<i32, 2>::zeros([2, 2]);
+    /// let next = NdArray::<i32, 2>::full(4, [2, 2]);
+    /// prev.assign(&next);
+    /// assert_eq!(prev, next);
     /// # }
     /// ```
-    pub fn ranges_with_step(ranges: (isize, isize), step: usize) -> Self
+    pub fn assign(&mut self, other: &NdArray<T, N>)
     where
-        T: num_traits::NumCast + num_traits::ToPrimitive,
+        T: Clone,
     {
-        Self::range((ranges.0, ranges.1, step))
+        if self.shape != other.shape {
+            panic!(
+                "Shape({:?}) don't match with Shape({:?})",
+                self.shape, other.shape
+            );
+        }
+        self.data.clone_from_slice(&other.data);
     }
 
-    /// Helper method in implementation to fill any `value` of size `X` (total size of array derived from shape)
+    /// Overwrite the elements with the elements of `values` in the row-major (C) order
     ///
-    /// ## Note
-    /// This is a private method in the implementation and cannot (and should never) be used outside this `impl` block
+    /// ## Panics
+    /// If the length of `values` differs from the size of the array, panics; message shown is **Length(`values.len()`) don't match with array Size(`len`)**
     ///
     /// ## Example
     ///
@@ -434,99 +586,1457 @@ impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let shape: [usize; 2] = [3, 2];
-    /// let arr = NdArray::<u16, 2>::zeros(shape); // uses value(...)
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// arr.copy_from_slice(&[1, 2, 3, 4]);
+    /// assert_eq!(arr[[1, 0]], 3);
     /// # }
     /// ```
-    fn values(val: T, shape: SizedArray<N>) -> Self {
-        let size: usize = Self::size_from_shape(&shape);
+    pub fn copy_from_slice(&mut self, values: &[T])
+    where
+        T: Copy,
+    {
+        if values.len() != self.len {
+            panic!(
+                "Length({}) don't match with array Size({})",
+                values.len(),
+                self.len
+            );
+        }
+        self.data.copy_from_slice(values);
+    }
+
+    /// Return a new NdArray of the same shape with `f` applied to a reference of every element, e.g. to convert the type of the elements
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let words = NdArray::<&str, 2>::from(&["a", "bb", "ccc", ""], [2, 2]);
+    /// let lens: NdArray<usize, 2> = words.map(|w| w.len());
+    /// assert_eq!(lens.as_slice(), &[1, 2, 3, 0]);
+    /// # }
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> NdArray<U, N>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let values: Vec<U> = self.data.iter().map(f).collect();
+        NdArray::from_owned_vec(values, self.view_shape())
+    }
+
+    /// Return a new NdArray of the same shape with `f` applied to a copy of every element. Same as [`NdArray::<T, N>::map(...)`](NdArray::map) for closures which take the element by value
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 1>::from(&[1.0, 4.0, 9.0], [3]);
+    /// assert_eq!(arr.mapv(f32::sqrt).as_slice(), &[1.0, 2.0, 3.0]);
+    /// # }
+    /// ```
+    pub fn mapv<U, F>(&self, mut f: F) -> NdArray<U, N>
+    where
+        T: Clone,
+        F: FnMut(T) -> U,
+    {
+        self.map(|x| f(x.clone()))
+    }
+
+    /// Apply `f` to a mutable reference of every element in-place, keeping the shape and the buffer
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i32, 2>::from(&[-2, 1, -1, 3], [2, 2]);
+    /// arr.map_inplace(|x| *x = (*x).max(0)); // relu
+    /// assert_eq!(arr.as_slice(), &[0, 1, 0, 3]);
+    /// # }
+    /// ```
+    pub fn map_inplace<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Return an owned copy of the NdArray, with a new buffer. Same as `clone()`, and provided for symmetry with [`ArrayView::to_owned()`](crate::view::ArrayView::to_owned)
+    pub fn to_owned(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.clone()
+    }
+
+    /// Calculate the stride of the array from the given `shape` and return as type `SizedArray<N>`
+    /// Helps in index navigation and the explanation is shown [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L78)
+    fn stride(shape: &SizedArray<N>) -> SizedArray<N> {
+        let mut strides: SizedArray<N> = [1usize; N];
+        if N == 0 {
+            return strides; // zero dimensional (0-D) array holds a single element
+        }
+        strides[N - 1] = std::mem::size_of::<T>();
+        for idx in (0..N - 1).rev() {
+            // Suppose shape: [1, 2, 3]
+            // strides[0] = size_of<T>
+            // strides[1] = strides[0] * shape[0] -> size_of<T> * 3
+            // strides[2] = strides[1] * shape[1] -> size_of<T> * 3 * 2
+
+            // For (i, j, k):
+            // Index = i * strides[0] + j * strides[1] + k * strides[2]
+            // This `Index` will be used to access the memory location of the sized array
+            // saturates only if another axis is zero, as the size of the elements is checked not to overflow
+            strides[idx] = strides[idx + 1].saturating_mul(shape[idx + 1]);
+        }
+
+        strides
+    }
 
-        let mut vec: Vec<T> = vec![val; size];
-        let len: usize = vec.len();
-        let ptr: *mut T = vec[..].as_mut_ptr();
-        let strides: SizedArray<N> = Self::stride(&shape);
-        std::mem::forget(vec); // prevents the Vec<T> from being dropped, ensuring the buffer remains valid
+    /// Calulate the size of the array from the given `shape` and return as `usize`
+    ///
+    /// ## Panics
+    /// If the size, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    fn size_from_shape(shape: &SizedArray<N>) -> usize {
+        checked_size::<T, N>(shape).unwrap_or_else(|| {
+            panic!(
+                "Shape({:?}) exceeds the maximum Size({})",
+                shape,
+                max_size::<T>()
+            )
+        })
+    }
+
+    /// Create an NdArray of shape `[1, .., len]` which takes the ownership of `arr`. Used by the range constructors
+    fn from_row(arr: Vec<T>) -> Self {
+        let len: usize = arr.len();
+        let mut shape: SizedArray<N> = [USIZE_ONE; N];
+        shape[N - 1] = len; // [1, .., x]: row-wise contiguous storage format
+        let mut strides: SizedArray<N> = [USIZE_ONE; N];
+        strides[N - 1] = std::mem::size_of::<T>(); // [1, .., x_stride]
 
         NdArray {
-            ptr,
+            data: arr,
             len,
             shape,
             strides,
         }
     }
 
-    /// Create a sized array completely filled with numeral zero or `0`. Requires shape of size `N`
+    /// Create an NdArray which takes the ownership of the buffer of `vec`
     ///
-    /// ## Examples
+    /// ## Note
+    /// The length of `vec` must be equal to the size derived from `shape`, and is only checked in debug builds. An empty `vec` with the shape `[1; N]` of `NdArray::<T, N>::new()` is also accepted. Use [`NdArray::<T, N>::from_vec(...)`](NdArray::from_vec) outside the crate
+    pub(crate) fn from_owned_vec(vec: Vec<T>, shape: SizedArray<N>) -> Self {
+        debug_assert!(
+            Some(vec.len()) == checked_size::<T, N>(&shape)
+                || (vec.is_empty() && shape == [USIZE_ONE; N]),
+            "Vec of length {} does not match Shape({:?})",
+            vec.len(),
+            shape
+        );
+
+        NdArray {
+            len: vec.len(),
+            data: vec,
+            shape,
+            strides: Self::stride(&shape),
+        }
+    }
+
+    /// Create an NdArray which takes the ownership of `vec`, without copying the elements. Requires shape of size `N`
+    ///
+    /// ## Panics
+    /// If shape is not equivalent to the length of `vec`, panics; message shown is **Shape(`shape`) don't match with array Size(`size`)**
+    ///
+    /// ## Example
     ///
     /// ```
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let shape: [usize; 2] = [3, 2];
-    /// let arr = NdArray::<u16, 2>::zeros(shape);
-    /// for i in 0..arr.shape()[0] {
-    ///     for j in 0..arr.shape()[1] {
-    ///         assert_eq!(arr[[i, j]], 0);
-    ///     }
-    /// }
+    /// let names: Vec<String> = vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()];
+    /// let arr = NdArray::<String, 2>::from_vec(names, [2, 2]);
+    /// assert_eq!(arr[[1, 0]], "c");
     /// # }
     /// ```
-    pub fn zeros(shape: SizedArray<N>) -> Self
+    pub fn from_vec(vec: Vec<T>, shape: SizedArray<N>) -> Self {
+        Self::try_from_vec(vec, shape).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Create an NdArray which takes the ownership of `vec`, or return a [`ShapeError`] if shape is not equivalent to the length of `vec`
+    pub fn try_from_vec(vec: Vec<T>, shape: SizedArray<N>) -> Result<Self, ShapeError> {
+        check_size::<T, N>(&shape, vec.len())?;
+        Ok(Self::from_owned_vec(vec, shape))
+    }
+
+    /// Create an NdArray of the given `shape` which takes the ownership of `vec` without copying the elements, or return a [`ShapeError`] if shape is not equivalent to the length of `vec`. Same as [`NdArray::<T, N>::try_from_vec(...)`](NdArray::try_from_vec), with the arguments in the order used by ndarray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let samples: Vec<f32> = (0..12).map(|idx| idx as f32 * 0.5).collect();
+    /// let ptr = samples.as_ptr();
+    /// let arr = NdArray::<f32, 2>::from_shape_vec([3, 4], samples).unwrap();
+    /// assert_eq!(arr[[2, 3]], 5.5);
+    /// assert_eq!(arr.as_slice().as_ptr(), ptr); // the buffer is reused
+    ///
+    /// assert!(NdArray::<f32, 2>::from_shape_vec([5, 5], vec![0.0; 24]).is_err());
+    /// # }
+    /// ```
+    pub fn from_shape_vec(shape: SizedArray<N>, vec: Vec<T>) -> Result<Self, ShapeError> {
+        Self::try_from_vec(vec, shape)
+    }
+
+    /// Create an NdArray of the given `shape`, where each element is the value returned by `f` for its index. The elements are visited in row-major (C) order
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // squared distance from the center of a 3x3 kernel
+    /// let kernel = NdArray::<usize, 2>::from_shape_fn([3, 3], |[i, j]| i.abs_diff(1).pow(2) + j.abs_diff(1).pow(2));
+    /// assert_eq!(kernel.as_slice(), &[2, 1, 2, 1, 0, 1, 2, 1, 2]);
+    /// # }
+    /// ```
+    pub fn from_shape_fn<F>(shape: SizedArray<N>, mut f: F) -> Self
     where
-        T: Default,
+        F: FnMut(SizedArray<N>) -> T,
     {
-        Self::values(T::default(), shape)
+        let size: usize = Self::size_from_shape(&shape);
+        let values: Vec<T> = (0..size)
+            .map(|pos| f(unravel_index(pos, &shape, Order::C)))
+            .collect();
+
+        Self::from_owned_vec(values, shape)
     }
 
-    /// Create a sized array completely filled with numeral one or `1`. Requires shape of size `N`
+    /// Create an NdArray by copying the elements of `arr`. Requires shape of size `N`
     ///
-    /// ## Examples
+    /// ## Panics
+    /// If shape is not equivalent to the length of `arr`, panics; message shown is **Shape(`shape`) don't match with array Size(`size`)**
+    ///
+    /// ## Example
     ///
     /// ```
     /// # use ndim::core::NdArray;
     /// #
     /// # fn main() {
-    /// let shape: [usize; 2] = [3, 2];
-    /// let arr = NdArray::<u16, 2>::ones(shape);
-    /// for i in 0..arr.shape()[0] {
-    ///     for j in 0..arr.shape()[1] {
-    ///         assert_eq!(arr[[i, j]], 1);
-    ///     }
-    /// }
+    /// let arr = NdArray::<f32, 2>::from_slice(&vec![0.5; 6], [3, 2]); // the temporary is copied
+    /// assert_eq!(arr[[2, 1]], 0.5);
     /// # }
     /// ```
-    pub fn ones(shape: SizedArray<N>) -> Self
+    pub fn from_slice(arr: &[T], shape: SizedArray<N>) -> Self
     where
-        T: num_traits::One,
+        T: Clone,
+    {
+        Self::try_from(arr, shape).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Create an NdArray by copying the elements of `arr`, or return a [`ShapeError`] if shape is not equivalent to the length of `arr`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 2>::try_from(&[1, 2, 3, 4, 5, 6], [2, 3]).unwrap();
+    /// assert_eq!(arr[[1, 0]], 4);
+    /// assert!(NdArray::<u16, 2>::try_from(&[1, 2, 3, 4, 5, 6], [4, 2]).is_err());
+    /// # }
+    /// ```
+    pub fn try_from(arr: &[T], shape: SizedArray<N>) -> Result<Self, ShapeError>
+    where
+        T: Clone,
     {
-        Self::values(T::one(), shape)
+        check_size::<T, N>(&shape, arr.len())?;
+        Ok(Self::from_owned_vec(arr.to_vec(), shape))
+    }
+
+    /// Reshape the NdArray to a new shape, or return a [`ShapeError`] if the new shape is not equivalent to current array size (or length). The NdArray is left unchanged on error
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, ShapeError};
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i8, 2>::zeros([2, 6]);
+    /// assert!(arr.try_reshape([3, 4]).is_ok());
+    /// assert_eq!(
+    ///     arr.try_reshape([5, 2]),
+    ///     Err(ShapeError::ReshapeMismatch { shape: vec![5, 2], size: 12 })
+    /// );
+    /// assert_eq!(*arr.shape(), [3, 4]);
+    /// # }
+    /// ```
+    pub fn try_reshape(&mut self, shape: SizedArray<N>) -> Result<(), ShapeError> {
+        if checked_size::<T, N>(&shape) != Some(self.len) {
+            return Err(ShapeError::ReshapeMismatch {
+                shape: shape.to_vec(),
+                size: self.len,
+            });
+        }
+
+        self.shape = shape;
+        self.strides = Self::stride(&shape);
+        Ok(())
+    }
+
+    /// Consume the NdArray and return it with a new shape of dimension `M`, or return a [`ShapeError`] if the new shape is not equivalent to current array size (or length). The buffer is reused without copying
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{Array, NdArray, ShapeError};
+    /// #
+    /// # fn main() {
+    /// let arr = Array::<f32>::arange(12);
+    /// assert_eq!(
+    ///     arr.clone().try_into_shape([5, 2]).unwrap_err(),
+    ///     ShapeError::ReshapeMismatch { shape: vec![5, 2], size: 12 }
+    /// );
+    /// let arr: NdArray<f32, 3> = arr.try_into_shape([2, 3, 2]).unwrap();
+    /// assert_eq!(arr[[1, 0, 1]], 7.0);
+    /// # }
+    /// ```
+    pub fn try_into_shape<const M: usize>(
+        self,
+        shape: SizedArray<M>,
+    ) -> Result<NdArray<T, M>, ShapeError> {
+        if checked_size::<T, M>(&shape) != Some(self.len) {
+            return Err(ShapeError::ReshapeMismatch {
+                shape: shape.to_vec(),
+                size: self.len,
+            });
+        }
+
+        Ok(NdArray::from_owned_vec(self.data, shape))
+    }
+
+    /// Consume the NdArray and return it with a new shape of dimension `M`, e.g. to turn a 1-D range into a 2-D array. The buffer is reused without copying
+    ///
+    /// ## Panics
+    /// If new shape is not equivalent to current array size (or length), panics; message shown is **New Shape(`shape`) don't match with current Size(`size`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{Array, Array2};
+    /// #
+    /// # fn main() {
+    /// let arr: Array2<f32> = Array::<f32>::arange(12).into_shape([3, 4]);
+    /// assert_eq!(*arr.shape(), [3, 4]);
+    /// assert_eq!(arr[[2, 1]], 9.0);
+    /// # }
+    /// ```
+    pub fn into_shape<const M: usize>(self, shape: SizedArray<M>) -> NdArray<T, M> {
+        self.try_into_shape(shape)
+            .unwrap_or_else(|err| panic!("{}", err))
     }
+
+    /// Consume the NdArray and return it without the axes of length 1, as an NdArray of dimension `M`. The buffer is reused without copying
+    ///
+    /// ## Panics
+    /// If `M` is not the number of axes longer than 1, panics; message shown is **Dimension(`M`) must match with the squeezed Dimension(`ndim`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 4>::zeros([1, 28, 1, 28]);
+    /// let image: NdArray<f32, 2> = arr.squeeze();
+    /// assert_eq!(*image.shape(), [28, 28]);
+    /// # }
+    /// ```
+    pub fn squeeze<const M: usize>(self) -> NdArray<T, M> {
+        let kept: Vec<usize> = self
+            .view_shape()
+            .into_iter()
+            .filter(|len| *len != 1)
+            .collect();
+        if kept.len() != M {
+            panic!(
+                "Dimension({}) must match with the squeezed Dimension({})",
+                M,
+                kept.len()
+            );
+        }
+
+        let mut shape: SizedArray<M> = [0; M];
+        shape.copy_from_slice(&kept);
+        self.into_shape(shape)
+    }
+
+    /// Consume the NdArray and return it with a new axis of length 1 at position `axis`, as an NdArray of dimension `M = N + 1`, similar to `numpy.expand_dims`. The buffer is reused without copying
+    ///
+    /// ## Panics
+    /// - If `M` is not one more than `N`, panics; message shown is **Dimension(`M`) must be one more than Dimension(`N`)**
+    /// - If `axis` is greater than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`M`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let image = NdArray::<u8, 3>::zeros([32, 32, 3]);
+    /// let batch: NdArray<u8, 4> = image.insert_axis(0);
+    /// assert_eq!(*batch.shape(), [1, 32, 32, 3]);
+    /// # }
+    /// ```
+    pub fn insert_axis<const M: usize>(self, axis: usize) -> NdArray<T, M> {
+        if M != N + 1 {
+            panic!("Dimension({}) must be one more than Dimension({})", M, N);
+        }
+        if axis > N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, M);
+        }
+
+        let old_shape: SizedArray<N> = self.view_shape();
+        let shape: SizedArray<M> = std::array::from_fn(|dim| match dim.cmp(&axis) {
+            std::cmp::Ordering::Less => old_shape[dim],
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => old_shape[dim - 1],
+        });
+        self.into_shape(shape)
+    }
+}
+
+impl<T: Debug + Copy + Default, const N: usize> NdArray<T, N> {
+    /// Calulate the size of the array from the given `range`, `step` and return as `usize`. A step of zero is treated as a step of one
+    fn size_from_range(pos: (isize, isize), step: usize) -> usize {
+        let range: usize = (pos.1 - pos.0).unsigned_abs();
+        // e.g., range = -1..5 (6ct) and step = 4 gives [-1, 3], i.e., ceil(6 / 4) values
+        range.div_ceil(step.max(1))
+    }
+
+    /// Create an empty NdArray object. The size of the NdArray is zero, the shape is equal to `[1; N]`, and the stride is equal to `[1; N]`.
+    ///
+    /// The reason to initialize shape and stride as `[1usize; N]` is because the shape is unknown when creating an NdArray using `NdArray::<T, N>::new()`. Hence, stride also takes the same value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // No memory is allocated for the sized array
+    /// // Hence, length is zero and the shape and strides are iniialized with 1's of size `N`
+    /// let arr = NdArray::<i8, 4>::new();
+    /// assert!(arr.is_empty());
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an NdArray from a sized array of type T, copying the elements. Requires shape of size `N`. Same as [`NdArray::<T, N>::from_slice(...)`](NdArray::from_slice)
+    ///
+    /// ## Panics
+    /// If shape is not equivalent to current array size (or length), panics, and returns **Shape(`shape`) don't match with current Size(`size`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let vec: Vec<i8> = (-2..22).collect();
+    /// let shape: [usize; 4] = [2, 2, 3, 2];
+    /// let arr = NdArray::<i8, 4>::from(&vec, shape);
+    /// # }
+    /// ```
+    pub fn from(arr: &[T], shape: SizedArray<N>) -> Self {
+        Self::from_slice(arr, shape)
+    }
+
+    /// Reshape the NdArray to a new shape. Accepts an sized array `[usize; N]`
+    ///
+    /// ## Panics
+    /// If new (given as an argument) shape is not equivalent to current array size (or length), panics; message shown is **New Shape(`shape`) don't match with current Size(`size`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let shape: [usize; 3] = [1, 1, 15];
+    /// let mut arr = NdArray::<i8, 3>::zeros(shape);
+    /// assert_eq!(*arr.shape(), shape);
+    ///
+    /// let new_shape = [1, 3, 5];
+    /// arr.reshape(new_shape);
+    /// assert_eq!(*arr.shape(), new_shape);
+    /// # }
+    /// ```
+    pub fn reshape(&mut self, shape: SizedArray<N>) {
+        self.try_reshape(shape)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Reshape the NdArray to a new shape, reading and placing the elements in the given `order`
+    ///
+    /// With [`Order::C`], this is the same as [`NdArray::<T, N>::reshape(...)`](NdArray::reshape). With [`Order::F`], the elements are read in column-major order and placed in column-major order of the new shape, similar to `reshape(shape, order='F')` in NumPy. The sized array is still stored in row-major order, hence the elements are moved in the memory
+    ///
+    /// ## Panics
+    /// If new shape is not equivalent to current array size (or length), panics; message shown is **New Shape(`shape`) don't match with current Size(`size`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::arange(6);
+    /// arr.reshape_with_order([2, 3], Order::F);
+    /// assert_eq!(arr.as_slice(), &[0, 2, 4, 1, 3, 5]);
+    /// # }
+    /// ```
+    pub fn reshape_with_order(&mut self, shape: SizedArray<N>, order: Order) {
+        if order == Order::C {
+            return self.reshape(shape);
+        }
+
+        let old_shape: SizedArray<N> = self.shape;
+        let values: Vec<T> = (0..self.len)
+            .map(|pos| self[unravel_index(pos, &old_shape, Order::F)])
+            .collect();
+        self.reshape(shape);
+        for (pos, value) in values.into_iter().enumerate() {
+            self[unravel_index(pos, &shape, Order::F)] = value;
+        }
+    }
+
+    /// Copy the elements of the NdArray in the given `order` into a one dimensional (1-D) NdArray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// assert_eq!(arr.flatten_with_order(Order::C).as_slice(), &[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(arr.flatten_with_order(Order::F).as_slice(), &[0, 3, 1, 4, 2, 5]);
+    /// # }
+    /// ```
+    pub fn flatten_with_order(&self, order: Order) -> NdArray<T, 1> {
+        let values: Vec<T> = match order {
+            Order::C => self.as_slice().to_vec(),
+            Order::F => (0..self.len)
+                .map(|pos| self[unravel_index(pos, &self.shape, Order::F)])
+                .collect(),
+        };
+        let len: usize = values.len();
+
+        NdArray::<T, 1>::from_owned_vec(values, [len])
+    }
+
+    /// Reshape the NdArray to a new shape, where one of the dimensions can be [`INFER`] (or `-1`). The inferred dimension is computed from the length of the array
+    ///
+    /// ## Panics
+    /// - If more than one dimension is inferred, panics; message shown is **Only one dimension can be inferred in Shape(`shape`)**
+    /// - If a dimension is negative and not [`INFER`], panics; message shown is **Invalid dimension(`dim`) in Shape(`shape`)**
+    /// - If the length of the array is not divisible by the other dimensions, panics; message shown is **Cannot infer Shape(`shape`) from current Size(`size`)**
+    /// - If none of the dimensions is inferred, panics as in [`NdArray::<T, N>::reshape(...)`](NdArray::reshape)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, INFER};
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<f32, 3>::arange(128 * 6);
+    /// arr.reshape_infer([2, INFER, 128]);
+    /// assert_eq!(*arr.shape(), [2, 3, 128]);
+    ///
+    /// arr.reshape_infer([-1, 1, 1]);
+    /// assert_eq!(*arr.shape(), [768, 1, 1]);
+    /// # }
+    /// ```
+    pub fn reshape_infer(&mut self, shape: [isize; N]) {
+        self.reshape(infer_shape(shape, self.len));
+    }
+
+    /// Consume the NdArray and return it with a new shape of dimension `M`, where one of the dimensions can be [`INFER`] (or `-1`). The buffer is reused without copying
+    ///
+    /// ## Panics
+    /// Same as [`NdArray::<T, N>::reshape_infer(...)`](NdArray::reshape_infer)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{Array, Array2, INFER};
+    /// #
+    /// # fn main() {
+    /// let samples: Vec<f64> = (0..30).map(|x| x as f64).collect();
+    /// let frames: Array2<f64> = Array::<f64>::from(&samples, [30]).into_shape_infer([INFER, 10]);
+    /// assert_eq!(*frames.shape(), [3, 10]);
+    /// # }
+    /// ```
+    pub fn into_shape_infer<const M: usize>(self, shape: [isize; M]) -> NdArray<T, M> {
+        let len: usize = self.len;
+        self.into_shape(infer_shape(shape, len))
+    }
+
+    /// Helper function to create a sized array from a range containing `start` and an `end` value along with a `step` value
+    ///
+    /// ## Note
+    /// - Accepts both positive and negative integers
+    /// - This is a private method in the implementation and cannot (and should never) be used outside this `impl` block
+    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    ///
+    /// ## Panics
+    /// - May panic if `start > end`; message shown is **Index out of bound**
+    /// - If `T::from(i)` conversion fails, panics; message shown is **Unable to convert to type T**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
+    /// let step: usize = 2;
+    /// let arr = NdArray::<i8, 2>::arange(range); // uses range(...) to construct a sized array
+    /// assert_eq!(*arr.len(), 5);
+    /// # }
+    /// ```
+    fn range(range: (isize, isize, usize)) -> Self
+    where
+        T: num_traits::NumCast + num_traits::ToPrimitive,
+    {
+        if range.0 > range.1 {
+            panic!("Index out of bound");
+        }
+
+        let end_range: usize = Self::size_from_range((range.0, range.1), range.2);
+        let mut arr: Vec<T> = Vec::<T>::with_capacity(end_range);
+        if range.2 == 0 {
+            for i in range.0..range.1 {
+                let val: T = T::from(i).expect("Unable to convert to type T"); // panics if it cannot construct to type T
+                arr.push(val);
+            }
+        } else {
+            for i in (range.0..range.1).step_by(range.2) {
+                let val: T = T::from(i).expect("Unable to convert to type T"); // panics if it cannot construct to type T
+                arr.push(val);
+            }
+        }
+
+        Self::from_row(arr)
+    }
+
+    /// Create a sized array with an `end` value starting from 0 within `usize` range
+    ///
+    /// ## Note
+    /// - Accepts only positive integers
+    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    ///
+    /// ## Panics
+    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
+    /// let step: usize = 2;
+    /// let arr = NdArray::<i8, 2>::arange(range);
+    /// assert_eq!(*arr.len(), 5);
+    /// # }
+    /// ```
+    pub fn arange(range: usize) -> Self
+    where
+        T: num_traits::NumCast + num_traits::ToPrimitive,
+    {
+        Self::range((0, range as isize, 0))
+    }
+
+    /// Create a sized array with an `end` value starting from 0 within `usize` range and a step value of range in `usize`
+    ///
+    /// ## Note
+    /// - Accepts only positive integers
+    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    ///
+    /// ## Panics
+    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let range: usize = 5; // `arr` ranges from 0 to 4 w/o step
+    /// let step: usize = 2;
+    /// let arr = NdArray::<i8, 2>::arange_with_step(range, step);
+    /// assert_eq!(*arr.len(), 3);
+    /// # }
+    /// ```
+    pub fn arange_with_step(range: usize, step: usize) -> Self
+    where
+        T: num_traits::NumCast + num_traits::ToPrimitive + Default + Copy,
+    {
+        Self::range((0, range as isize, step))
+    }
+
+    /// Create a sized array with `start` and `end` values within `isize` range
+    ///
+    /// ## Note
+    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    /// ## Panics
+    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let ranges: (isize, isize) = (-1, 5); // `arr` ranges from -1 to 4
+    /// let arr = NdArray::<i8, 2>::ranges(ranges);
+    /// assert_eq!(*arr.len(), 6);
+    /// # }
+    /// ```
+    pub fn ranges(ranges: (isize, isize)) -> Self
+    where
+        T: num_traits::NumCast + num_traits::ToPrimitive + Default + Copy,
+    {
+        Self::range((ranges.0, ranges.1, 0))
+    }
+
+    /// Create a sized array with `start` and `end` values within `isize` range and a step value of range in `usize`
+    ///
+    /// ## Note
+    /// - `end` will not be included while creating the array. Hence the array range is `start..=(end - 1)`
+    /// ## Panics
+    /// Check function `NdArray<T, N>::range(...)` or click [here](https://github.com/noobsiecoder/ndim/blob/main/src/core.rs#L271)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let ranges: (isize, isize) = (-1, 5); // `arr` ranges from -1 to 4 w/o step
+    /// let step: usize = 2;
+    /// let arr = NdArray::<i8, 2>::ranges_with_step(ranges, step);
+    /// assert_eq!(*arr.len(), 3);
+    /// # }
+    /// ```
+    pub fn ranges_with_step(ranges: (isize, isize), step: usize) -> Self
+    where
+        T: num_traits::NumCast + num_traits::ToPrimitive,
+    {
+        Self::range((ranges.0, ranges.1, step))
+    }
+
+    /// Create an NdArray completely filled with `value`. Requires shape of size `N`
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 2>::full(0.5, [3, 2]);
+    /// assert_eq!(arr.as_slice(), &[0.5; 6]);
+    /// # }
+    /// ```
+    pub fn full(value: T, shape: SizedArray<N>) -> Self {
+        let size: usize = Self::size_from_shape(&shape);
+
+        Self::from_owned_vec(vec![value; size], shape)
+    }
+
+    /// Create an NdArray with the shape of `other` (of any type), completely filled with `value`
+    ///
+    /// ## Note
+    /// If `other` is created using `NdArray::<U, N>::new()`, the returned NdArray is also empty
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let image = NdArray::<u8, 3>::zeros([4, 4, 3]);
+    /// let mask = NdArray::<bool, 3>::full_like(&image, true);
+    /// assert_eq!(mask.shape(), image.shape());
+    /// assert!(mask.as_slice().iter().all(|value| *value));
+    /// # }
+    /// ```
+    pub fn full_like<U>(other: &NdArray<U, N>, value: T) -> Self {
+        if *other.len() != Self::size_from_shape(other.shape()) {
+            return Self::new();
+        }
+        Self::full(value, *other.shape())
+    }
+
+    /// Create a sized array completely filled with numeral zero or `0`. Requires shape of size `N`
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let shape: [usize; 2] = [3, 2];
+    /// let arr = NdArray::<u16, 2>::zeros(shape);
+    /// for i in 0..arr.shape()[0] {
+    ///     for j in 0..arr.shape()[1] {
+    ///         assert_eq!(arr[[i, j]], 0);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn zeros(shape: SizedArray<N>) -> Self
+    where
+        T: Default,
+    {
+        Self::full(T::default(), shape)
+    }
+
+    /// Create a sized array completely filled with numeral zero or `0`, or return an [`NdimError::Allocation`] if the size derived from shape overflows, or its memory cannot be reserved. Same as [`NdArray::<T, N>::zeros(...)`](NdArray::zeros) without panicking or aborting, e.g. for a shape read from an untrusted file header
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::error::NdimError;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 2>::try_zeros([2, 3]).unwrap();
+    /// assert_eq!(arr, NdArray::<f32, 2>::zeros([2, 3]));
+    ///
+    /// let err = NdArray::<f32, 2>::try_zeros([usize::MAX, 2]).unwrap_err();
+    /// assert_eq!(err, NdimError::Allocation { size: usize::MAX });
+    /// # }
+    /// ```
+    pub fn try_zeros(shape: SizedArray<N>) -> Result<Self, NdimError> {
+        let size: usize = checked_size::<T, N>(&shape).ok_or(NdimError::Allocation {
+            size: shape
+                .iter()
+                .fold(USIZE_ONE, |size, len| size.saturating_mul(*len)),
+        })?;
+        let mut values: Vec<T> = Vec::new();
+        values
+            .try_reserve_exact(size)
+            .map_err(|_| NdimError::Allocation { size })?;
+        values.resize(size, T::default());
+
+        Ok(Self::from_owned_vec(values, shape))
+    }
+
+    /// Create a sized array completely filled with numeral one or `1`. Requires shape of size `N`
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let shape: [usize; 2] = [3, 2];
+    /// let arr = NdArray::<u16, 2>::ones(shape);
+    /// for i in 0..arr.shape()[0] {
+    ///     for j in 0..arr.shape()[1] {
+    ///         assert_eq!(arr[[i, j]], 1);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn ones(shape: SizedArray<N>) -> Self
+    where
+        T: num_traits::One,
+    {
+        Self::full(T::one(), shape)
+    }
+
+    /// Create an NdArray with the shape of `other` (of any type), completely filled with numeral zero or `0`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let counts = NdArray::<u32, 2>::from(&[4, 1, 7, 2], [2, 2]);
+    /// let weights = NdArray::<f64, 2>::zeros_like(&counts);
+    /// assert_eq!(*weights.shape(), [2, 2]);
+    /// assert_eq!(weights.as_slice(), &[0.0; 4]);
+    /// # }
+    /// ```
+    pub fn zeros_like<U>(other: &NdArray<U, N>) -> Self {
+        Self::full_like(other, T::default())
+    }
+
+    /// Create an NdArray with the shape of `other` (of any type), completely filled with numeral one or `1`
+    pub fn ones_like<U>(other: &NdArray<U, N>) -> Self
+    where
+        T: num_traits::One,
+    {
+        Self::full_like(other, T::one())
+    }
+}
+
+impl<T, const N: usize> NdArray<MaybeUninit<T>, N> {
+    /// Create an NdArray of the given `shape` whose elements are not initialized, skipping the initialization pass of `NdArray::<T, N>::zeros(...)`. Use when every element is written before being read, e.g. an output buffer
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use ndim::core::NdArray;
+    ///
+    /// # fn main() {
+    /// let mut out = NdArray::<MaybeUninit<f64>, 2>::uninit([2, 3]);
+    /// for (pos, value) in out.as_mut_slice().iter_mut().enumerate() {
+    ///     value.write(pos as f64 * 0.5);
+    /// }
+    /// // SAFETY: every element is written above
+    /// let out: NdArray<f64, 2> = unsafe { out.assume_init() };
+    /// assert_eq!(out[[1, 2]], 2.5);
+    /// # }
+    /// ```
+    pub fn uninit(shape: SizedArray<N>) -> Self {
+        let size: usize = Self::size_from_shape(&shape);
+        let mut values: Vec<MaybeUninit<T>> = Vec::with_capacity(size);
+        // SAFETY: `MaybeUninit<T>` does not require initialization, and the capacity is `size`
+        unsafe { values.set_len(size) };
+
+        Self::from_owned_vec(values, shape)
+    }
+
+    /// Convert into an NdArray of type T, assuming every element is initialized. The buffer, shape and strides are reused without copying
+    ///
+    /// ## Safety
+    /// Every element must be initialized, e.g. using `MaybeUninit::write`. Reading an uninitialized element is undefined behavior
+    pub unsafe fn assume_init(self) -> NdArray<T, N> {
+        let mut values: std::mem::ManuallyDrop<Vec<MaybeUninit<T>>> =
+            std::mem::ManuallyDrop::new(self.data);
+        // SAFETY: `MaybeUninit<T>` has the same layout as T, and the elements are initialized by the contract of the caller
+        let values: Vec<T> = Vec::from_raw_parts(
+            values.as_mut_ptr() as *mut T,
+            values.len(),
+            values.capacity(),
+        );
+
+        NdArray {
+            data: values,
+            len: self.len,
+            shape: self.shape,
+            strides: self.strides,
+        }
+    }
+}
+
+impl<T: num_traits::Float, const N: usize> NdArray<T, N> {
+    /// Create a sized array of `num` evenly spaced values from `start` to `stop`, similar to `numpy.linspace`. The shape is `[1, .., num]`, same as `NdArray::<T, N>::arange(...)`
+    ///
+    /// ## Note
+    /// If `endpoint` is true, `stop` is the last value. Otherwise, `stop` is excluded and the spacing is `(stop - start) / num`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::linspace(0.0, 1.0, 5, true);
+    /// assert_eq!(arr.as_slice(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+    ///
+    /// let arr = NdArray::<f64, 2>::linspace(0.0, 1.0, 4, false);
+    /// assert_eq!(*arr.shape(), [1, 4]);
+    /// assert_eq!(arr.as_slice(), &[0.0, 0.25, 0.5, 0.75]);
+    /// # }
+    /// ```
+    pub fn linspace(start: T, stop: T, num: usize, endpoint: bool) -> Self {
+        let div: usize = if endpoint { num.saturating_sub(1) } else { num };
+        let step: T = if div == 0 {
+            T::zero()
+        } else {
+            (stop - start) / T::from(div).expect("Unable to convert to type T")
+        };
+        let mut arr: Vec<T> = (0..num)
+            .map(|idx| start + T::from(idx).expect("Unable to convert to type T") * step)
+            .collect();
+        if endpoint && num > 1 {
+            arr[num - 1] = stop; // avoids the rounding error of the last value
+        }
+
+        Self::from_row(arr)
+    }
+
+    /// Create a sized array of `num` values spaced evenly on a log scale, from `base ^ start_exp` to `base ^ stop_exp` (both included), similar to `numpy.logspace`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::logspace(0.0, 3.0, 4, 10.0);
+    /// assert_eq!(arr.as_slice(), &[1.0, 10.0, 100.0, 1000.0]);
+    /// # }
+    /// ```
+    pub fn logspace(start_exp: T, stop_exp: T, num: usize, base: T) -> Self {
+        let exps: Self = Self::linspace(start_exp, stop_exp, num, true);
+        Self::from_row(exps.data.into_iter().map(|exp| base.powf(exp)).collect())
+    }
+
+    /// Create a sized array of `num` values spaced evenly on a log scale, i.e., forming a geometric progression from `start` to `stop` (both included), similar to `numpy.geomspace`
+    ///
+    /// ## Panics
+    /// If `start` or `stop` is zero, or their signs differ, panics; message shown is **Start(`start`) and Stop(`stop`) must be non-zero with the same sign**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::geomspace(-1.0, -16.0, 5);
+    /// let expected = [-1.0, -2.0, -4.0, -8.0, -16.0];
+    /// assert!(arr.as_slice().iter().zip(expected).all(|(x, y)| (x - y).abs() < 1e-12));
+    /// # }
+    /// ```
+    pub fn geomspace(start: T, stop: T, num: usize) -> Self
+    where
+        T: Debug,
+    {
+        if start.is_zero() || stop.is_zero() || start.is_sign_negative() != stop.is_sign_negative()
+        {
+            panic!(
+                "Start({:?}) and Stop({:?}) must be non-zero with the same sign",
+                start, stop
+            );
+        }
+
+        let sign: T = start.signum();
+        let logs: Self = Self::linspace(start.abs().ln(), stop.abs().ln(), num, true);
+        let mut arr: Vec<T> = logs.data.into_iter().map(|log| sign * log.exp()).collect();
+        if num > 0 {
+            arr[0] = start; // avoids the rounding error of the endpoints
+            arr[num - 1] = stop;
+        }
+
+        Self::from_row(arr)
+    }
+
+    /// Create a sized array of values from `start` to `stop` (excluded), spaced by a fractional `step`, similar to `numpy.arange` for floats. The shape is `[1, .., len]`
+    ///
+    /// ## Note
+    /// - The number of values is `ceil((stop - start) / step)`, hence a negative `step` counts down from `start`, and the array is empty if `stop` cannot be reached
+    /// - Values are computed as `start + i * step` to avoid accumulating rounding errors. Similar to NumPy, a value close to `stop` may be included due to rounding, and [`NdArray::<T, N>::linspace(...)`](NdArray::linspace) should be preferred when the number of values matters
+    ///
+    /// ## Panics
+    /// - If `step` is zero, panics; message shown is **Step cannot be zero**
+    /// - If any argument is not finite, panics; message shown is **Range(`start`, `stop`) with Step(`step`) is not finite**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::arange_f(0.0, 1.0, 0.25);
+    /// assert_eq!(arr.as_slice(), &[0.0, 0.25, 0.5, 0.75]);
+    ///
+    /// let arr = NdArray::<f64, 1>::arange_f(1.0, 0.0, -0.5);
+    /// assert_eq!(arr.as_slice(), &[1.0, 0.5]);
+    /// assert_eq!(*NdArray::<f64, 1>::arange_f(0.0, 1.0, 0.1).len(), 10);
+    /// # }
+    /// ```
+    pub fn arange_f(start: T, stop: T, step: T) -> Self
+    where
+        T: Debug,
+    {
+        if step.is_zero() {
+            panic!("Step cannot be zero");
+        }
+        let count: T = ((stop - start) / step).ceil();
+        if !count.is_finite() || !start.is_finite() {
+            panic!(
+                "Range({:?}, {:?}) with Step({:?}) is not finite",
+                start, stop, step
+            );
+        }
+
+        let len: usize = count
+            .max(T::zero())
+            .to_usize()
+            .expect("Unable to convert to type usize");
+        let arr: Vec<T> = (0..len)
+            .map(|idx| start + T::from(idx).expect("Unable to convert to type T") * step)
+            .collect();
+
+        Self::from_row(arr)
+    }
+}
+
+impl<T: Debug + Copy + Default + num_traits::One> NdArray<T, 2> {
+    /// Create a two dimensional (2-D) array of shape `[rows, cols]` with ones on the `k`-th diagonal and zeros elsewhere, similar to `numpy.eye`
+    ///
+    /// ## Note
+    /// The main diagonal is `k = 0`. A positive `k` refers to a diagonal above the main diagonal, and a negative `k` to a diagonal below it. If the diagonal is outside the shape, the array is filled with zeros
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<i32>::eye(2, 3, 1);
+    /// assert_eq!(arr.as_slice(), &[0, 1, 0, 0, 0, 1]);
+    /// # }
+    /// ```
+    pub fn eye(rows: usize, cols: usize, k: isize) -> Self {
+        let mut arr: Self = Self::zeros([rows, cols]);
+        for row in 0..rows {
+            let col: isize = row as isize + k;
+            if col >= 0 && (col as usize) < cols {
+                arr[[row, col as usize]] = T::one();
+            }
+        }
+
+        arr
+    }
+
+    /// Create a square identity matrix of shape `[n, n]`, similar to `numpy.identity`. Same as `eye(n, n, 0)`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<f64>::identity(3);
+    /// assert_eq!(arr[[1, 1]], 1.0);
+    /// assert_eq!(arr[[1, 2]], 0.0);
+    /// # }
+    /// ```
+    pub fn identity(n: usize) -> Self {
+        Self::eye(n, n, 0)
+    }
+
+    /// Create a matrix of shape `[rows, cols]` with ones at and below the `k`-th diagonal and zeros elsewhere, similar to `numpy.tri`. Useful as a (causal) mask
+    ///
+    /// ## Note
+    /// The main diagonal is `k = 0`. A positive `k` also includes `k` diagonals above the main diagonal, and a negative `k` excludes the main diagonal and `|k| - 1` diagonals below it
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<u8>::tri(3, 3, 0);
+    /// assert_eq!(arr.as_slice(), &[1, 0, 0, 1, 1, 0, 1, 1, 1]);
+    /// let arr = Array2::<u8>::tri(2, 3, -1);
+    /// assert_eq!(arr.as_slice(), &[0, 0, 0, 1, 0, 0]);
+    /// # }
+    /// ```
+    pub fn tri(rows: usize, cols: usize, k: isize) -> Self {
+        Self::from_shape_fn([rows, cols], |[row, col]| {
+            if col as isize <= row as isize + k {
+                T::one()
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+impl<T: Debug + Copy + Default> NdArray<T, 2> {
+    /// Create a square two dimensional (2-D) array with the elements of `diag` on the main diagonal, and zeros (default values) elsewhere
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{Array, Array2};
+    /// #
+    /// # fn main() {
+    /// let diag = Array::<u8>::from(&[1, 2, 3], [3]);
+    /// let arr = Array2::<u8>::from_diag(&diag);
+    /// assert_eq!(arr.as_slice(), &[1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    /// # }
+    /// ```
+    pub fn from_diag(diag: &NdArray<T, 1>) -> Self {
+        let n: usize = *diag.len();
+        let mut arr: Self = Self::zeros([n, n]);
+        for (pos, value) in diag.as_slice().iter().enumerate() {
+            arr[[pos, pos]] = *value;
+        }
+
+        arr
+    }
+
+    /// Return the `k`-th diagonal as a one dimensional (1-D) array, similar to `numpy.diagonal`
+    ///
+    /// ## Note
+    /// The main diagonal is `k = 0`. A positive `k` refers to a diagonal above the main diagonal, and a negative `k` to a diagonal below it. If the diagonal is outside the shape, the returned array has no elements
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<i32>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// assert_eq!(arr.diagonal(0).as_slice(), &[0, 4]);
+    /// assert_eq!(arr.diagonal(1).as_slice(), &[1, 5]);
+    /// assert_eq!(arr.diagonal(-1).as_slice(), &[3]);
+    /// # }
+    /// ```
+    pub fn diagonal(&self, k: isize) -> NdArray<T, 1> {
+        let [rows, cols] = *self.view().shape(); // empty for `NdArray::<T, 2>::new()`
+        let (row, col): (usize, usize) = if k >= 0 {
+            (0, k.unsigned_abs())
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+        let len: usize = rows.saturating_sub(row).min(cols.saturating_sub(col));
+        let values: Vec<T> = (0..len).map(|pos| self[[row + pos, col + pos]]).collect();
+
+        NdArray::from_owned_vec(values, [len])
+    }
+
+    /// Return a copy with the elements above the `k`-th diagonal set to zero, i.e., the lower triangle, similar to `numpy.tril`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<i32>::from(&[1, 2, 3, 4, 5, 6, 7, 8, 9], [3, 3]);
+    /// assert_eq!(arr.tril(0).as_slice(), &[1, 0, 0, 4, 5, 0, 7, 8, 9]);
+    /// assert_eq!(arr.tril(-1).as_slice(), &[0, 0, 0, 4, 0, 0, 7, 8, 0]);
+    /// # }
+    /// ```
+    pub fn tril(&self, k: isize) -> Self {
+        self.triangle(|row, col| col <= row + k)
+    }
+
+    /// Return a copy with the elements below the `k`-th diagonal set to zero, i.e., the upper triangle, similar to `numpy.triu`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::Array2;
+    /// #
+    /// # fn main() {
+    /// let arr = Array2::<i32>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// assert_eq!(arr.triu(0).as_slice(), &[1, 2, 3, 0, 5, 6]);
+    /// assert_eq!(arr.triu(1).as_slice(), &[0, 2, 3, 0, 0, 6]);
+    /// # }
+    /// ```
+    pub fn triu(&self, k: isize) -> Self {
+        self.triangle(|row, col| col >= row + k)
+    }
+
+    /// Return a copy keeping the elements whose (signed) row and column satisfy `keep`, and setting the others to zero
+    fn triangle<F: Fn(isize, isize) -> bool>(&self, keep: F) -> Self {
+        Self::from_shape_fn(self.view_shape(), |[row, col]| {
+            if keep(row as isize, col as isize) {
+                self[[row, col]]
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+/// Calculate the index using strides and the given index. Returns a value which can be used to access the memory of the 1-d sized array
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// fn main() {
+///     let shape: [usize; 2] = [2, 2];
+///     let mut arr = NdArray::<u16, 2>::zeros(shape);
+///     // mutable pointer is accessed and the value is changed pointing to [1, 1]
+///     // `get_index<..>(index: &.., strides: &..)` is used here to access the memory from the 1-d contiguous array
+///     arr[[1, 1]] = 12;
+///     assert_eq!(arr[[1, 1]], 12);
+/// }
+/// ```
+fn get_index<const N: usize>(index: &SizedArray<N>, strides: &SizedArray<N>) -> usize {
+    let mut idx: usize = 0;
+    for i in 0..N {
+        idx += index[i] * strides[i]
+    }
+
+    if N == 0 {
+        return 0;
+    }
+    idx / strides[N - 1]
+}
+
+/// Create an empty NdArray, same as `NdArray::<T, N>::new()`. Allows deriving `Default` for types holding an NdArray
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// #[derive(Default)]
+/// struct Image {
+///     pixels: NdArray<u8, 3>,
+/// }
+///
+/// let image = Image::default();
+/// assert!(image.pixels.is_empty());
+/// assert_eq!(*image.pixels.shape(), [1, 1, 1]);
+/// # }
+/// ```
+impl<T, const N: usize> Default for NdArray<T, N> {
+    fn default() -> Self {
+        NdArray {
+            data: Vec::new(),
+            len: 0,
+            shape: [USIZE_ONE; N],
+            strides: [USIZE_ONE; N],
+        }
+    }
+}
+
+/// Maximum number of elements of type T in an NdArray, such that the number of bytes does not overflow `isize::MAX`. Zero sized types are treated as a single byte
+fn max_size<T>() -> usize {
+    isize::MAX as usize / std::mem::size_of::<T>().max(1)
+}
+
+/// Calculate the size of an NdArray of type T from `shape` using checked multiplication. Returns `None` if the size overflows [`max_size`]
+pub(crate) fn checked_size<T, const N: usize>(shape: &SizedArray<N>) -> Option<usize> {
+    if shape.contains(&0) {
+        return Some(0); // other axes may be as large as `usize::MAX`
+    }
+    shape
+        .iter()
+        .try_fold(USIZE_ONE, |size, len| size.checked_mul(*len))
+        .filter(|size| *size <= max_size::<T>())
+}
+
+/// Return a [`ShapeError`] if the size derived from `shape` is not equal to `size`, or overflows
+fn check_size<T, const N: usize>(shape: &SizedArray<N>, size: usize) -> Result<(), ShapeError> {
+    if checked_size::<T, N>(shape) != Some(size) {
+        return Err(ShapeError::SizeMismatch {
+            shape: shape.to_vec(),
+            size,
+        });
+    }
+    Ok(())
+}
+
+/// Create `N` coordinate arrays of dimension `N` from the 1-D `coords`, where the k-th array holds the k-th coordinate at every point of the grid
+fn grid<T: Copy, const N: usize>(coords: [&[T]; N], indexing: Indexing) -> [NdArray<T, N>; N] {
+    let mut perm: SizedArray<N> = std::array::from_fn(|dim| dim);
+    if indexing == Indexing::Xy && N > 1 {
+        perm.swap(0, 1);
+    }
+    let mut shape: SizedArray<N> = [0; N];
+    for dim in 0..N {
+        shape[perm[dim]] = coords[dim].len();
+    }
+
+    std::array::from_fn(|dim| NdArray::from_shape_fn(shape, |index| coords[dim][index[perm[dim]]]))
+}
+
+/// Return the coordinate matrices of the grid of the 1-D arrays `x` and `y`, similar to `numpy.meshgrid`
+///
+/// ## Note
+/// With [`Indexing::Xy`], the matrices have a shape of `[y.len(), x.len()]`. With [`Indexing::Ij`], the matrices have a shape of `[x.len(), y.len()]`
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::{meshgrid, Array, Indexing};
+///
+/// # fn main() {
+/// let x = Array::<f64>::from(&[0.0, 1.0, 2.0], [3]);
+/// let y = Array::<f64>::from(&[10.0, 20.0], [2]);
+/// let (xx, yy) = meshgrid(&x, &y, Indexing::Xy);
+/// assert_eq!(*xx.shape(), [2, 3]);
+/// assert_eq!(xx.as_slice(), &[0.0, 1.0, 2.0, 0.0, 1.0, 2.0]);
+/// assert_eq!(yy.as_slice(), &[10.0, 10.0, 10.0, 20.0, 20.0, 20.0]);
+///
+/// // evaluate a function on the grid
+/// let dist: Vec<f64> = xx.as_slice().iter().zip(yy.as_slice()).map(|(x, y)| x.hypot(*y)).collect();
+/// assert_eq!(dist[5], 2f64.hypot(20.0));
+/// # }
+/// ```
+pub fn meshgrid<T: Copy>(
+    x: &NdArray<T, 1>,
+    y: &NdArray<T, 1>,
+    indexing: Indexing,
+) -> (NdArray<T, 2>, NdArray<T, 2>) {
+    let [xx, yy] = grid([x.as_slice(), y.as_slice()], indexing);
+    (xx, yy)
 }
 
-/// Calculate the index using strides and the given index. Returns a value which can be used to access the memory of the 1-d sized array
+/// Return the coordinate arrays of the 3-D grid of the 1-D arrays `x`, `y` and `z`, similar to `numpy.meshgrid`
+///
+/// ## Note
+/// With [`Indexing::Xy`], the arrays have a shape of `[y.len(), x.len(), z.len()]`. With [`Indexing::Ij`], the arrays have a shape of `[x.len(), y.len(), z.len()]`
 ///
 /// ## Example
 ///
 /// ```
-/// use ndim::core::NdArray;
+/// use ndim::core::{meshgrid3, Array, Indexing};
 ///
-/// fn main() {
-///     let shape: [usize; 2] = [2, 2];
-///     let mut arr = NdArray::<u16, 2>::zeros(shape);
-///     // mutable pointer is accessed and the value is changed pointing to [1, 1]
-///     // `get_index<..>(index: &.., strides: &..)` is used here to access the memory from the 1-d contiguous array
-///     arr[[1, 1]] = 12;
-///     assert_eq!(arr[[1, 1]], 12);
-/// }
+/// # fn main() {
+/// let x = Array::<i32>::from(&[0, 1], [2]);
+/// let y = Array::<i32>::from(&[0, 1, 2], [3]);
+/// let z = Array::<i32>::from(&[5], [1]);
+/// let (xx, yy, zz) = meshgrid3(&x, &y, &z, Indexing::Ij);
+/// assert_eq!(*xx.shape(), [2, 3, 1]);
+/// assert_eq!((xx[[1, 2, 0]], yy[[1, 2, 0]], zz[[1, 2, 0]]), (1, 2, 5));
+/// # }
 /// ```
-fn get_index<T, const N: usize>(index: &SizedArray<N>, strides: &SizedArray<N>) -> usize {
-    let mut idx: usize = 0;
-    for i in 0..N {
-        idx += index[i] * strides[i]
-    }
-
-    idx / strides[N - 1]
+pub fn meshgrid3<T: Copy>(
+    x: &NdArray<T, 1>,
+    y: &NdArray<T, 1>,
+    z: &NdArray<T, 1>,
+    indexing: Indexing,
+) -> (NdArray<T, 3>, NdArray<T, 3>, NdArray<T, 3>) {
+    let [xx, yy, zz] = grid([x.as_slice(), y.as_slice(), z.as_slice()], indexing);
+    (xx, yy, zz)
 }
 
 /// Use for indexing immutable NdArray
@@ -534,7 +2044,6 @@ fn get_index<T, const N: usize>(index: &SizedArray<N>, strides: &SizedArray<N>)
 /// ## Note
 /// - Calls `get_index::<..>(index: &.., strides: &..)` to access the data from the contiguous sized 1-d array
 /// - `Panics` if the index is larger than the length of the sized array
-/// - Unsafe block accesses the value in the memory by calculating the offset from the pointer: `*mut T + idx`
 ///
 /// ## Example
 ///
@@ -551,62 +2060,229 @@ impl<T, const N: usize> Index<SizedArray<N>> for NdArray<T, N> {
     type Output = T;
 
     fn index(&self, index: SizedArray<N>) -> &Self::Output {
-        let idx = get_index::<T, N>(&index, &self.strides);
+        let idx = get_index::<N>(&index, &self.strides);
         if idx >= self.len {
             panic!("Index out of bounds")
         }
-        unsafe { &*self.ptr.add(idx) }
+        &self.data[idx]
     }
 }
 
 /// Use for indexing mutable NdArray
 impl<T, const N: usize> IndexMut<SizedArray<N>> for NdArray<T, N> {
     fn index_mut(&mut self, index: SizedArray<N>) -> &mut Self::Output {
-        let idx = get_index::<T, N>(&index, &self.strides);
+        let idx = get_index::<N>(&index, &self.strides);
         if idx >= self.len {
             panic!("Index out of bounds")
         }
-        unsafe { &mut *self.ptr.add(idx) }
+        &mut self.data[idx]
     }
 }
 
-/// Drop *mut T when it goes out of scope
-///
-/// Manually dropping by getting a slice from the pointer. Total size allocated by the pointer is also taken to get this slice. Then, the drop is performed.
+/// Implement `Index` and `IndexMut` with a tuple `$t` of `N` indices for an NdArray of dimension `N`, forwarding to the indexing with `[usize; N]`
+macro_rules! impl_tuple_index {
+    ($(($n:literal, $t:ty, ($($idx:ident),+))),*) => {
+        $(
+            impl<T> Index<$t> for NdArray<T, $n> {
+                type Output = T;
+
+                fn index(&self, ($($idx,)+): $t) -> &Self::Output {
+                    &self[[$($idx),+]]
+                }
+            }
+
+            impl<T> IndexMut<$t> for NdArray<T, $n> {
+                fn index_mut(&mut self, ($($idx,)+): $t) -> &mut Self::Output {
+                    &mut self[[$($idx),+]]
+                }
+            }
+        )*
+    };
+}
+
+// Use for indexing an NdArray with a tuple, e.g. `arr[(i, j)]`, which is the same as `arr[[i, j]]`
+impl_tuple_index!(
+    (1, (usize,), (i)),
+    (2, (usize, usize), (i, j)),
+    (3, (usize, usize, usize), (i, j, k)),
+    (4, (usize, usize, usize, usize), (i, j, k, l))
+);
+
+/// Use for comparing two NdArrays. NdArrays are equal if their shapes are equal, and the elements at every index are equal
 ///
 /// ## Note
-/// - Pointer is not dropped if null
-/// - Though drop occurs inside unsafe block, it takes the right size of the array. This does not mean that undefined behaviour cannot happen
+/// The strides of an NdArray are derived from its shape, hence comparing the contiguous sized arrays compares the elements index by index
 ///
 /// ## Example
 ///
 /// ```
 /// use ndim::core::NdArray;
 ///
-/// fn main() {
-///     {
-///         let arr = NdArray::<u16, 2>::new();
-///         // Drop implementation is called
-///         // `arr` as it goes out of scope here
-///     }
-///     // `arr` is not accessible anymore
-/// }
+/// # fn main() {
+/// let arr = NdArray::<u16, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+/// let mut range = NdArray::<u16, 2>::arange(6);
+/// range.reshape([2, 3]);
+/// assert_eq!(arr, range);
+///
+/// range.reshape([3, 2]);
+/// assert_ne!(arr, range); // same elements, different shape
+/// # }
 /// ```
-impl<T, const N: usize> Drop for NdArray<T, N> {
-    fn drop(&mut self) {
-        if !self.ptr.is_null() {
-            unsafe {
-                let slice: &mut [T] = std::slice::from_raw_parts_mut(self.ptr, self.len);
-                std::ptr::drop_in_place(slice);
-            }
+impl<T: PartialEq, const N: usize> PartialEq for NdArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for NdArray<T, N> {}
+
+/// Use for creating a one dimensional (1-D) array from an array of length `L`. Use `into()`, as NdArray has an associated function `from(arr, shape)`
+impl<T, const L: usize> From<[T; L]> for NdArray<T, 1> {
+    fn from(arr: [T; L]) -> Self {
+        NdArray::from_owned_vec(arr.into(), [L])
+    }
+}
+
+/// Use for creating a two dimensional (2-D) array from nested arrays of `R` rows and `C` columns
+///
+/// ## Note
+/// NdArray has an associated function `from(arr, shape)`, hence use `into()` (or `<Array2<T> as From<_>>::from(...)`) for the conversion
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::Array2;
+///
+/// # fn main() {
+/// let arr: Array2<i32> = [[1, 2, 3], [4, 5, 6]].into();
+/// assert_eq!(*arr.shape(), [2, 3]);
+/// assert_eq!(arr[[1, 0]], 4);
+/// # }
+/// ```
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for NdArray<T, 2> {
+    fn from(arr: [[T; C]; R]) -> Self {
+        let values: Vec<T> = arr.into_iter().flatten().collect();
+        NdArray::from_owned_vec(values, [R, C])
+    }
+}
+
+/// Use for creating a three dimensional (3-D) array from nested arrays of shape `[D, R, C]`
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::Array3;
+///
+/// # fn main() {
+/// let arr: Array3<i32> = [[[1, 2], [3, 4]], [[5, 6], [7, 8]], [[9, 10], [11, 12]]].into();
+/// assert_eq!(*arr.shape(), [3, 2, 2]);
+/// assert_eq!(arr[[2, 0, 1]], 10);
+/// # }
+/// ```
+impl<T, const D: usize, const R: usize, const C: usize> From<[[[T; C]; R]; D]> for NdArray<T, 3> {
+    fn from(arr: [[[T; C]; R]; D]) -> Self {
+        let values: Vec<T> = arr.into_iter().flatten().flatten().collect();
+        NdArray::from_owned_vec(values, [D, R, C])
+    }
+}
+
+/// Use for creating a two dimensional (2-D) array from rows of type `Vec<T>`. Returns [`ShapeError::RaggedRows`] if the rows do not have the same length
+///
+/// ## Note
+/// NdArray has an associated function `try_from(arr, shape)`, hence use `try_into()` (or `<Array2<T> as TryFrom<_>>::try_from(...)`) for the conversion
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::{Array2, ShapeError};
+///
+/// # fn main() {
+/// let rows: Vec<Vec<f32>> = vec![vec![0.5, 1.5], vec![2.5, 3.5], vec![4.5, 5.5]];
+/// let arr: Array2<f32> = rows.try_into().unwrap();
+/// assert_eq!(*arr.shape(), [3, 2]);
+///
+/// let ragged: Result<Array2<u8>, ShapeError> = vec![vec![1, 2], vec![3]].try_into();
+/// assert_eq!(ragged, Err(ShapeError::RaggedRows { row: 1, len: 1, expected: 2 }));
+/// # }
+/// ```
+impl<T> TryFrom<Vec<Vec<T>>> for NdArray<T, 2> {
+    type Error = ShapeError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let cols: usize = rows.first().map_or(0, Vec::len);
+        if let Some((row, len)) = rows
+            .iter()
+            .map(Vec::len)
+            .enumerate()
+            .find(|(_, len)| *len != cols)
+        {
+            return Err(ShapeError::RaggedRows {
+                row,
+                len,
+                expected: cols,
+            });
         }
+
+        let shape: SizedArray<2> = [rows.len(), cols];
+        Ok(NdArray::from_owned_vec(
+            rows.into_iter().flatten().collect(),
+            shape,
+        ))
     }
 }
 
+/// Create a one, two or three dimensional NdArray from nested literals, similar to ndarray's `array!`. The shape is inferred from the nesting
+///
+/// ## Note
+/// The literals are converted into nested arrays, hence rows of different lengths are rejected at compile time
+///
+/// ## Example
+///
+/// ```
+/// use ndim::array;
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let vector = array![1.0, 2.0, 3.0];
+/// assert_eq!(*vector.shape(), [3]);
+///
+/// let matrix = array![[1, 2], [3, 4], [5, 6]];
+/// assert_eq!(matrix, NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6], [3, 2]));
+///
+/// let cube = array![[[1u8, 2], [3, 4]], [[5, 6], [7, 8]]];
+/// assert_eq!(*cube.shape(), [2, 2, 2]);
+/// assert_eq!(cube[[1, 0, 1]], 6);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// use ndim::array;
+///
+/// # fn main() {
+/// let ragged = array![[1, 2], [3]];
+/// # }
+/// ```
+#[macro_export]
+macro_rules! array {
+    ($([$([$($x:expr),* $(,)?]),+ $(,)?]),+ $(,)?) => {
+        <$crate::core::NdArray<_, 3> as ::core::convert::From<_>>::from([$([$([$($x),*]),+]),+])
+    };
+    ($([$($x:expr),* $(,)?]),+ $(,)?) => {
+        <$crate::core::NdArray<_, 2> as ::core::convert::From<_>>::from([$([$($x),*]),+])
+    };
+    ($($x:expr),* $(,)?) => {
+        <$crate::core::NdArray<_, 1> as ::core::convert::From<_>>::from([$($x),*])
+    };
+}
+
 //
 #[cfg(test)]
+#[allow(clippy::needless_range_loop)]
 mod core_ndim_t {
-    use crate::core::{Array, Array2, Array3, NdArray};
+    use crate::core::{
+        meshgrid, meshgrid3, Array, Array2, Array3, Indexing, NdArray, Order, ShapeError, INFER,
+    };
+    use crate::error::NdimError;
 
     // Test for the creation of zeros in an NdArray
     // Access the memory at location (x, y) and mutate it
@@ -757,7 +2433,7 @@ mod core_ndim_t {
             .step_by(2)
             .map(f32::from)
             .collect::<Vec<f32>>();
-        let size: usize = if range % 2 == 0 {
+        let size: usize = if range.is_multiple_of(2) {
             range / 2
         } else {
             (range / 2) + 1
@@ -837,4 +2513,702 @@ mod core_ndim_t {
         assert_eq!(*data.shape(), new_shape);
         assert_eq!(*data.strides(), new_strides);
     }
+
+    // Test NdArray<T, N>::reshape_infer(...) for a 3-D NdArray
+    // Check if the inferred dimension and the strides are correct
+    #[test]
+    fn reshape_infer_3dim_t() {
+        let range: usize = u16::MAX as usize;
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(range);
+
+        data.reshape_infer([3, INFER, 4369]);
+        assert_eq!(*data.shape(), [3, 5, 4369]);
+        assert_eq!(data[[1, 2, 1234]], 31817);
+
+        data.reshape_infer([INFER, 17, 1]);
+        assert_eq!(*data.shape(), [3855, 17, 1]);
+        assert_eq!(*data.strides(), [34, 2, 2]);
+    }
+
+    // Test NdArray<T, N>::reshape_infer(...) with more than one inferred dimension
+    #[test]
+    #[should_panic(expected = "Only one dimension can be inferred in Shape([-1, -1])")]
+    fn reshape_infer_twice_t() {
+        let mut data: NdArray<u8, 2> = Array2::<u8>::zeros([2, 3]);
+        data.reshape_infer([INFER, INFER]);
+    }
+
+    // Test NdArray<T, N>::reshape_infer(...) when the length is not divisible by the other dimensions
+    #[test]
+    #[should_panic(expected = "Cannot infer Shape([4, -1]) from current Size(6)")]
+    fn reshape_infer_indivisible_t() {
+        let mut data: NdArray<u8, 2> = Array2::<u8>::zeros([2, 3]);
+        data.reshape_infer([4, INFER]);
+    }
+
+    // Test NdArray<T, N>::reshape_with_order(...) for a 2-D NdArray in column-major order
+    // Check if reading the new shape in column-major order gives back the same sequence
+    #[test]
+    fn reshape_with_order_2dim_t() {
+        let mut data: NdArray<u16, 2> = Array2::<u16>::arange(12);
+        data.reshape([3, 4]);
+        let before: NdArray<u16, 1> = data.flatten_with_order(Order::F);
+
+        data.reshape_with_order([2, 6], Order::F);
+        assert_eq!(*data.shape(), [2, 6]);
+        assert_eq!(*data.strides(), [12, 2]);
+        assert_eq!(
+            data.flatten_with_order(Order::F).as_slice(),
+            before.as_slice()
+        );
+        assert_eq!(data[[1, 0]], 4);
+        assert_eq!(data[[0, 1]], 8);
+    }
+
+    // Test NdArray<T, N>::flatten_with_order(...) for a 3-D NdArray
+    #[test]
+    fn flatten_with_order_3dim_t() {
+        let arr: [i32; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        let data: NdArray<i32, 3> = Array3::<i32>::from(&arr, [2, 2, 2]);
+
+        assert_eq!(data.flatten_with_order(Order::C).as_slice(), &arr);
+        assert_eq!(
+            data.flatten_with_order(Order::F).as_slice(),
+            &[0, 4, 2, 6, 1, 5, 3, 7]
+        );
+    }
+
+    // Test NdArray<T, N>::from_slice(...) copies the elements, so the source can be dropped or mutated
+    #[test]
+    fn from_slice_copies_t() {
+        let mut arr: Vec<i64> = vec![1, 2, 3, 4, 5, 6];
+        let mut data: NdArray<i64, 2> = Array2::<i64>::from_slice(&arr, [2, 3]);
+        arr[0] = 100;
+        data[[1, 2]] = -6;
+        assert_eq!(data.as_slice(), &[1, 2, 3, 4, 5, -6]);
+        assert_eq!(arr, vec![100, 2, 3, 4, 5, 6]);
+
+        drop(arr);
+        let temporary: NdArray<i64, 1> =
+            NdArray::<i64, 1>::from(&std::iter::repeat_n(7, 3).collect::<Vec<i64>>(), [3]);
+        assert_eq!(temporary.as_slice(), &[7, 7, 7]);
+    }
+
+    // Test NdArray<T, N>::from_vec(...) with a type which is not Copy
+    #[test]
+    fn from_vec_t() {
+        let names: Vec<String> = (0..6).map(|idx| format!("n{}", idx)).collect();
+        let mut data: NdArray<String, 3> = NdArray::<String, 3>::from_vec(names, [1, 3, 2]);
+        assert_eq!(data[[0, 2, 1]], "n5");
+
+        data[[0, 0, 0]].push('!');
+        assert_eq!(data.as_slice()[0], "n0!");
+    }
+
+    // Test NdArray<T, N>::from_vec(...) with a shape which does not match the length
+    #[test]
+    #[should_panic(expected = "Shape([2, 2]) don't match with array Size(3)")]
+    fn from_vec_wrong_shape_t() {
+        NdArray::<u8, 2>::from_vec(vec![1, 2, 3], [2, 2]);
+    }
+
+    // Test NdArray<T, N>::clone(...) allocates a new buffer for the elements
+    #[test]
+    fn clone_3dim_t() {
+        let arr: [i16; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        let mut data: NdArray<i16, 3> = Array3::<i16>::from(&arr, [2, 2, 2]);
+        let copy: NdArray<i16, 3> = data.clone();
+        data[[1, 1, 1]] = -1;
+
+        assert_eq!(copy.shape(), data.shape());
+        assert_eq!(copy.strides(), data.strides());
+        assert_eq!(copy.as_slice(), &arr);
+        assert_ne!(copy.as_ptr(), data.as_ptr());
+
+        let owned: NdArray<i16, 3> = data.to_owned();
+        assert_eq!(owned[[1, 1, 1]], -1);
+    }
+
+    // Test NdArray<T, N>::eq(...) for 2-D NdArrays with equal and unequal shapes and elements
+    #[test]
+    fn eq_2dim_t() {
+        let arr: [f32; 4] = [0.5, 1.0, f32::NAN, 2.0];
+        let data: NdArray<f32, 2> = Array2::<f32>::from(&arr, [2, 2]);
+        assert_ne!(data, data.clone()); // NaN is not equal to itself
+
+        let data: NdArray<i8, 2> = Array2::<i8>::from(&[1, 2, 3, 4], [2, 2]);
+        let mut copy: NdArray<i8, 2> = data.clone();
+        assert_eq!(data, copy);
+        copy[[1, 0]] = 0;
+        assert_ne!(data, copy);
+
+        assert_eq!(Array2::<i8>::new(), Array2::<i8>::new());
+        assert_ne!(Array2::<i8>::new(), Array2::<i8>::zeros([1, 1]));
+    }
+
+    // Test NdArray<T, N> can be moved into, and shared between threads
+    #[test]
+    fn send_sync_t() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<NdArray<f64, 3>>();
+
+        let data: NdArray<u32, 2> = Array2::<u32>::from(&[1, 2, 3, 4, 5, 6], [3, 2]);
+        let moved: NdArray<u32, 2> = std::thread::spawn(move || {
+            let mut data: NdArray<u32, 2> = data;
+            data[[2, 1]] = 60;
+            data
+        })
+        .join()
+        .unwrap();
+        assert_eq!(moved.as_slice(), &[1, 2, 3, 4, 5, 60]);
+
+        let sums: Vec<u32> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..3)
+                .map(|row| {
+                    let moved: &NdArray<u32, 2> = &moved;
+                    scope.spawn(move || moved[[row, 0]] + moved[[row, 1]])
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        assert_eq!(sums, vec![3, 7, 65]);
+    }
+
+    // Test NdArray<T, N>::default(...) is the same as NdArray<T, N>::new(), for a type which is not Copy
+    #[test]
+    fn default_is_empty_t() {
+        let data: NdArray<String, 2> = NdArray::<String, 2>::default();
+        assert!(data.is_empty());
+        assert_eq!(*data.shape(), [1, 1]);
+        assert_eq!(*data.strides(), [1, 1]);
+        assert_eq!(Array2::<u8>::default(), Array2::<u8>::new());
+
+        assert!(Array3::<u8>::zeros([2, 0, 2]).is_empty());
+        assert!(!Array3::<u8>::zeros([2, 1, 2]).is_empty());
+    }
+
+    // Test NdArray<T, N>::get(...) and NdArray<T, N>::get_mut(...) for indices within and out of the shape of a 3-D NdArray
+    #[test]
+    fn get_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        data.reshape([2, 3, 4]);
+        assert_eq!(data.get([1, 2, 3]), Some(&23));
+        assert_eq!(data.get([0, 1, 7]), None); // within the length, but not within the last axis
+        assert_eq!(data.get([2, 0, 0]), None);
+
+        *data.get_mut([0, 2, 1]).unwrap() = 100;
+        assert_eq!(data[[0, 2, 1]], 100);
+        assert!(data.get_mut([0, 3, 0]).is_none());
+
+        let empty: NdArray<u16, 3> = Array3::<u16>::new();
+        assert_eq!(empty.get([0, 0, 0]), None);
+    }
+
+    // Test NdArray<T, N>::uget(...) and NdArray<T, N>::uget_mut(...) match the checked indexing of a 3-D NdArray
+    #[test]
+    fn uget_3dim_t() {
+        let mut data: NdArray<i32, 3> = Array3::<i32>::arange(24);
+        data.reshape([4, 3, 2]);
+        for i in 0..4 {
+            for j in 0..3 {
+                for k in 0..2 {
+                    assert_eq!(unsafe { data.uget([i, j, k]) }, &data[[i, j, k]]);
+                    unsafe { *data.uget_mut([i, j, k]) *= -1 };
+                }
+            }
+        }
+        assert_eq!(data[[3, 2, 1]], -23);
+        assert_eq!(data.as_slice().iter().sum::<i32>(), -276);
+    }
+
+    // Test NdArray<T, N>::try_from(...), NdArray<T, N>::try_from_vec(...) and NdArray<T, N>::try_reshape(...) return a ShapeError instead of panicking
+    #[test]
+    fn try_from_reshape_t() {
+        let arr: [u8; 6] = [0, 1, 2, 3, 4, 5];
+        assert_eq!(
+            Array2::<u8>::try_from(&arr, [4, 2]),
+            Err(ShapeError::SizeMismatch {
+                shape: vec![4, 2],
+                size: 6
+            })
+        );
+        assert!(NdArray::<u8, 1>::try_from_vec(vec![1, 2], [3]).is_err());
+
+        let mut data: NdArray<u8, 3> = Array3::<u8>::try_from(&arr, [1, 2, 3]).unwrap();
+        let err: ShapeError = data.try_reshape([2, 2, 2]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "New Shape([2, 2, 2]) don't match with current Size(6)"
+        );
+        assert_eq!(*data.shape(), [1, 2, 3]);
+
+        data.try_reshape([3, 1, 2]).unwrap();
+        assert_eq!(data[[2, 0, 1]], 5);
+    }
+
+    // Test shapes whose size or number of bytes overflows
+    #[test]
+    fn overflowing_shape_t() {
+        use crate::core::checked_size;
+
+        assert_eq!(checked_size::<u8, 2>(&[usize::MAX, 2]), None);
+        assert_eq!(checked_size::<u8, 2>(&[usize::MAX / 2, 2]), None); // exceeds isize::MAX
+        assert_eq!(checked_size::<u64, 2>(&[1 << 30, 1 << 30]), None); // bytes overflow
+        assert_eq!(checked_size::<u64, 2>(&[1 << 20, 1 << 20]), Some(1 << 40));
+        assert_eq!(checked_size::<u8, 3>(&[0, usize::MAX, 2]), Some(0));
+
+        let data: NdArray<u8, 3> = Array3::<u8>::zeros([0, usize::MAX, 2]);
+        assert!(data.is_empty());
+        assert!(Array2::<u8>::try_from(&[0; 2], [usize::MAX, 2]).is_err());
+        let mut data: NdArray<u8, 2> = Array2::<u8>::zeros([2, 2]);
+        assert!(data.try_reshape([usize::MAX / 2 + 1, 4]).is_err());
+    }
+
+    // Test NdArray<T, N>::zeros(...) with a shape whose size overflows
+    #[test]
+    #[should_panic(
+        expected = "Shape([18446744073709551615, 2]) exceeds the maximum Size(1152921504606846975)"
+    )]
+    fn zeros_overflow_t() {
+        Array2::<u64>::zeros([usize::MAX, 2]);
+    }
+
+    // Test NdArray<T, N>::full(...) and the _like(...) constructors for a 3-D NdArray
+    #[test]
+    fn full_like_3dim_t() {
+        let data: NdArray<i8, 3> = Array3::<i8>::full(-3, [2, 1, 3]);
+        assert_eq!(data.as_slice(), &[-3; 6]);
+
+        let ones: NdArray<f32, 3> = Array3::<f32>::ones_like(&data);
+        let zeros: NdArray<u64, 3> = Array3::<u64>::zeros_like(&data);
+        let full: NdArray<char, 3> = Array3::<char>::full_like(&data, 'x');
+        assert_eq!(*ones.shape(), [2, 1, 3]);
+        assert_eq!(ones.as_slice(), &[1.0; 6]);
+        assert_eq!(zeros.as_slice(), &[0; 6]);
+        assert_eq!(full[[1, 0, 2]], 'x');
+
+        assert_eq!(
+            *Array3::<u8>::zeros_like(&Array3::<u8>::zeros([2, 0, 4])).shape(),
+            [2, 0, 4]
+        );
+        assert!(Array3::<u8>::ones_like(&Array3::<i8>::new()).is_empty());
+    }
+
+    // Test NdArray<T, 2>::eye(...) for square and rectangular shapes with offset diagonals
+    #[test]
+    fn eye_2dim_t() {
+        assert_eq!(Array2::<u8>::identity(3), Array2::<u8>::eye(3, 3, 0));
+        assert_eq!(
+            Array2::<u8>::identity(3).as_slice(),
+            &[1, 0, 0, 0, 1, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            Array2::<i16>::eye(4, 2, -2).as_slice(),
+            &[0, 0, 0, 0, 1, 0, 0, 1]
+        );
+        assert_eq!(Array2::<f32>::eye(2, 2, 5).as_slice(), &[0.0; 4]);
+        assert!(Array2::<f32>::identity(0).is_empty());
+    }
+
+    // Test NdArray<T, 2>::from_diag(...) and NdArray<T, 2>::diagonal(...) for square and rectangular shapes
+    #[test]
+    fn diagonal_2dim_t() {
+        let diag: NdArray<f64, 1> = Array::<f64>::from(&[1.5, -2.0], [2]);
+        let data: NdArray<f64, 2> = Array2::<f64>::from_diag(&diag);
+        assert_eq!(data.as_slice(), &[1.5, 0.0, 0.0, -2.0]);
+        assert_eq!(data.diagonal(0), diag);
+
+        let mut data: NdArray<u32, 2> = Array2::<u32>::arange(12);
+        data.reshape([4, 3]);
+        assert_eq!(data.diagonal(0).as_slice(), &[0, 4, 8]);
+        assert_eq!(data.diagonal(-2).as_slice(), &[6, 10]);
+        assert_eq!(data.diagonal(2).as_slice(), &[2]);
+        assert!(data.diagonal(3).is_empty());
+        assert!(data.diagonal(-4).is_empty());
+        assert!(Array2::<u32>::new().diagonal(0).is_empty());
+    }
+
+    // Test NdArray<T, N>::linspace(...) with and without the endpoint, and with fewer than two values
+    #[test]
+    fn linspace_t() {
+        let data: NdArray<f64, 1> = Array::<f64>::linspace(0.0, 1.0, 1000, true);
+        assert_eq!(*data.len(), 1000);
+        assert_eq!(data[[999]], 1.0);
+        assert!((data[[1]] - 1.0 / 999.0).abs() < 1e-15);
+
+        let data: NdArray<f32, 2> = Array2::<f32>::linspace(2.0, -1.0, 3, false);
+        assert_eq!(data.as_slice(), &[2.0, 1.0, 0.0]);
+        assert_eq!(*data.shape(), [1, 3]);
+
+        assert_eq!(Array::<f64>::linspace(3.0, 5.0, 1, true).as_slice(), &[3.0]);
+        assert!(Array::<f64>::linspace(3.0, 5.0, 0, true).is_empty());
+    }
+
+    // Test NdArray<T, N>::logspace(...) and NdArray<T, N>::geomspace(...) for frequency sweeps
+    #[test]
+    fn logspace_geomspace_t() {
+        let data: NdArray<f64, 1> = Array::<f64>::logspace(1.0, 4.0, 7, 10.0);
+        assert_eq!(data[[0]], 10.0);
+        assert!((data[[1]] - 10f64.powf(1.5)).abs() < 1e-9);
+        assert!((data[[6]] - 1e4).abs() < 1e-9);
+        let data: NdArray<f32, 2> = Array2::<f32>::logspace(0.0, 4.0, 5, 2.0);
+        assert_eq!(data.as_slice(), &[1.0, 2.0, 4.0, 8.0, 16.0]);
+
+        let data: NdArray<f64, 1> = Array::<f64>::geomspace(20.0, 20000.0, 4);
+        assert_eq!(data[[0]], 20.0);
+        assert!((data[[1]] - 200.0).abs() < 1e-9);
+        assert!((data[[2]] - 2000.0).abs() < 1e-9);
+        assert_eq!(data[[3]], 20000.0);
+        assert!(Array::<f64>::geomspace(1.0, 2.0, 0).is_empty());
+    }
+
+    // Test NdArray<T, N>::geomspace(...) with signs which differ
+    #[test]
+    #[should_panic(expected = "Start(-1.0) and Stop(8.0) must be non-zero with the same sign")]
+    fn geomspace_sign_t() {
+        Array::<f64>::geomspace(-1.0, 8.0, 4);
+    }
+
+    // Test NdArray<T, N>::arange_f(...) with fractional, negative and unreachable steps
+    #[test]
+    fn arange_f_t() {
+        let data: NdArray<f64, 1> = Array::<f64>::arange_f(0.0, 1.0, 0.1);
+        assert_eq!(*data.len(), 10);
+        assert!((data[[9]] - 0.9).abs() < 1e-12);
+
+        let data: NdArray<f32, 2> = Array2::<f32>::arange_f(-1.0, 1.0, 0.75);
+        assert_eq!(data.as_slice(), &[-1.0, -0.25, 0.5]);
+        assert_eq!(*data.shape(), [1, 3]);
+
+        assert_eq!(
+            Array::<f64>::arange_f(2.0, -1.0, -1.5).as_slice(),
+            &[2.0, 0.5]
+        );
+        assert!(Array::<f64>::arange_f(0.0, 1.0, -0.1).is_empty());
+        assert!(Array::<f64>::arange_f(1.0, 1.0, 0.1).is_empty());
+    }
+
+    // Test NdArray<T, N>::arange_f(...) with a step of zero
+    #[test]
+    #[should_panic(expected = "Step cannot be zero")]
+    fn arange_f_zero_step_t() {
+        Array::<f64>::arange_f(0.0, 1.0, 0.0);
+    }
+
+    // Test meshgrid(...) and meshgrid3(...) with both indexing conventions
+    #[test]
+    fn meshgrid_t() {
+        let x: NdArray<u8, 1> = Array::<u8>::from(&[1, 2, 3], [3]);
+        let y: NdArray<u8, 1> = Array::<u8>::from(&[7, 8], [2]);
+        let (xx, yy) = meshgrid(&x, &y, Indexing::Ij);
+        assert_eq!(*xx.shape(), [3, 2]);
+        assert_eq!(xx.as_slice(), &[1, 1, 2, 2, 3, 3]);
+        assert_eq!(yy.as_slice(), &[7, 8, 7, 8, 7, 8]);
+
+        let z: NdArray<u8, 1> = Array::<u8>::from(&[0, 9], [2]);
+        let (xx, yy, zz) = meshgrid3(&x, &y, &z, Indexing::Xy);
+        assert_eq!(*xx.shape(), [2, 3, 2]);
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..2 {
+                    assert_eq!(xx[[i, j, k]], x[[j]]);
+                    assert_eq!(yy[[i, j, k]], y[[i]]);
+                    assert_eq!(zz[[i, j, k]], z[[k]]);
+                }
+            }
+        }
+
+        let (xx, _) = meshgrid(&x, &Array::<u8>::from(&[], [0]), Indexing::Xy);
+        assert_eq!(*xx.shape(), [0, 3]);
+    }
+
+    // Test NdArray<T, N>::from_shape_fn(...) visits each index of a 3-D NdArray once, in row-major order
+    #[test]
+    fn from_shape_fn_3dim_t() {
+        let mut visited: Vec<[usize; 3]> = Vec::new();
+        let data: NdArray<String, 3> = NdArray::<String, 3>::from_shape_fn([2, 1, 2], |index| {
+            visited.push(index);
+            format!("{:?}", index)
+        });
+        assert_eq!(visited, vec![[0, 0, 0], [0, 0, 1], [1, 0, 0], [1, 0, 1]]);
+        assert_eq!(data[[1, 0, 0]], "[1, 0, 0]");
+
+        let data: NdArray<u8, 3> = Array3::<u8>::from_shape_fn([2, 0, 2], |_| unreachable!());
+        assert!(data.is_empty());
+    }
+
+    // Test NdArray<T, N>::from_shape_vec(...) takes the ownership of the Vec, and validates its length
+    #[test]
+    fn from_shape_vec_t() {
+        let values: Vec<u64> = (0..8).collect();
+        let ptr: *const u64 = values.as_ptr();
+        let data: NdArray<u64, 3> = NdArray::<u64, 3>::from_shape_vec([2, 2, 2], values).unwrap();
+        assert_eq!(data.as_ptr(), ptr);
+        assert_eq!(data[[1, 0, 1]], 5);
+
+        assert_eq!(
+            NdArray::<u64, 2>::from_shape_vec([3, 3], vec![1; 8]),
+            Err(ShapeError::SizeMismatch {
+                shape: vec![3, 3],
+                size: 8
+            })
+        );
+    }
+
+    // Test NdArray<T, N>::try_zeros(...) returns an allocation error for a shape whose size overflows
+    #[test]
+    fn try_zeros_t() {
+        let data: NdArray<u32, 3> = NdArray::<u32, 3>::try_zeros([2, 0, usize::MAX]).unwrap();
+        assert!(data.is_empty());
+        assert_eq!(
+            NdArray::<u8, 2>::try_zeros([3, 2]).unwrap().as_slice(),
+            &[0; 6]
+        );
+        assert_eq!(
+            NdArray::<u64, 2>::try_zeros([1 << 40, 1 << 30]),
+            Err(NdimError::Allocation { size: usize::MAX })
+        );
+    }
+
+    // Test the conversion of nested arrays and rows of type Vec<T>
+    #[test]
+    fn from_nested_t() {
+        let data: NdArray<i32, 2> = [[1, -2], [3, -4], [5, -6]].into();
+        assert_eq!(data, Array2::<i32>::from(&[1, -2, 3, -4, 5, -6], [3, 2]));
+        let data: NdArray<u8, 3> = <Array3<u8> as From<_>>::from([[[1], [2]]]);
+        assert_eq!(*data.shape(), [1, 2, 1]);
+
+        let rows: Vec<Vec<String>> = vec![vec!["a".to_string()], vec!["b".to_string()]];
+        let data: NdArray<String, 2> = rows.try_into().unwrap();
+        assert_eq!(data[[1, 0]], "b");
+
+        let data: NdArray<u8, 2> = Vec::<Vec<u8>>::new().try_into().unwrap();
+        assert_eq!(*data.shape(), [0, 0]);
+        let data: Result<NdArray<u8, 2>, ShapeError> =
+            vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]].try_into();
+        assert_eq!(
+            data.unwrap_err().to_string(),
+            "Row(2) has Length(3), expected Length(2)"
+        );
+    }
+
+    // Test the array! macro for 1-D, 2-D and 3-D literals, with trailing commas and expressions
+    #[test]
+    fn array_macro_t() {
+        let data: NdArray<u16, 1> = array![4, 2 * 3, 8,];
+        assert_eq!(data.as_slice(), &[4, 6, 8]);
+
+        let data: NdArray<f64, 2> = array![[0.5, 1.5, 2.5], [3.5, 4.5, 5.5],];
+        assert_eq!(*data.shape(), [2, 3]);
+        assert_eq!(data[[1, 2]], 5.5);
+
+        let data: NdArray<char, 3> = array![[['a'], ['b']], [['c'], ['d']], [['e'], ['f']]];
+        assert_eq!(*data.shape(), [3, 2, 1]);
+        assert_eq!(data[[2, 1, 0]], 'f');
+    }
+
+    // Test NdArray<MaybeUninit<T>, N>::uninit(...) and assume_init(...) reuse the buffer, for a type which is not Copy
+    #[test]
+    fn uninit_3dim_t() {
+        let mut data = NdArray::<std::mem::MaybeUninit<String>, 3>::uninit([2, 1, 2]);
+        assert_eq!(*data.len(), 4);
+        for (pos, value) in data.as_mut_slice().iter_mut().enumerate() {
+            value.write(pos.to_string());
+        }
+        let ptr = data.as_ptr() as *const String;
+
+        let data: NdArray<String, 3> = unsafe { data.assume_init() };
+        assert_eq!(data.as_ptr(), ptr);
+        let size: usize = std::mem::size_of::<String>();
+        assert_eq!(*data.strides(), [2 * size, 2 * size, size]);
+        assert_eq!(*data.shape(), [2, 1, 2]);
+        assert_eq!(data[[1, 0, 1]], "3");
+    }
+
+    // Test fill(...), assign(...) and copy_from_slice(...) reuse the buffer of a 3-D NdArray
+    #[test]
+    fn fill_assign_3dim_t() {
+        let mut data = Array3::<i64>::zeros([2, 2, 3]);
+        let ptr = data.as_ptr();
+        data.fill(9);
+        assert!(data.as_slice().iter().all(|x| *x == 9));
+
+        let other = Array3::<i64>::full(-1, [2, 2, 3]);
+        data.assign(&other);
+        assert_eq!(data, other);
+
+        let values: Vec<i64> = (0..12).collect();
+        data.copy_from_slice(&values);
+        assert_eq!(data[[1, 1, 2]], 11);
+        assert_eq!(data.as_ptr(), ptr);
+    }
+
+    // Test assigning an NdArray of a different shape
+    #[test]
+    #[should_panic(expected = "Shape([2, 3]) don't match with Shape([3, 2])")]
+    fn assign_wrong_shape_t() {
+        let mut data = Array2::<u8>::zeros([2, 3]);
+        data.assign(&Array2::<u8>::zeros([3, 2]));
+    }
+
+    // Test tri(...) matches tril(...) and triu(...) of a matrix of ones
+    #[test]
+    fn tri_2dim_t() {
+        let ones = Array2::<i8>::ones([3, 4]);
+        for k in -3..4 {
+            assert_eq!(Array2::<i8>::tri(3, 4, k), ones.tril(k));
+            let upper: Vec<i8> = ones.triu(k).as_slice().to_vec();
+            let lower: Vec<i8> = ones.tril(k - 1).as_slice().to_vec();
+            let sum: Vec<i8> = upper.iter().zip(lower.iter()).map(|(a, b)| a + b).collect();
+            assert_eq!(sum, vec![1; 12]); // triu(k) and tril(k - 1) are complements
+        }
+        assert!(Array2::<i8>::new().tril(0).is_empty());
+        assert_eq!(Array2::<i8>::zeros([0, 0]).triu(0).shape(), &[0, 0]);
+        assert_eq!(Array2::<i8>::zeros([0, 3]).tril(1).shape(), &[0, 3]);
+    }
+
+    // Test changing the dimension of NdArrays, including an empty NdArray
+    #[test]
+    fn into_shape_t() {
+        let data = Array3::<i16>::from_shape_fn([2, 3, 4], |[i, j, k]| (i * 12 + j * 4 + k) as i16);
+        let ptr = data.as_ptr();
+        let flat: NdArray<i16, 1> = data.into_shape([24]);
+        assert_eq!(flat.as_ptr(), ptr);
+        assert_eq!(flat.as_slice(), (0..24).collect::<Vec<i16>>().as_slice());
+
+        let data: NdArray<i16, 4> = flat.into_shape([2, 2, 3, 2]);
+        assert_eq!(data[[1, 1, 2, 1]], 23);
+        assert_eq!(*data.strides(), [24, 12, 4, 2]);
+
+        let empty: NdArray<i16, 2> = Array3::<i16>::new().into_shape([0, 5]);
+        assert!(empty.is_empty());
+    }
+
+    // Test changing the dimension to a shape of a different size
+    #[test]
+    #[should_panic(expected = "New Shape([4, 4]) don't match with current Size(12)")]
+    fn into_shape_mismatch_t() {
+        Array::<u8>::arange(12).into_shape([4, 4]);
+    }
+
+    // Test changing the dimension with an inferred dimension
+    #[test]
+    fn into_shape_infer_t() {
+        let data: NdArray<u8, 3> = Array2::<u8>::zeros([6, 4]).into_shape_infer([2, INFER, 3]);
+        assert_eq!(*data.shape(), [2, 4, 3]);
+        let data: NdArray<u8, 1> = data.into_shape_infer([INFER]);
+        assert_eq!(*data.shape(), [24]);
+    }
+
+    // Test into_vec(...) returns the buffer of the NdArray
+    #[test]
+    fn into_vec_t() {
+        let data = Array3::<String>::from_shape_fn([1, 2, 2], |[_, j, k]| format!("{}{}", j, k));
+        let ptr = data.as_ptr();
+        let flat = data.flatten();
+        assert_eq!(*flat.shape(), [4]);
+
+        let values: Vec<String> = data.into_vec();
+        assert_eq!(values.as_ptr(), ptr);
+        assert_eq!(values, flat.as_slice());
+    }
+
+    // Test removing and inserting axes of length 1
+    #[test]
+    fn squeeze_insert_axis_t() {
+        let data = Array3::<u8>::from_shape_fn([1, 3, 1], |[_, j, _]| j as u8);
+        let data: NdArray<u8, 1> = data.squeeze();
+        assert_eq!(data.as_slice(), &[0, 1, 2]);
+
+        let data: NdArray<u8, 2> = data.insert_axis(1);
+        assert_eq!(*data.shape(), [3, 1]);
+        let data: NdArray<u8, 3> = data.insert_axis(2);
+        assert_eq!(*data.shape(), [3, 1, 1]);
+        assert_eq!(data[[2, 0, 0]], 2);
+
+        let empty: NdArray<u8, 4> = Array3::<u8>::new().insert_axis(3);
+        assert!(empty.is_empty());
+    }
+
+    // Test squeezing into a dimension which differs from the axes longer than 1
+    #[test]
+    #[should_panic(expected = "Dimension(1) must match with the squeezed Dimension(2)")]
+    fn squeeze_wrong_dimension_t() {
+        Array3::<u8>::zeros([2, 1, 2]).squeeze::<1>();
+    }
+
+    // Test signed indices of a 3-D NdArray against the equivalent unsigned indices
+    #[test]
+    fn at_3dim_t() {
+        let mut data =
+            Array3::<i32>::from_shape_fn([2, 3, 4], |[i, j, k]| (i * 12 + j * 4 + k) as i32);
+        assert_eq!(*data.at([-1, -1, -1]), 23);
+        assert_eq!(*data.at([-2, 1, -4]), data[[0, 1, 0]]);
+        *data.at_mut([0, -3, 2]) = -1;
+        assert_eq!(data[[0, 0, 2]], -1);
+    }
+
+    // Test a negative index longer than its axis
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn at_out_of_bounds_t() {
+        let data = Array2::<u8>::zeros([2, 3]);
+        data.at([0, -4]);
+    }
+
+    // Test indexing NdArrays of 1 to 4 dimensions with tuples
+    #[test]
+    fn tuple_index_t() {
+        let mut data = Array::<u8>::arange(4);
+        data[(3,)] = 9;
+        assert_eq!(data[[3]], 9);
+
+        let coords: Vec<(usize, usize)> = vec![(0, 1), (2, 0)];
+        let mut data = Array2::<u8>::zeros([3, 2]);
+        for coord in coords {
+            data[coord] += 1;
+        }
+        assert_eq!(data.as_slice(), &[0, 1, 0, 0, 1, 0]);
+
+        let data = Array3::<usize>::from_shape_fn([2, 2, 2], |[i, j, k]| i * 4 + j * 2 + k);
+        assert_eq!(data[(1, 0, 1)], 5);
+        let data: NdArray<usize, 4> = data.insert_axis(0);
+        assert_eq!(data[(0, 1, 1, 0)], 6);
+    }
+
+    // Test mapping the elements of a 3-D NdArray to another type, by value and in-place
+    #[test]
+    fn map_3dim_t() {
+        let mut data =
+            Array3::<u32>::from_shape_fn([2, 3, 4], |[i, j, k]| (100 * i + 10 * j + k) as u32);
+        let text: NdArray<String, 3> = data.map(|x| x.to_string());
+        assert_eq!(*text.shape(), [2, 3, 4]);
+        assert_eq!(text[[1, 2, 3]], "123");
+        assert_eq!(text.mapv(|x| x.len())[[0, 0, 3]], 1);
+
+        data.map_inplace(|x| *x %= 10);
+        assert_eq!(
+            data,
+            Array3::<u32>::from_shape_fn([2, 3, 4], |[_, _, k]| k as u32)
+        );
+        assert!(NdArray::<u8, 3>::new().map(|x| *x).is_empty());
+    }
+
+    // Test element-wise operations on an empty NdArray created using NdArray::<T, N>::new()
+    // Check if the results are empty instead of panicking
+    #[test]
+    fn new_elementwise_ops_t() {
+        let a = NdArray::<f64, 2>::new();
+        let b = NdArray::<f64, 2>::new();
+        assert!((&a + &b).is_empty());
+        assert!((&a * 2.0).is_empty());
+        assert!(crate::math::maximum(&a, &b).is_empty());
+        assert!(a.eq_elem(&b).is_empty());
+        assert!(a.signum().is_empty());
+        assert!(a.abs().is_empty());
+        assert!(NdArray::<i32, 1>::new().signum().is_empty());
+    }
 }
@@ -0,0 +1,256 @@
+use std::fmt;
+
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{core::NdArray, error::NdimError, view::ArrayView};
+
+/// Descriptor of the element type of an NdArray, similar to `numpy.dtype`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// #
+/// # fn main() {
+/// let arr = NdArray::<i16, 2>::zeros([2, 3]);
+/// let dtype = arr.dtype();
+/// assert_eq!(dtype.name(), "i16");
+/// assert_eq!(dtype.size(), 2);
+/// assert!(dtype.is_integer() && dtype.is_signed() && !dtype.is_float());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DType {
+    name: &'static str,
+    size: usize,
+    kind: Kind,
+}
+
+/// Category of an element type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Bool,
+    Signed,
+    Unsigned,
+    Float,
+}
+
+impl DType {
+    /// Return the name of the element type, e.g. `f32`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the size of an element in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Return true if the element type is a floating point type
+    pub fn is_float(&self) -> bool {
+        self.kind == Kind::Float
+    }
+
+    /// Return true if the element type is a (signed or unsigned) integer type
+    pub fn is_integer(&self) -> bool {
+        matches!(self.kind, Kind::Signed | Kind::Unsigned)
+    }
+
+    /// Return true if the element type can represent negative values, i.e. signed integers and floating point types
+    pub fn is_signed(&self) -> bool {
+        matches!(self.kind, Kind::Signed | Kind::Float)
+    }
+
+    /// Return true if the element type is `bool`
+    pub fn is_bool(&self) -> bool {
+        self.kind == Kind::Bool
+    }
+}
+
+impl fmt::Display for DType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Element types with a [`DType`] descriptor
+pub trait HasDType {
+    /// The descriptor of the element type
+    const DTYPE: DType;
+}
+
+/// Implement `HasDType` for every primitive `$t` of the category `$kind`
+macro_rules! impl_has_dtype {
+    ($kind:ident => $($t:ty),*) => {
+        $(
+            impl HasDType for $t {
+                const DTYPE: DType = DType {
+                    name: stringify!($t),
+                    size: std::mem::size_of::<$t>(),
+                    kind: Kind::$kind,
+                };
+            }
+        )*
+    };
+}
+
+impl_has_dtype!(Bool => bool);
+impl_has_dtype!(Signed => i8, i16, i32, i64, i128, isize);
+impl_has_dtype!(Unsigned => u8, u16, u32, u64, u128, usize);
+impl_has_dtype!(Float => f32, f64);
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Return the number of dimensions (`N`) of the NdArray
+    pub fn ndim(&self) -> usize {
+        N
+    }
+
+    /// Return the size of an element in bytes
+    pub fn itemsize(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    /// Return the number of bytes occupied by the elements of the NdArray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 3>::zeros([2, 3, 4]);
+    /// assert_eq!(arr.ndim(), 3);
+    /// assert_eq!(arr.itemsize(), 8);
+    /// assert_eq!(arr.nbytes(), 192);
+    /// # }
+    /// ```
+    pub fn nbytes(&self) -> usize {
+        self.len() * self.itemsize()
+    }
+
+    /// Return the descriptor of the element type
+    pub fn dtype(&self) -> DType
+    where
+        T: HasDType,
+    {
+        T::DTYPE
+    }
+
+    /// Convert the elements to type U, or return [`NdimError::Cast`] for the first element which cannot be represented by type U (e.g. a negative value for an unsigned type, or `NaN` for an integer type)
+    ///
+    /// ## Note
+    /// Floats are truncated towards zero when converted to an integer type, similar to `as`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::error::NdimError;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 1>::from(&[1, 200, 300], [3]);
+    /// let wide = arr.try_cast::<i16>().unwrap();
+    /// assert_eq!(wide.as_slice(), &[1, 200, 300]);
+    ///
+    /// let err = arr.try_cast::<u8>().unwrap_err();
+    /// assert!(matches!(err, NdimError::Cast { position: 2, .. }));
+    /// # }
+    /// ```
+    pub fn try_cast<U>(&self) -> Result<NdArray<U, N>, NdimError>
+    where
+        T: Copy + ToPrimitive,
+        U: NumCast + HasDType,
+    {
+        let values: Vec<U> = self
+            .as_slice()
+            .iter()
+            .enumerate()
+            .map(|(position, value)| {
+                U::from(*value).ok_or(NdimError::Cast {
+                    position,
+                    dtype: U::DTYPE,
+                })
+            })
+            .collect::<Result<Vec<U>, NdimError>>()?;
+
+        Ok(NdArray::from_owned_vec(values, self.view_shape()))
+    }
+}
+
+impl<T, const N: usize> ArrayView<'_, T, N> {
+    /// Return the number of dimensions (`N`) of the view
+    pub fn ndim(&self) -> usize {
+        N
+    }
+
+    /// Return the size of an element in bytes
+    pub fn itemsize(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    /// Return the number of bytes occupied by the elements of the view (excluding any gaps between them)
+    pub fn nbytes(&self) -> usize {
+        self.len() * self.itemsize()
+    }
+
+    /// Return the descriptor of the element type
+    pub fn dtype(&self) -> DType
+    where
+        T: HasDType,
+    {
+        T::DTYPE
+    }
+}
+
+#[cfg(test)]
+mod dtype_t {
+    use crate::{
+        core::{Array, Array2},
+        dtype::{DType, HasDType},
+        error::NdimError,
+    };
+
+    // Test the descriptors of different element types
+    #[test]
+    fn dtype_flags_t() {
+        let flags = |dtype: DType| (dtype.is_float(), dtype.is_integer(), dtype.is_signed());
+        assert_eq!(flags(f32::DTYPE), (true, false, true));
+        assert_eq!(flags(u64::DTYPE), (false, true, false));
+        assert_eq!(flags(i8::DTYPE), (false, true, true));
+        assert!(bool::DTYPE.is_bool());
+        assert_eq!(u128::DTYPE.size(), 16);
+        assert_eq!(format!("{}", usize::DTYPE), "usize");
+    }
+
+    // Test the memory of an NdArray and its view
+    #[test]
+    fn nbytes_t() {
+        let data = Array2::<u32>::zeros([3, 5]);
+        let (_, right) = data.split_at(1, 2);
+        assert_eq!(data.nbytes(), 60);
+        assert_eq!(right.nbytes(), 36);
+        assert_eq!(right.dtype(), data.dtype());
+        assert_eq!(Array::<f32>::new().nbytes(), 0);
+    }
+
+    // Test try_cast(...) between floats and integers
+    #[test]
+    fn try_cast_t() {
+        let data = Array2::<f64>::from(&[0.5, -1.75, 2.0, 255.9], [2, 2]);
+        let ints = data.try_cast::<i16>().unwrap();
+        assert_eq!(ints.as_slice(), &[0, -1, 2, 255]);
+        assert_eq!(*ints.shape(), [2, 2]);
+
+        assert_eq!(
+            data.try_cast::<u8>().unwrap_err(),
+            NdimError::Cast {
+                position: 1,
+                dtype: u8::DTYPE
+            }
+        );
+        let nan = Array::<f32>::from(&[1.0, f32::NAN], [2]);
+        assert!(nan.try_cast::<i64>().is_err());
+        assert!(nan.try_cast::<f64>().unwrap()[[1]].is_nan());
+        assert!(Array2::<f64>::new().try_cast::<u8>().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+#[cfg(any(feature = "zarr", feature = "safetensors"))]
+use std::sync::Arc;
+
+#[cfg(feature = "zarr")]
+use crate::chunked::ZarrError;
+#[cfg(feature = "safetensors")]
+use crate::safetensors::SafetensorsError;
+use crate::{core::ShapeError, dtype::DType};
+
+/// Error returned by the fallible APIs of the crate, so that failures can be handled uniformly using `?`
+///
+/// The messages are the same as the panics of the infallible counterparts
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+/// use ndim::error::NdimError;
+///
+/// fn first_row(values: &[f64], cols: usize) -> Result<f32, NdimError> {
+///     let arr = NdArray::<f64, 2>::try_from(values, [values.len() / cols, cols])?;
+///     let row = arr.try_index_axis::<1>(0, 0)?;
+///     let row = row.to_owned().try_cast::<f32>()?;
+///     Ok(row.as_slice().iter().sum())
+/// }
+///
+/// # fn main() {
+/// assert_eq!(first_row(&[1.0, 2.0, 3.0, 4.0], 2), Ok(3.0));
+/// assert!(matches!(first_row(&[1.0, 2.0, 3.0], 2), Err(NdimError::Shape(_))));
+/// assert!(matches!(first_row(&[], 2), Err(NdimError::IndexOutOfBounds { .. })));
+/// # }
+/// ```
+///
+/// With the `zarr` and `safetensors` features, a `ZarrError` or `SafetensorsError` also converts into an `NdimError`.
+/// As these hold an `io::Error`, they are shared in an `Arc`, and two of them are only equal if they are the same error
+#[derive(Debug, Clone)]
+pub enum NdimError {
+    /// A shape does not match the number of elements
+    Shape(ShapeError),
+    /// An index is not within the shape
+    IndexOutOfBounds {
+        index: Vec<usize>,
+        shape: Vec<usize>,
+    },
+    /// The element at the (flat) `position` cannot be represented by the target type
+    Cast { position: usize, dtype: DType },
+    /// The memory for `size` elements cannot be allocated
+    Allocation { size: usize },
+    /// An axis is not less than the dimension `ndim`
+    AxisOutOfRange { axis: usize, ndim: usize },
+    /// A chunked array could not be created, read or written
+    #[cfg(feature = "zarr")]
+    Zarr(Arc<ZarrError>),
+    /// NdArrays could not be saved or loaded in the safetensors format
+    #[cfg(feature = "safetensors")]
+    Safetensors(Arc<SafetensorsError>),
+}
+
+impl PartialEq for NdimError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NdimError::Shape(a), NdimError::Shape(b)) => a == b,
+            (
+                NdimError::IndexOutOfBounds { index, shape },
+                NdimError::IndexOutOfBounds {
+                    index: other_index,
+                    shape: other_shape,
+                },
+            ) => index == other_index && shape == other_shape,
+            (
+                NdimError::Cast { position, dtype },
+                NdimError::Cast {
+                    position: other_position,
+                    dtype: other_dtype,
+                },
+            ) => position == other_position && dtype == other_dtype,
+            (NdimError::Allocation { size }, NdimError::Allocation { size: other_size }) => {
+                size == other_size
+            }
+            (
+                NdimError::AxisOutOfRange { axis, ndim },
+                NdimError::AxisOutOfRange {
+                    axis: other_axis,
+                    ndim: other_ndim,
+                },
+            ) => axis == other_axis && ndim == other_ndim,
+            #[cfg(feature = "zarr")]
+            (NdimError::Zarr(a), NdimError::Zarr(b)) => Arc::ptr_eq(a, b),
+            #[cfg(feature = "safetensors")]
+            (NdimError::Safetensors(a), NdimError::Safetensors(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for NdimError {}
+
+impl fmt::Display for NdimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NdimError::Shape(err) => write!(f, "{}", err),
+            NdimError::IndexOutOfBounds { index, shape } => {
+                write!(
+                    f,
+                    "Index out of bounds: Index({:?}) for Shape({:?})",
+                    index, shape
+                )
+            }
+            NdimError::Cast { position, dtype } => write!(
+                f,
+                "Element at Position({}) cannot be cast to DType({})",
+                position, dtype
+            ),
+            NdimError::Allocation { size } => {
+                write!(f, "Memory for Size({}) cannot be allocated", size)
+            }
+            NdimError::AxisOutOfRange { axis, ndim } => {
+                write!(f, "Axis({}) out of bounds for Dimension({})", axis, ndim)
+            }
+            #[cfg(feature = "zarr")]
+            NdimError::Zarr(err) => write!(f, "{}", err),
+            #[cfg(feature = "safetensors")]
+            NdimError::Safetensors(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for NdimError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NdimError::Shape(err) => Some(err),
+            #[cfg(feature = "zarr")]
+            NdimError::Zarr(err) => Some(err.as_ref()),
+            #[cfg(feature = "safetensors")]
+            NdimError::Safetensors(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ShapeError> for NdimError {
+    fn from(err: ShapeError) -> Self {
+        NdimError::Shape(err)
+    }
+}
+
+#[cfg(feature = "zarr")]
+impl From<ZarrError> for NdimError {
+    fn from(err: ZarrError) -> Self {
+        NdimError::Zarr(Arc::new(err))
+    }
+}
+
+#[cfg(feature = "safetensors")]
+impl From<SafetensorsError> for NdimError {
+    fn from(err: SafetensorsError) -> Self {
+        NdimError::Safetensors(Arc::new(err))
+    }
+}
+
+#[cfg(test)]
+mod error_t {
+    use std::error::Error;
+
+    use crate::{
+        core::{Array2, ShapeError},
+        dtype::HasDType,
+        error::NdimError,
+    };
+
+    // Test the conversion of a ShapeError and the messages of the variants
+    #[test]
+    fn messages_t() {
+        let err: NdimError = Array2::<u8>::try_from(&[1, 2, 3], [2, 2])
+            .unwrap_err()
+            .into();
+        assert_eq!(
+            err.to_string(),
+            "Shape([2, 2]) don't match with array Size(3)"
+        );
+        assert!(err.source().unwrap().is::<ShapeError>());
+
+        let err = NdimError::AxisOutOfRange { axis: 3, ndim: 2 };
+        assert_eq!(err.to_string(), "Axis(3) out of bounds for Dimension(2)");
+        let err = NdimError::Cast {
+            position: 4,
+            dtype: u8::DTYPE,
+        };
+        assert_eq!(
+            err.to_string(),
+            "Element at Position(4) cannot be cast to DType(u8)"
+        );
+        assert!(err.source().is_none());
+    }
+
+    // Test the conversion of a ZarrError and a SafetensorsError with `?`
+    #[cfg(all(feature = "zarr", feature = "safetensors"))]
+    #[test]
+    fn io_errors_t() {
+        use crate::{
+            chunked::{ChunkedArray, ZarrError},
+            safetensors::{SafetensorsError, SafetensorsFile},
+        };
+
+        fn open_safetensors(dir: &std::path::Path) -> Result<(), NdimError> {
+            SafetensorsFile::open(dir.join("missing.safetensors"))?;
+            Ok(())
+        }
+
+        fn open_chunked(dir: &std::path::Path) -> Result<(), NdimError> {
+            ChunkedArray::<f32, 2>::open(dir.join("missing.zarr"))?;
+            Ok(())
+        }
+
+        let dir = std::env::temp_dir();
+        let err = open_safetensors(&dir).unwrap_err();
+        assert!(matches!(err, NdimError::Safetensors(_)));
+        assert!(err.source().unwrap().is::<SafetensorsError>());
+        assert_eq!(err, err.clone());
+
+        let err = open_chunked(&dir).unwrap_err();
+        assert!(matches!(err, NdimError::Zarr(_)));
+        assert!(err.source().unwrap().is::<ZarrError>());
+        assert_ne!(err, open_chunked(&dir).unwrap_err());
+    }
+}
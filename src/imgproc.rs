@@ -0,0 +1,323 @@
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::{core::NdArray, dtype::HasDType};
+
+/// Interpolation of the pixel values between the pixel centres, used while resizing and warping images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Value of the closest pixel
+    Nearest,
+    /// Weighted average of the 4 closest pixels
+    #[default]
+    Bilinear,
+}
+
+/// Image of `height x width` pixels with `channels` interleaved channels (`HWC` layout), stored in row-major order
+struct Image<'a, T> {
+    data: &'a [T],
+    height: usize,
+    width: usize,
+    channels: usize,
+}
+
+impl<T: Copy + ToPrimitive> Image<'_, T> {
+    fn at(&self, y: usize, x: usize, channel: usize) -> f64 {
+        self.data[(y * self.width + x) * self.channels + channel]
+            .to_f64()
+            .unwrap_or(f64::NAN)
+    }
+
+    /// Sample `channel` at the (sub-pixel) position `(y, x)`, where pixel centres are at integer positions. Returns `None` outside the pixel centres
+    fn sample(&self, y: f64, x: f64, channel: usize, interpolation: Interpolation) -> Option<f64> {
+        // tolerate rounding errors of the transformed positions at the borders
+        let eps: f64 = 1e-9;
+        let (max_y, max_x): (f64, f64) = ((self.height - 1) as f64, (self.width - 1) as f64);
+        if !(-eps..=max_y + eps).contains(&y) || !(-eps..=max_x + eps).contains(&x) {
+            return None;
+        }
+        let (y, x): (f64, f64) = (y.clamp(0.0, max_y), x.clamp(0.0, max_x));
+
+        Some(match interpolation {
+            Interpolation::Nearest => self.at(y.round() as usize, x.round() as usize, channel),
+            Interpolation::Bilinear => {
+                let (y0, x0): (usize, usize) = (y.floor() as usize, x.floor() as usize);
+                let (y1, x1): (usize, usize) =
+                    ((y0 + 1).min(self.height - 1), (x0 + 1).min(self.width - 1));
+                let (dy, dx): (f64, f64) = (y - y0 as f64, x - x0 as f64);
+                let top: f64 =
+                    self.at(y0, x0, channel) * (1.0 - dx) + self.at(y0, x1, channel) * dx;
+                let bottom: f64 =
+                    self.at(y1, x0, channel) * (1.0 - dx) + self.at(y1, x1, channel) * dx;
+                top * (1.0 - dy) + bottom * dy
+            }
+        })
+    }
+}
+
+/// Convert an interpolated value to `T`, rounding for integer types. Values not representable are `T::default()`
+fn from_f64<T: NumCast + Default + HasDType>(value: f64) -> T {
+    let value: f64 = if T::DTYPE.is_float() {
+        value
+    } else {
+        value.round()
+    };
+    T::from(value).unwrap_or_default()
+}
+
+/// Resize `image` to `height x width` pixels, mapping the pixel centres (half-pixel offsets) similar to OpenCV and Pillow
+fn resize_image<T>(
+    image: &Image<'_, T>,
+    height: usize,
+    width: usize,
+    interpolation: Interpolation,
+) -> Vec<T>
+where
+    T: Copy + Default + ToPrimitive + NumCast + HasDType,
+{
+    let mut out: Vec<T> = Vec::with_capacity(height * width * image.channels);
+    if image.height == 0 || image.width == 0 {
+        out.resize(height * width * image.channels, T::default());
+        return out;
+    }
+
+    let (scale_y, scale_x): (f64, f64) = (
+        image.height as f64 / height as f64,
+        image.width as f64 / width as f64,
+    );
+    for row in 0..height {
+        for col in 0..width {
+            let (y, x): (f64, f64) = match interpolation {
+                // nearest pixel of the centre, which is never outside the image
+                Interpolation::Nearest => (
+                    (((row as f64 + 0.5) * scale_y).floor()).min(image.height as f64 - 1.0),
+                    (((col as f64 + 0.5) * scale_x).floor()).min(image.width as f64 - 1.0),
+                ),
+                Interpolation::Bilinear => (
+                    ((row as f64 + 0.5) * scale_y - 0.5).clamp(0.0, image.height as f64 - 1.0),
+                    ((col as f64 + 0.5) * scale_x - 0.5).clamp(0.0, image.width as f64 - 1.0),
+                ),
+            };
+            for channel in 0..image.channels {
+                let value: f64 = image
+                    .sample(y, x, channel, interpolation)
+                    .unwrap_or_default();
+                out.push(from_f64(value));
+            }
+        }
+    }
+    out
+}
+
+/// Warp `image` by the inverse affine map `matrix` from output to input positions `[x, y]`
+fn warp_image<T>(
+    image: &Image<'_, T>,
+    matrix: &[[f64; 3]; 2],
+    interpolation: Interpolation,
+) -> Vec<T>
+where
+    T: Copy + Default + ToPrimitive + NumCast + HasDType,
+{
+    let mut out: Vec<T> = Vec::with_capacity(image.data.len());
+    for row in 0..image.height {
+        for col in 0..image.width {
+            let (x, y): (f64, f64) = (col as f64, row as f64);
+            let src_x: f64 = matrix[0][0] * x + matrix[0][1] * y + matrix[0][2];
+            let src_y: f64 = matrix[1][0] * x + matrix[1][1] * y + matrix[1][2];
+            for channel in 0..image.channels {
+                out.push(match image.sample(src_y, src_x, channel, interpolation) {
+                    Some(value) => from_f64(value),
+                    None => T::default(),
+                });
+            }
+        }
+    }
+    out
+}
+
+impl<T: Copy + Default + ToPrimitive + NumCast + HasDType> NdArray<T, 2> {
+    /// Return the image resized to `new_hw = [height, width]` pixels. Pixel centres are mapped with half-pixel offsets, similar to OpenCV and Pillow, and integer pixel values are rounded
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::imgproc::Interpolation;
+    /// #
+    /// # fn main() {
+    /// let image = NdArray::<u8, 2>::from(&[0, 100, 200, 50], [2, 2]);
+    /// let nearest = image.resize([4, 4], Interpolation::Nearest);
+    /// assert_eq!(&nearest.as_slice()[..4], &[0, 0, 100, 100]);
+    ///
+    /// let bilinear = image.resize([2, 4], Interpolation::Bilinear);
+    /// assert_eq!(bilinear.as_slice(), &[0, 25, 75, 100, 200, 163, 88, 50]);
+    /// # }
+    /// ```
+    pub fn resize(&self, new_hw: [usize; 2], interpolation: Interpolation) -> NdArray<T, 2> {
+        let [height, width] = self.view_shape();
+        let image = Image {
+            data: self.as_slice(),
+            height,
+            width,
+            channels: 1,
+        };
+        NdArray::from_owned_vec(
+            resize_image(&image, new_hw[0], new_hw[1], interpolation),
+            new_hw,
+        )
+    }
+
+    /// Return the image warped by the affine `matrix`, which maps every output position `[x, y]` (column, row) to its input position `matrix * [x, y, 1]`, similar to `scipy.ndimage.affine_transform`. Output pixels mapped outside the input are `T::default()`
+    ///
+    /// ## Note
+    /// To warp by a forward map (input to output positions), pass its inverse
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::imgproc::Interpolation;
+    /// #
+    /// # fn main() {
+    /// let image = NdArray::<f32, 2>::from(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+    /// let shifted = image.warp_affine([[1.0, 0.0, 1.0], [0.0, 1.0, 0.0]], Interpolation::Nearest); // shift left by 1 pixel
+    /// assert_eq!(shifted.as_slice(), &[2.0, 3.0, 0.0, 5.0, 6.0, 0.0]);
+    ///
+    /// let half = image.warp_affine([[1.0, 0.0, 0.5], [0.0, 1.0, 0.0]], Interpolation::Bilinear);
+    /// assert_eq!(half.as_slice(), &[1.5, 2.5, 0.0, 4.5, 5.5, 0.0]);
+    /// # }
+    /// ```
+    pub fn warp_affine(
+        &self,
+        matrix: [[f64; 3]; 2],
+        interpolation: Interpolation,
+    ) -> NdArray<T, 2> {
+        let [height, width] = self.view_shape();
+        let image = Image {
+            data: self.as_slice(),
+            height,
+            width,
+            channels: 1,
+        };
+        NdArray::from_owned_vec(warp_image(&image, &matrix, interpolation), [height, width])
+    }
+}
+
+impl<T: Copy + Default + ToPrimitive + NumCast + HasDType> NdArray<T, 3> {
+    /// Return the image of shape `[height, width, channels]` resized to `new_hw = [height, width]` pixels, resizing each channel separately
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::imgproc::Interpolation;
+    /// #
+    /// # fn main() {
+    /// let rgb = NdArray::<u8, 3>::from(&[255, 0, 10, 0, 255, 20], [1, 2, 3]);
+    /// let resized = rgb.resize([1, 1], Interpolation::Bilinear);
+    /// assert_eq!(resized.shape(), &[1, 1, 3]);
+    /// assert_eq!(resized.as_slice(), &[128, 128, 15]);
+    /// # }
+    /// ```
+    pub fn resize(&self, new_hw: [usize; 2], interpolation: Interpolation) -> NdArray<T, 3> {
+        let [height, width, channels] = self.view_shape();
+        let image = Image {
+            data: self.as_slice(),
+            height,
+            width,
+            channels,
+        };
+        let values: Vec<T> = resize_image(&image, new_hw[0], new_hw[1], interpolation);
+        NdArray::from_owned_vec(values, [new_hw[0], new_hw[1], channels])
+    }
+
+    /// Return the image of shape `[height, width, channels]` warped by the affine `matrix`, warping each channel separately. See the 2-D `warp_affine` for the map
+    pub fn warp_affine(
+        &self,
+        matrix: [[f64; 3]; 2],
+        interpolation: Interpolation,
+    ) -> NdArray<T, 3> {
+        let [height, width, channels] = self.view_shape();
+        let image = Image {
+            data: self.as_slice(),
+            height,
+            width,
+            channels,
+        };
+        NdArray::from_owned_vec(
+            warp_image(&image, &matrix, interpolation),
+            [height, width, channels],
+        )
+    }
+}
+
+#[cfg(test)]
+mod imgproc_t {
+    use crate::{
+        core::{Array2, Array3},
+        imgproc::Interpolation,
+    };
+
+    // Test if upscaling and downscaling a float image preserves a linear gradient
+    #[test]
+    fn resize_gradient_t() {
+        let values: Vec<f64> = (0..16).map(|i| (i % 4) as f64).collect();
+        let image = Array2::<f64>::from(&values, [4, 4]);
+
+        let up = image.resize([8, 8], Interpolation::Bilinear);
+        assert_eq!(up.shape(), &[8, 8]);
+        let row: Vec<f64> = (0..8).map(|col| up[[3, col]]).collect();
+        assert_eq!(row, vec![0.0, 0.25, 0.75, 1.25, 1.75, 2.25, 2.75, 3.0]);
+
+        let down = image.resize([2, 2], Interpolation::Bilinear);
+        assert_eq!(down.as_slice(), &[0.5, 2.5, 0.5, 2.5]);
+        let nearest = image.resize([2, 2], Interpolation::Nearest);
+        assert_eq!(nearest.as_slice(), &[1.0, 3.0, 1.0, 3.0]);
+    }
+
+    // Test rotating a 3-D image by 90 degrees around its centre, keeping the channels
+    #[test]
+    fn warp_rotation_3dim_t() {
+        let values: Vec<u16> = (0..18).collect();
+        let image = Array3::<u16>::from(&values, [3, 3, 2]);
+        // output [x, y] samples input [y, 2 - x], i.e. rotates counter-clockwise
+        let rotated =
+            image.warp_affine([[0.0, 1.0, 0.0], [-1.0, 0.0, 2.0]], Interpolation::Bilinear);
+
+        assert_eq!(rotated.shape(), &[3, 3, 2]);
+        assert_eq!(&rotated.as_slice()[..6], &[12, 13, 6, 7, 0, 1]);
+        assert_eq!(&rotated.as_slice()[6..8], &[14, 15]);
+        assert_eq!(&rotated.as_slice()[8..10], &[8, 9]);
+    }
+
+    // Test warping an image (mostly) outside the input
+    #[test]
+    fn warp_outside_t() {
+        let image = Array2::<u8>::ones([2, 2]);
+        let scaled = image.warp_affine([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0]], Interpolation::Nearest);
+        assert_eq!(scaled.as_slice(), &[1, 0, 0, 0]);
+    }
+
+    // Test resizing and warping an empty image created using NdArray::<T, N>::new()
+    // Check if resizing fills the new shape with the default value
+    #[test]
+    fn new_image_t() {
+        let identity: [[f64; 3]; 2] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        let gray = Array2::<u8>::new();
+        assert_eq!(
+            gray.resize([1, 2], Interpolation::Bilinear).as_slice(),
+            &[0, 0]
+        );
+        assert!(gray
+            .warp_affine(identity, Interpolation::Nearest)
+            .is_empty());
+
+        let rgb = Array3::<u8>::new();
+        assert_eq!(
+            rgb.resize([2, 2], Interpolation::Nearest).shape(),
+            &[2, 2, 0]
+        );
+        assert!(rgb
+            .warp_affine(identity, Interpolation::Bilinear)
+            .is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::core::{NdArray, SizedArray};
+
+/// Check that `mask` has the shape of the array being selected from
+///
+/// ## Panics
+/// If the shapes differ, panics; message shown is **Shape(`mask`) of the mask don't match with Shape(`shape`)**
+fn check_mask<const N: usize>(mask: &NdArray<bool, N>, shape: SizedArray<N>) {
+    if mask.view_shape() != shape {
+        panic!(
+            "Shape({:?}) of the mask don't match with Shape({:?})",
+            mask.view_shape(),
+            shape
+        );
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Return the elements where `mask` is true, in row-major (C) order, as a one dimensional (1-D) NdArray. Same as `a[mask]` in NumPy
+    ///
+    /// ## Panics
+    /// If `mask` does not have the shape of the NdArray, panics; message shown is **Shape(`mask`) of the mask don't match with Shape(`shape`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[-1, 2, -3, 4], [2, 2]);
+    /// let mask = NdArray::<bool, 2>::from(&[false, true, false, true], [2, 2]);
+    /// assert_eq!(arr.mask_select(&mask).as_slice(), &[2, 4]);
+    /// # }
+    /// ```
+    pub fn mask_select(&self, mask: &NdArray<bool, N>) -> NdArray<T, 1>
+    where
+        T: Clone,
+    {
+        check_mask(mask, self.view_shape());
+        let values: Vec<T> = self
+            .as_slice()
+            .iter()
+            .zip(mask.as_slice())
+            .filter(|(_, keep)| **keep)
+            .map(|(value, _)| value.clone())
+            .collect();
+        let len: usize = values.len();
+
+        NdArray::from_owned_vec(values, [len])
+    }
+
+    /// Overwrite the elements where `mask` is true with `value`. Same as `a[mask] = value` in NumPy
+    ///
+    /// ## Panics
+    /// If `mask` does not have the shape of the NdArray, panics; message shown is **Shape(`mask`) of the mask don't match with Shape(`shape`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // a[a < 0] = 0
+    /// let mut arr = NdArray::<i32, 2>::from(&[-1, 2, -3, 4], [2, 2]);
+    /// let values: Vec<bool> = arr.as_slice().iter().map(|x| *x < 0).collect();
+    /// let mask = NdArray::<bool, 2>::from(&values, [2, 2]);
+    /// arr.mask_assign(&mask, 0);
+    /// assert_eq!(arr.as_slice(), &[0, 2, 0, 4]);
+    /// # }
+    /// ```
+    pub fn mask_assign(&mut self, mask: &NdArray<bool, N>, value: T)
+    where
+        T: Clone,
+    {
+        check_mask(mask, self.view_shape());
+        for (elem, keep) in self.as_mut_slice().iter_mut().zip(mask.as_slice()) {
+            if *keep {
+                *elem = value.clone();
+            }
+        }
+    }
+
+    /// Gather the subarrays at the positions `indices` along `axis` into a new NdArray, e.g. the rows of an embedding table for a batch of tokens. Same as `numpy.take(a, indices, axis)`
+    ///
+    /// ## Note
+    /// Positions may repeat and appear in any order. The length of `axis` in the returned NdArray is the length of `indices`
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If a position is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let table = NdArray::<f32, 2>::from(&[0.0, 0.1, 1.0, 1.1, 2.0, 2.1], [3, 2]);
+    /// let tokens = NdArray::<usize, 1>::from(&[2, 0, 2], [3]);
+    /// let rows = table.take(&tokens, 0);
+    /// assert_eq!(*rows.shape(), [3, 2]);
+    /// assert_eq!(rows.as_slice(), &[2.0, 2.1, 0.0, 0.1, 2.0, 2.1]);
+    /// # }
+    /// ```
+    pub fn take(&self, indices: &NdArray<usize, 1>, axis: usize) -> NdArray<T, N>
+    where
+        T: Clone,
+    {
+        if axis >= N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+        }
+        let positions: &[usize] = indices.as_slice();
+        let mut shape: SizedArray<N> = *self.view().shape();
+        if positions.iter().any(|pos| *pos >= shape[axis]) {
+            panic!("Index out of bounds");
+        }
+
+        shape[axis] = positions.len();
+        NdArray::from_shape_fn(shape, |mut index| {
+            index[axis] = positions[index[axis]];
+            self[index].clone()
+        })
+    }
+
+    /// Scatter `values` to the flat positions `indices` of the NdArray in row-major (C) order, i.e., the element at position `indices[i]` is overwritten with `values[i]`. Same as `numpy.put(a, indices, values)`
+    ///
+    /// ## Note
+    /// If a position repeats, the last value written to it is kept
+    ///
+    /// ## Panics
+    /// - If the lengths of `indices` and `values` differ, panics; message shown is **Length(`values`) of the values don't match with Length(`indices`) of the indices**
+    /// - If a position is not less than the length of the NdArray, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 3]);
+    /// let indices = NdArray::<usize, 1>::from(&[0, 4], [2]);
+    /// let values = NdArray::<u8, 1>::from(&[7, 9], [2]);
+    /// arr.put(&indices, &values);
+    /// assert_eq!(arr.as_slice(), &[7, 0, 0, 0, 9, 0]);
+    /// # }
+    /// ```
+    pub fn put(&mut self, indices: &NdArray<usize, 1>, values: &NdArray<T, 1>)
+    where
+        T: Clone,
+    {
+        if indices.as_slice().len() != values.as_slice().len() {
+            panic!(
+                "Length({}) of the values don't match with Length({}) of the indices",
+                values.as_slice().len(),
+                indices.as_slice().len()
+            );
+        }
+
+        let elems: &mut [T] = self.as_mut_slice();
+        for (pos, value) in indices.as_slice().iter().zip(values.as_slice()) {
+            match elems.get_mut(*pos) {
+                Some(elem) => *elem = value.clone(),
+                None => panic!("Index out of bounds"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod indexing_t {
+    use crate::core::{Array, Array3, NdArray};
+
+    // Test selecting and assigning the elements of a 3-D NdArray with a mask
+    #[test]
+    fn mask_3dim_t() {
+        let mut data =
+            Array3::<i64>::from_shape_fn([2, 3, 2], |[i, j, k]| (i * 6 + j * 2 + k) as i64);
+        let mask =
+            Array3::<bool>::from_shape_fn([2, 3, 2], |[i, j, k]| (i * 6 + j * 2 + k) % 3 == 0);
+        assert_eq!(data.mask_select(&mask).as_slice(), &[0, 3, 6, 9]);
+
+        data.mask_assign(&mask, -1);
+        assert_eq!(data[[1, 1, 1]], -1);
+        assert_eq!(data[[1, 2, 0]], 10);
+        assert!(data.mask_select(&mask).as_slice().iter().all(|x| *x == -1));
+    }
+
+    // Test selecting with a mask of a different shape
+    #[test]
+    #[should_panic(expected = "Shape([2, 1]) of the mask don't match with Shape([1, 2])")]
+    fn mask_wrong_shape_t() {
+        let data = NdArray::<u8, 2>::zeros([1, 2]);
+        data.mask_select(&NdArray::<bool, 2>::from(&[true, false], [2, 1]));
+    }
+
+    // Test masks of empty NdArrays, e.g. created using NdArray::<T, N>::new()
+    #[test]
+    fn mask_empty_t() {
+        let mut data = NdArray::<u8, 2>::zeros([0, 0]);
+        assert!(data.mask_select(&NdArray::new()).is_empty());
+        data.mask_assign(&NdArray::new(), 1);
+        assert!(NdArray::<u8, 2>::new()
+            .mask_select(&NdArray::<bool, 2>::zeros([0, 0]))
+            .is_empty());
+    }
+
+    // Test gathering along every axis of a 3-D NdArray, and scattering the gathered elements back
+    #[test]
+    fn take_put_3dim_t() {
+        let data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        let indices = NdArray::<usize, 1>::from(&[1, 1, 0], [3]);
+        let taken = data.take(&indices, 1);
+        assert_eq!(*taken.shape(), [2, 3, 4]);
+        assert_eq!(taken[[1, 2, 3]], 103);
+        assert_eq!(*data.take(&indices, 0).shape(), [3, 3, 4]);
+        assert!(data.take(&Array::<usize>::arange(0), 2).is_empty());
+
+        let mut copy = Array3::<usize>::zeros([2, 3, 4]);
+        let positions = Array::<usize>::arange(24);
+        copy.put(&positions, &data.flatten());
+        assert_eq!(copy, data);
+    }
+
+    // Test gathering a position longer than the axis
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn take_out_of_bounds_t() {
+        let data = NdArray::<u8, 2>::zeros([2, 3]);
+        data.take(&NdArray::<usize, 1>::from(&[3], [1]), 1);
+    }
+}
@@ -0,0 +1,2233 @@
+use std::marker::PhantomData;
+
+use crate::{
+    core::{NdArray, Order, ShapeError, SizedArray},
+    view::{offset, ArrayView, ArrayViewMut},
+};
+
+/// Iterator over consecutive chunks of a fixed thickness along an axis. The last chunk may be thinner if the length of the axis is not divisible by the chunk size
+///
+/// Created using [`NdArray::axis_chunks_iter`] or [`ArrayView::axis_chunks_iter`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::zeros([5, 3]);
+/// let shapes: Vec<[usize; 2]> = arr.axis_chunks_iter(0, 2).map(|chunk| *chunk.shape()).collect();
+/// assert_eq!(shapes, vec![[2, 3], [2, 3], [1, 3]]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AxisChunksIter<'a, T, const N: usize> {
+    rest: ArrayView<'a, T, N>,
+    axis: usize,
+    size: usize,
+}
+
+impl<'a, T, const N: usize> AxisChunksIter<'a, T, N> {
+    /// Create the iterator over the chunks of `view`
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    fn new(view: ArrayView<'a, T, N>, axis: usize, size: usize) -> Self {
+        if axis >= N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+        }
+        if size == 0 {
+            panic!("Chunk size cannot be zero");
+        }
+
+        AxisChunksIter {
+            rest: view,
+            axis,
+            size,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for AxisChunksIter<'a, T, N> {
+    type Item = ArrayView<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len: usize = self.rest.shape()[self.axis];
+        if len == 0 {
+            return None;
+        }
+
+        let (chunk, rest) = self.rest.split_at(self.axis, self.size.min(len));
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count: usize = self.rest.shape()[self.axis].div_ceil(self.size);
+        (count, Some(count))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for AxisChunksIter<'_, T, N> {}
+
+/// Iterator over consecutive mutable chunks of a fixed thickness along an axis. The last chunk may be thinner if the length of the axis is not divisible by the chunk size
+///
+/// Created using [`NdArray::axis_chunks_iter_mut`] or [`ArrayViewMut::axis_chunks_iter_mut`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u8, 1>::zeros([5]);
+/// for (n, mut chunk) in arr.axis_chunks_iter_mut(0, 2).enumerate() {
+///     chunk.fill(n as u8);
+/// }
+/// assert_eq!(arr.as_slice(), &[0, 0, 1, 1, 2]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AxisChunksIterMut<'a, T, const N: usize> {
+    rest: Option<ArrayViewMut<'a, T, N>>,
+    axis: usize,
+    size: usize,
+}
+
+impl<'a, T, const N: usize> AxisChunksIterMut<'a, T, N> {
+    /// Create the iterator over the mutable chunks of `view`
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    fn new(view: ArrayViewMut<'a, T, N>, axis: usize, size: usize) -> Self {
+        if axis >= N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+        }
+        if size == 0 {
+            panic!("Chunk size cannot be zero");
+        }
+
+        AxisChunksIterMut {
+            rest: Some(view),
+            axis,
+            size,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for AxisChunksIterMut<'a, T, N> {
+    type Item = ArrayViewMut<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest: ArrayViewMut<'a, T, N> = self.rest.take()?;
+        let len: usize = rest.shape()[self.axis];
+        if len == 0 {
+            return None;
+        }
+
+        let (chunk, rest) = rest.split_at(self.axis, self.size.min(len));
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count: usize = self
+            .rest
+            .as_ref()
+            .map_or(0, |rest| rest.shape()[self.axis].div_ceil(self.size));
+        (count, Some(count))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for AxisChunksIterMut<'_, T, N> {}
+
+/// Check the axis and the dimension `M = N - 1` of the subviews, shared by [`AxisIter`] and [`AxisIterMut`]
+///
+/// ## Panics
+/// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+fn check_axis_iter<const N: usize, const M: usize>(axis: usize) {
+    if M + 1 != N {
+        panic!("Dimension({}) must be one less than Dimension({})", M, N);
+    }
+    if axis >= N {
+        panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+    }
+}
+
+/// Iterator over the subviews of one lower dimension (`M = N - 1`) at each position along an axis, e.g. the rows of a matrix for axis 0 or the frames of a 3-D stack
+///
+/// Created using [`NdArray::axis_iter`], [`NdArray::outer_iter`] or the same methods of [`ArrayView`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+/// let sums: Vec<u8> = arr.outer_iter::<1>().map(|row| row.iter().sum()).collect();
+/// assert_eq!(sums, vec![6, 15]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AxisIter<'a, T, const N: usize, const M: usize> {
+    rest: ArrayView<'a, T, N>,
+    axis: usize,
+}
+
+impl<'a, T, const N: usize, const M: usize> AxisIter<'a, T, N, M> {
+    /// Create the iterator over the subviews of `view`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    fn new(view: ArrayView<'a, T, N>, axis: usize) -> Self {
+        check_axis_iter::<N, M>(axis);
+        AxisIter { rest: view, axis }
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> Iterator for AxisIter<'a, T, N, M> {
+    type Item = ArrayView<'a, T, M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.shape()[self.axis] == 0 {
+            return None;
+        }
+
+        let (first, rest) = self.rest.split_at(self.axis, 1);
+        self.rest = rest;
+        Some(first.index_axis(self.axis, 0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count: usize = self.rest.shape()[self.axis];
+        (count, Some(count))
+    }
+}
+
+impl<T, const N: usize, const M: usize> ExactSizeIterator for AxisIter<'_, T, N, M> {}
+
+/// Iterator over the mutable subviews of one lower dimension (`M = N - 1`) at each position along an axis
+///
+/// Created using [`NdArray::axis_iter_mut`], [`NdArray::outer_iter_mut`] or the same methods of [`ArrayViewMut`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u8, 2>::zeros([2, 3]);
+/// for (j, mut column) in arr.axis_iter_mut::<1>(1).enumerate() {
+///     column.fill(j as u8);
+/// }
+/// assert_eq!(arr.as_slice(), &[0, 1, 2, 0, 1, 2]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AxisIterMut<'a, T, const N: usize, const M: usize> {
+    rest: Option<ArrayViewMut<'a, T, N>>,
+    axis: usize,
+}
+
+impl<'a, T, const N: usize, const M: usize> AxisIterMut<'a, T, N, M> {
+    /// Create the iterator over the mutable subviews of `view`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    fn new(view: ArrayViewMut<'a, T, N>, axis: usize) -> Self {
+        check_axis_iter::<N, M>(axis);
+        AxisIterMut {
+            rest: Some(view),
+            axis,
+        }
+    }
+}
+
+impl<'a, T, const N: usize, const M: usize> Iterator for AxisIterMut<'a, T, N, M> {
+    type Item = ArrayViewMut<'a, T, M>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest: ArrayViewMut<'a, T, N> = self.rest.take()?;
+        if rest.shape()[self.axis] == 0 {
+            return None;
+        }
+
+        let (first, rest) = rest.split_at(self.axis, 1);
+        self.rest = Some(rest);
+        Some(first.index_axis(self.axis, 0))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count: usize = self.rest.as_ref().map_or(0, |rest| rest.shape()[self.axis]);
+        (count, Some(count))
+    }
+}
+
+impl<T, const N: usize, const M: usize> ExactSizeIterator for AxisIterMut<'_, T, N, M> {}
+
+/// Position of the lanes along `axis`, shared by [`Lanes`] and [`LanesMut`]
+#[derive(Debug, Clone, Copy)]
+struct LanePos<const N: usize> {
+    shape: SizedArray<N>,
+    strides: SizedArray<N>,
+    axis: usize,
+    pos: usize,
+    count: usize,
+}
+
+impl<const N: usize> LanePos<N> {
+    /// Create the position of the first lane along `axis`
+    ///
+    /// ## Panics
+    /// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    fn new(shape: SizedArray<N>, strides: SizedArray<N>, axis: usize) -> Self {
+        if axis >= N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+        }
+
+        // every index of the other axes starts a lane
+        let count: usize = shape
+            .iter()
+            .enumerate()
+            .filter(|(dim, _)| *dim != axis)
+            .map(|(_, len)| len)
+            .product();
+
+        LanePos {
+            shape,
+            strides,
+            axis,
+            pos: 0,
+            count,
+        }
+    }
+
+    /// Return the element offset of the next lane (in row-major order of the other axes) and advance to the lane after it
+    fn next<T>(&mut self) -> Option<usize> {
+        if self.pos >= self.count {
+            return None;
+        }
+
+        let mut rest: usize = self.pos;
+        let mut index: SizedArray<N> = [0; N];
+        for dim in (0..N).rev().filter(|dim| *dim != self.axis) {
+            index[dim] = rest % self.shape[dim];
+            rest /= self.shape[dim];
+        }
+        self.pos += 1;
+
+        Some(offset::<T, N>(&index, &self.strides))
+    }
+
+    /// Shape and strides (in bytes) of a lane
+    fn lane(&self) -> ([usize; 1], [usize; 1]) {
+        ([self.shape[self.axis]], [self.strides[self.axis]])
+    }
+
+    /// Number of lanes left
+    fn remaining(&self) -> usize {
+        self.count - self.pos
+    }
+}
+
+/// Iterator over every one dimensional (1-D) lane along an axis, e.g. each column of a matrix for axis 0, or each pixel's channel vector for the last axis of an image
+///
+/// Created using [`NdArray::lanes`] or [`ArrayView::lanes`]. Lanes are visited in the row-major order of the other axes
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+/// let sums: Vec<u8> = arr.lanes(0).map(|col| col[[0]] + col[[1]]).collect();
+/// assert_eq!(sums, vec![5, 7, 9]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Lanes<'a, T, const N: usize> {
+    ptr: *const T,
+    pos: LanePos<N>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> Iterator for Lanes<'a, T, N> {
+    type Item = ArrayView<'a, T, 1>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start: usize = self.pos.next::<T>()?;
+        let (shape, strides) = self.pos.lane();
+        // SAFETY: the lane is within the memory borrowed immutably for 'a
+        Some(unsafe { ArrayView::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Lanes<'_, T, N> {}
+
+/// Iterator over every mutable one dimensional (1-D) lane along an axis
+///
+/// Created using [`NdArray::lanes_mut`] or [`ArrayViewMut::lanes_mut`]. Lanes are visited in the row-major order of the other axes
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u8, 2>::zeros([2, 3]);
+/// for (i, mut row) in arr.lanes_mut(1).enumerate() {
+///     row[[0]] = i as u8 + 1;
+/// }
+/// assert_eq!(arr[[1, 0]], 2);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LanesMut<'a, T, const N: usize> {
+    ptr: *mut T,
+    pos: LanePos<N>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const N: usize> Iterator for LanesMut<'a, T, N> {
+    type Item = ArrayViewMut<'a, T, 1>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start: usize = self.pos.next::<T>()?;
+        let (shape, strides) = self.pos.lane();
+        // SAFETY: the lane is within the memory borrowed mutably for 'a and distinct lanes do not overlap
+        Some(unsafe { ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for LanesMut<'_, T, N> {}
+
+// SAFETY: `Lanes` yields views which behave like `&'a T` and `LanesMut` yields views which behave like `&'a mut T`
+unsafe impl<T: Sync, const N: usize> Send for Lanes<'_, T, N> {}
+unsafe impl<T: Send, const N: usize> Send for LanesMut<'_, T, N> {}
+
+/// Position of the elements in a logical order, shared by [`IndexedIter`] and [`IndexedIterMut`]
+#[derive(Debug, Clone, Copy)]
+struct ElemPos<const N: usize> {
+    shape: SizedArray<N>,
+    strides: SizedArray<N>,
+    index: SizedArray<N>,
+    order: Order,
+    pos: usize,
+    count: usize,
+}
+
+impl<const N: usize> ElemPos<N> {
+    /// Create the position of the first element in row-major (C) order
+    fn new(shape: SizedArray<N>, strides: SizedArray<N>) -> Self {
+        Self::with_order(shape, strides, Order::C)
+    }
+
+    /// Create the position of the first element in the given `order`
+    fn with_order(shape: SizedArray<N>, strides: SizedArray<N>, order: Order) -> Self {
+        ElemPos {
+            shape,
+            strides,
+            index: [0; N],
+            order,
+            pos: 0,
+            count: shape.iter().product(),
+        }
+    }
+
+    /// Return the index and element offset of the next element, and advance to the element after it
+    fn next<T>(&mut self) -> Option<(SizedArray<N>, usize)> {
+        if self.pos >= self.count {
+            return None;
+        }
+
+        let index: SizedArray<N> = self.index;
+        // increment the fastest axis first (the last in C order and the first in F order), carrying over to the slower axes
+        for step in 0..N {
+            let dim: usize = match self.order {
+                Order::C => N - 1 - step,
+                Order::F => step,
+            };
+            self.index[dim] += 1;
+            if self.index[dim] < self.shape[dim] {
+                break;
+            }
+            self.index[dim] = 0;
+        }
+        self.pos += 1;
+
+        Some((index, offset::<T, N>(&index, &self.strides)))
+    }
+
+    /// Number of elements left
+    fn remaining(&self) -> usize {
+        self.count - self.pos
+    }
+}
+
+/// Iterator over references to every element in row-major (C) order, following the strides of the array
+///
+/// Created using [`NdArray::iter`] or [`ArrayView::iter`], and by iterating over `&NdArray` or an `ArrayView` in a `for` loop
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+/// let columns = arr.permuted_axes([1, 0]); // transposed view, visits the columns
+/// let values: Vec<u8> = columns.iter().copied().collect();
+/// assert_eq!(values, vec![1, 4, 2, 5, 3, 6]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Iter<'a, T, const N: usize> {
+    inner: IndexedIter<'a, T, N>,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, elem)| elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+/// Iterator over mutable references to every element in row-major (C) order, following the strides of the array
+///
+/// Created using [`NdArray::iter_mut`] or [`ArrayViewMut::iter_mut`], and by iterating over `&mut NdArray` or an `ArrayViewMut` in a `for` loop
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u8, 2>::zeros([2, 3]);
+/// for elem in &mut arr {
+///     *elem += 1;
+/// }
+/// assert!(arr.iter().all(|x| *x == 1));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IterMut<'a, T, const N: usize> {
+    inner: IndexedIterMut<'a, T, N>,
+}
+
+impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, elem)| elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IterMut<'_, T, N> {}
+
+/// Iterator over the index and a reference of every element in row-major (C) order, following the strides of the array
+///
+/// Created using [`NdArray::indexed_iter`] or [`ArrayView::indexed_iter`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[0, 5, 0, 0, 0, 7], [2, 3]);
+/// let nonzero: Vec<([usize; 2], u8)> = arr
+///     .indexed_iter()
+///     .filter(|(_, x)| **x != 0)
+///     .map(|(index, x)| (index, *x))
+///     .collect();
+/// assert_eq!(nonzero, vec![([0, 1], 5), ([1, 2], 7)]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IndexedIter<'a, T, const N: usize> {
+    ptr: *const T,
+    pos: ElemPos<N>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> IndexedIter<'a, T, N> {
+    /// Create the iterator over the elements of `view` in the given `order`
+    fn new(view: ArrayView<'a, T, N>, order: Order) -> Self {
+        IndexedIter {
+            ptr: view.as_ptr(),
+            pos: ElemPos::with_order(*view.shape(), *view.strides(), order),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for IndexedIter<'a, T, N> {
+    type Item = (SizedArray<N>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, start) = self.pos.next::<T>()?;
+        // SAFETY: the element is within the memory borrowed immutably for 'a
+        Some((index, unsafe { &*self.ptr.wrapping_add(start) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IndexedIter<'_, T, N> {}
+
+/// Iterator over the index and a mutable reference of every element in row-major (C) order, following the strides of the array
+///
+/// Created using [`NdArray::indexed_iter_mut`] or [`ArrayViewMut::indexed_iter_mut`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<usize, 2>::zeros([2, 2]);
+/// for ([i, j], elem) in arr.indexed_iter_mut() {
+///     *elem = 10 * i + j;
+/// }
+/// assert_eq!(arr.as_slice(), &[0, 1, 10, 11]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct IndexedIterMut<'a, T, const N: usize> {
+    ptr: *mut T,
+    pos: ElemPos<N>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const N: usize> IndexedIterMut<'a, T, N> {
+    /// Create the iterator over the elements of `view`
+    fn new(view: ArrayViewMut<'a, T, N>) -> Self {
+        let (ptr, shape, strides) = view.into_raw_parts();
+        IndexedIterMut {
+            ptr,
+            pos: ElemPos::new(shape, strides),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for IndexedIterMut<'a, T, N> {
+    type Item = (SizedArray<N>, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, start) = self.pos.next::<T>()?;
+        // SAFETY: the element is within the memory borrowed mutably for 'a and each element is yielded once
+        Some((index, unsafe { &mut *self.ptr.wrapping_add(start) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IndexedIterMut<'_, T, N> {}
+
+/// Iterator over every overlapping window of a given shape, sliding with a step of one along each axis. Windows are visited in row-major (C) order of their first element
+///
+/// Created using [`NdArray::windows`] or [`ArrayView::windows`]. See [`NdArray::sliding_window_view`] for all windows as one view
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// // moving sum of 3 samples
+/// let signal = NdArray::<u8, 1>::from(&[1, 2, 3, 4, 5], [5]);
+/// let sums: Vec<u8> = signal.windows([3]).map(|w| w.iter().sum()).collect();
+/// assert_eq!(sums, vec![6, 9, 12]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Windows<'a, T, const N: usize> {
+    ptr: *const T,
+    pos: ElemPos<N>,
+    window: SizedArray<N>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> Windows<'a, T, N> {
+    /// Create the iterator over the windows of `view`
+    ///
+    /// ## Panics
+    /// - If the window has a zero length, panics; message shown is **Window size cannot be zero**
+    /// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+    fn new(view: ArrayView<'a, T, N>, window: SizedArray<N>) -> Self {
+        if window.contains(&0) {
+            panic!("Window size cannot be zero");
+        }
+        let shape: &SizedArray<N> = view.shape();
+        if window.iter().zip(shape.iter()).any(|(w, len)| w > len) {
+            panic!("Window({:?}) exceeds Shape({:?})", window, shape);
+        }
+
+        // one window starts at every index which leaves room for the window
+        let mut starts: SizedArray<N> = *shape;
+        for (len, w) in starts.iter_mut().zip(window.iter()) {
+            *len = *len - w + 1;
+        }
+
+        Windows {
+            ptr: view.as_ptr(),
+            pos: ElemPos::new(starts, *view.strides()),
+            window,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Windows<'a, T, N> {
+    type Item = ArrayView<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, start) = self.pos.next::<T>()?;
+        // SAFETY: the window is within the memory borrowed immutably for 'a
+        Some(unsafe {
+            ArrayView::from_raw_parts(self.ptr.wrapping_add(start), self.window, self.pos.strides)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Windows<'_, T, N> {}
+
+/// Position of the non-overlapping chunks of shape `chunk`, shared by [`ExactChunks`] and [`ExactChunksMut`]. The elements which do not fill a whole chunk are skipped
+///
+/// ## Panics
+/// If the chunk has a zero length, panics; message shown is **Chunk size cannot be zero**
+fn chunk_pos<const N: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    chunk: &SizedArray<N>,
+) -> ElemPos<N> {
+    if chunk.contains(&0) {
+        panic!("Chunk size cannot be zero");
+    }
+
+    let mut counts: SizedArray<N> = [0; N];
+    let mut steps: SizedArray<N> = [0; N];
+    for dim in 0..N {
+        counts[dim] = shape[dim] / chunk[dim];
+        steps[dim] = strides[dim] * chunk[dim];
+    }
+    ElemPos::new(counts, steps)
+}
+
+/// Iterator over the non-overlapping chunks of a given shape, e.g. the tiles of an image. The elements at the end of an axis which do not fill a whole chunk are skipped. Chunks are visited in row-major (C) order
+///
+/// Created using [`NdArray::exact_chunks`] or [`ArrayView::exact_chunks`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 1>::arange(7);
+/// let firsts: Vec<u8> = arr.exact_chunks([3]).map(|chunk| chunk[[0]]).collect();
+/// assert_eq!(firsts, vec![0, 3]); // 6 is skipped
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ExactChunks<'a, T, const N: usize> {
+    ptr: *const T,
+    pos: ElemPos<N>,
+    chunk: SizedArray<N>,
+    strides: SizedArray<N>,
+    marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, const N: usize> Iterator for ExactChunks<'a, T, N> {
+    type Item = ArrayView<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, start) = self.pos.next::<T>()?;
+        // SAFETY: the chunk is within the memory borrowed immutably for 'a
+        Some(unsafe {
+            ArrayView::from_raw_parts(self.ptr.wrapping_add(start), self.chunk, self.strides)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ExactChunks<'_, T, N> {}
+
+/// Iterator over the mutable non-overlapping chunks of a given shape. The elements at the end of an axis which do not fill a whole chunk are skipped. Chunks are visited in row-major (C) order
+///
+/// Created using [`NdArray::exact_chunks_mut`] or [`ArrayViewMut::exact_chunks_mut`]
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u8, 2>::zeros([4, 4]);
+/// for (n, mut tile) in arr.exact_chunks_mut([2, 2]).enumerate() {
+///     tile.fill(n as u8);
+/// }
+/// assert_eq!(arr[[0, 3]], 1);
+/// assert_eq!(arr[[3, 0]], 2);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ExactChunksMut<'a, T, const N: usize> {
+    ptr: *mut T,
+    pos: ElemPos<N>,
+    chunk: SizedArray<N>,
+    strides: SizedArray<N>,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const N: usize> Iterator for ExactChunksMut<'a, T, N> {
+    type Item = ArrayViewMut<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, start) = self.pos.next::<T>()?;
+        // SAFETY: the chunk is within the memory borrowed mutably for 'a and distinct chunks do not overlap
+        Some(unsafe {
+            ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), self.chunk, self.strides)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pos.remaining(), Some(self.pos.remaining()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ExactChunksMut<'_, T, N> {}
+
+// SAFETY: `IndexedIter`, `Windows` and `ExactChunks` behave like `&'a T`, and `IndexedIterMut` and `ExactChunksMut` behave like `&'a mut T`
+unsafe impl<T: Sync, const N: usize> Send for IndexedIter<'_, T, N> {}
+unsafe impl<T: Send, const N: usize> Send for IndexedIterMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Send for Windows<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Send for ExactChunks<'_, T, N> {}
+unsafe impl<T: Send, const N: usize> Send for ExactChunksMut<'_, T, N> {}
+
+impl<'a, T, const N: usize> ArrayView<'a, T, N> {
+    /// Return an iterator over references to every element of the view in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [3, 2]);
+    /// let (_, bottom) = arr.split_at(0, 1);
+    /// assert_eq!(bottom.iter().sum::<u8>(), 18);
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'a, T, N> {
+        Iter {
+            inner: self.indexed_iter(),
+        }
+    }
+
+    /// Return an iterator over the index and a reference of every element of the view in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let (_, right) = arr.split_at(1, 1);
+    /// let (index, _) = right.indexed_iter().last().unwrap();
+    /// assert_eq!(index, [1, 1]); // the index is within the view
+    /// # }
+    /// ```
+    pub fn indexed_iter(&self) -> IndexedIter<'a, T, N> {
+        IndexedIter::new(*self, Order::C)
+    }
+
+    /// Return an iterator over references to every element of the view in the given `order`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let (_, right) = arr.split_at(1, 1);
+    /// let values: Vec<u8> = right.iter_with_order(Order::F).copied().collect();
+    /// assert_eq!(values, vec![2, 5, 3, 6]);
+    /// # }
+    /// ```
+    pub fn iter_with_order(&self, order: Order) -> Iter<'a, T, N> {
+        Iter {
+            inner: IndexedIter::new(*self, order),
+        }
+    }
+
+    /// Return an iterator over the index and a reference of every element of the view in the given `order`
+    pub fn indexed_iter_with_order(&self, order: Order) -> IndexedIter<'a, T, N> {
+        IndexedIter::new(*self, order)
+    }
+
+    /// Return an iterator over the subviews of dimension `M = N - 1` at each position along `axis`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let firsts: Vec<u8> = arr.view().axis_iter::<1>(1).map(|col| col[[0]]).collect();
+    /// assert_eq!(firsts, vec![1, 2]);
+    /// # }
+    /// ```
+    pub fn axis_iter<const M: usize>(self, axis: usize) -> AxisIter<'a, T, N, M> {
+        AxisIter::new(self, axis)
+    }
+
+    /// Return an iterator over the subviews of dimension `M = N - 1` along the first axis. Same as `axis_iter(0)`
+    ///
+    /// ## Panics
+    /// If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    pub fn outer_iter<const M: usize>(self) -> AxisIter<'a, T, N, M> {
+        AxisIter::new(self, 0)
+    }
+
+    /// Return an iterator over every overlapping view of shape `window`, sliding with a step of one along each axis
+    ///
+    /// ## Panics
+    /// - If the window has a zero length, panics; message shown is **Window size cannot be zero**
+    /// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+    pub fn windows(self, window: SizedArray<N>) -> Windows<'a, T, N> {
+        Windows::new(self, window)
+    }
+
+    /// Return an iterator over the non-overlapping views of shape `chunk`, skipping the elements which do not fill a whole chunk
+    ///
+    /// ## Panics
+    /// If the chunk has a zero length, panics; message shown is **Chunk size cannot be zero**
+    pub fn exact_chunks(self, chunk: SizedArray<N>) -> ExactChunks<'a, T, N> {
+        ExactChunks {
+            ptr: self.as_ptr(),
+            pos: chunk_pos(self.shape(), self.strides(), &chunk),
+            chunk,
+            strides: *self.strides(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Return an iterator over consecutive chunks of thickness `size` along `axis`. The last chunk may be thinner
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 1>::arange(7);
+    /// let firsts: Vec<u16> = arr.view().axis_chunks_iter(0, 3).map(|chunk| chunk[[0]]).collect();
+    /// assert_eq!(firsts, vec![0, 3, 6]);
+    /// # }
+    /// ```
+    pub fn axis_chunks_iter(self, axis: usize, size: usize) -> AxisChunksIter<'a, T, N> {
+        AxisChunksIter::new(self, axis, size)
+    }
+
+    /// Return an iterator over every 1-D lane along `axis`
+    ///
+    /// ## Panics
+    /// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let rows: Vec<u8> = arr.view().lanes(1).map(|row| row[[1]]).collect();
+    /// assert_eq!(rows, vec![2, 4]);
+    /// # }
+    /// ```
+    pub fn lanes(self, axis: usize) -> Lanes<'a, T, N> {
+        Lanes {
+            ptr: self.as_ptr(),
+            pos: LanePos::new(*self.shape(), *self.strides(), axis),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ArrayViewMut<'a, T, N> {
+    /// Return an iterator over references to every element of the view in row-major (C) order
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        self.view().iter()
+    }
+
+    /// Return an iterator over mutable references to every element of the view in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// let (_, mut right) = arr.split_at_mut(1, 1);
+    /// right.iter_mut().for_each(|x| *x = 7);
+    /// assert_eq!(arr.as_slice(), &[0, 7, 0, 7]);
+    /// # }
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        self.view_mut().into_iter()
+    }
+
+    /// Return an iterator over the index and a mutable reference of every element of the view in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([3, 3]);
+    /// let mut view = arr.view_mut();
+    /// for ([i, j], elem) in view.indexed_iter_mut() {
+    ///     if i == j {
+    ///         *elem = 1;
+    ///     }
+    /// }
+    /// assert_eq!(arr, NdArray::<u8, 2>::identity(3));
+    /// # }
+    /// ```
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T, N> {
+        IndexedIterMut::new(self.view_mut())
+    }
+
+    /// Return an iterator over the mutable subviews of dimension `M = N - 1` at each position along `axis`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// for mut row in arr.view_mut().axis_iter_mut::<1>(0) {
+    ///     row[[1]] = 4;
+    /// }
+    /// assert_eq!(arr.as_slice(), &[0, 4, 0, 4]);
+    /// # }
+    /// ```
+    pub fn axis_iter_mut<const M: usize>(self, axis: usize) -> AxisIterMut<'a, T, N, M> {
+        AxisIterMut::new(self, axis)
+    }
+
+    /// Return an iterator over consecutive mutable chunks of thickness `size` along `axis`. The last chunk may be thinner
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    pub fn axis_chunks_iter_mut(self, axis: usize, size: usize) -> AxisChunksIterMut<'a, T, N> {
+        AxisChunksIterMut::new(self, axis, size)
+    }
+
+    /// Return an iterator over the non-overlapping mutable views of shape `chunk`, skipping the elements which do not fill a whole chunk
+    ///
+    /// ## Panics
+    /// If the chunk has a zero length, panics; message shown is **Chunk size cannot be zero**
+    pub fn exact_chunks_mut(self, chunk: SizedArray<N>) -> ExactChunksMut<'a, T, N> {
+        let (ptr, shape, strides) = self.into_raw_parts();
+        ExactChunksMut {
+            ptr,
+            pos: chunk_pos(&shape, &strides, &chunk),
+            chunk,
+            strides,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the mutable subviews of dimension `M = N - 1` along the first axis. Same as `axis_iter_mut(0)`
+    ///
+    /// ## Panics
+    /// If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    pub fn outer_iter_mut<const M: usize>(self) -> AxisIterMut<'a, T, N, M> {
+        AxisIterMut::new(self, 0)
+    }
+
+    /// Return an iterator over every mutable 1-D lane along `axis`
+    ///
+    /// ## Panics
+    /// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// for mut col in arr.view_mut().lanes_mut(0) {
+    ///     col[[1]] = 9;
+    /// }
+    /// assert_eq!(arr[[1, 0]], 9);
+    /// assert_eq!(arr[[1, 1]], 9);
+    /// # }
+    /// ```
+    pub fn lanes_mut(self, axis: usize) -> LanesMut<'a, T, N> {
+        let (ptr, shape, strides) = self.into_raw_parts();
+        LanesMut {
+            ptr,
+            pos: LanePos::new(shape, strides, axis),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Return an iterator over references to every element in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[3, -1, 4, -1], [2, 2]);
+    /// assert_eq!(arr.iter().filter(|x| **x < 0).count(), 2);
+    /// assert_eq!(arr.iter().max(), Some(&4));
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        self.view().iter()
+    }
+
+    /// Return an iterator over mutable references to every element in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i32, 1>::from(&[1, 2, 3], [3]);
+    /// arr.iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(arr.as_slice(), &[10, 20, 30]);
+    /// # }
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        self.view_mut().into_iter()
+    }
+
+    /// Return an iterator over the index and a reference of every element in row-major (C) order, e.g. to track the position of the maximum
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[3, 9, 4, 1], [2, 2]);
+    /// let (argmax, _) = arr.indexed_iter().max_by_key(|(_, x)| **x).unwrap();
+    /// assert_eq!(argmax, [0, 1]);
+    /// # }
+    /// ```
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T, N> {
+        self.view().indexed_iter()
+    }
+
+    /// Return an iterator over references to every element in the given `order`, e.g. [`Order::F`] to export the elements to a column-major consumer such as LAPACK, R or MATLAB without a transposed copy
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let column_major: Vec<u8> = arr.iter_with_order(Order::F).copied().collect();
+    /// assert_eq!(column_major, vec![1, 4, 2, 5, 3, 6]);
+    /// # }
+    /// ```
+    pub fn iter_with_order(&self, order: Order) -> Iter<'_, T, N> {
+        self.view().iter_with_order(order)
+    }
+
+    /// Return an iterator over the index and a reference of every element in the given `order`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// let indices: Vec<[usize; 2]> = arr.indexed_iter_with_order(Order::F).map(|(index, _)| index).collect();
+    /// assert_eq!(indices, vec![[0, 0], [1, 0], [0, 1], [1, 1]]);
+    /// # }
+    /// ```
+    pub fn indexed_iter_with_order(&self, order: Order) -> IndexedIter<'_, T, N> {
+        self.view().indexed_iter_with_order(order)
+    }
+
+    /// Return an iterator over the index and a mutable reference of every element in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<usize, 2>::zeros([2, 3]);
+    /// arr.indexed_iter_mut().for_each(|([i, j], x)| *x = i * j);
+    /// assert_eq!(arr[[1, 2]], 2);
+    /// # }
+    /// ```
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T, N> {
+        IndexedIterMut::new(self.view_mut())
+    }
+
+    /// Return an iterator over the subviews of dimension `M = N - 1` at each position along `axis`, e.g. the frames of a 3-D stack for axis 0
+    ///
+    /// ## Note
+    /// `M` may need to be annotated, e.g. `arr.axis_iter::<2>(0)`. The iterator is [`Send`] for `T: Sync`, hence the subviews can be processed on other threads
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut stack = NdArray::<u16, 3>::arange(12);
+    /// stack.reshape([3, 2, 2]); // 3 frames of 2x2
+    /// let firsts: Vec<u16> = stack.axis_iter::<2>(0).map(|frame| frame[[0, 0]]).collect();
+    /// assert_eq!(firsts, vec![0, 4, 8]);
+    /// assert_eq!(stack.axis_iter::<2>(2).len(), 2);
+    /// # }
+    /// ```
+    pub fn axis_iter<const M: usize>(&self, axis: usize) -> AxisIter<'_, T, N, M> {
+        self.view().axis_iter(axis)
+    }
+
+    /// Return an iterator over the mutable subviews of dimension `M = N - 1` at each position along `axis`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<f32, 2>::from(&[1.0, 3.0, 2.0, 6.0], [2, 2]);
+    /// for mut column in arr.axis_iter_mut::<1>(1) {
+    ///     let first: f32 = column[[0]];
+    ///     column.iter_mut().for_each(|x| *x /= first);
+    /// }
+    /// assert_eq!(arr.as_slice(), &[1.0, 1.0, 2.0, 2.0]);
+    /// # }
+    /// ```
+    pub fn axis_iter_mut<const M: usize>(&mut self, axis: usize) -> AxisIterMut<'_, T, N, M> {
+        self.view_mut().axis_iter_mut(axis)
+    }
+
+    /// Return an iterator over the subviews of dimension `M = N - 1` along the first axis, e.g. the rows of a matrix. Same as `axis_iter(0)`
+    ///
+    /// ## Panics
+    /// If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [3, 2]);
+    /// let rows: Vec<Vec<u8>> = arr.outer_iter::<1>().map(|row| row.iter().copied().collect()).collect();
+    /// assert_eq!(rows, vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    /// # }
+    /// ```
+    pub fn outer_iter<const M: usize>(&self) -> AxisIter<'_, T, N, M> {
+        self.view().outer_iter()
+    }
+
+    /// Return an iterator over the mutable subviews of dimension `M = N - 1` along the first axis. Same as `axis_iter_mut(0)`
+    ///
+    /// ## Panics
+    /// If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    pub fn outer_iter_mut<const M: usize>(&mut self) -> AxisIterMut<'_, T, N, M> {
+        self.view_mut().outer_iter_mut()
+    }
+
+    /// Return an iterator over every overlapping view of shape `window`, sliding with a step of one along each axis, e.g. the neighbourhoods of a convolution
+    ///
+    /// ## Panics
+    /// - If the window has a zero length, panics; message shown is **Window size cannot be zero**
+    /// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // local maxima of the 2x2 neighbourhoods
+    /// let image = NdArray::<u8, 2>::from(&[1, 5, 2, 4, 3, 9], [2, 3]);
+    /// let maxima: Vec<u8> = image.windows([2, 2]).map(|w| *w.iter().max().unwrap()).collect();
+    /// assert_eq!(maxima, vec![5, 9]);
+    /// # }
+    /// ```
+    pub fn windows(&self, window: SizedArray<N>) -> Windows<'_, T, N> {
+        self.view().windows(window)
+    }
+
+    /// Return an iterator over the non-overlapping views of shape `chunk`, e.g. the tiles of a blocked matrix algorithm. The elements at the end of an axis which do not fill a whole chunk are skipped
+    ///
+    /// ## Note
+    /// Use [`NdArray::<T, N>::axis_chunks_iter(...)`](NdArray::axis_chunks_iter) to keep the thinner chunk at the end of an axis
+    ///
+    /// ## Panics
+    /// If the chunk has a zero length, panics; message shown is **Chunk size cannot be zero**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut image = NdArray::<u16, 2>::arange(20);
+    /// image.reshape([4, 5]);
+    /// // sum of each 2x2 tile, the last column is skipped
+    /// let sums: Vec<u16> = image.exact_chunks([2, 2]).map(|tile| tile.iter().sum()).collect();
+    /// assert_eq!(sums, vec![12, 20, 52, 60]);
+    /// # }
+    /// ```
+    pub fn exact_chunks(&self, chunk: SizedArray<N>) -> ExactChunks<'_, T, N> {
+        self.view().exact_chunks(chunk)
+    }
+
+    /// Return an iterator over the non-overlapping mutable views of shape `chunk`. The elements at the end of an axis which do not fill a whole chunk are skipped
+    ///
+    /// ## Panics
+    /// If the chunk has a zero length, panics; message shown is **Chunk size cannot be zero**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 1>::zeros([5]);
+    /// for mut pair in arr.exact_chunks_mut([2]) {
+    ///     pair[[1]] = 1;
+    /// }
+    /// assert_eq!(arr.as_slice(), &[0, 1, 0, 1, 0]);
+    /// # }
+    /// ```
+    pub fn exact_chunks_mut(&mut self, chunk: SizedArray<N>) -> ExactChunksMut<'_, T, N> {
+        self.view_mut().exact_chunks_mut(chunk)
+    }
+
+    /// Return an iterator over consecutive chunks of thickness `size` along `axis`, e.g. minibatches of a dataset stored as one array. The last chunk may be thinner
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut dataset = NdArray::<f32, 2>::arange(20);
+    /// dataset.reshape([10, 2]); // 10 samples with 2 features each
+    /// for batch in dataset.axis_chunks_iter(0, 4) {
+    ///     assert!(batch.shape()[0] <= 4);
+    /// }
+    /// assert_eq!(dataset.axis_chunks_iter(0, 4).len(), 3);
+    /// # }
+    /// ```
+    pub fn axis_chunks_iter(&self, axis: usize, size: usize) -> AxisChunksIter<'_, T, N> {
+        AxisChunksIter::new(self.view(), axis, size)
+    }
+
+    /// Return an iterator over consecutive mutable chunks of thickness `size` along `axis`, e.g. to process a large array block by block. The last chunk may be thinner
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `size` is zero, panics; message shown is **Chunk size cannot be zero**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i32, 2>::ones([2, 5]);
+    /// for mut block in arr.axis_chunks_iter_mut(1, 2) {
+    ///     let width: i32 = block.shape()[1] as i32;
+    ///     block.iter_mut().for_each(|x| *x = width);
+    /// }
+    /// assert_eq!(arr.as_slice(), &[2, 2, 2, 2, 1, 2, 2, 2, 2, 1]);
+    /// # }
+    /// ```
+    pub fn axis_chunks_iter_mut(
+        &mut self,
+        axis: usize,
+        size: usize,
+    ) -> AxisChunksIterMut<'_, T, N> {
+        AxisChunksIterMut::new(self.view_mut(), axis, size)
+    }
+
+    /// Return an iterator over every 1-D lane along `axis`, e.g. the columns of a matrix for axis 0 and its rows for axis 1
+    ///
+    /// ## Panics
+    /// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // 2x2 image with 3 channels per pixel
+    /// let mut image = NdArray::<u8, 3>::arange(12);
+    /// image.reshape([2, 2, 3]);
+    /// let pixels: Vec<u8> = image.lanes(2).map(|px| px[[0]]).collect();
+    /// assert_eq!(pixels, vec![0, 3, 6, 9]);
+    /// # }
+    /// ```
+    pub fn lanes(&self, axis: usize) -> Lanes<'_, T, N> {
+        self.view().lanes(axis)
+    }
+
+    /// Return an iterator over every mutable 1-D lane along `axis`
+    ///
+    /// ## Panics
+    /// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i32, 2>::ones([3, 2]);
+    /// for mut col in arr.lanes_mut(0) {
+    ///     col[[2]] = -1;
+    /// }
+    /// assert_eq!(arr[[2, 1]], -1);
+    /// # }
+    /// ```
+    pub fn lanes_mut(&mut self, axis: usize) -> LanesMut<'_, T, N> {
+        self.view_mut().lanes_mut(axis)
+    }
+
+    /// Return an iterator over every row, i.e., the 1-D lanes along the last axis. Same as `lanes(N - 1)`
+    ///
+    /// ## Panics
+    /// If `N` is zero, panics; message shown is **Axis(0) out of bounds for Dimension(0)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let sums: Vec<u8> = arr.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![6, 15]);
+    /// # }
+    /// ```
+    pub fn rows(&self) -> Lanes<'_, T, N> {
+        self.lanes(N.saturating_sub(1))
+    }
+
+    /// Return an iterator over every mutable row, i.e., the 1-D lanes along the last axis. Same as `lanes_mut(N - 1)`
+    ///
+    /// ## Panics
+    /// If `N` is zero, panics; message shown is **Axis(0) out of bounds for Dimension(0)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // scale every row to sum to one
+    /// let mut arr = NdArray::<f32, 2>::from(&[1.0, 3.0, 2.0, 2.0], [2, 2]);
+    /// for mut row in arr.rows_mut() {
+    ///     let sum: f32 = row.iter().sum();
+    ///     row.iter_mut().for_each(|x| *x /= sum);
+    /// }
+    /// assert_eq!(arr.as_slice(), &[0.25, 0.75, 0.5, 0.5]);
+    /// # }
+    /// ```
+    pub fn rows_mut(&mut self) -> LanesMut<'_, T, N> {
+        self.lanes_mut(N.saturating_sub(1))
+    }
+
+    /// Return an iterator over every column, i.e., the 1-D lanes along the first axis. Same as `lanes(0)`
+    ///
+    /// ## Panics
+    /// If `N` is zero, panics; message shown is **Axis(0) out of bounds for Dimension(0)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let maxima: Vec<u8> = arr.columns().map(|col| *col.iter().max().unwrap()).collect();
+    /// assert_eq!(maxima, vec![4, 5, 6]);
+    /// # }
+    /// ```
+    pub fn columns(&self) -> Lanes<'_, T, N> {
+        self.lanes(0)
+    }
+
+    /// Return an iterator over every mutable column, i.e., the 1-D lanes along the first axis. Same as `lanes_mut(0)`
+    ///
+    /// ## Panics
+    /// If `N` is zero, panics; message shown is **Axis(0) out of bounds for Dimension(0)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // subtract the mean of every column
+    /// let mut arr = NdArray::<f32, 2>::from(&[1.0, 2.0, 3.0, 6.0], [2, 2]);
+    /// for mut col in arr.columns_mut() {
+    ///     let mean: f32 = col.iter().sum::<f32>() / 2.0;
+    ///     col.iter_mut().for_each(|x| *x -= mean);
+    /// }
+    /// assert_eq!(arr.as_slice(), &[-1.0, -2.0, 1.0, 2.0]);
+    /// # }
+    /// ```
+    pub fn columns_mut(&mut self) -> LanesMut<'_, T, N> {
+        self.lanes_mut(0)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for ArrayView<'a, T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for ArrayViewMut<'a, T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            inner: IndexedIterMut::new(self),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a NdArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut NdArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Use for consuming an NdArray and iterating over its elements in row-major (C) order
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// #
+/// # fn main() {
+/// let arr = NdArray::<String, 1>::from_vec(vec!["a".to_string(), "b".to_string()], [2]);
+/// let joined: String = arr.into_iter().collect();
+/// assert_eq!(joined, "ab");
+/// # }
+/// ```
+impl<T, const N: usize> IntoIterator for NdArray<T, N> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Call `f` with a reference to every element in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let mut seen: Vec<u8> = Vec::new();
+    /// arr.for_each(|x| seen.push(*x));
+    /// assert_eq!(seen, vec![1, 2, 3, 4]);
+    /// # }
+    /// ```
+    pub fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(&T),
+    {
+        self.as_slice().iter().for_each(f);
+    }
+
+    /// Fold every element in row-major (C) order into an accumulator, starting with `init`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 2>::from(&[3.0, -4.0, 0.0, 12.0], [2, 2]);
+    /// let norm: f64 = arr.fold(0.0, |acc, x| acc + x * x).sqrt();
+    /// assert_eq!(norm, 13.0);
+    /// # }
+    /// ```
+    pub fn fold<U, F>(&self, init: U, f: F) -> U
+    where
+        F: FnMut(U, &T) -> U,
+    {
+        self.as_slice().iter().fold(init, f)
+    }
+
+    /// Reduce every element in row-major (C) order with `f`, starting with the first element. Returns `None` if the NdArray is empty
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u32, 2>::from(&[12, 18, 30, 42], [2, 2]);
+    /// let gcd = |mut a: u32, b: &u32| {
+    ///     let mut b: u32 = *b;
+    ///     while b != 0 {
+    ///         (a, b) = (b, a % b);
+    ///     }
+    ///     a
+    /// };
+    /// assert_eq!(arr.reduce(gcd), Some(6));
+    /// assert_eq!(NdArray::<u32, 2>::new().reduce(gcd), None);
+    /// # }
+    /// ```
+    pub fn reduce<F>(&self, mut f: F) -> Option<T>
+    where
+        T: Clone,
+        F: FnMut(T, &T) -> T,
+    {
+        let (first, rest) = self.as_slice().split_first()?;
+        Some(rest.iter().fold(first.clone(), &mut f))
+    }
+
+    /// Fold every 1-D lane along `axis` into an accumulator starting with `init`, and return the results as an NdArray of one lower dimension (`M = N - 1`), e.g. a custom reduction of every column
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, -5, 3, 2, 4, -6], [2, 3]);
+    /// // largest magnitude of every column
+    /// let peaks: NdArray<i32, 1> = arr.fold_axis(0, 0, |acc, x| acc.max(x.abs()));
+    /// assert_eq!(peaks.as_slice(), &[2, 5, 6]);
+    /// # }
+    /// ```
+    pub fn fold_axis<const M: usize, U, F>(&self, axis: usize, init: U, mut f: F) -> NdArray<U, M>
+    where
+        U: Clone,
+        F: FnMut(U, &T) -> U,
+    {
+        check_axis_iter::<N, M>(axis);
+        let values: Vec<U> = self
+            .lanes(axis)
+            .map(|lane| lane.iter().fold(init.clone(), &mut f))
+            .collect();
+
+        let full: SizedArray<N> = self.view_shape();
+        let mut shape: SizedArray<M> = [0; M];
+        for (pos, dim) in (0..N).filter(|dim| *dim != axis).enumerate() {
+            shape[pos] = full[dim];
+        }
+        NdArray::from_owned_vec(values, shape)
+    }
+}
+
+/// Apply `f` to every 1-D lane along `axis` of `x`, returning an NdArray of the same shape holding the filtered lanes
+///
+/// ## Panics
+/// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+pub(crate) fn map_lanes<const N: usize>(
+    x: &NdArray<f64, N>,
+    axis: usize,
+    f: impl Fn(&[f64]) -> Vec<f64>,
+) -> NdArray<f64, N> {
+    let mut out: NdArray<f64, N> = NdArray::from_owned_vec(vec![0.0; *x.len()], x.view_shape());
+    let mut lane: Vec<f64> = Vec::new();
+    for (view, mut out_lane) in x.lanes(axis).zip(out.lanes_mut(axis)) {
+        lane.clear();
+        lane.extend((0..view.len()).map(|i| view[[i]]));
+        for (i, value) in f(&lane).into_iter().enumerate() {
+            out_lane[[i]] = value;
+        }
+    }
+    out
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Create an NdArray of the given `shape` from the elements of `iter`, in row-major (C) order. Returns a [`ShapeError`] if the number of elements does not match the shape
+    ///
+    /// ## Note
+    /// Every element of `iter` is consumed to count the elements, hence `iter` must be finite
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let squares = NdArray::<u32, 2>::from_iter_shaped([2, 3], (0..6).map(|x| x * x)).unwrap();
+    /// assert_eq!(squares[[1, 2]], 25);
+    /// assert!(NdArray::<u32, 2>::from_iter_shaped([2, 3], 0..5).is_err());
+    /// # }
+    /// ```
+    pub fn from_iter_shaped<I>(shape: SizedArray<N>, iter: I) -> Result<Self, ShapeError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let mut values: Vec<T> = Vec::with_capacity(iter.size_hint().0);
+        values.extend(iter);
+        NdArray::try_from_vec(values, shape)
+    }
+}
+
+/// Use for collecting an iterator into a one dimensional (1-D) array
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// #
+/// # fn main() {
+/// let arr: NdArray<f64, 1> = (1..=4).map(|x| 1.0 / x as f64).collect();
+/// assert_eq!(*arr.shape(), [4]);
+/// assert_eq!(arr[[3]], 0.25);
+/// # }
+/// ```
+impl<T> FromIterator<T> for NdArray<T, 1> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        let len: usize = values.len();
+        NdArray::from_owned_vec(values, [len])
+    }
+}
+
+/// Extension of iterators to collect the elements into an NdArray of a given shape
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::{NdArray, ShapeError};
+/// use ndim::iter::CollectShaped;
+///
+/// fn grid() -> Result<NdArray<usize, 2>, ShapeError> {
+///     let arr = (0..12).filter(|x| x % 2 == 0).chain(100..106).collect_shaped([3, 4])?;
+///     Ok(arr)
+/// }
+///
+/// # fn main() {
+/// assert_eq!(grid().unwrap()[[2, 3]], 105);
+/// # }
+/// ```
+pub trait CollectShaped: Iterator + Sized {
+    /// Collect the elements into an NdArray of the given `shape`. Same as [`NdArray::<T, N>::from_iter_shaped(...)`](NdArray::from_iter_shaped)
+    fn collect_shaped<const N: usize>(
+        self,
+        shape: SizedArray<N>,
+    ) -> Result<NdArray<Self::Item, N>, ShapeError> {
+        NdArray::from_iter_shaped(shape, self)
+    }
+}
+
+impl<I: Iterator> CollectShaped for I {}
+
+#[cfg(test)]
+mod iter_t {
+    use crate::{
+        core::{Array3, NdArray, Order, ShapeError},
+        iter::CollectShaped,
+    };
+
+    // Test axis_chunks_iter(...) along every axis of a 3-D NdArray
+    // Check if the chunks hold the right values and the last chunk is thinner
+    #[test]
+    fn axis_chunks_iter_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(60);
+        data.reshape([3, 4, 5]);
+
+        for axis in 0..3 {
+            let chunks: Vec<_> = data.axis_chunks_iter(axis, 2).collect();
+            let len: usize = data.shape()[axis];
+            assert_eq!(chunks.len(), len.div_ceil(2));
+            assert_eq!(chunks.last().unwrap().shape()[axis], 2 - len % 2);
+
+            for (n, chunk) in chunks.iter().enumerate() {
+                for i in 0..chunk.shape()[0] {
+                    for j in 0..chunk.shape()[1] {
+                        for k in 0..chunk.shape()[2] {
+                            let mut idx: [usize; 3] = [i, j, k];
+                            idx[axis] += n * 2;
+                            assert_eq!(chunk[[i, j, k]], data[idx]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Test axis_chunks_iter_mut(...) along every axis of a 3-D NdArray
+    // Check if every element is visited once and the last chunk is thinner
+    #[test]
+    fn axis_chunks_iter_mut_3dim_t() {
+        for axis in 0..3 {
+            let mut data = Array3::<usize>::zeros([3, 4, 5]);
+            let len: usize = data.shape()[axis];
+            let mut chunks = data.axis_chunks_iter_mut(axis, 2);
+            assert_eq!(chunks.len(), len.div_ceil(2));
+            for (n, mut chunk) in chunks.by_ref().enumerate() {
+                chunk.iter_mut().for_each(|x| *x += n + 1);
+            }
+            assert_eq!(chunks.len(), 0);
+
+            for (index, elem) in data.indexed_iter() {
+                assert_eq!(*elem, index[axis] / 2 + 1);
+            }
+        }
+    }
+
+    // Test axis_chunks_iter(...) with a chunk size larger than the axis
+    #[test]
+    fn axis_chunks_iter_large_size_t() {
+        let data = NdArray::<u8, 2>::zeros([3, 2]);
+        let chunks: Vec<_> = data.axis_chunks_iter(0, 10).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(*chunks[0].shape(), [3, 2]);
+    }
+
+    // Test lanes(...) along every axis of a 3-D NdArray
+    // Check if the count of the lanes and the values in each lane are correct
+    #[test]
+    fn lanes_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        data.reshape([2, 3, 4]);
+
+        for axis in 0..3 {
+            let lanes: Vec<_> = data.lanes(axis).collect();
+            assert_eq!(lanes.len(), 24 / data.shape()[axis]);
+
+            // visit the other axes in row-major order
+            let mut n: usize = 0;
+            for i in 0..2 {
+                for j in 0..3 {
+                    for k in 0..4 {
+                        let mut idx: [usize; 3] = [i, j, k];
+                        if idx[axis] != 0 {
+                            continue;
+                        }
+                        for pos in 0..data.shape()[axis] {
+                            idx[axis] = pos;
+                            assert_eq!(lanes[n][[pos]], data[idx]);
+                        }
+                        n += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Test lanes_mut(...) of a split view
+    // Check if only the lanes of the view are mutated
+    #[test]
+    fn lanes_mut_split_t() {
+        let mut data = NdArray::<u32, 2>::zeros([3, 4]);
+        let (_, right) = data.split_at_mut(1, 2);
+        for (n, mut row) in right.lanes_mut(1).enumerate() {
+            for pos in 0..2 {
+                row[[pos]] = (n * 2 + pos + 1) as u32;
+            }
+        }
+
+        for i in 0..3 {
+            assert_eq!(data[[i, 0]], 0);
+            assert_eq!(data[[i, 1]], 0);
+            assert_eq!(data[[i, 2]], (i * 2 + 1) as u32);
+            assert_eq!(data[[i, 3]], (i * 2 + 2) as u32);
+        }
+    }
+
+    // Test iter(...), iter_mut(...) and into_iter(...) of a 3-D NdArray and of its transposed view
+    // Check if the elements are visited in row-major order of the logical index
+    #[test]
+    fn iter_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        data.reshape([2, 3, 4]);
+        let values: Vec<u16> = data.iter().copied().collect();
+        assert_eq!(values, (0..24).collect::<Vec<u16>>());
+        assert_eq!(data.iter().len(), 24);
+
+        let reversed = data.reversed_axes();
+        let mut n: usize = 0;
+        for k in 0..4 {
+            for j in 0..3 {
+                for i in 0..2 {
+                    assert_eq!(reversed.iter().nth(n), Some(&data[[i, j, k]]));
+                    n += 1;
+                }
+            }
+        }
+
+        for (n, elem) in (&mut data).into_iter().enumerate() {
+            *elem += n as u16;
+        }
+        assert_eq!(data[[1, 2, 3]], 46);
+        let owned: Vec<u16> = data.into_iter().collect();
+        assert_eq!(owned[23], 46);
+    }
+
+    // Test iter_mut(...) of a split view
+    // Check if only the elements of the view are mutated
+    #[test]
+    fn iter_mut_split_t() {
+        let mut data = NdArray::<u32, 2>::zeros([3, 4]);
+        let (_, mut right) = data.split_at_mut(1, 3);
+        for (n, elem) in right.iter_mut().enumerate() {
+            *elem = n as u32 + 1;
+        }
+        assert_eq!(data.iter().filter(|x| **x != 0).count(), 3);
+        assert_eq!(data[[2, 3]], 3);
+        assert_eq!(NdArray::<u8, 2>::new().iter().next(), None);
+    }
+
+    // Test indexed_iter(...) and indexed_iter_mut(...) of a 3-D NdArray and of its permuted view
+    // Check if every index points to the yielded element
+    #[test]
+    fn indexed_iter_3dim_t() {
+        let mut data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        for (n, (index, elem)) in data.indexed_iter().enumerate() {
+            assert_eq!(data[index], *elem);
+            assert_eq!(*elem, data.as_slice()[n]);
+        }
+
+        let permuted = data.permuted_axes([2, 0, 1]);
+        assert_eq!(*permuted.shape(), [4, 2, 3]);
+        for ([k, i, j], elem) in permuted.indexed_iter() {
+            assert_eq!(*elem, 100 * i + 10 * j + k);
+        }
+
+        for ([i, j, k], elem) in data.indexed_iter_mut() {
+            *elem -= i + j + k;
+        }
+        assert_eq!(data[[1, 2, 3]], 117);
+        assert_eq!(data.indexed_iter().len(), 24);
+    }
+
+    // Test axis_iter(...) and axis_iter_mut(...) along every axis of a 3-D NdArray
+    // Check if each subview is the same as index_axis(...) at its position
+    #[test]
+    fn axis_iter_3dim_t() {
+        let mut data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        for axis in 0..3 {
+            let subviews: Vec<_> = data.axis_iter::<2>(axis).collect();
+            assert_eq!(subviews.len(), data.shape()[axis]);
+            for (pos, subview) in subviews.iter().enumerate() {
+                assert_eq!(
+                    subview.to_owned(),
+                    data.index_axis::<2>(axis, pos).to_owned()
+                );
+            }
+        }
+
+        for (k, mut lane) in data.axis_iter_mut::<2>(2).enumerate() {
+            lane.iter_mut().for_each(|x| *x -= k);
+        }
+        assert!(data.iter().all(|x| x % 10 == 0));
+        assert_eq!(data.outer_iter::<2>().len(), 2);
+        assert_eq!(
+            Array3::<u8>::zeros([2, 0, 3]).axis_iter::<2>(1).next(),
+            None
+        );
+    }
+
+    // Test axis_iter(...) with a subview dimension which is not one less
+    #[test]
+    #[should_panic(expected = "Dimension(1) must be one less than Dimension(3)")]
+    fn axis_iter_wrong_dimension_t() {
+        let data = Array3::<u8>::zeros([2, 2, 2]);
+        data.axis_iter::<1>(0);
+    }
+
+    // Test rows(...), columns(...) and their mutable variants of a 3-D NdArray
+    // Check if they are the lanes along the last and the first axis
+    #[test]
+    fn rows_columns_3dim_t() {
+        let mut data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        assert_eq!(data.rows().len(), 6);
+        assert_eq!(data.columns().len(), 12);
+        for (row, lane) in data.rows().zip(data.lanes(2)) {
+            assert_eq!(row.to_owned(), lane.to_owned());
+        }
+        for (col, lane) in data.columns().zip(data.lanes(0)) {
+            assert_eq!(col.to_owned(), lane.to_owned());
+        }
+
+        for mut row in data.rows_mut() {
+            row[[3]] = 0;
+        }
+        for mut col in data.columns_mut() {
+            col[[1]] += 1;
+        }
+        assert_eq!(data[[0, 2, 3]], 0);
+        assert_eq!(data[[1, 2, 3]], 1);
+        assert_eq!(data[[1, 2, 2]], 123);
+    }
+
+    // Test windows(...) of a 3-D NdArray and of a split view
+    // Check if every window is the same as the sliding window view at its position
+    #[test]
+    fn windows_3dim_t() {
+        let data = Array3::<usize>::from_shape_fn([3, 4, 5], |[i, j, k]| 100 * i + 10 * j + k);
+        let windows: Vec<_> = data.windows([2, 3, 2]).collect();
+        assert_eq!(windows.len(), 2 * 2 * 4);
+        let view = data.sliding_window_view::<6>([2, 3, 2]);
+        for (n, window) in windows.iter().enumerate() {
+            let [i, j, k] = [n / 8, n / 4 % 2, n % 4];
+            assert_eq!(*window.shape(), [2, 3, 2]);
+            assert_eq!(window[[1, 2, 1]], data[[i + 1, j + 2, k + 1]]);
+            assert_eq!(
+                window.to_owned(),
+                view.subview::<3, 3>([i, j, k]).to_owned()
+            );
+        }
+
+        let (_, right) = data.split_at(2, 3);
+        let last = right.windows([3, 4, 2]).last().unwrap();
+        assert_eq!(last[[0, 0, 0]], 3);
+        assert_eq!(data.windows([3, 4, 5]).len(), 1);
+    }
+
+    // Test windows(...) with a window longer than its axis
+    #[test]
+    #[should_panic(expected = "Window([1, 3]) exceeds Shape([2, 2])")]
+    fn windows_too_long_t() {
+        let data = NdArray::<u8, 2>::zeros([2, 2]);
+        data.windows([1, 3]);
+    }
+
+    // Test windows(...) with a window of a zero length, which would be empty
+    #[test]
+    #[should_panic(expected = "Window size cannot be zero")]
+    fn windows_zero_t() {
+        let data = NdArray::<u8, 2>::zeros([2, 2]);
+        data.windows([2, 0]);
+    }
+
+    // Test exact_chunks(...) and exact_chunks_mut(...) of a 3-D NdArray
+    // Check if the chunks hold the right values and the remainders are skipped
+    #[test]
+    fn exact_chunks_3dim_t() {
+        let mut data = Array3::<usize>::from_shape_fn([3, 4, 5], |[i, j, k]| 100 * i + 10 * j + k);
+        let chunks: Vec<_> = data.exact_chunks([2, 2, 2]).collect();
+        assert_eq!(chunks.len(), 2 * 2);
+        for (n, chunk) in chunks.iter().enumerate() {
+            let [j, k] = [n / 2 * 2, n % 2 * 2];
+            assert_eq!(*chunk.shape(), [2, 2, 2]);
+            assert_eq!(chunk[[1, 1, 1]], data[[1, j + 1, k + 1]]);
+        }
+
+        for mut chunk in data.exact_chunks_mut([3, 4, 2]) {
+            chunk.fill(0);
+        }
+        assert_eq!(data.iter().filter(|x| **x != 0).count(), 3 * 4);
+        assert_eq!(data[[2, 3, 4]], 234);
+        assert_eq!(data.exact_chunks([4, 1, 1]).len(), 0);
+    }
+
+    // Test exact_chunks(...) with a chunk of zero length
+    #[test]
+    #[should_panic(expected = "Chunk size cannot be zero")]
+    fn exact_chunks_zero_size_t() {
+        let data = NdArray::<u8, 2>::zeros([3, 2]);
+        data.exact_chunks([1, 0]);
+    }
+
+    // Test fold(...), reduce(...) and fold_axis(...) along every axis of a 3-D NdArray
+    // Check if the folded lanes match with a fold over index_axis(...)
+    #[test]
+    fn fold_axis_3dim_t() {
+        let data =
+            Array3::<u64>::from_shape_fn([2, 3, 4], |[i, j, k]| (100 * i + 10 * j + k) as u64);
+        assert_eq!(data.fold(0, |acc, x| acc + x), data.iter().sum::<u64>());
+        assert_eq!(data.reduce(|a, b| a.max(*b)), Some(123));
+        let mut count: usize = 0;
+        data.for_each(|_| count += 1);
+        assert_eq!(count, 24);
+        assert!(NdArray::<u8, 3>::new()
+            .fold_axis::<2, _, _>(1, 0, |acc, x| acc + x)
+            .is_empty());
+
+        for axis in 0..3 {
+            let folded: NdArray<Vec<u64>, 2> = data.fold_axis(axis, Vec::new(), |mut acc, x| {
+                acc.push(*x);
+                acc
+            });
+            for (index, lane) in folded.indexed_iter() {
+                let mut full: Vec<usize> = index.to_vec();
+                full.insert(axis, 0);
+                for (pos, x) in lane.iter().enumerate() {
+                    full[axis] = pos;
+                    assert_eq!(*x, data[[full[0], full[1], full[2]]]);
+                }
+            }
+        }
+    }
+
+    // Test iter_with_order(...) and flatten_with_order(...) of a 3-D NdArray and of its transposed view
+    // Check if column-major order of an array is row-major order of its reversed axes
+    #[test]
+    fn iter_with_order_3dim_t() {
+        let data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        let reversed = data.reversed_axes();
+        let column_major: Vec<usize> = data.iter_with_order(Order::F).copied().collect();
+        assert_eq!(
+            column_major,
+            reversed.iter().copied().collect::<Vec<usize>>()
+        );
+        assert_eq!(column_major[1], 100);
+        assert_eq!(
+            data.flatten_with_order(Order::F).as_slice(),
+            &column_major[..]
+        );
+        assert_eq!(reversed.flatten_with_order(Order::F), data.flatten());
+
+        for (index, elem) in data.indexed_iter_with_order(Order::F) {
+            assert_eq!(data[index], *elem);
+        }
+        assert_eq!(data.iter_with_order(Order::C).len(), 24);
+    }
+
+    // Test axis_chunks_iter(...) with a chunk size of zero
+    #[test]
+    #[should_panic(expected = "Chunk size cannot be zero")]
+    fn axis_chunks_iter_zero_size_t() {
+        let data = NdArray::<u8, 2>::zeros([3, 2]);
+        data.axis_chunks_iter(1, 0);
+    }
+
+    // Test collecting iterators into NdArrays, with and without a shape
+    #[test]
+    fn collect_shaped_t() {
+        let data: NdArray<String, 3> = (0..8)
+            .map(|x| x.to_string())
+            .collect_shaped([2, 2, 2])
+            .unwrap();
+        assert_eq!(data[[1, 1, 0]], "6");
+        assert_eq!(
+            (0..7).collect_shaped([2, 2, 2]),
+            Err(ShapeError::SizeMismatch {
+                shape: vec![2, 2, 2],
+                size: 7
+            })
+        );
+
+        let data: NdArray<u8, 1> = "ndim".bytes().collect();
+        assert_eq!(data.as_slice(), b"ndim");
+        let data: NdArray<u8, 1> = std::iter::empty().collect();
+        assert!(data.is_empty());
+    }
+}
@@ -0,0 +1,495 @@
+//! Dense kernels routed through LAPACK with the `lapack` feature. Every kernel works on row-major buffers and returns `None` if LAPACK reports a failure, in which case the pure-Rust implementation is used
+//!
+//! LAPACK expects column-major buffers. A row-major buffer is the column-major buffer of the transpose, hence symmetric kernels read the upper triangle (`b'U'`), which is the lower triangle in row-major order
+
+use num_traits::Float;
+
+/// LAPACK routines of a real element type, i.e. the `s` routines for `f32` and the `d` routines for `f64`
+///
+/// ## Safety
+/// Every routine has the contract of the LAPACK routine it calls, i.e. each buffer must hold the number of elements implied by the sizes and leading dimensions
+#[allow(clippy::too_many_arguments)]
+pub trait Lapack: Float {
+    /// `?gesv`
+    unsafe fn gesv(
+        n: i32,
+        nrhs: i32,
+        a: &mut [Self],
+        lda: i32,
+        ipiv: &mut [i32],
+        b: &mut [Self],
+        ldb: i32,
+        info: &mut i32,
+    );
+    /// `?getrf`
+    unsafe fn getrf(m: i32, n: i32, a: &mut [Self], lda: i32, ipiv: &mut [i32], info: &mut i32);
+    /// `?potrf`
+    unsafe fn potrf(uplo: u8, n: i32, a: &mut [Self], lda: i32, info: &mut i32);
+    /// `?geqrf`
+    unsafe fn geqrf(
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+    /// `?orgqr`
+    unsafe fn orgqr(
+        m: i32,
+        n: i32,
+        k: i32,
+        a: &mut [Self],
+        lda: i32,
+        tau: &[Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+    /// `?gesdd`
+    unsafe fn gesdd(
+        jobz: u8,
+        m: i32,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        s: &mut [Self],
+        u: &mut [Self],
+        ldu: i32,
+        vt: &mut [Self],
+        ldvt: i32,
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        info: &mut i32,
+    );
+    /// `?syev`
+    unsafe fn syev(
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+    /// `?sygv`
+    unsafe fn sygv(
+        itype: &[i32],
+        jobz: u8,
+        uplo: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        w: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+    /// `?gees` without sorting the eigenvalues
+    unsafe fn gees(
+        jobvs: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        sdim: &mut i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        vs: &mut [Self],
+        ldvs: i32,
+        work: &mut [Self],
+        lwork: i32,
+        bwork: &mut [i32],
+        info: &mut i32,
+    );
+}
+
+/// Implement [`Lapack`] for `$t` with the routines `$gesv`, `$getrf`, ...
+macro_rules! impl_lapack {
+    ($($t:ty: $gesv:ident, $getrf:ident, $potrf:ident, $geqrf:ident, $orgqr:ident, $gesdd:ident, $syev:ident, $sygv:ident, $gees:ident);* $(;)?) => {
+        $(
+            #[allow(clippy::too_many_arguments)]
+            impl Lapack for $t {
+                unsafe fn gesv(n: i32, nrhs: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32], b: &mut [$t], ldb: i32, info: &mut i32) {
+                    lapack::$gesv(n, nrhs, a, lda, ipiv, b, ldb, info)
+                }
+
+                unsafe fn getrf(m: i32, n: i32, a: &mut [$t], lda: i32, ipiv: &mut [i32], info: &mut i32) {
+                    lapack::$getrf(m, n, a, lda, ipiv, info)
+                }
+
+                unsafe fn potrf(uplo: u8, n: i32, a: &mut [$t], lda: i32, info: &mut i32) {
+                    lapack::$potrf(uplo, n, a, lda, info)
+                }
+
+                unsafe fn geqrf(m: i32, n: i32, a: &mut [$t], lda: i32, tau: &mut [$t], work: &mut [$t], lwork: i32, info: &mut i32) {
+                    lapack::$geqrf(m, n, a, lda, tau, work, lwork, info)
+                }
+
+                unsafe fn orgqr(m: i32, n: i32, k: i32, a: &mut [$t], lda: i32, tau: &[$t], work: &mut [$t], lwork: i32, info: &mut i32) {
+                    lapack::$orgqr(m, n, k, a, lda, tau, work, lwork, info)
+                }
+
+                unsafe fn gesdd(jobz: u8, m: i32, n: i32, a: &mut [$t], lda: i32, s: &mut [$t], u: &mut [$t], ldu: i32, vt: &mut [$t], ldvt: i32, work: &mut [$t], lwork: i32, iwork: &mut [i32], info: &mut i32) {
+                    lapack::$gesdd(jobz, m, n, a, lda, s, u, ldu, vt, ldvt, work, lwork, iwork, info)
+                }
+
+                unsafe fn syev(jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, w: &mut [$t], work: &mut [$t], lwork: i32, info: &mut i32) {
+                    lapack::$syev(jobz, uplo, n, a, lda, w, work, lwork, info)
+                }
+
+                unsafe fn sygv(itype: &[i32], jobz: u8, uplo: u8, n: i32, a: &mut [$t], lda: i32, b: &mut [$t], ldb: i32, w: &mut [$t], work: &mut [$t], lwork: i32, info: &mut i32) {
+                    lapack::$sygv(itype, jobz, uplo, n, a, lda, b, ldb, w, work, lwork, info)
+                }
+
+                unsafe fn gees(jobvs: u8, n: i32, a: &mut [$t], lda: i32, sdim: &mut i32, wr: &mut [$t], wi: &mut [$t], vs: &mut [$t], ldvs: i32, work: &mut [$t], lwork: i32, bwork: &mut [i32], info: &mut i32) {
+                    lapack::$gees(jobvs, b'N', None, n, a, lda, sdim, wr, wi, vs, ldvs, work, lwork, bwork, info)
+                }
+            }
+        )*
+    };
+}
+
+impl_lapack!(
+    f32: sgesv, sgetrf, spotrf, sgeqrf, sorgqr, sgesdd, ssyev, ssygv, sgees;
+    f64: dgesv, dgetrf, dpotrf, dgeqrf, dorgqr, dgesdd, dsyev, dsygv, dgees;
+);
+
+/// Transpose of the row-major `rows x cols` matrix `a`, i.e. its column-major buffer
+fn transpose<T: Copy>(a: &[T], rows: usize, cols: usize) -> Vec<T> {
+    (0..rows * cols)
+        .map(|pos| a[(pos % rows) * cols + pos / rows])
+        .collect()
+}
+
+/// Convert a size into a LAPACK integer, if it fits
+fn int(n: usize) -> Option<i32> {
+    i32::try_from(n).ok()
+}
+
+/// Size of the workspace returned by a query with `lwork = -1`, which is at least `min`
+fn work_size<T: Float>(query: T, min: usize) -> Option<usize> {
+    Some(query.to_usize()?.max(min).max(1))
+}
+
+/// Solve `a x = b` for the `n x n` matrix `a` and the `n x k` right-hand side `b` using `?gesv`
+pub(crate) fn solve<T: Lapack>(a: &[T], b: &[T], n: usize, k: usize) -> Option<Vec<T>> {
+    let (n32, k32) = (int(n)?, int(k)?);
+    let mut a: Vec<T> = transpose(a, n, n);
+    // the right-hand side is `n x k` in column-major order
+    let mut x: Vec<T> = transpose(b, n, k);
+    let mut ipiv: Vec<i32> = vec![0; n];
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `n x n` and `n x k` elements with leading dimension `n`
+    unsafe {
+        T::gesv(
+            n32,
+            k32,
+            &mut a,
+            n32.max(1),
+            &mut ipiv,
+            &mut x,
+            n32.max(1),
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    Some(transpose(&x, k, n))
+}
+
+/// LU factorization with partial pivoting of the `n x n` matrix `a` using `?getrf`. Returns the row permutation and the factors packed into one row-major matrix, as the pure-Rust implementation
+pub(crate) fn lu<T: Lapack>(a: &[T], n: usize) -> Option<(Vec<usize>, Vec<T>)> {
+    let n32: i32 = int(n)?;
+    let mut lu: Vec<T> = transpose(a, n, n);
+    let mut ipiv: Vec<i32> = vec![0; n];
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `n x n` and `n` elements with leading dimension `n`
+    unsafe { T::getrf(n32, n32, &mut lu, n32.max(1), &mut ipiv, &mut info) };
+    // a positive `info` reports an exactly singular `u`, whose factorization is still complete
+    if info < 0 {
+        return None;
+    }
+
+    // the (1-based) row interchanges, applied in order
+    let mut perm: Vec<usize> = (0..n).collect();
+    for (row, pivot) in ipiv.iter().enumerate() {
+        perm.swap(row, usize::try_from(*pivot - 1).ok()?);
+    }
+    Some((perm, transpose(&lu, n, n)))
+}
+
+/// Reduced QR factorization of the `m x n` matrix `a` using `?geqrf` and `?orgqr`. Returns `q` (`m x k`) and `r` (`k x n`) with `k = min(m, n)`
+pub(crate) fn qr<T: Lapack>(a: &[T], m: usize, n: usize) -> Option<(Vec<T>, Vec<T>)> {
+    let k: usize = m.min(n);
+    let (m32, n32, k32) = (int(m)?, int(n)?, int(k)?);
+    let mut qr: Vec<T> = transpose(a, m, n);
+    let mut tau: Vec<T> = vec![T::zero(); k];
+    let mut query: [T; 1] = [T::zero()];
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `m x n` and `k` elements with leading dimension `m`, and `lwork = -1` only queries the workspace
+    unsafe {
+        T::geqrf(
+            m32,
+            n32,
+            &mut qr,
+            m32.max(1),
+            &mut tau,
+            &mut query,
+            -1,
+            &mut info,
+        )
+    };
+    let mut work: Vec<T> = vec![T::zero(); work_size(query[0], n)?];
+    let lwork: i32 = int(work.len())?;
+    // SAFETY: as above, with a workspace of `lwork` elements
+    unsafe {
+        T::geqrf(
+            m32,
+            n32,
+            &mut qr,
+            m32.max(1),
+            &mut tau,
+            &mut work,
+            lwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    // `r` is the upper triangle of the leading `k` rows
+    let mut r: Vec<T> = vec![T::zero(); k * n];
+    for i in 0..k {
+        (i..n).for_each(|j| r[i * n + j] = qr[j * m + i]);
+    }
+
+    // SAFETY: the leading `m x k` elements hold the reflectors, with leading dimension `m`
+    unsafe {
+        T::orgqr(
+            m32,
+            k32,
+            k32,
+            &mut qr,
+            m32.max(1),
+            &tau,
+            &mut query,
+            -1,
+            &mut info,
+        )
+    };
+    let mut work: Vec<T> = vec![T::zero(); work_size(query[0], k)?];
+    let lwork: i32 = int(work.len())?;
+    // SAFETY: as above, with a workspace of `lwork` elements
+    unsafe {
+        T::orgqr(
+            m32,
+            k32,
+            k32,
+            &mut qr,
+            m32.max(1),
+            &tau,
+            &mut work,
+            lwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    Some((transpose(&qr[..m * k], k, m), r))
+}
+
+/// Reduced singular value decomposition of the `m x n` matrix `a` using `?gesdd`. Returns `u` (`m x k`), the singular values in descending order and `vt` (`k x n`) with `k = min(m, n)`
+///
+/// The row-major `a` is the column-major `a^T = v s u^T`, hence the column-major `u` and `vt` computed by LAPACK are the row-major `vt` and `u`
+pub(crate) fn svd<T: Lapack>(a: &[T], m: usize, n: usize) -> Option<(Vec<T>, Vec<T>, Vec<T>)> {
+    let k: usize = m.min(n);
+    let (m32, n32, k32) = (int(m)?, int(n)?, int(k)?);
+    let mut at: Vec<T> = a.to_vec();
+    let mut s: Vec<T> = vec![T::zero(); k];
+    let mut vt: Vec<T> = vec![T::zero(); n * k];
+    let mut u: Vec<T> = vec![T::zero(); k * m];
+    let mut iwork: Vec<i32> = vec![0; 8 * k];
+    let mut query: [T; 1] = [T::zero()];
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `n x m`, `k`, `n x k`, `k x m` and `8k` elements with leading dimensions `n`, `n` and `k`, and `lwork = -1` only queries the workspace
+    unsafe {
+        T::gesdd(
+            b'S',
+            n32,
+            m32,
+            &mut at,
+            n32.max(1),
+            &mut s,
+            &mut vt,
+            n32.max(1),
+            &mut u,
+            k32.max(1),
+            &mut query,
+            -1,
+            &mut iwork,
+            &mut info,
+        )
+    };
+    let mut work: Vec<T> = vec![T::zero(); work_size(query[0], 1)?];
+    let lwork: i32 = int(work.len())?;
+    // SAFETY: as above, with a workspace of `lwork` elements
+    unsafe {
+        T::gesdd(
+            b'S',
+            n32,
+            m32,
+            &mut at,
+            n32.max(1),
+            &mut s,
+            &mut vt,
+            n32.max(1),
+            &mut u,
+            k32.max(1),
+            &mut work,
+            lwork,
+            &mut iwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    Some((u, s, vt))
+}
+
+/// Lower Cholesky factor of the symmetric `n x n` matrix `a` (lower triangle) using `?potrf`
+pub(crate) fn cholesky<T: Lapack>(a: &[T], n: usize) -> Option<Vec<T>> {
+    let n32: i32 = int(n)?;
+    let mut l: Vec<T> = a.to_vec();
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffer holds `n x n` elements with leading dimension `n`
+    unsafe { T::potrf(b'U', n32, &mut l, n32.max(1), &mut info) };
+    if info != 0 {
+        return None;
+    }
+
+    // clear the upper triangle, which still holds the input
+    for i in 0..n {
+        (i + 1..n).for_each(|j| l[i * n + j] = T::zero());
+    }
+    Some(l)
+}
+
+/// Eigenvalues and eigenvectors (as columns) of the symmetric `n x n` matrix `a` (lower triangle) using `?syev`
+pub(crate) fn eigh<T: Lapack>(a: &[T], n: usize) -> Option<(Vec<T>, Vec<T>)> {
+    let n32: i32 = int(n)?;
+    let mut v: Vec<T> = a.to_vec();
+    let mut w: Vec<T> = vec![T::zero(); n];
+    let mut work: Vec<T> = vec![T::zero(); (3 * n).max(1)];
+    let lwork: i32 = int(work.len())?;
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `n x n`, `n` and `3n` elements with leading dimension `n`
+    unsafe {
+        T::syev(
+            b'V',
+            b'U',
+            n32,
+            &mut v,
+            n32.max(1),
+            &mut w,
+            &mut work,
+            lwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    // the eigenvectors are the columns in column-major order, i.e. the rows in row-major order
+    Some((w, transpose(&v, n, n)))
+}
+
+/// Eigenvalues and `b`-orthonormal eigenvectors (as columns) of `a x = λ b x` for the symmetric `a` and the symmetric positive definite `b` (lower triangles) using `?sygv`
+pub(crate) fn eig_generalized<T: Lapack>(a: &[T], b: &[T], n: usize) -> Option<(Vec<T>, Vec<T>)> {
+    let n32: i32 = int(n)?;
+    let mut v: Vec<T> = a.to_vec();
+    let mut l: Vec<T> = b.to_vec();
+    let mut w: Vec<T> = vec![T::zero(); n];
+    let mut work: Vec<T> = vec![T::zero(); (3 * n).max(1)];
+    let lwork: i32 = int(work.len())?;
+    let mut info: i32 = 0;
+
+    // SAFETY: the buffers hold `n x n`, `n x n`, `n` and `3n` elements with leading dimension `n`
+    unsafe {
+        T::sygv(
+            &[1],
+            b'V',
+            b'U',
+            n32,
+            &mut v,
+            n32.max(1),
+            &mut l,
+            n32.max(1),
+            &mut w,
+            &mut work,
+            lwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    Some((w, transpose(&v, n, n)))
+}
+
+/// Real Schur form `t` and Schur vectors `z` of the `n x n` matrix `a` using `?gees`
+pub(crate) fn schur<T: Lapack>(a: &[T], n: usize) -> Option<(Vec<T>, Vec<T>)> {
+    let n32: i32 = int(n)?;
+    let mut t: Vec<T> = transpose(a, n, n);
+    let mut z: Vec<T> = vec![T::zero(); n * n];
+    let (mut wr, mut wi) = (vec![T::zero(); n], vec![T::zero(); n]);
+    let mut work: Vec<T> = vec![T::zero(); (4 * n).max(1)];
+    let lwork: i32 = int(work.len())?;
+    let mut bwork: Vec<i32> = Vec::new();
+    let (mut sdim, mut info) = (0, 0);
+
+    // SAFETY: the buffers hold `n x n`, `n x n`, `n`, `n` and `4n` elements with leading dimension `n`. `bwork` is not referenced without sorting
+    unsafe {
+        T::gees(
+            b'V',
+            n32,
+            &mut t,
+            n32.max(1),
+            &mut sdim,
+            &mut wr,
+            &mut wi,
+            &mut z,
+            n32.max(1),
+            &mut work,
+            lwork,
+            &mut bwork,
+            &mut info,
+        )
+    };
+    if info != 0 {
+        return None;
+    }
+
+    Some((transpose(&t, n, n), transpose(&z, n, n)))
+}
@@ -0,0 +1,262 @@
+use std::ops::Range;
+
+use crate::{
+    core::{NdArray, SizedArray},
+    view::{is_c_contiguous, ArrayView, ArrayViewMut},
+};
+
+/// Return true if the `strides` (in bytes) describe a column-major (F) contiguous layout of `shape`. Strides of axes with a single element are ignored
+fn is_f_contiguous<T, const N: usize>(shape: &SizedArray<N>, strides: &SizedArray<N>) -> bool {
+    let mut rev_shape: SizedArray<N> = *shape;
+    let mut rev_strides: SizedArray<N> = *strides;
+    rev_shape.reverse();
+    rev_strides.reverse();
+
+    is_c_contiguous::<T, N>(&rev_shape, &rev_strides)
+}
+
+/// Return true if the elements occupy a single block of memory without gaps, in any order of the axes
+fn is_dense<T, const N: usize>(shape: &SizedArray<N>, strides: &SizedArray<N>) -> bool {
+    if shape.contains(&0) {
+        return true;
+    }
+
+    let mut axes: Vec<usize> = (0..N).filter(|dim| shape[*dim] != 1).collect();
+    axes.sort_by_key(|dim| strides[*dim]);
+    let mut expected: usize = std::mem::size_of::<T>();
+    for dim in axes {
+        if strides[dim] != expected {
+            return false;
+        }
+        expected *= shape[dim];
+    }
+
+    true
+}
+
+/// Range of the addresses (in bytes) of the memory spanned by the elements. Empty if there are no elements
+fn byte_range<T, const N: usize>(
+    ptr: *const T,
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+) -> Range<usize> {
+    let start: usize = ptr as usize;
+    if shape.contains(&0) {
+        return start..start;
+    }
+
+    let last: usize = shape
+        .iter()
+        .zip(strides.iter())
+        .map(|(len, stride)| (len - 1) * stride)
+        .sum();
+    start..start + last + std::mem::size_of::<T>().max(1)
+}
+
+impl<T, const N: usize> ArrayView<'_, T, N> {
+    /// Return true if the view is contiguous in row-major (C) order, also known as the standard layout
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::zeros([3, 4]);
+    /// let (top, _) = arr.split_at(0, 2);
+    /// let (left, _) = arr.split_at(1, 2);
+    /// assert!(top.is_standard_layout());
+    /// assert!(!left.is_standard_layout());
+    /// # }
+    /// ```
+    pub fn is_standard_layout(&self) -> bool {
+        is_c_contiguous::<T, N>(self.shape(), self.strides())
+    }
+
+    /// Return true if the view is contiguous in column-major (F) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::zeros([3, 4]);
+    /// assert!(!arr.view().is_fortran_layout());
+    /// let (row, _) = arr.split_at(0, 1); // a single row is contiguous in both orders
+    /// assert!(row.is_fortran_layout());
+    /// # }
+    /// ```
+    pub fn is_fortran_layout(&self) -> bool {
+        is_f_contiguous::<T, N>(self.shape(), self.strides())
+    }
+
+    /// Return true if the elements of the view occupy a single block of memory without gaps, in any order of the axes
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 3>::zeros([2, 3, 4]);
+    /// let (first, _) = arr.split_at(0, 1);
+    /// assert!(first.is_contiguous());
+    /// let (_, last) = arr.split_at(2, 3);
+    /// assert!(!last.is_contiguous());
+    /// # }
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        is_dense::<T, N>(self.shape(), self.strides())
+    }
+
+    /// Return the element offset of the first element of the view from the first element of `array`. Returns `None` if the view does not start within the memory of `array`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u32, 2>::zeros([3, 4]);
+    /// let (_, bottom) = arr.split_at(0, 1);
+    /// assert_eq!(bottom.offset_in(&arr), Some(4));
+    ///
+    /// let other = NdArray::<u32, 2>::zeros([3, 4]);
+    /// assert_eq!(bottom.offset_in(&other), None);
+    /// # }
+    /// ```
+    pub fn offset_in<const M: usize>(&self, array: &NdArray<T, M>) -> Option<usize> {
+        let start: usize = array.as_ptr() as usize;
+        let ptr: usize = self.as_ptr() as usize;
+        let size: usize = std::mem::size_of::<T>().max(1);
+        if ptr < start || ptr >= start + *array.len() * size {
+            return None;
+        }
+
+        Some((ptr - start) / size)
+    }
+
+    /// Return true if the memory spanned by this view overlaps the memory spanned by `other`
+    ///
+    /// ## Note
+    /// Similar to `numpy.may_share_memory`, only the bounds of the memory are compared. Hence, two interleaved views (e.g. even and odd columns) are reported to share memory even if no element is shared
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::zeros([4, 2]);
+    /// let (top, bottom) = arr.split_at(0, 2);
+    /// assert!(!top.may_share_memory(&bottom));
+    /// assert!(top.may_share_memory(&arr.view()));
+    /// # }
+    /// ```
+    pub fn may_share_memory<const M: usize>(&self, other: &ArrayView<'_, T, M>) -> bool {
+        let this: Range<usize> = byte_range(self.as_ptr(), self.shape(), self.strides());
+        let other: Range<usize> = byte_range(other.as_ptr(), other.shape(), other.strides());
+
+        !this.is_empty() && !other.is_empty() && this.start < other.end && other.start < this.end
+    }
+}
+
+impl<T, const N: usize> ArrayViewMut<'_, T, N> {
+    /// Return true if the view is contiguous in row-major (C) order, also known as the standard layout
+    pub fn is_standard_layout(&self) -> bool {
+        self.view().is_standard_layout()
+    }
+
+    /// Return true if the view is contiguous in column-major (F) order
+    pub fn is_fortran_layout(&self) -> bool {
+        self.view().is_fortran_layout()
+    }
+
+    /// Return true if the elements of the view occupy a single block of memory without gaps, in any order of the axes
+    pub fn is_contiguous(&self) -> bool {
+        self.view().is_contiguous()
+    }
+
+    /// Return the element offset of the first element of the view from the first element of `array`. Returns `None` if the view does not start within the memory of `array`
+    pub fn offset_in<const M: usize>(&self, array: &NdArray<T, M>) -> Option<usize> {
+        self.view().offset_in(array)
+    }
+
+    /// Return true if the memory spanned by this view overlaps the memory spanned by `other`. Only the bounds of the memory are compared
+    pub fn may_share_memory<const M: usize>(&self, other: &ArrayView<'_, T, M>) -> bool {
+        self.view().may_share_memory(other)
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Return true if the NdArray is contiguous in row-major (C) order. Always true, as an NdArray owns a contiguous sized array in row-major order
+    pub fn is_standard_layout(&self) -> bool {
+        self.view().is_standard_layout()
+    }
+
+    /// Return true if the NdArray is contiguous in column-major (F) order. True only if at most one axis has more than one element
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// assert!(NdArray::<f32, 2>::zeros([1, 5]).is_fortran_layout());
+    /// assert!(!NdArray::<f32, 2>::zeros([2, 5]).is_fortran_layout());
+    /// # }
+    /// ```
+    pub fn is_fortran_layout(&self) -> bool {
+        self.view().is_fortran_layout()
+    }
+
+    /// Return true if the elements occupy a single block of memory without gaps. Always true for an NdArray
+    pub fn is_contiguous(&self) -> bool {
+        self.view().is_contiguous()
+    }
+}
+
+#[cfg(test)]
+mod layout_t {
+    use crate::core::{Array2, Array3, NdArray};
+
+    // Test the layout of the halves of a 3-D NdArray split along every axis
+    #[test]
+    fn split_layout_3dim_t() {
+        let data: NdArray<u16, 3> = Array3::<u16>::zeros([2, 3, 4]);
+        assert!(data.is_standard_layout());
+        assert!(!data.is_fortran_layout());
+
+        let layouts: Vec<(bool, bool)> = (0..3)
+            .map(|axis| {
+                let (left, _) = data.split_at(axis, 1);
+                (left.is_standard_layout(), left.is_contiguous())
+            })
+            .collect();
+        assert_eq!(layouts, vec![(true, true), (false, false), (false, false)]);
+    }
+
+    // Test the element offset of the views of a 2-D NdArray
+    #[test]
+    fn offset_in_2dim_t() {
+        let data = Array2::<f64>::zeros([4, 5]);
+        let (_, bottom) = data.split_at(0, 3);
+        let (_, corner) = bottom.split_at(1, 2);
+        assert_eq!(bottom.offset_in(&data), Some(15));
+        assert_eq!(corner.offset_in(&data), Some(17));
+    }
+
+    // Test the memory shared by the views of different arrays
+    #[test]
+    fn may_share_memory_t() {
+        let mut data = Array2::<i32>::zeros([3, 3]);
+        let other = Array2::<i32>::zeros([3, 3]);
+        let (left, right) = data.split_at_mut(1, 1);
+        assert!(left.may_share_memory(&right.view())); // interleaved rows share the bounds of the memory
+        assert!(!left.may_share_memory(&other.view()));
+
+        let empty = NdArray::<i32, 2>::new();
+        assert!(!empty.view().may_share_memory(&other.view()));
+    }
+}
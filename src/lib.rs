@@ -46,12 +46,12 @@
 //! + **N-Dimensional Array Storage:** Efficient storage and manipulation of n-dimensional arrays.
 //! + **Generic Data Types:** Supports various numeric types, including integers and floating-point numbers.
 //! + **Basic Array Operations:** Provides basic operations such as array creation, indexing, and element-wise operations.
+//! + **Mapping and looping:** Loop over the n-dimensional array with iterators, `Zip`, and (viz.) map, fold and reduce methods.
 //!
 //! ## Upcoming Features
 //!
 //! + **Fancy printing:** Print the n-dimensional array conforming to its shape.
 //! + **Axes mutation:** Change values of the n-dimensional array in an axis and much more with axes.
-//! + **Mapping and other looping support:** Loop over the n-dimensional array by using (viz.) map, reduce, filter, etc. methods.
 //! + **BLAS Support:** Integration with Basic Linear Algebra Subprograms (BLAS) for advanced linear algebra operations.
 //!
 
@@ -64,15 +64,60 @@
 /// + [`Array2<N>`](https://docs.rs/ndim/latest/ndim/core/type.Array2.html)
 /// + [`Array3<N>`](https://docs.rs/ndim/latest/ndim/core/type.Array3.html)
 /// + [`Array4<N>`](https://docs.rs/ndim/latest/ndim/core/type.Array4.html)
+/// + [`Order`](https://docs.rs/ndim/latest/ndim/core/enum.Order.html)
+/// + [`Indexing`](https://docs.rs/ndim/latest/ndim/core/enum.Indexing.html)
+/// + [`ShapeError`](https://docs.rs/ndim/latest/ndim/core/enum.ShapeError.html)
 ///
 /// ## APIs (available in NdArray)
 ///
-/// + [`NdArray::<T, N>::new()`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.new) 
-///     + Create an empty NdArray
+/// + [`array![..]`](https://docs.rs/ndim/latest/ndim/macro.array.html)
+///     + Create a 1-D, 2-D or 3-D NdArray from nested literals
+/// + [`NdArray::<T, N>::new()`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.new)
+///     + Create an empty NdArray (same as `NdArray::<T, N>::default()`)
+/// + [`NdArray::<T, N>::is_empty(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.is_empty)
+///     + Check if the NdArray has no elements
+/// + [`NdArray::<T, N>::get(&self, index: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.get)
+///     + Access an element, returning `None` if the index is out of the shape (see `get_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::at(&self, index: [isize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.at)
+///     + Access an element with negative indices counted from the end of each axis (see `at_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::uget(&self, index: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.uget)
+///     + Access an element without checking the bounds (unsafe, see `uget_mut` for the mutable variant)
 /// + [`NdArray::<T, N>::from(arr: &[T], shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from)
-///     + Create an NdArray from a sized array with a shape
+///     + Create an NdArray from a sized array with a shape, copying the elements
+/// + [`NdArray::<T, N>::from_slice(arr: &[T], shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_slice)
+///     + Create an NdArray by copying the elements of a slice
+/// + [`NdArray::<T, N>::from_vec(vec: Vec<T>, shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_vec)
+///     + Create an NdArray which takes the ownership of a Vec
+/// + [`NdArray::<T, N>::from_shape_vec(shape: [usize; N], vec: Vec<T>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_shape_vec)
+///     + Create an NdArray which takes the ownership of a Vec, returning a `ShapeError` instead of panicking
+/// + [`NdArray::<T, N>::from_shape_fn(shape: [usize; N], f: F)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_shape_fn)
+///     + Create an NdArray from a function of the index of each element
+/// + [`NdArray::<MaybeUninit<T>, N>::uninit(shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.uninit)
+///     + Create an NdArray whose elements are not initialized, converted using the unsafe `assume_init()` once written
+/// + [`NdArray::<T, N>::assign(&mut self, other: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.assign)
+///     + Overwrite the elements with those of an NdArray of the same shape (see `fill` and `copy_from_slice`)
+/// + [`NdArray::<T, N>::map(&self, f: F)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.map)
+///     + Apply a closure to every element and return a new NdArray (see `mapv` for by-value closures, and `map_inplace` to mutate in-place)
+/// + [`NdArray::<T, N>::try_from(arr: &[T], shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.try_from)
+///     + Create an NdArray from a sized array, returning a `ShapeError` instead of panicking (see `try_from_vec` for the moving variant)
 /// + [`NdArray::<T, N>::reshape(&mut self, shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.reshape)
 ///     + Reshape an NdArray
+/// + [`NdArray::<T, N>::try_reshape(&mut self, shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.try_reshape)
+///     + Reshape an NdArray, returning a `ShapeError` instead of panicking
+/// + [`NdArray::<T, N>::into_shape(self, shape: [usize; M])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.into_shape)
+///     + Reshape an NdArray into a different dimension without copying (see `try_into_shape` for the fallible variant)
+/// + [`NdArray::<T, N>::into_vec(self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.into_vec)
+///     + Return the elements as a Vec without copying (see `flatten` for a 1-D copy and `ravel` for a 1-D view)
+/// + [`NdArray::<T, N>::insert_axis(self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.insert_axis)
+///     + Add an axis of length 1, returning an NdArray of one higher dimension (see `squeeze` to remove axes of length 1)
+/// + [`NdArray::<T, N>::reshape_infer(&mut self, shape: [isize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.reshape_infer)
+///     + Reshape an NdArray, inferring one dimension from its length
+/// + [`NdArray::<T, N>::reshape_with_order(&mut self, shape: [usize; N], order: Order)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.reshape_with_order)
+///     + Reshape an NdArray in row-major (C) or column-major (F) order
+/// + [`NdArray::<T, N>::flatten_with_order(&self, order: Order)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.flatten_with_order)
+///     + Copy the elements of an NdArray into a 1-D NdArray in the given order
+/// + [`NdArray::<T, N>::as_slice(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.as_slice)
+///     + Access the sized array as a contiguous slice
 ///
 /// + [`NdArray::<T, N>::arange(range: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.arange)
 ///     + Create an NdArray from 0 to an end value for type T
@@ -82,9 +127,511 @@
 ///     + Create an NdArray from a start value, and a end value for type T
 /// + [`NdArray::<T, N>::ranges_with_step(range: (isize, isize), step: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.ranges_with_step)
 ///     + Create an NdArray from a start value, end value, and a step value for type T
+/// + [`NdArray::<T, N>::linspace(start: T, stop: T, num: usize, endpoint: bool)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.linspace)
+///     + Create an NdArray of evenly spaced floating point values
+/// + [`NdArray::<T, N>::arange_f(start: T, stop: T, step: T)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.arange_f)
+///     + Create an NdArray from a start value to an end value with a fractional step for floats
+/// + [`NdArray::<T, N>::logspace(start_exp: T, stop_exp: T, num: usize, base: T)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.logspace)
+///     + Create an NdArray of values spaced evenly on a log scale (see `geomspace` to use the endpoints instead of the exponents)
 ///
 /// + [`NdArray::<T, N>::zeros(shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.zeros)
-///     + Create an NdArray with zeros
+///     + Create an NdArray with zeros (see `try_zeros` to return an `NdimError` if the memory cannot be allocated)
 /// + [`NdArray::<T, N>::ones(shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.ones)
 ///     + Create an NdArray with ones
+/// + [`meshgrid(x: &NdArray<T, 1>, y: &NdArray<T, 1>, indexing: Indexing)`](https://docs.rs/ndim/latest/ndim/core/fn.meshgrid.html)
+///     + Create the coordinate matrices of a 2-D grid (see `meshgrid3` for a 3-D grid)
+/// + [`NdArray::<T, N>::full(value: T, shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.full)
+///     + Create an NdArray filled with a value
+/// + [`NdArray::<T, N>::full_like(other: &NdArray<U, N>, value: T)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.full_like)
+///     + Create an NdArray filled with a value, with the shape of another NdArray (see `zeros_like` and `ones_like`)
+/// + [`NdArray::<T, 2>::eye(rows: usize, cols: usize, k: isize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.eye)
+///     + Create a 2-D array with ones on a diagonal (see `identity` for a square identity matrix)
+/// + [`NdArray::<T, 2>::from_diag(diag: &NdArray<T, 1>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_diag)
+///     + Create a square 2-D array with the elements of a 1-D array on the diagonal
+/// + [`NdArray::<T, 2>::diagonal(&self, k: isize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.diagonal)
+///     + Return the k-th diagonal of a 2-D array as a 1-D array
+/// + [`NdArray::<T, 2>::tri(rows: usize, cols: usize, k: isize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.tri)
+///     + Create a 2-D array with ones at and below a diagonal (see `tril` and `triu` for the triangles of an array)
 pub mod core;
+
+/// Error type returned by the fallible APIs of the crate
+///
+/// ## Types
+/// + [`NdimError`](https://docs.rs/ndim/latest/ndim/error/enum.NdimError.html)
+///
+/// ## APIs with docs
+/// + [`NdArray::<T, N>::try_index_axis(&self, axis: usize, index: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.try_index_axis)
+///     + Select a position along an axis, returning an `NdimError` instead of panicking
+pub mod error;
+
+/// Traits converting other types into N-dimensional arrays
+///
+/// ## Types
+/// + [`ArrayLike<T, N>`](https://docs.rs/ndim/latest/ndim/traits/trait.ArrayLike.html)
+///
+/// ## APIs with docs
+/// + [`ArrayLike::<T, N>::into_array(self, shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/traits/trait.ArrayLike.html#tymethod.into_array)
+///     + Convert a slice, a Vec (without copying) or an array into an NdArray, returning `ShapeError::TooLong` or `ShapeError::TooShort` if the length does not match (see `array` for the borrowing variant)
+pub mod traits;
+
+/// API to build N-dimensional arrays incrementally
+///
+/// ## Types
+/// + [`NdArrayBuilder<T>`](https://docs.rs/ndim/latest/ndim/builder/struct.NdArrayBuilder.html)
+///
+/// ## APIs with docs
+/// + [`NdArrayBuilder::<T>::push_row(&mut self, row: &[T])`](https://docs.rs/ndim/latest/ndim/builder/struct.NdArrayBuilder.html#method.push_row)
+///     + Append a row, checking its length against the first row (see `push_row_vec` for the moving variant)
+/// + [`NdArrayBuilder::<T>::build(self)`](https://docs.rs/ndim/latest/ndim/builder/struct.NdArrayBuilder.html#method.build)
+///     + Finish the rows as a 2-D array (see `build3` for a 3-D array)
+pub mod builder;
+
+/// API to render 1-D and 2-D arrays as Markdown and LaTeX tables
+///
+/// ## Types
+///
+/// + [`Align`](https://docs.rs/ndim/latest/ndim/table/enum.Align.html)
+/// + [`TableOptions`](https://docs.rs/ndim/latest/ndim/table/struct.TableOptions.html)
+///
+/// ## APIs (available in NdArray)
+///
+/// + [`NdArray::<T, N>::to_markdown(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.to_markdown)
+///     + Render a 1-D or 2-D NdArray as a Markdown table
+/// + [`NdArray::<T, N>::to_latex(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.to_latex)
+///     + Render a 1-D or 2-D NdArray as a LaTeX table
+pub mod table;
+
+/// API to describe how an N-dimensional array is sliced
+///
+/// ## Types
+///
+/// + [`SliceArg`](https://docs.rs/ndim/latest/ndim/slice/enum.SliceArg.html)
+/// + [`SliceInfo<K>`](https://docs.rs/ndim/latest/ndim/slice/struct.SliceInfo.html)
+///
+/// ## Macros
+///
+/// + [`s![..]`](https://docs.rs/ndim/latest/ndim/macro.s.html)
+///     + Build a `SliceInfo<K>` from ranges, steps and indices
+pub mod slice;
+
+/// API to borrow an N-dimensional array without copying its data
+///
+/// ## Types
+///
+/// + [`ArrayView<'a, T, N>`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html)
+/// + [`ArrayViewMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayViewMut.html)
+/// + [`CowArray<'a, T, N>`](https://docs.rs/ndim/latest/ndim/view/enum.CowArray.html)
+///
+/// ## APIs (available in NdArray)
+///
+/// + [`NdArray::<T, N>::view(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.view)
+///     + Borrow the NdArray as a read-only view
+/// + [`NdArray::<T, N>::view_mut(&mut self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.view_mut)
+///     + Borrow the NdArray as a mutable view
+/// + [`NdArray::<T, N>::ravel_with_order(&self, order: Order)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.ravel_with_order)
+///     + Flatten the NdArray in the given order, borrowing it when possible
+/// + [`ArrayView::<'a, T, N>::as_standard_layout(self)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.as_standard_layout)
+///     + Borrow the view if it is contiguous, else copy it into a contiguous NdArray
+/// + [`ArrayView::<'a, T, N>::to_owned(&self)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.to_owned)
+///     + Copy the elements of the view into a new NdArray
+/// + [`NdArray::<T, N>::index_axis(&self, axis: usize, index: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.index_axis)
+///     + Select a position along an axis and return a view of one lower dimension (see `index_axis_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::subview(&self, index: [usize; K])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.subview)
+///     + Fix the leading axes and return a view of the remaining axes (see `subview_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::as_strided(&self, shape: [usize; M], strides: [usize; M])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.as_strided)
+///     + Create a view with arbitrary shape and strides (unsafe, see `try_as_strided` for the checked variant)
+/// + [`NdArray::<T, N>::sliding_window_view(&self, window: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.sliding_window_view)
+///     + Return a view of every overlapping window of the NdArray, built safely on `as_strided`
+/// + [`NdArray::<T, N>::split_at(&self, axis: usize, index: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.split_at)
+///     + Split the NdArray into two non-overlapping views along an axis
+/// + [`NdArray::<T, N>::split_at_mut(&mut self, axis: usize, index: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.split_at_mut)
+///     + Split the NdArray into two non-overlapping mutable views along an axis
+/// + [`NdArray::<T, N>::slice(&self, info: SliceInfo<N>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.slice)
+///     + Slice the NdArray with `s![..]` and return a view without copying (see `slice_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::permuted_axes(&self, axes: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.permuted_axes)
+///     + Reorder the axes of a view without copying (see `swap_axes`, `reversed_axes` and `t` for a 2-D transpose)
+/// + [`NdArray::<T, 2>::diag(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.diag)
+///     + Return the main diagonal as a strided view (see `diag_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::broadcast_to(&self, shape: [usize; M])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.broadcast_to)
+///     + Broadcast to a larger shape as a read-only view with zero strides on the repeated axes
+pub mod view;
+
+/// API to iterate over an N-dimensional array
+///
+/// ## Types
+///
+/// + [`Iter<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.Iter.html)
+/// + [`IterMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.IterMut.html)
+/// + [`IndexedIter<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.IndexedIter.html)
+/// + [`IndexedIterMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.IndexedIterMut.html)
+/// + [`AxisIter<'a, T, N, M>`](https://docs.rs/ndim/latest/ndim/iter/struct.AxisIter.html)
+/// + [`AxisIterMut<'a, T, N, M>`](https://docs.rs/ndim/latest/ndim/iter/struct.AxisIterMut.html)
+/// + [`AxisChunksIter<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.AxisChunksIter.html)
+/// + [`AxisChunksIterMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.AxisChunksIterMut.html)
+/// + [`Lanes<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.Lanes.html)
+/// + [`LanesMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.LanesMut.html)
+/// + [`Windows<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.Windows.html)
+/// + [`ExactChunks<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.ExactChunks.html)
+/// + [`ExactChunksMut<'a, T, N>`](https://docs.rs/ndim/latest/ndim/iter/struct.ExactChunksMut.html)
+/// + [`CollectShaped`](https://docs.rs/ndim/latest/ndim/iter/trait.CollectShaped.html)
+///
+/// ## APIs (available in NdArray)
+///
+/// + [`NdArray::<T, N>::iter(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.iter)
+///     + Iterate over every element in row-major order (see `iter_mut`, and `IntoIterator` for `&NdArray`, `&mut NdArray` and `NdArray`)
+/// + [`NdArray::<T, N>::iter_with_order(&self, order: Order)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.iter_with_order)
+///     + Iterate over every element in row-major (C) or column-major (F) order (see `indexed_iter_with_order`, and `flatten_with_order` to copy)
+/// + [`NdArray::<T, N>::indexed_iter(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.indexed_iter)
+///     + Iterate over the index and value of every element in row-major order (see `indexed_iter_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::axis_iter(&self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.axis_iter)
+///     + Iterate over the subviews of one lower dimension along an axis (see `outer_iter` for the first axis, and `axis_iter_mut` and `outer_iter_mut` for the mutable variants)
+/// + [`NdArray::<T, N>::axis_chunks_iter(&self, axis: usize, size: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.axis_chunks_iter)
+///     + Iterate over consecutive chunks of a given thickness along an axis
+/// + [`NdArray::<T, N>::axis_chunks_iter_mut(&mut self, axis: usize, size: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.axis_chunks_iter_mut)
+///     + Iterate over consecutive mutable chunks of a given thickness along an axis
+/// + [`NdArray::<T, N>::lanes(&self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.lanes)
+///     + Iterate over every 1-D lane along an axis
+/// + [`NdArray::<T, N>::lanes_mut(&mut self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.lanes_mut)
+///     + Iterate over every mutable 1-D lane along an axis
+/// + [`NdArray::<T, N>::rows(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.rows)
+///     + Iterate over every row, i.e., the lanes along the last axis (see `rows_mut`, and `columns` and `columns_mut` for the first axis)
+/// + [`NdArray::<T, N>::windows(&self, window: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.windows)
+///     + Iterate over every overlapping window of a given shape
+/// + [`NdArray::<T, N>::exact_chunks(&self, chunk: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.exact_chunks)
+///     + Iterate over the non-overlapping chunks of a given shape, skipping the remainders (see `exact_chunks_mut` for the mutable variant)
+/// + [`NdArray::<T, N>::fold(&self, init: U, f: F)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.fold)
+///     + Fold every element into an accumulator (see `for_each`, and `reduce` to start with the first element)
+/// + [`NdArray::<T, N>::fold_axis(&self, axis: usize, init: U, f: F)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.fold_axis)
+///     + Fold every lane along an axis and return an NdArray of one lower dimension
+/// + [`NdArray::<T, N>::from_iter_shaped(shape: [usize; N], iter: I)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.from_iter_shaped)
+///     + Collect an iterator into an NdArray of a given shape (see `CollectShaped::collect_shaped` for the iterator extension)
+pub mod iter;
+
+/// API to traverse several N-dimensional arrays of the same shape in lock-step
+///
+/// ## Types
+///
+/// + [`Zip<P, N>`](https://docs.rs/ndim/latest/ndim/zip/struct.Zip.html)
+/// + [`NdProducer<N>`](https://docs.rs/ndim/latest/ndim/zip/trait.NdProducer.html)
+/// + [`IntoNdProducer<N>`](https://docs.rs/ndim/latest/ndim/zip/trait.IntoNdProducer.html)
+///
+/// ## APIs
+///
+/// + [`Zip::<(P,), N>::from(producer: I)`](https://docs.rs/ndim/latest/ndim/zip/struct.Zip.html#method.from)
+///     + Start a traversal with an NdArray or a view, and add up to 3 more with `and`
+/// + [`Zip::<P, N>::and_broadcast(self, producer: I)`](https://docs.rs/ndim/latest/ndim/zip/struct.Zip.html#method.and_broadcast)
+///     + Add a read-only array of a lower or equal dimension, broadcast to the shape of the traversal
+/// + [`Zip::<P, N>::for_each(self, f: F)`](https://docs.rs/ndim/latest/ndim/zip/struct.Zip.html#method.for_each)
+///     + Call a closure with the elements of every array at each index (see `map_collect` to collect the results)
+///
+/// ## APIs (available in NdArray)
+///
+/// + [`NdArray::<T, N>::zip_mut_with(&mut self, rhs: &NdArray<U, N>, f: F)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.zip_mut_with)
+///     + Combine another array, broadcast to the same shape, into the NdArray in-place
+pub mod zip;
+
+/// API to query the memory layout of N-dimensional arrays and views
+///
+/// ## APIs (available in NdArray, ArrayView and ArrayViewMut)
+///
+/// + [`ArrayView::<'a, T, N>::is_standard_layout(&self)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.is_standard_layout)
+///     + Check if the elements are contiguous in row-major (C) order
+/// + [`ArrayView::<'a, T, N>::is_fortran_layout(&self)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.is_fortran_layout)
+///     + Check if the elements are contiguous in column-major (F) order
+/// + [`ArrayView::<'a, T, N>::is_contiguous(&self)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.is_contiguous)
+///     + Check if the elements occupy a single block of memory
+/// + [`ArrayView::<'a, T, N>::offset_in(&self, array: &NdArray<T, M>)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.offset_in)
+///     + Element offset of a view in the array it borrows from
+/// + [`ArrayView::<'a, T, N>::may_share_memory(&self, other: &ArrayView<'_, T, M>)`](https://docs.rs/ndim/latest/ndim/view/struct.ArrayView.html#method.may_share_memory)
+///     + Check if two views may alias the same memory
+pub mod layout;
+
+/// API for linear algebra on N-dimensional arrays. With the `lapack` feature, the `f32`/`f64` decompositions use LAPACK
+///
+/// ## Note
+/// The `lapack` feature selects no LAPACK provider. A LAPACK (and BLAS) library must be linked separately, e.g. with `RUSTFLAGS="-l lapack -l blas"` for the system library, or by depending on `lapack-src` with a backend and adding `extern crate lapack_src;`. Otherwise the build fails to link with undefined symbols such as `dgesv_`
+///
+/// ## APIs with docs
+/// + [`cross(a: &NdArray<T, 1>, b: &NdArray<T, 1>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.cross.html)
+///     + Cross product of two 3-vectors
+/// + [`cross_batched(a: &NdArray<T, N>, b: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.cross_batched.html)
+///     + Cross product of the 3-vectors along the last axis
+/// + [`solve(a: &NdArray<T, 2>, b: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.solve.html)
+///     + Solve a linear system using LU factorization
+/// + [`lu(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.lu.html)
+///     + LU factorization with partial pivoting
+/// + [`qr(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.qr.html)
+///     + Reduced QR factorization using Householder reflections
+/// + [`svd(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.svd.html)
+///     + Reduced singular value decomposition using one-sided Jacobi rotations
+/// + [`expm(a: &NdArray<f64, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.expm.html)
+///     + Matrix exponential using scaling and squaring with a Padé approximation
+/// + [`schur(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.schur.html)
+///     + Real Schur decomposition using Hessenberg reduction and Francis QR iterations
+/// + [`cholesky(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.cholesky.html)
+///     + Cholesky factor of a symmetric positive definite matrix
+/// + [`eigh(a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.eigh.html)
+///     + Eigenvalues and eigenvectors of a symmetric matrix
+/// + [`eig_generalized(a: &NdArray<T, 2>, b: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/linalg/fn.eig_generalized.html)
+///     + Generalized eigenvalue problem `a x = λ b x` for a symmetric-definite pair
+pub mod linalg;
+
+// LAPACK kernels used by `linalg` with the `lapack` feature
+#[cfg(feature = "lapack")]
+mod lapack;
+
+/// API for tridiagonal and banded matrices, storing only their diagonals
+///
+/// ## Types
+/// + [`Tridiagonal`](https://docs.rs/ndim/latest/ndim/banded/struct.Tridiagonal.html)
+/// + [`Banded`](https://docs.rs/ndim/latest/ndim/banded/struct.Banded.html)
+///
+/// ## APIs with docs
+/// + [`Tridiagonal::solve(&self, b: &NdArray<f64, 1>)`](https://docs.rs/ndim/latest/ndim/banded/struct.Tridiagonal.html#method.solve)
+///     + Solve a tridiagonal system using the Thomas algorithm
+/// + [`Banded::solve(&self, b: &NdArray<f64, 1>)`](https://docs.rs/ndim/latest/ndim/banded/struct.Banded.html#method.solve)
+///     + Solve a banded system using Gaussian elimination with partial pivoting
+pub mod banded;
+
+/// API for sparse matrices and their products with N-dimensional arrays
+///
+/// ## Types
+/// + [`CsrMatrix`](https://docs.rs/ndim/latest/ndim/sparse/struct.CsrMatrix.html)
+///
+/// ## APIs with docs
+/// + [`CsrMatrix::<T>::matvec(&self, x: &NdArray<T, 1>)`](https://docs.rs/ndim/latest/ndim/sparse/struct.CsrMatrix.html#method.matvec)
+///     + Product of a CSR matrix and a dense vector (see `matvec_parallel` for the threaded variant)
+/// + [`CsrMatrix::<T>::matmul(&self, b: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/sparse/struct.CsrMatrix.html#method.matmul)
+///     + Product of a CSR matrix and a dense matrix (see `matmul_parallel` for the threaded variant)
+/// + [`CsrMatrix::<T>::rmatmul(&self, a: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/sparse/struct.CsrMatrix.html#method.rmatmul)
+///     + Product of a dense matrix and a CSR matrix (see `rmatmul_parallel` for the threaded variant)
+pub mod sparse;
+
+/// API for two dimensional arrays with a shape known at compile time
+///
+/// ## Types
+/// + [`StaticArray`](https://docs.rs/ndim/latest/ndim/static_array/struct.StaticArray.html)
+///
+/// ## APIs with docs
+/// + [`StaticArray::<T, R, C>::matmul(&self, other: &StaticArray<T, C, K>)`](https://docs.rs/ndim/latest/ndim/static_array/struct.StaticArray.html#method.matmul)
+///     + Matrix product, with the shapes checked at compile time
+/// + [`StaticArray::<T, R, C>::concat_rows(&self, other: &StaticArray<T, R2, C>)`](https://docs.rs/ndim/latest/ndim/static_array/struct.StaticArray.html#method.concat_rows)
+///     + Stack rows (see `concat_cols` for columns), with the shapes checked at compile time
+/// + [`StaticArray::<T, R, C>::from_ndarray(array: &NdArray<T, 2>)`](https://docs.rs/ndim/latest/ndim/static_array/struct.StaticArray.html#method.from_ndarray)
+///     + Convert from a runtime-shaped NdArray (see `to_ndarray` for the reverse)
+pub mod static_array;
+
+/// API for descriptive statistics along an axis of N-dimensional arrays
+///
+/// ## APIs with docs
+/// + [`NdArray::<T, N>::mean(&self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.mean)
+///     + Arithmetic mean along an axis (see `var` for the variance)
+/// + [`NdArray::<T, N>::skew(&self, axis: usize, bias: bool)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.skew)
+///     + Sample skewness along an axis
+/// + [`NdArray::<T, N>::kurtosis(&self, axis: usize, fisher: bool, bias: bool)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.kurtosis)
+///     + Sample (excess) kurtosis along an axis
+/// + [`NdArray::<T, N>::mode(&self, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.mode)
+///     + Most frequent value and its count along an axis
+/// + [`NdArray::<T, N>::groupby_sum(&self, labels: &NdArray<L, 1>, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.groupby_sum)
+///     + Segment reduction keyed by a label array (see `groupby_mean` and `groupby_max`)
+pub mod stats;
+
+/// API for element-wise mathematical functions of N-dimensional arrays, with broadcasting
+///
+/// ## APIs with docs
+/// + [`maximum(a: &NdArray<T, N>, b: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/math/fn.maximum.html)
+///     + Element-wise maximum, propagating NaN (see `minimum`)
+/// + [`maximum_scalar(a: &NdArray<T, N>, value: T)`](https://docs.rs/ndim/latest/ndim/math/fn.maximum_scalar.html)
+///     + Element-wise maximum with a scalar, propagating NaN (see `minimum_scalar`)
+/// + [`fmax(a: &NdArray<T, N>, b: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/math/fn.fmax.html)
+///     + Element-wise maximum, ignoring NaN (see `fmin`)
+/// + [`hypot(a: &NdArray<T, N>, b: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/math/fn.hypot.html)
+///     + Element-wise length of the hypotenuse (see `atan2` and `copysign`)
+/// + [`heaviside(x: &NdArray<T, N>, h0: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/math/fn.heaviside.html)
+///     + Element-wise Heaviside step function
+/// + [`NdArray::<T, N>::eq_elem(&self, other: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.eq_elem)
+///     + Element-wise equality as a boolean NdArray (see `ne_elem`)
+/// + [`NdArray::<T, N>::gt(&self, other: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.gt)
+///     + Element-wise ordering as a boolean NdArray (see `ge`, `lt` and `le`)
+/// + [`NdArray::<T, N>::clip(&self, min: Option<T>, max: Option<T>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.clip)
+///     + Element-wise clamping into a range, optionally bounded on either side (see `clip_inplace`)
+/// + [`NdArray::<T, N>::signum(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.signum)
+///     + Element-wise sign of a signed integer or float NdArray, mapping zero to zero
+/// + [`NdArray::<T, N>::abs(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.abs)
+///     + Element-wise absolute value of a signed integer or float NdArray
+/// + [`NdArray::<T, N>::floor(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.floor)
+///     + Element-wise rounding of a float NdArray (see `ceil`, `round` and `trunc`)
+pub mod math;
+
+/// API for geometric transforms of 2-D images and 3-D images with channels (`[height, width, channels]`)
+///
+/// ## Types
+/// + [`Interpolation`](https://docs.rs/ndim/latest/ndim/imgproc/enum.Interpolation.html)
+///     + Nearest or bilinear interpolation of the pixel values
+///
+/// ## APIs with docs
+/// + [`NdArray::<T, 2>::resize(&self, new_hw: [usize; 2], interpolation: Interpolation)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.resize)
+///     + Resize an image (also for `NdArray<T, 3>`)
+/// + [`NdArray::<T, 2>::warp_affine(&self, matrix: [[f64; 3]; 2], interpolation: Interpolation)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.warp_affine)
+///     + Warp an image by an affine map (also for `NdArray<T, 3>`)
+pub mod imgproc;
+
+/// API for multi-dimensional image filters, similar to `scipy.ndimage`
+///
+/// ## APIs with docs
+/// + [`generic_filter(input: &NdArray<T, N>, footprint: &NdArray<bool, N>, f: F)`](https://docs.rs/ndim/latest/ndim/ndimage/fn.generic_filter.html)
+///     + Apply a closure to the values selected by a footprint
+/// + [`grey_erosion(input: &NdArray<T, N>, footprint: &NdArray<bool, N>)`](https://docs.rs/ndim/latest/ndim/ndimage/fn.grey_erosion.html)
+///     + Grayscale erosion (see `grey_dilation`)
+/// + [`binary_erosion(input: &NdArray<bool, N>, footprint: &NdArray<bool, N>)`](https://docs.rs/ndim/latest/ndim/ndimage/fn.binary_erosion.html)
+///     + Binary erosion (see `binary_dilation`)
+/// + [`sobel(input: &NdArray<T, N>, axis: usize)`](https://docs.rs/ndim/latest/ndim/ndimage/fn.sobel.html)
+///     + Sobel gradient along an axis (see `prewitt`)
+pub mod ndimage;
+
+/// API for signal processing of N-dimensional arrays. Requires the `signal` feature
+///
+/// ## APIs with docs
+/// + [`hann(len: usize, symmetric: bool)`](https://docs.rs/ndim/latest/ndim/signal/fn.hann.html)
+///     + Hann window (see `hamming`, `blackman` and `kaiser`)
+/// + [`stft(signal: &NdArray<f64, 1>, window: &NdArray<f64, 1>, hop: usize)`](https://docs.rs/ndim/latest/ndim/signal/fn.stft.html)
+///     + Short-time Fourier transform as a 2-D time-frequency array
+/// + [`spectrogram(signal: &NdArray<f64, 1>, window: &NdArray<f64, 1>, hop: usize)`](https://docs.rs/ndim/latest/ndim/signal/fn.spectrogram.html)
+///     + Power spectrogram as a 2-D time-frequency array
+/// + [`lfilter(b: &[f64], a: &[f64], x: &NdArray<f64, N>, axis: usize)`](https://docs.rs/ndim/latest/ndim/signal/fn.lfilter.html)
+///     + IIR or FIR filter along an axis (see `filtfilt` for zero phase)
+/// + [`savgol_filter(x: &NdArray<f64, N>, window: usize, order: usize, axis: usize)`](https://docs.rs/ndim/latest/ndim/signal/fn.savgol_filter.html)
+///     + Savitzky-Golay smoothing along an axis
+#[cfg(feature = "signal")]
+pub mod signal;
+
+/// API for element-wise special functions of floating point N-dimensional arrays
+///
+/// ## APIs with docs
+/// + [`erf(arr: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/special/fn.erf.html)
+///     + Error function
+/// + [`gamma(arr: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/special/fn.gamma.html)
+///     + Gamma function (see `lgamma` for its logarithm)
+/// + [`expm1(arr: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/special/fn.expm1.html)
+///     + `exp(x) - 1`, accurate close to zero (see `log1p`)
+/// + [`sinc(arr: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/special/fn.sinc.html)
+///     + Normalized sinc function
+pub mod special;
+
+/// API for element-wise operators of N-dimensional arrays
+///
+/// ## APIs with docs
+/// + `&a + &b`, `&a - &b`, `&a * &b`, `&a / &b` and `&a % &b`
+///     + Element-wise arithmetic operators of two NdArrays, with broadcasting of `b` of a lower or equal dimension, e.g. `[3, 1, 5] + [4, 5]` (also for consumed operands, whose buffer is reused)
+/// + `&a + scalar`, `&a - scalar`, `&a * scalar`, `&a / scalar` and `&a % scalar`
+///     + Element-wise arithmetic operators of an NdArray and a scalar (also `scalar + &a` for the primitive types)
+/// + `a += &b`, `a -= &b`, `a *= &b`, `a /= &b` and `a %= &b`
+///     + Element-wise arithmetic operators of two NdArrays, in-place, with `b` of a lower or equal dimension broadcast to the shape of `a`
+/// + `a += scalar`, `a -= scalar`, `a *= scalar`, `a /= scalar` and `a %= scalar`
+///     + Element-wise arithmetic operators of an NdArray and a scalar, in-place
+/// + `&a & &b`, `&a | &b`, `&a ^ &b`, `&a << &b` and `&a >> &b`
+///     + Element-wise bitwise operators of two NdArrays, with broadcasting of `b` of a lower or equal dimension (also for consumed operands, whose buffer is reused)
+/// + `&a & scalar`, `&a | scalar`, `&a ^ scalar`, `&a << scalar` and `&a >> scalar`
+///     + Element-wise bitwise operators of an NdArray and a scalar
+/// + `a &= &b`, `a |= &b`, `a ^= &b`, `a <<= &b` and `a >>= &b`
+///     + Element-wise bitwise operators of two NdArrays (or of an NdArray and a scalar), in-place, with `b` of a lower or equal dimension broadcast to the shape of `a`
+/// + `-&a` and `-a`
+///     + Element-wise negation of an NdArray (the buffer of a consumed operand is reused)
+pub mod ops;
+
+/// API for set operations on N-dimensional arrays, similar to NumPy
+///
+/// ## APIs with docs
+/// + [`unique(arr: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/setops/fn.unique.html)
+///     + Sorted distinct elements
+/// + [`intersect1d(a: &NdArray<T, N>, b: &NdArray<T, M>)`](https://docs.rs/ndim/latest/ndim/setops/fn.intersect1d.html)
+///     + Sorted distinct elements found in both arrays
+/// + [`union1d(a: &NdArray<T, N>, b: &NdArray<T, M>)`](https://docs.rs/ndim/latest/ndim/setops/fn.union1d.html)
+///     + Sorted distinct elements found in either array
+/// + [`setdiff1d(a: &NdArray<T, N>, b: &NdArray<T, M>)`](https://docs.rs/ndim/latest/ndim/setops/fn.setdiff1d.html)
+///     + Sorted distinct elements of the first array not found in the second
+/// + [`NdArray::<T, N>::isin(&self, test_values: &NdArray<T, M>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.isin)
+///     + Element-wise membership mask
+pub mod setops;
+
+/// API for boolean mask and integer array indexing of N-dimensional arrays, similar to NumPy
+///
+/// ## APIs (available in NdArray)
+/// + [`NdArray::<T, N>::mask_select(&self, mask: &NdArray<bool, N>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.mask_select)
+///     + Elements where a mask is true, as a 1-D array
+/// + [`NdArray::<T, N>::mask_assign(&mut self, mask: &NdArray<bool, N>, value: T)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.mask_assign)
+///     + Overwrite the elements where a mask is true
+/// + [`NdArray::<T, N>::take(&self, indices: &NdArray<usize, 1>, axis: usize)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.take)
+///     + Gather the subarrays at integer positions along an axis
+/// + [`NdArray::<T, N>::put(&mut self, indices: &NdArray<usize, 1>, values: &NdArray<T, 1>)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.put)
+///     + Scatter values to flat positions
+pub mod indexing;
+
+/// API to describe the element type and memory of N-dimensional arrays
+///
+/// ## Types
+/// + [`DType`](https://docs.rs/ndim/latest/ndim/dtype/struct.DType.html)
+/// + [`HasDType`](https://docs.rs/ndim/latest/ndim/dtype/trait.HasDType.html)
+///
+/// ## APIs with docs
+/// + [`NdArray::<T, N>::dtype(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.dtype)
+///     + Descriptor of the element type (name, size, float/integer/signed flags)
+/// + [`NdArray::<T, N>::nbytes(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.nbytes)
+///     + Number of bytes occupied by the elements (see also `itemsize` and `ndim`)
+/// + [`NdArray::<T, N>::try_cast(&self)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.try_cast)
+///     + Convert the elements to another type, returning an `NdimError` if an element cannot be represented
+pub mod dtype;
+
+/// API to save and load named N-dimensional arrays in the safetensors format. Requires the `safetensors` feature
+///
+/// ## Types
+/// + [`SafetensorsWriter`](https://docs.rs/ndim/latest/ndim/safetensors/struct.SafetensorsWriter.html)
+/// + [`SafetensorsFile`](https://docs.rs/ndim/latest/ndim/safetensors/struct.SafetensorsFile.html)
+/// + [`SafetensorsError`](https://docs.rs/ndim/latest/ndim/safetensors/enum.SafetensorsError.html)
+///
+/// ## APIs with docs
+/// + [`SafetensorsWriter::add(&mut self, name: &str, array: &NdArray<T, N>)`](https://docs.rs/ndim/latest/ndim/safetensors/struct.SafetensorsWriter.html#method.add)
+///     + Add a named NdArray to be written
+/// + [`SafetensorsFile::load(&self, name: &str)`](https://docs.rs/ndim/latest/ndim/safetensors/struct.SafetensorsFile.html#method.load)
+///     + Load a named NdArray from a memory-mapped file
+/// + [`SafetensorsFile::view(&self, name: &str)`](https://docs.rs/ndim/latest/ndim/safetensors/struct.SafetensorsFile.html#method.view)
+///     + Borrow a named NdArray from a memory-mapped file without copying
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// API for chunked N-dimensional arrays stored on disk in the Zarr v2 layout, which are processed chunk by chunk. Requires the `zarr` feature
+///
+/// ## Types
+/// + [`ChunkedArray`](https://docs.rs/ndim/latest/ndim/chunked/struct.ChunkedArray.html)
+/// + [`Compression`](https://docs.rs/ndim/latest/ndim/chunked/enum.Compression.html)
+/// + [`ZarrError`](https://docs.rs/ndim/latest/ndim/chunked/enum.ZarrError.html)
+///
+/// ## APIs with docs
+/// + [`ChunkedArray::<T, N>::create(path: P, shape: [usize; N], chunks: [usize; N], compression: Compression)`](https://docs.rs/ndim/latest/ndim/chunked/struct.ChunkedArray.html#method.create)
+///     + Create an empty chunked array (see `open` for an existing array)
+/// + [`ChunkedArray::<T, N>::read_region(&self, start: [usize; N], shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/chunked/struct.ChunkedArray.html#method.read_region)
+///     + Read a box of elements as an NdArray (see `write_region`)
+/// + [`ChunkedArray::<T, N>::read_slice(&self, info: &SliceInfo<N>)`](https://docs.rs/ndim/latest/ndim/chunked/struct.ChunkedArray.html#method.read_slice)
+///     + Read the elements selected by `s!` as an NdArray
+/// + [`ChunkedArray::<T, N>::iter_chunks(&self)`](https://docs.rs/ndim/latest/ndim/chunked/struct.ChunkedArray.html#method.iter_chunks)
+///     + Iterate over the chunks as NdArrays
+#[cfg(feature = "zarr")]
+pub mod chunked;
+
+/// API for N-dimensional arrays of random samples. Requires the `rand` feature
+///
+/// ## APIs (available in NdArray)
+/// + [`NdArray::<T, N>::random(shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.random)
+///     + Create an NdArray with uniform samples over `[0, 1)`
+/// + [`NdArray::<T, N>::randn(shape: [usize; N])`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.randn)
+///     + Create an NdArray with standard normal samples
+/// + [`NdArray::<T, N>::sample(shape: [usize; N], dist: &D, rng: &mut R)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.sample)
+///     + Create an NdArray with samples of any distribution, e.g. of the `rand_distr` crate, from a user-supplied generator
+/// + [`NdArray::<T, N>::randint(low: T, high: T, shape: [usize; N], rng: &mut R)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.randint)
+///     + Create an NdArray with uniform samples of `low..high` from a user-supplied generator
+/// + [`NdArray::<T, 1>::choice(arr: &NdArray<T, 1>, n: usize, replace: bool, rng: &mut R)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.choice)
+///     + Choose elements at random, with or without replacement
+/// + [`NdArray::<T, N>::shuffle(&mut self, axis: usize, rng: &mut R)`](https://docs.rs/ndim/latest/ndim/core/struct.NdArray.html#method.shuffle)
+///     + Shuffle the subarrays along an axis in place (see `permutation` for a shuffled range)
+#[cfg(feature = "rand")]
+pub mod random;
@@ -0,0 +1,1662 @@
+use std::{
+    cmp::Ordering,
+    ops::{Mul, Range, Sub},
+};
+
+use num_traits::Float;
+
+use crate::core::{NdArray, SizedArray};
+
+mod scalar {
+    /// LAPACK routines of the element type, with the `lapack` feature
+    #[cfg(feature = "lapack")]
+    pub trait Backend: crate::lapack::Lapack {}
+    /// LAPACK routines of the element type, with the `lapack` feature
+    #[cfg(not(feature = "lapack"))]
+    pub trait Backend {}
+
+    impl Backend for f32 {}
+    impl Backend for f64 {}
+
+    /// Conversion from and into `f64`, in which the pure-Rust implementations compute
+    pub trait Sealed: Backend + Copy {
+        fn widen(self) -> f64;
+        fn narrow(x: f64) -> Self;
+    }
+
+    impl Sealed for f32 {
+        fn widen(self) -> f64 {
+            self as f64
+        }
+
+        fn narrow(x: f64) -> Self {
+            x as f32
+        }
+    }
+
+    impl Sealed for f64 {
+        fn widen(self) -> f64 {
+            self
+        }
+
+        fn narrow(x: f64) -> Self {
+            x
+        }
+    }
+}
+
+/// Element type of the decompositions, i.e. `f32` or `f64`. This trait is sealed
+///
+/// The pure-Rust implementations compute in `f64`. With the `lapack` feature, the `s` routines of LAPACK are used for `f32` and the `d` routines for `f64`
+pub trait LinalgScalar: Float + scalar::Sealed {}
+
+impl LinalgScalar for f32 {}
+impl LinalgScalar for f64 {}
+
+/// Elements of `a` as `f64`
+fn widen<T: LinalgScalar>(a: &[T]) -> Vec<f64> {
+    a.iter().map(|x| x.widen()).collect()
+}
+
+/// Array of shape `shape` from the `f64` values
+fn narrow<T: LinalgScalar, const N: usize>(values: Vec<f64>, shape: [usize; N]) -> NdArray<T, N> {
+    NdArray::from_owned_vec(values.into_iter().map(T::narrow).collect(), shape)
+}
+
+/// Cross product of three elements
+fn cross3<T>(a: &[T], b: &[T]) -> [T; 3]
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Cross product of two 3-vectors `a` and `b`
+///
+/// ## Panics
+/// If either `a` or `b` does not have exactly three elements, panics; message shown is **Cross product requires Shape([3]), found Shape(\[...\])**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::cross;
+/// #
+/// # fn main() {
+/// let x = NdArray::<i32, 1>::from(&[1, 0, 0], [3]);
+/// let y = NdArray::<i32, 1>::from(&[0, 1, 0], [3]);
+/// let z = cross(&x, &y);
+/// assert_eq!(z.as_slice(), &[0, 0, 1]);
+/// # }
+/// ```
+pub fn cross<T>(a: &NdArray<T, 1>, b: &NdArray<T, 1>) -> NdArray<T, 1>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    for shape in [a.shape(), b.shape()] {
+        if shape[0] != 3 {
+            panic!(
+                "Cross product requires Shape([3]), found Shape({:?})",
+                shape
+            );
+        }
+    }
+
+    NdArray::from_owned_vec(cross3(a.as_slice(), b.as_slice()).to_vec(), [3])
+}
+
+/// Cross product of the 3-vectors along the last axis of `a` and `b`, i.e. `out[i, .., :] = cross(a[i, .., :], b[i, .., :])`
+///
+/// ## Panics
+/// - If the shapes of `a` and `b` differ, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+/// - If the last axis does not have exactly three elements, panics; message shown is **Cross product requires 3 elements along the last axis, found Shape(\[...\])**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::cross_batched;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0], [2, 3]);
+/// let b = NdArray::<f64, 2>::from(&[0.0, 1.0, 0.0, 0.0, 0.0, 1.0], [2, 3]);
+/// let c = cross_batched(&a, &b);
+/// assert_eq!(c.shape(), &[2, 3]);
+/// assert_eq!(c.as_slice(), &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+/// # }
+/// ```
+pub fn cross_batched<T, const N: usize>(a: &NdArray<T, N>, b: &NdArray<T, N>) -> NdArray<T, N>
+where
+    T: Copy + Mul<Output = T> + Sub<Output = T>,
+{
+    let shape: SizedArray<N> = a.view_shape();
+    if shape != b.view_shape() {
+        panic!(
+            "Shape({:?}) does not match Shape({:?})",
+            shape,
+            b.view_shape()
+        );
+    }
+    if N == 0 || shape[N - 1] != 3 {
+        panic!(
+            "Cross product requires 3 elements along the last axis, found Shape({:?})",
+            shape
+        );
+    }
+
+    let values: Vec<T> = a
+        .as_slice()
+        .chunks_exact(3)
+        .zip(b.as_slice().chunks_exact(3))
+        .flat_map(|(a, b)| cross3(a, b))
+        .collect();
+    NdArray::from_owned_vec(values, shape)
+}
+
+/// Return the size `n` of the square matrix `a`
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+fn square_size<T>(a: &NdArray<T, 2>) -> usize {
+    let [rows, cols] = a.view_shape();
+    if rows != cols {
+        panic!("Matrix of Shape({:?}) is not square", [rows, cols]);
+    }
+
+    rows
+}
+
+/// Product of the row-major matrices `a` (`n x m`) and `b` (`m x p`)
+fn matmul(a: &[f64], b: &[f64], n: usize, m: usize, p: usize) -> Vec<f64> {
+    let mut out: Vec<f64> = vec![0.0; n * p];
+    for i in 0..n {
+        for k in 0..m {
+            let aik: f64 = a[i * m + k];
+            if aik == 0.0 {
+                continue;
+            }
+            for j in 0..p {
+                out[i * p + j] += aik * b[k * p + j];
+            }
+        }
+    }
+
+    out
+}
+
+/// Transpose of the row-major `rows x cols` matrix `a`
+fn transpose(a: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|pos| a[(pos % rows) * cols + pos / rows])
+        .collect()
+}
+
+/// LU factorization with partial pivoting of the row-major `n x n` matrix `a`, in place. Return the row permutation. A zero pivot leaves its column as is, hence the factorization is complete for a singular matrix
+fn lu_decompose(a: &mut [f64], n: usize) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..n).collect();
+    for col in 0..n {
+        let pivot: usize = (col..n)
+            .max_by(|x, y| a[x * n + col].abs().total_cmp(&a[y * n + col].abs()))
+            .unwrap();
+        if pivot != col {
+            for j in 0..n {
+                a.swap(pivot * n + j, col * n + j);
+            }
+            perm.swap(pivot, col);
+        }
+
+        let diag: f64 = a[col * n + col];
+        if diag == 0.0 {
+            continue;
+        }
+        for row in col + 1..n {
+            let factor: f64 = a[row * n + col] / diag;
+            a[row * n + col] = factor;
+            for j in col + 1..n {
+                a[row * n + j] -= factor * a[col * n + j];
+            }
+        }
+    }
+
+    perm
+}
+
+/// LU factorization as [`lu_decompose`]. Return the row permutation, or `None` if the matrix is singular
+fn lu_factor(a: &mut [f64], n: usize) -> Option<Vec<usize>> {
+    let perm: Vec<usize> = lu_decompose(a, n);
+    if (0..n).any(|i| a[i * n + i] == 0.0 || !a[i * n + i].is_finite()) {
+        return None;
+    }
+
+    Some(perm)
+}
+
+/// Solve `LU x = P b` for the `n x k` row-major right-hand side `b`, using the factors from [`lu_factor`]
+fn lu_solve(lu: &[f64], perm: &[usize], b: &[f64], n: usize, k: usize) -> Vec<f64> {
+    let mut x: Vec<f64> = Vec::with_capacity(n * k);
+    for row in perm {
+        x.extend_from_slice(&b[row * k..(row + 1) * k]);
+    }
+
+    // forward substitution with the unit lower triangle
+    for i in 0..n {
+        for p in 0..i {
+            let factor: f64 = lu[i * n + p];
+            for j in 0..k {
+                x[i * k + j] -= factor * x[p * k + j];
+            }
+        }
+    }
+    // backward substitution with the upper triangle
+    for i in (0..n).rev() {
+        for p in i + 1..n {
+            let factor: f64 = lu[i * n + p];
+            for j in 0..k {
+                x[i * k + j] -= factor * x[p * k + j];
+            }
+        }
+        for j in 0..k {
+            x[i * k + j] /= lu[i * n + i];
+        }
+    }
+
+    x
+}
+
+/// Solve the linear system `a x = b` for `x`, using LU factorization with partial pivoting. `b` is either a vector of shape `[n]` or a matrix of shape `[n, k]`, and `x` has the same shape as `b`
+///
+/// Returns `None` if `a` is singular
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `sgesv`/`dgesv` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// - If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+/// - If `b` is not 1-D or 2-D, or has a different number of rows, panics; message shown is **Shape(\[...\]) of the right-hand side does not match Shape(\[...\])**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::solve;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[2.0, 1.0, 1.0, 3.0], [2, 2]);
+/// let b = NdArray::<f64, 1>::from(&[3.0, 5.0], [2]);
+/// let x = solve(&a, &b).unwrap();
+/// assert!((x[[0]] - 0.8).abs() < 1e-12 && (x[[1]] - 1.4).abs() < 1e-12);
+///
+/// let singular = NdArray::<f64, 2>::ones([2, 2]);
+/// assert!(solve(&singular, &b).is_none());
+/// # }
+/// ```
+pub fn solve<T: LinalgScalar, const N: usize>(
+    a: &NdArray<T, 2>,
+    b: &NdArray<T, N>,
+) -> Option<NdArray<T, N>> {
+    let n: usize = square_size(a);
+    let shape: SizedArray<N> = b.view_shape();
+    if !(N == 1 || N == 2) || shape[0] != n {
+        panic!(
+            "Shape({:?}) of the right-hand side does not match Shape({:?})",
+            shape,
+            a.view_shape()
+        );
+    }
+    let k: usize = if N == 2 { shape[1] } else { 1 };
+
+    #[cfg(feature = "lapack")]
+    if let Some(x) = crate::lapack::solve(a.as_slice(), b.as_slice(), n, k) {
+        return Some(NdArray::from_owned_vec(x, shape));
+    }
+
+    let mut lu: Vec<f64> = widen(a.as_slice());
+    let perm: Vec<usize> = lu_factor(&mut lu, n)?;
+    let x: Vec<f64> = lu_solve(&lu, &perm, &widen(b.as_slice()), n, k);
+
+    Some(narrow(x, shape))
+}
+
+/// LU factorization with partial pivoting of the square matrix `a`. Returns `(perm, l, u)` where `l` is unit lower triangular and `u` is upper triangular, such that row `i` of `l u` is row `perm[i]` of `a`
+///
+/// The factorization also exists for a singular `a`, in which case `u` has a zero on its diagonal
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `sgetrf`/`dgetrf` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::lu;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[1.0, 2.0, 3.0, 4.0], [2, 2]);
+/// let (perm, l, u) = lu(&a);
+/// assert_eq!(perm.as_slice(), &[1, 0]);
+/// assert_eq!(l[[0, 0]], 1.0);
+/// assert!((l[[1, 0]] - 1.0 / 3.0).abs() < 1e-12);
+/// assert_eq!(u.as_slice()[..2], [3.0, 4.0]);
+/// assert!((u[[1, 1]] - 2.0 / 3.0).abs() < 1e-12);
+/// # }
+/// ```
+pub fn lu<T: LinalgScalar>(a: &NdArray<T, 2>) -> (NdArray<usize, 1>, NdArray<T, 2>, NdArray<T, 2>) {
+    let n: usize = square_size(a);
+    let split = |perm: Vec<usize>, lu: Vec<T>| {
+        let mut l: Vec<T> = vec![T::zero(); n * n];
+        let mut u: Vec<T> = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in 0..n {
+                match j.cmp(&i) {
+                    Ordering::Less => l[i * n + j] = lu[i * n + j],
+                    Ordering::Equal => {
+                        l[i * n + j] = T::one();
+                        u[i * n + j] = lu[i * n + j];
+                    }
+                    Ordering::Greater => u[i * n + j] = lu[i * n + j],
+                }
+            }
+        }
+
+        (
+            NdArray::from_owned_vec(perm, [n]),
+            NdArray::from_owned_vec(l, [n, n]),
+            NdArray::from_owned_vec(u, [n, n]),
+        )
+    };
+
+    #[cfg(feature = "lapack")]
+    if let Some((perm, lu)) = crate::lapack::lu(a.as_slice(), n) {
+        return split(perm, lu);
+    }
+
+    let mut lu: Vec<f64> = widen(a.as_slice());
+    let perm: Vec<usize> = lu_decompose(&mut lu, n);
+    split(perm, lu.into_iter().map(T::narrow).collect())
+}
+
+/// Reduced QR factorization of the row-major `m x n` matrix `r` using Householder reflections. Return `q` (`m x k`) and `r` (`k x n`) with `k = min(m, n)`
+fn householder_qr(mut r: Vec<f64>, m: usize, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let k: usize = m.min(n);
+    let mut reflectors: Vec<(Vec<f64>, f64)> = Vec::with_capacity(k);
+    for j in 0..k {
+        let mut v: Vec<f64> = (j..m).map(|i| r[i * n + j]).collect();
+        let norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let beta: f64 = if norm == 0.0 {
+            0.0
+        } else {
+            v[0] += if v[0] >= 0.0 { norm } else { -norm };
+            2.0 / v.iter().map(|x| x * x).sum::<f64>()
+        };
+
+        for col in j..n {
+            let dot: f64 = (0..m - j).map(|p| v[p] * r[(j + p) * n + col]).sum::<f64>() * beta;
+            (0..m - j).for_each(|p| r[(j + p) * n + col] -= dot * v[p]);
+        }
+        (j + 1..m).for_each(|i| r[i * n + j] = 0.0);
+        reflectors.push((v, beta));
+    }
+
+    // q is the product of the reflectors applied to the leading `k` columns of the identity
+    let mut q: Vec<f64> = vec![0.0; m * k];
+    (0..k).for_each(|i| q[i * k + i] = 1.0);
+    for (j, (v, beta)) in reflectors.iter().enumerate().rev() {
+        for col in 0..k {
+            let dot: f64 = (0..m - j).map(|p| v[p] * q[(j + p) * k + col]).sum::<f64>() * beta;
+            (0..m - j).for_each(|p| q[(j + p) * k + col] -= dot * v[p]);
+        }
+    }
+
+    r.truncate(k * n);
+    (q, r)
+}
+
+/// Reduced QR factorization `a = q r` of the `m x n` matrix `a`, using Householder reflections. Returns `(q, r)` where `q` (`m x k`) has orthonormal columns and `r` (`k x n`) is upper triangular, with `k = min(m, n)`
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `sgeqrf`/`dgeqrf` and `sorgqr`/`dorgqr` are used, falling back to the pure-Rust implementation if they fail
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::qr;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[3.0, 1.0, 4.0, 2.0, 0.0, 5.0], [3, 2]);
+/// let (q, r) = qr(&a);
+/// assert_eq!((q.shape(), r.shape()), (&[3, 2], &[2, 2]));
+/// assert!((r[[0, 0]].abs() - 5.0).abs() < 1e-12);
+/// assert_eq!(r[[1, 0]], 0.0);
+/// for i in 0..3 {
+///     for j in 0..2 {
+///         let qr: f64 = (0..2).map(|k| q[[i, k]] * r[[k, j]]).sum();
+///         assert!((qr - a[[i, j]]).abs() < 1e-12);
+///     }
+/// }
+/// # }
+/// ```
+pub fn qr<T: LinalgScalar>(a: &NdArray<T, 2>) -> (NdArray<T, 2>, NdArray<T, 2>) {
+    let [m, n] = a.view_shape();
+    let k: usize = m.min(n);
+
+    #[cfg(feature = "lapack")]
+    if let Some((q, r)) = crate::lapack::qr(a.as_slice(), m, n) {
+        return (
+            NdArray::from_owned_vec(q, [m, k]),
+            NdArray::from_owned_vec(r, [k, n]),
+        );
+    }
+
+    let (q, r) = householder_qr(widen(a.as_slice()), m, n);
+    (narrow(q, [m, k]), narrow(r, [k, n]))
+}
+
+/// Singular value decomposition of the row-major `m x n` matrix `a` with `m >= n`, using one-sided Jacobi rotations. Return `u` (`m x n`), the singular values in descending order and `vt` (`n x n`), or `None` if the rotations do not converge
+fn jacobi_svd(mut a: Vec<f64>, m: usize, n: usize) -> Option<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+    let mut v: Vec<f64> = vec![0.0; n * n];
+    (0..n).for_each(|i| v[i * n + i] = 1.0);
+    let dot = |a: &[f64], p: usize, q: usize| (0..m).map(|i| a[i * n + p] * a[i * n + q]).sum();
+    let tol: f64 = m as f64 * f64::EPSILON;
+
+    // rotate pairs of columns until they are orthogonal
+    let mut converged: bool = false;
+    for _ in 0..100 {
+        converged = true;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (alpha, beta, gamma): (f64, f64, f64) =
+                    (dot(&a, p, p), dot(&a, q, q), dot(&a, p, q));
+                if gamma.abs() <= tol * (alpha * beta).sqrt() {
+                    continue;
+                }
+                converged = false;
+                let zeta: f64 = (beta - alpha) / (2.0 * gamma);
+                let t: f64 = zeta.signum() / (zeta.abs() + zeta.hypot(1.0));
+                let c: f64 = 1.0 / t.hypot(1.0);
+                let s: f64 = t * c;
+                for (mat, rows) in [(&mut a, m), (&mut v, n)] {
+                    for i in 0..rows {
+                        let (x, y) = (mat[i * n + p], mat[i * n + q]);
+                        mat[i * n + p] = c * x - s * y;
+                        mat[i * n + q] = s * x + c * y;
+                    }
+                }
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    // the singular values are the norms of the columns, and `u` holds the normalized columns
+    let norms: Vec<f64> = (0..n).map(|j| dot(&a, j, j).sqrt()).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+    let zero: f64 = norms.iter().fold(0.0, |max, x| max.max(*x)) * tol;
+    let s: Vec<f64> = order.iter().map(|j| norms[*j]).collect();
+    let mut u: Vec<f64> = vec![0.0; m * n];
+    let mut vt: Vec<f64> = vec![0.0; n * n];
+    for (col, j) in order.iter().enumerate() {
+        (0..n).for_each(|i| vt[col * n + i] = v[i * n + j]);
+        if s[col] > zero {
+            (0..m).for_each(|i| u[i * n + col] = a[i * n + j] / s[col]);
+        }
+    }
+
+    // complete the columns of the zero singular values, with the unit vector farthest from the other columns
+    for col in (0..n).filter(|col| s[*col] <= zero) {
+        let residual = |unit: usize| -> Vec<f64> {
+            let mut w: Vec<f64> = vec![0.0; m];
+            w[unit] = 1.0;
+            for _ in 0..2 {
+                for other in (0..n).filter(|other| *other != col) {
+                    let proj: f64 = (0..m).map(|i| u[i * n + other] * w[i]).sum();
+                    (0..m).for_each(|i| w[i] -= proj * u[i * n + other]);
+                }
+            }
+            w
+        };
+        let norm = |w: &[f64]| w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let w: Vec<f64> = (0..m)
+            .map(residual)
+            .max_by(|x, y| norm(x).total_cmp(&norm(y)))
+            .unwrap();
+        let len: f64 = norm(&w);
+        (0..m).for_each(|i| u[i * n + col] = w[i] / len);
+    }
+
+    Some((u, s, vt))
+}
+
+/// Reduced singular value decomposition `a = u diag(s) vt` of the `m x n` matrix `a`, using one-sided Jacobi rotations. Returns `(u, s, vt)` where `u` (`m x k`) and `vt^T` (`n x k`) have orthonormal columns and the singular values `s` are in descending order, with `k = min(m, n)`
+///
+/// Returns `None` if `a` has an infinite or NaN element, or if the rotations do not converge
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `sgesdd`/`dgesdd` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::svd;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[3.0, 0.0, 0.0, 0.0, -2.0, 0.0], [2, 3]);
+/// let (u, s, vt) = svd(&a).unwrap();
+/// assert_eq!((u.shape(), s.shape(), vt.shape()), (&[2, 2], &[2], &[2, 3]));
+/// assert!((s[[0]] - 3.0).abs() < 1e-12 && (s[[1]] - 2.0).abs() < 1e-12);
+/// # }
+/// ```
+pub fn svd<T: LinalgScalar>(
+    a: &NdArray<T, 2>,
+) -> Option<(NdArray<T, 2>, NdArray<T, 1>, NdArray<T, 2>)> {
+    let [m, n] = a.view_shape();
+    let k: usize = m.min(n);
+    if a.as_slice().iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+
+    #[cfg(feature = "lapack")]
+    if let Some((u, s, vt)) = crate::lapack::svd(a.as_slice(), m, n) {
+        return Some((
+            NdArray::from_owned_vec(u, [m, k]),
+            NdArray::from_owned_vec(s, [k]),
+            NdArray::from_owned_vec(vt, [k, n]),
+        ));
+    }
+
+    let (u, s, vt) = if m >= n {
+        jacobi_svd(widen(a.as_slice()), m, n)?
+    } else {
+        // a^T = u s vt, hence a = vt^T s u^T
+        let (u, s, vt) = jacobi_svd(transpose(&widen(a.as_slice()), m, n), n, m)?;
+        (transpose(&vt, m, m), s, transpose(&u, n, m))
+    };
+
+    Some((narrow(u, [m, k]), narrow(s, [k]), narrow(vt, [k, n])))
+}
+
+/// Coefficients of the Padé approximants of degree 3, 5, 7 and 9, with the largest 1-norm each is accurate for (Higham, 2005)
+const PADE: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068e0,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+];
+
+/// Coefficients of the Padé approximant of degree 13, and the largest 1-norm it is accurate for
+const PADE_13: (f64, [f64; 14]) = (
+    5.371920351148152e0,
+    [
+        64764752532480000.0,
+        32382376266240000.0,
+        7771770303897600.0,
+        1187353796428800.0,
+        129060195264000.0,
+        10559470521600.0,
+        670442572800.0,
+        33522128640.0,
+        1323241920.0,
+        40840800.0,
+        960960.0,
+        16380.0,
+        182.0,
+        1.0,
+    ],
+);
+
+/// Matrix exponential `e^a` of the square matrix `a`, using scaling and squaring with a Padé approximation (Higham, 2005)
+///
+/// ## Note
+/// If `a` has an infinite or NaN element, every element of the result is NaN
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::expm;
+/// #
+/// # fn main() {
+/// // rotation by 1 radian
+/// let a = NdArray::<f64, 2>::from(&[0.0, -1.0, 1.0, 0.0], [2, 2]);
+/// let r = expm(&a);
+/// assert!((r[[0, 0]] - 1f64.cos()).abs() < 1e-12);
+/// assert!((r[[1, 0]] - 1f64.sin()).abs() < 1e-12);
+/// # }
+/// ```
+pub fn expm(a: &NdArray<f64, 2>) -> NdArray<f64, 2> {
+    let n: usize = square_size(a);
+    let mut identity: Vec<f64> = vec![0.0; n * n];
+    (0..n).for_each(|i| identity[i * n + i] = 1.0);
+    let axpy = |out: &mut [f64], coef: f64, x: &[f64]| {
+        out.iter_mut().zip(x).for_each(|(out, x)| *out += coef * x);
+    };
+
+    let norm: f64 = (0..n)
+        .map(|j| (0..n).map(|i| a[[i, j]].abs()).sum::<f64>())
+        .fold(0.0, f64::max);
+    if !norm.is_finite() {
+        return NdArray::from_owned_vec(vec![f64::NAN; n * n], [n, n]);
+    }
+
+    let mut a: Vec<f64> = a.as_slice().to_vec();
+    let mut squarings: i32 = 0;
+    let (u, v): (Vec<f64>, Vec<f64>) = match PADE.iter().find(|(theta, _)| norm <= *theta) {
+        Some((_, coefs)) => {
+            // U = A * sum(b[odd] A^(k-1)), V = sum(b[even] A^k)
+            let a2: Vec<f64> = matmul(&a, &a, n, n, n);
+            let mut power: Vec<f64> = identity.clone();
+            let mut odd: Vec<f64> = vec![0.0; n * n];
+            let mut even: Vec<f64> = vec![0.0; n * n];
+            for pair in coefs.chunks(2) {
+                axpy(&mut even, pair[0], &power);
+                axpy(&mut odd, pair[1], &power);
+                power = matmul(&power, &a2, n, n, n);
+            }
+            (matmul(&a, &odd, n, n, n), even)
+        }
+        None => {
+            let (theta, b) = PADE_13;
+            if norm > theta {
+                squarings = (norm / theta).log2().ceil() as i32;
+                let scale: f64 = 0.5f64.powi(squarings);
+                a.iter_mut().for_each(|x| *x *= scale);
+            }
+            let a2: Vec<f64> = matmul(&a, &a, n, n, n);
+            let a4: Vec<f64> = matmul(&a2, &a2, n, n, n);
+            let a6: Vec<f64> = matmul(&a4, &a2, n, n, n);
+
+            // U = A (A6 (b13 A6 + b11 A4 + b9 A2) + b7 A6 + b5 A4 + b3 A2 + b1 I)
+            let mut inner: Vec<f64> = vec![0.0; n * n];
+            axpy(&mut inner, b[13], &a6);
+            axpy(&mut inner, b[11], &a4);
+            axpy(&mut inner, b[9], &a2);
+            let mut odd: Vec<f64> = matmul(&a6, &inner, n, n, n);
+            axpy(&mut odd, b[7], &a6);
+            axpy(&mut odd, b[5], &a4);
+            axpy(&mut odd, b[3], &a2);
+            axpy(&mut odd, b[1], &identity);
+
+            // V = A6 (b12 A6 + b10 A4 + b8 A2) + b6 A6 + b4 A4 + b2 A2 + b0 I
+            let mut inner: Vec<f64> = vec![0.0; n * n];
+            axpy(&mut inner, b[12], &a6);
+            axpy(&mut inner, b[10], &a4);
+            axpy(&mut inner, b[8], &a2);
+            let mut even: Vec<f64> = matmul(&a6, &inner, n, n, n);
+            axpy(&mut even, b[6], &a6);
+            axpy(&mut even, b[4], &a4);
+            axpy(&mut even, b[2], &a2);
+            axpy(&mut even, b[0], &identity);
+
+            (matmul(&a, &odd, n, n, n), even)
+        }
+    };
+
+    // solve (V - U) R = (V + U)
+    let mut denom: Vec<f64> = v.iter().zip(&u).map(|(v, u)| v - u).collect();
+    let numer: Vec<f64> = v.iter().zip(&u).map(|(v, u)| v + u).collect();
+    let mut r: Vec<f64> = match lu_factor(&mut denom, n) {
+        Some(perm) => lu_solve(&denom, &perm, &numer, n, n),
+        None => vec![f64::NAN; n * n],
+    };
+    for _ in 0..squarings {
+        r = matmul(&r, &r, n, n, n);
+    }
+
+    NdArray::from_owned_vec(r, [n, n])
+}
+
+/// Householder reflector `(v, beta)` mapping `w` onto a multiple of the first unit vector, with `P = I - beta v v^T`. `beta` is zero if `w` is zero
+fn householder<const K: usize>(w: [f64; K]) -> ([f64; K], f64) {
+    let norm: f64 = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return (w, 0.0);
+    }
+
+    let mut v: [f64; K] = w;
+    v[0] += if w[0] >= 0.0 { norm } else { -norm };
+    let beta: f64 = 2.0 / v.iter().map(|x| x * x).sum::<f64>();
+    (v, beta)
+}
+
+/// Apply the reflector `(v, beta)` from the left to the rows `first..first + K` and the columns `cols` of the row-major `n x n` matrix `h`
+fn reflect_rows<const K: usize>(
+    h: &mut [f64],
+    n: usize,
+    (v, beta): ([f64; K], f64),
+    first: usize,
+    cols: Range<usize>,
+) {
+    for j in cols {
+        let dot: f64 = (0..K).map(|p| v[p] * h[(first + p) * n + j]).sum::<f64>() * beta;
+        (0..K).for_each(|p| h[(first + p) * n + j] -= dot * v[p]);
+    }
+}
+
+/// Apply the reflector `(v, beta)` from the right to the columns `first..first + K` and the rows `rows` of the row-major `n x n` matrix `h`
+fn reflect_cols<const K: usize>(
+    h: &mut [f64],
+    n: usize,
+    (v, beta): ([f64; K], f64),
+    first: usize,
+    rows: Range<usize>,
+) {
+    for i in rows {
+        let dot: f64 = (0..K).map(|p| v[p] * h[i * n + first + p]).sum::<f64>() * beta;
+        (0..K).for_each(|p| h[i * n + first + p] -= dot * v[p]);
+    }
+}
+
+/// Real Schur decomposition `a = z t z^T` of the square matrix `a`, where `z` is orthogonal and `t` is quasi upper triangular. Returns `(t, z)`
+///
+/// The diagonal of `t` holds the real eigenvalues of `a` and 2x2 blocks, each holding a pair of complex conjugate eigenvalues. The matrix is first reduced to the Hessenberg form, followed by the Francis double shift QR iterations
+///
+/// Returns `None` if `a` has an infinite or NaN element, or if the iterations do not converge
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `sgees`/`dgees` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::schur;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[4.0, 1.0, 2.0, 3.0], [2, 2]);
+/// let (t, z) = schur(&a).unwrap();
+/// // the eigenvalues are 2 and 5
+/// let mut eig = [t[[0, 0]], t[[1, 1]]];
+/// eig.sort_by(f64::total_cmp);
+/// assert!((eig[0] - 2.0).abs() < 1e-12 && (eig[1] - 5.0).abs() < 1e-12);
+/// assert!(t[[1, 0]] == 0.0);
+/// assert_eq!(z.shape(), &[2, 2]);
+/// # }
+/// ```
+pub fn schur<T: LinalgScalar>(a: &NdArray<T, 2>) -> Option<(NdArray<T, 2>, NdArray<T, 2>)> {
+    let n: usize = square_size(a);
+    if a.as_slice().iter().any(|x| !x.is_finite()) {
+        return None;
+    }
+
+    #[cfg(feature = "lapack")]
+    if let Some((t, z)) = crate::lapack::schur(a.as_slice(), n) {
+        return Some((
+            NdArray::from_owned_vec(t, [n, n]),
+            NdArray::from_owned_vec(z, [n, n]),
+        ));
+    }
+
+    let mut h: Vec<f64> = widen(a.as_slice());
+    let mut z: Vec<f64> = vec![0.0; n * n];
+    (0..n).for_each(|i| z[i * n + i] = 1.0);
+
+    // reduce to the Hessenberg form, one column at a time
+    for k in 0..n.saturating_sub(2) {
+        let len: usize = n - k - 1;
+        let w: Vec<f64> = (k + 1..n).map(|i| h[i * n + k]).collect();
+        let norm: f64 = w.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let mut v: Vec<f64> = w;
+        v[0] += if v[0] >= 0.0 { norm } else { -norm };
+        let beta: f64 = 2.0 / v.iter().map(|x| x * x).sum::<f64>();
+
+        for j in k..n {
+            let dot: f64 = (0..len).map(|p| v[p] * h[(k + 1 + p) * n + j]).sum::<f64>() * beta;
+            (0..len).for_each(|p| h[(k + 1 + p) * n + j] -= dot * v[p]);
+        }
+        for mat in [&mut h, &mut z] {
+            for i in 0..n {
+                let dot: f64 = (0..len).map(|p| v[p] * mat[i * n + k + 1 + p]).sum::<f64>() * beta;
+                (0..len).for_each(|p| mat[i * n + k + 1 + p] -= dot * v[p]);
+            }
+        }
+        (k + 2..n).for_each(|i| h[i * n + k] = 0.0);
+    }
+
+    // Francis double shift QR iterations on the active block `low..=high`
+    let mut high: usize = n.saturating_sub(1);
+    let mut iter: usize = 0;
+    let mut total: usize = 0;
+    while high > 0 {
+        let mut low: usize = high;
+        while low > 0 {
+            let scale: f64 = h[(low - 1) * n + low - 1].abs() + h[low * n + low].abs();
+            if h[low * n + low - 1].abs() <= f64::EPSILON * scale {
+                h[low * n + low - 1] = 0.0;
+                break;
+            }
+            low -= 1;
+        }
+
+        if low == high {
+            high -= 1;
+            iter = 0;
+            continue;
+        }
+        if low + 1 == high {
+            // standardize a 2x2 block with real eigenvalues into an upper triangle
+            let (p, q) = (high - 1, high);
+            let (h00, h01, h10, h11) = (h[p * n + p], h[p * n + q], h[q * n + p], h[q * n + q]);
+            let half: f64 = 0.5 * (h00 - h11);
+            let disc: f64 = half * half + h01 * h10;
+            if disc >= 0.0 {
+                let root: f64 = if half >= 0.0 {
+                    disc.sqrt()
+                } else {
+                    -disc.sqrt()
+                };
+                let lambda: f64 = 0.5 * (h00 + h11) + root;
+                // eigenvector of `lambda`, as the first column of the rotation
+                let (x, y) = if (lambda - h11).abs() >= h01.abs() {
+                    (lambda - h11, h10)
+                } else {
+                    (h01, lambda - h00)
+                };
+                let norm: f64 = x.hypot(y);
+                if norm > 0.0 {
+                    let (cs, sn) = (x / norm, y / norm);
+                    for j in p..n {
+                        let (u, w) = (h[p * n + j], h[q * n + j]);
+                        h[p * n + j] = cs * u + sn * w;
+                        h[q * n + j] = -sn * u + cs * w;
+                    }
+                    for mat in [&mut h, &mut z] {
+                        for i in 0..n {
+                            let (u, w) = (mat[i * n + p], mat[i * n + q]);
+                            mat[i * n + p] = cs * u + sn * w;
+                            mat[i * n + q] = -sn * u + cs * w;
+                        }
+                    }
+                }
+                h[q * n + p] = 0.0;
+            }
+            high = high.saturating_sub(2);
+            iter = 0;
+            continue;
+        }
+
+        iter += 1;
+        total += 1;
+        if total > 100 * n {
+            return None;
+        }
+        // sum and product of the two shifts, with exceptional shifts when the iterations stagnate
+        let (sum, product) = if iter.is_multiple_of(10) {
+            let s: f64 = h[high * n + high - 1].abs() + h[(high - 1) * n + high - 2].abs();
+            (1.5 * s, s * s)
+        } else {
+            let (p, q) = (high - 1, high);
+            (
+                h[p * n + p] + h[q * n + q],
+                h[p * n + p] * h[q * n + q] - h[p * n + q] * h[q * n + p],
+            )
+        };
+
+        let at = |i: usize, j: usize| i * n + j;
+        let mut x: f64 = h[at(low, low)] * h[at(low, low)]
+            + h[at(low, low + 1)] * h[at(low + 1, low)]
+            - sum * h[at(low, low)]
+            + product;
+        let mut y: f64 = h[at(low + 1, low)] * (h[at(low, low)] + h[at(low + 1, low + 1)] - sum);
+        let mut w: f64 = h[at(low + 1, low)] * h[at(low + 2, low + 1)];
+        for k in low..high - 1 {
+            let reflector = householder([x, y, w]);
+            let first_col: usize = if k > low { k - 1 } else { low };
+            reflect_rows(&mut h, n, reflector, k, first_col..n);
+            if k > low {
+                h[at(k + 1, k - 1)] = 0.0;
+                h[at(k + 2, k - 1)] = 0.0;
+            }
+            reflect_cols(&mut h, n, reflector, k, 0..(k + 4).min(high + 1));
+            reflect_cols(&mut z, n, reflector, k, 0..n);
+
+            x = h[at(k + 1, k)];
+            y = h[at(k + 2, k)];
+            if k + 3 <= high {
+                w = h[at(k + 3, k)];
+            }
+        }
+        let reflector = householder([x, y]);
+        reflect_rows(&mut h, n, reflector, high - 1, high - 2..n);
+        h[at(high, high - 2)] = 0.0;
+        reflect_cols(&mut h, n, reflector, high - 1, 0..high + 1);
+        reflect_cols(&mut z, n, reflector, high - 1, 0..n);
+    }
+
+    // clear the round-off below the sub-diagonal
+    for i in 2..n {
+        (0..i - 1).for_each(|j| h[i * n + j] = 0.0);
+    }
+
+    Some((narrow(h, [n, n]), narrow(z, [n, n])))
+}
+
+/// Symmetric `n x n` row-major matrix from the lower triangle of `a`
+fn symmetric_from_lower(a: &[f64], n: usize) -> Vec<f64> {
+    let mut sym: Vec<f64> = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            sym[i * n + j] = a[i * n + j];
+            sym[j * n + i] = a[i * n + j];
+        }
+    }
+
+    sym
+}
+
+/// Cholesky factor `l` of the symmetric positive definite matrix `a`, such that `a = l l^T` and `l` is lower triangular. Only the lower triangle of `a` is read
+///
+/// Returns `None` if `a` is not positive definite
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `spotrf`/`dpotrf` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::cholesky;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[4.0, 2.0, 2.0, 5.0], [2, 2]);
+/// let l = cholesky(&a).unwrap();
+/// assert_eq!(l.as_slice(), &[2.0, 0.0, 1.0, 2.0]);
+/// # }
+/// ```
+pub fn cholesky<T: LinalgScalar>(a: &NdArray<T, 2>) -> Option<NdArray<T, 2>> {
+    let n: usize = square_size(a);
+    #[cfg(feature = "lapack")]
+    if let Some(l) = crate::lapack::cholesky(a.as_slice(), n) {
+        return Some(NdArray::from_owned_vec(l, [n, n]));
+    }
+
+    Some(narrow(cholesky_lower(&widen(a.as_slice()), n)?, [n, n]))
+}
+
+/// Lower Cholesky factor of the symmetric `n x n` row-major matrix `a` (lower triangle), or `None` if it is not positive definite
+fn cholesky_lower(a: &[f64], n: usize) -> Option<Vec<f64>> {
+    let mut l: Vec<f64> = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum();
+            let value: f64 = a[i * n + j] - dot;
+            if i == j {
+                if value <= 0.0 || !value.is_finite() {
+                    return None;
+                }
+                l[i * n + i] = value.sqrt();
+            } else {
+                l[i * n + j] = value / l[j * n + j];
+            }
+        }
+    }
+
+    Some(l)
+}
+
+/// Eigenvalues (in ascending order) and eigenvectors of the symmetric `n x n` row-major matrix `a`, using cyclic Jacobi rotations. The eigenvectors are the columns of the second matrix
+fn jacobi_eigen(mut a: Vec<f64>, n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut v: Vec<f64> = vec![0.0; n * n];
+    (0..n).for_each(|i| v[i * n + i] = 1.0);
+    let norm: f64 = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i * n + j] * a[i * n + j])
+            .sum::<f64>()
+            .sqrt();
+        if off <= f64::EPSILON * norm {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq: f64 = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta: f64 = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t: f64 = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c: f64 = 1.0 / t.hypot(1.0);
+                let s: f64 = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|i, j| a[i * n + i].total_cmp(&a[j * n + j]));
+    let values: Vec<f64> = order.iter().map(|i| a[i * n + i]).collect();
+    let vectors: Vec<f64> = (0..n)
+        .flat_map(|row| order.iter().map(move |col| (row, *col)))
+        .map(|(row, col)| v[row * n + col])
+        .collect();
+
+    (values, vectors)
+}
+
+/// Eigenvalues (in ascending order) and orthonormal eigenvectors of the symmetric matrix `a`, using cyclic Jacobi rotations. Only the lower triangle of `a` is read
+///
+/// Returns `(w, v)` where `a v[:, i] = w[i] v[:, i]`, i.e. the eigenvectors are the columns of `v`
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `ssyev`/`dsyev` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// If `a` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::eigh;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[2.0, 1.0, 1.0, 2.0], [2, 2]);
+/// let (w, v) = eigh(&a);
+/// assert!((w[[0]] - 1.0).abs() < 1e-12 && (w[[1]] - 3.0).abs() < 1e-12);
+/// assert!((v[[0, 1]].abs() - 0.5f64.sqrt()).abs() < 1e-12);
+/// # }
+/// ```
+pub fn eigh<T: LinalgScalar>(a: &NdArray<T, 2>) -> (NdArray<T, 1>, NdArray<T, 2>) {
+    let n: usize = square_size(a);
+    #[cfg(feature = "lapack")]
+    if let Some((values, vectors)) = crate::lapack::eigh(a.as_slice(), n) {
+        return (
+            NdArray::from_owned_vec(values, [n]),
+            NdArray::from_owned_vec(vectors, [n, n]),
+        );
+    }
+
+    let (values, vectors) = jacobi_eigen(symmetric_from_lower(&widen(a.as_slice()), n), n);
+
+    (narrow(values, [n]), narrow(vectors, [n, n]))
+}
+
+/// Solve the generalized eigenvalue problem `a x = λ b x` for the symmetric matrix `a` and the symmetric positive definite matrix `b`, by reducing it to a standard symmetric problem using the Cholesky factor of `b`. Only the lower triangles of `a` and `b` are read
+///
+/// Returns `(w, x)` with the eigenvalues `w` in ascending order and the eigenvectors as the columns of `x`, normalized such that `x^T b x = I`. Returns `None` if `b` is not positive definite
+///
+/// ## Note
+/// With the `lapack` feature, LAPACK `ssygv`/`dsygv` is used, falling back to the pure-Rust implementation if it fails
+///
+/// ## Panics
+/// - If `a` or `b` is not square, panics; message shown is **Matrix of Shape(\[...\]) is not square**
+/// - If the shapes of `a` and `b` differ, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::linalg::eig_generalized;
+/// #
+/// # fn main() {
+/// // stiffness and mass matrices of two masses connected by springs
+/// let k = NdArray::<f64, 2>::from(&[2.0, -1.0, -1.0, 1.0], [2, 2]);
+/// let m = NdArray::<f64, 2>::from(&[2.0, 0.0, 0.0, 1.0], [2, 2]);
+/// let (w, x) = eig_generalized(&k, &m).unwrap();
+/// assert!((w[[0]] - (1.0 - 0.5f64.sqrt())).abs() < 1e-12);
+/// assert!((w[[1]] - (1.0 + 0.5f64.sqrt())).abs() < 1e-12);
+/// assert_eq!(x.shape(), &[2, 2]);
+/// # }
+/// ```
+pub fn eig_generalized<T: LinalgScalar>(
+    a: &NdArray<T, 2>,
+    b: &NdArray<T, 2>,
+) -> Option<(NdArray<T, 1>, NdArray<T, 2>)> {
+    let n: usize = square_size(a);
+    square_size(b);
+    if a.view_shape() != b.view_shape() {
+        panic!(
+            "Shape({:?}) does not match Shape({:?})",
+            a.view_shape(),
+            b.view_shape()
+        );
+    }
+
+    #[cfg(feature = "lapack")]
+    if let Some((values, vectors)) = crate::lapack::eig_generalized(a.as_slice(), b.as_slice(), n) {
+        return Some((
+            NdArray::from_owned_vec(values, [n]),
+            NdArray::from_owned_vec(vectors, [n, n]),
+        ));
+    }
+
+    let l: Vec<f64> = cholesky_lower(&widen(b.as_slice()), n)?;
+    // solve `l y = m` in place, column by column
+    let forward = |mut m: Vec<f64>| -> Vec<f64> {
+        for j in 0..n {
+            for i in 0..n {
+                let dot: f64 = (0..i).map(|k| l[i * n + k] * m[k * n + j]).sum();
+                m[i * n + j] = (m[i * n + j] - dot) / l[i * n + i];
+            }
+        }
+        m
+    };
+
+    // c = l^-1 a l^-T, which is symmetric
+    let y: Vec<f64> = forward(symmetric_from_lower(&widen(a.as_slice()), n));
+    let c: Vec<f64> = forward(transpose(&y, n, n));
+    let (values, mut vectors) = jacobi_eigen(symmetric_from_lower(&c, n), n);
+
+    // x = l^-T v, by backward substitution with `l^T`
+    for j in 0..n {
+        for i in (0..n).rev() {
+            let dot: f64 = (i + 1..n).map(|k| l[k * n + i] * vectors[k * n + j]).sum();
+            vectors[i * n + j] = (vectors[i * n + j] - dot) / l[i * n + i];
+        }
+    }
+
+    Some((narrow(values, [n]), narrow(vectors, [n, n])))
+}
+
+#[cfg(test)]
+mod linalg_t {
+    use crate::{
+        core::{Array, Array2, Array3, NdArray},
+        linalg::{
+            cholesky, cross, cross_batched, eig_generalized, eigh, expm, lu, qr, schur, solve, svd,
+        },
+    };
+    #[cfg(feature = "lapack")]
+    use crate::{
+        lapack,
+        linalg::{
+            cholesky_lower, householder_qr, jacobi_eigen, jacobi_svd, lu_decompose, lu_factor,
+            lu_solve, symmetric_from_lower,
+        },
+    };
+
+    // Test the cross product of two 1-D NdArray
+    // Check if the result is perpendicular to both inputs
+    #[test]
+    fn cross_1dim_t() {
+        let a = Array::<i64>::from(&[2, -3, 4], [3]);
+        let b = Array::<i64>::from(&[5, 6, -7], [3]);
+        let c: NdArray<i64, 1> = cross(&a, &b);
+        assert_eq!(c.as_slice(), &[-3, 34, 27]);
+
+        for v in [&a, &b] {
+            let dot: i64 = (0..3).map(|i| v[[i]] * c[[i]]).sum();
+            assert_eq!(dot, 0);
+        }
+    }
+
+    // Test the batched cross product of two 3-D NdArray
+    // Check if every lane matches the 1-D cross product
+    #[test]
+    fn cross_batched_3dim_t() {
+        let values: Vec<i32> = (0..18).collect();
+        let reversed: Vec<i32> = values.iter().rev().copied().collect();
+        let a = Array3::<i32>::from(&values, [2, 3, 3]);
+        let b = Array3::<i32>::from(&reversed, [2, 3, 3]);
+        let c = cross_batched(&a, &b);
+        assert_eq!(c.shape(), &[2, 3, 3]);
+        for (k, lane) in c.as_slice().chunks(3).enumerate() {
+            let x = Array::<i32>::from(&a.as_slice()[3 * k..3 * k + 3], [3]);
+            let y = Array::<i32>::from(&b.as_slice()[3 * k..3 * k + 3], [3]);
+            assert_eq!(lane, cross(&x, &y).as_slice());
+        }
+    }
+
+    // Test the cross product of vectors which are not 3-vectors
+    #[test]
+    #[should_panic(expected = "Cross product requires Shape([3]), found Shape([4])")]
+    fn cross_invalid_shape_t() {
+        let a = Array::<f32>::zeros([3]);
+        let b = Array::<f32>::zeros([4]);
+        cross(&a, &b);
+    }
+
+    // Test solve(...) with a 3x3 system and two right-hand sides
+    // Check if a x reproduces b
+    #[test]
+    fn solve_2dim_t() {
+        let a = Array2::<f64>::from(&[0.0, 2.0, 1.0, 1.0, -1.0, 4.0, 3.0, 1.0, -2.0], [3, 3]);
+        let b = Array2::<f64>::from(&[1.0, 0.0, 2.0, 1.0, -1.0, 3.0], [3, 2]);
+        let x: NdArray<f64, 2> = solve(&a, &b).unwrap();
+        for i in 0..3 {
+            for j in 0..2 {
+                let ax: f64 = (0..3).map(|k| a[[i, k]] * x[[k, j]]).sum();
+                assert!((ax - b[[i, j]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    // Test solve(...) with f32 elements
+    #[test]
+    fn solve_f32_t() {
+        let a = Array2::<f32>::from(&[4.0, 1.0, 2.0, 3.0], [2, 2]);
+        let b = Array::<f32>::from(&[1.0, 2.0], [2]);
+        let x: NdArray<f32, 1> = solve(&a, &b).unwrap();
+        assert!((x[[0]] - 0.1).abs() < 1e-6 && (x[[1]] - 0.6).abs() < 1e-6);
+    }
+
+    // Test lu(...) with a 3x3 matrix and a singular matrix
+    // Check if l u reproduces the permuted rows of a
+    #[test]
+    fn lu_3dim_t() {
+        let a = Array2::<f64>::from(&[0.0, 2.0, 1.0, 1.0, -1.0, 4.0, 3.0, 1.0, -2.0], [3, 3]);
+        let singular = Array2::<f64>::from(&[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0], [3, 3]);
+        for a in [a, singular] {
+            let (perm, l, u) = lu(&a);
+            for i in 0..3 {
+                assert_eq!(l[[i, i]], 1.0);
+                for j in 0..3 {
+                    if j > i {
+                        assert_eq!(l[[i, j]], 0.0);
+                    }
+                    if j < i {
+                        assert_eq!(u[[i, j]], 0.0);
+                    }
+                    let lu: f64 = (0..3).map(|k| l[[i, k]] * u[[k, j]]).sum();
+                    assert!((lu - a[[perm[[i]], j]]).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    // Test qr(...) with a tall and a wide matrix
+    // Check if q has orthonormal columns, r is upper triangular and q r reproduces a
+    #[test]
+    fn qr_2dim_t() {
+        let tall = Array2::<f64>::from(
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, -1.0, 0.0, 2.0],
+            [4, 3],
+        );
+        let wide = Array2::<f64>::from(&[2.0, -1.0, 0.0, 3.0, 1.0, 4.0, 1.0, -2.0], [2, 4]);
+        for a in [tall, wide] {
+            let [m, n] = a.view_shape();
+            let k: usize = m.min(n);
+            let (q, r) = qr(&a);
+            assert_eq!((q.shape(), r.shape()), (&[m, k], &[k, n]));
+            for i in 0..k {
+                for j in 0..k {
+                    let qtq: f64 = (0..m).map(|p| q[[p, i]] * q[[p, j]]).sum();
+                    assert!((qtq - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+                }
+                (0..i).for_each(|j| assert_eq!(r[[i, j]], 0.0));
+            }
+            for i in 0..m {
+                for j in 0..n {
+                    let qr: f64 = (0..k).map(|p| q[[i, p]] * r[[p, j]]).sum();
+                    assert!((qr - a[[i, j]]).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    // Test svd(...) with a tall, a wide and a rank deficient matrix
+    // Check if u and vt^T have orthonormal columns, s is descending and u diag(s) vt reproduces a
+    #[test]
+    fn svd_2dim_t() {
+        let tall = Array2::<f64>::from(
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, -1.0, 0.0, 2.0],
+            [4, 3],
+        );
+        let wide = Array2::<f64>::from(&[2.0, -1.0, 0.0, 3.0, 1.0, 4.0, 1.0, -2.0], [2, 4]);
+        let rank1 = Array2::<f64>::from(&[1.0, 2.0, 2.0, 4.0, 3.0, 6.0], [3, 2]);
+        for a in [tall, wide, rank1] {
+            let [m, n] = a.view_shape();
+            let k: usize = m.min(n);
+            let (u, s, vt) = svd(&a).unwrap();
+            assert_eq!((u.shape(), s.shape(), vt.shape()), (&[m, k], &[k], &[k, n]));
+            assert!(s.as_slice().windows(2).all(|pair| pair[0] >= pair[1]));
+            for i in 0..k {
+                for j in 0..k {
+                    let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                    let utu: f64 = (0..m).map(|p| u[[p, i]] * u[[p, j]]).sum();
+                    let vvt: f64 = (0..n).map(|p| vt[[i, p]] * vt[[j, p]]).sum();
+                    assert!((utu - expected).abs() < 1e-12 && (vvt - expected).abs() < 1e-12);
+                }
+            }
+            for i in 0..m {
+                for j in 0..n {
+                    let usv: f64 = (0..k).map(|p| u[[i, p]] * s[[p]] * vt[[p, j]]).sum();
+                    assert!((usv - a[[i, j]]).abs() < 1e-12);
+                }
+            }
+        }
+
+        let nan = Array2::<f64>::from(&[1.0, f64::NAN, 0.0, 1.0], [2, 2]);
+        assert!(svd(&nan).is_none());
+    }
+
+    // Test the decompositions of an empty NdArray created using NdArray::<T, N>::new()
+    // Check if they are treated as 0x0 matrices
+    #[test]
+    fn new_decompositions_t() {
+        let a = Array2::<f64>::new();
+        assert!(solve(&a, &Array::<f64>::new()).unwrap().is_empty());
+        assert!(lu(&a).1.is_empty());
+        assert!(qr(&a).0.is_empty());
+        assert!(svd(&a).unwrap().1.is_empty());
+        assert!(cholesky(&a).unwrap().is_empty());
+        assert!(eigh(&a).0.is_empty());
+    }
+
+    // Test expm(...) with matrices of known exponentials, for small and large norms
+    #[test]
+    fn expm_known_t() {
+        let zero = expm(&Array2::<f64>::zeros([3, 3]));
+        assert_eq!(
+            zero.as_slice(),
+            &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        );
+
+        // nilpotent: e^a = I + a
+        let shear = expm(&Array2::<f64>::from(&[0.0, 1.0, 0.0, 0.0], [2, 2]));
+        assert_eq!(shear.as_slice(), &[1.0, 1.0, 0.0, 1.0]);
+
+        // diagonal with a large norm, which requires scaling and squaring
+        for scale in [1e-3, 0.5, 2.0, 20.0] {
+            let diag = Array2::<f64>::from(&[scale, 0.0, 0.0, -scale], [2, 2]);
+            let exp = expm(&diag);
+            assert!((exp[[0, 0]] / scale.exp() - 1.0).abs() < 1e-12);
+            assert!((exp[[1, 1]] / (-scale).exp() - 1.0).abs() < 1e-12);
+            assert!(exp[[0, 1]].abs() < 1e-12);
+        }
+    }
+
+    // Test expm(...) on a rotation generator with a large angle
+    // Check if the result is the rotation matrix
+    #[test]
+    fn expm_rotation_t() {
+        let angle: f64 = 10.0;
+        let exp = expm(&Array2::<f64>::from(&[0.0, -angle, angle, 0.0], [2, 2]));
+        assert!((exp[[0, 0]] - angle.cos()).abs() < 1e-10);
+        assert!((exp[[0, 1]] + angle.sin()).abs() < 1e-10);
+        assert!((exp[[1, 0]] - angle.sin()).abs() < 1e-10);
+    }
+
+    // Check if `z` is orthogonal, `t` is quasi upper triangular, and `z t z^T` reproduces `a`
+    fn check_schur(a: &NdArray<f64, 2>, t: &NdArray<f64, 2>, z: &NdArray<f64, 2>) {
+        let n: usize = a.shape()[0];
+        for i in 0..n {
+            for j in 0..n {
+                let ztz: f64 = (0..n).map(|k| z[[k, i]] * z[[k, j]]).sum();
+                assert!((ztz - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+
+                let zt: f64 = (0..n)
+                    .flat_map(|k| (0..n).map(move |l| (k, l)))
+                    .map(|(k, l)| z[[i, k]] * t[[k, l]] * z[[j, l]])
+                    .sum();
+                assert!((zt - a[[i, j]]).abs() < 1e-10);
+                if i > j + 1 {
+                    assert_eq!(t[[i, j]], 0.0);
+                }
+            }
+        }
+        // no two consecutive sub-diagonal elements, i.e. blocks are at most 2x2
+        for i in 2..n {
+            assert!(t[[i, i - 1]] == 0.0 || t[[i - 1, i - 2]] == 0.0);
+        }
+    }
+
+    // Test schur(...) with a non-symmetric 5x5 matrix with complex eigenvalues
+    #[test]
+    fn schur_general_t() {
+        let a = Array2::<f64>::from(
+            &[
+                1.0, 2.0, -1.0, 0.5, 3.0, //
+                -2.0, 1.0, 0.0, 4.0, 1.0, //
+                0.0, 3.0, 2.0, -1.0, 0.0, //
+                1.0, -1.0, 1.0, 0.0, 2.0, //
+                2.0, 0.0, -3.0, 1.0, 1.0,
+            ],
+            [5, 5],
+        );
+        let (t, z) = schur(&a).unwrap();
+        check_schur(&a, &t, &z);
+        let trace: f64 = (0..5).map(|i| t[[i, i]]).sum();
+        assert!((trace - 5.0).abs() < 1e-10);
+    }
+
+    // Test schur(...) with a symmetric matrix
+    // Check if the Schur form is diagonal with the eigenvalues
+    #[test]
+    fn schur_symmetric_t() {
+        let a = Array2::<f64>::from(&[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0], [3, 3]);
+        let (t, z) = schur(&a).unwrap();
+        check_schur(&a, &t, &z);
+
+        let mut eig: Vec<f64> = (0..3).map(|i| t[[i, i]]).collect();
+        eig.sort_by(f64::total_cmp);
+        let sqrt2: f64 = 2f64.sqrt();
+        for (found, expected) in eig.iter().zip([2.0 - sqrt2, 2.0, 2.0 + sqrt2]) {
+            assert!((found - expected).abs() < 1e-12);
+        }
+        assert!(t[[1, 0]] == 0.0 && t[[2, 1]] == 0.0);
+    }
+
+    // Test schur(...) with a rotation, which has a pair of complex eigenvalues
+    #[test]
+    fn schur_rotation_t() {
+        let a = Array2::<f64>::from(&[0.0, -1.0, 1.0, 0.0], [2, 2]);
+        let (t, z) = schur(&a).unwrap();
+        check_schur(&a, &t, &z);
+        assert!(t[[1, 0]] != 0.0);
+    }
+
+    // Test eigh(...) with a symmetric 4x4 matrix
+    // Check if every pair satisfies a v = w v
+    #[test]
+    fn eigh_4dim_t() {
+        let a = Array2::<f64>::from(
+            &[
+                4.0, 1.0, -2.0, 2.0, //
+                1.0, 2.0, 0.0, 1.0, //
+                -2.0, 0.0, 3.0, -2.0, //
+                2.0, 1.0, -2.0, -1.0,
+            ],
+            [4, 4],
+        );
+        let (w, v) = eigh(&a);
+        for col in 0..4 {
+            for row in 0..4 {
+                let av: f64 = (0..4).map(|k| a[[row, k]] * v[[k, col]]).sum();
+                assert!((av - w[[col]] * v[[row, col]]).abs() < 1e-10);
+            }
+        }
+        assert!(w.as_slice().windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    // Test eig_generalized(...) with a symmetric and a symmetric positive definite matrix
+    // Check if a x = w b x and x^T b x = I
+    #[test]
+    fn eig_generalized_3dim_t() {
+        let a = Array2::<f64>::from(&[1.0, 2.0, 0.0, 2.0, -1.0, 1.0, 0.0, 1.0, 3.0], [3, 3]);
+        let b = Array2::<f64>::from(&[4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0], [3, 3]);
+        let (w, x) = eig_generalized(&a, &b).unwrap();
+        let mul = |m: &NdArray<f64, 2>, row: usize, col: usize| -> f64 {
+            (0..3).map(|k| m[[row, k]] * x[[k, col]]).sum()
+        };
+        for col in 0..3 {
+            for row in 0..3 {
+                assert!((mul(&a, row, col) - w[[col]] * mul(&b, row, col)).abs() < 1e-10);
+            }
+            for other in 0..3 {
+                let xbx: f64 = (0..3).map(|k| x[[k, other]] * mul(&b, k, col)).sum();
+                assert!((xbx - if other == col { 1.0 } else { 0.0 }).abs() < 1e-10);
+            }
+        }
+    }
+
+    // Test eig_generalized(...) and cholesky(...) with a matrix which is not positive definite
+    #[test]
+    fn not_positive_definite_t() {
+        let a = Array2::<f64>::from(&[1.0, 0.0, 0.0, 1.0], [2, 2]);
+        let b = Array2::<f64>::from(&[1.0, 2.0, 2.0, 1.0], [2, 2]);
+        assert!(cholesky(&b).is_none());
+        assert!(eig_generalized(&a, &b).is_none());
+    }
+
+    // The tests below only run with the `lapack` feature, which requires a LAPACK library to link against (see the `lapack` job in CI)
+    // They call the LAPACK kernels directly and compare them with the pure-Rust implementations, which the public APIs fall back to
+
+    // Check if `x` and `y` match element-wise within `tol`
+    #[cfg(feature = "lapack")]
+    fn assert_close(x: &[f64], y: &[f64], tol: f64) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).abs() < tol, "{} != {}", x, y);
+        }
+    }
+
+    // Test the LAPACK solve and LU factorization against the pure-Rust LU, for f64 and f32
+    #[cfg(feature = "lapack")]
+    #[test]
+    fn lapack_solve_lu_t() {
+        let a: [f64; 9] = [0.0, 2.0, 1.0, 1.0, -1.0, 4.0, 3.0, 1.0, -2.0];
+        let b: [f64; 6] = [1.0, 0.0, 2.0, 1.0, -1.0, 3.0];
+        let mut factors: Vec<f64> = a.to_vec();
+        let perm: Vec<usize> = lu_factor(&mut factors, 3).unwrap();
+        let x: Vec<f64> = lapack::solve(&a, &b, 3, 2).unwrap();
+        assert_close(&x, &lu_solve(&factors, &perm, &b, 3, 2), 1e-12);
+
+        let (lapack_perm, lapack_factors) = lapack::lu(&a, 3).unwrap();
+        let mut factors: Vec<f64> = a.to_vec();
+        assert_eq!(lapack_perm, lu_decompose(&mut factors, 3));
+        assert_close(&lapack_factors, &factors, 1e-12);
+
+        let a32: Vec<f32> = a.iter().map(|x| *x as f32).collect();
+        let b32: Vec<f32> = b.iter().map(|x| *x as f32).collect();
+        let x32: Vec<f64> = lapack::solve(&a32, &b32, 3, 2)
+            .unwrap()
+            .iter()
+            .map(|x| *x as f64)
+            .collect();
+        assert_close(&x32, &x, 1e-5);
+    }
+
+    // Test the LAPACK QR and SVD against the pure-Rust Householder QR and Jacobi SVD of a tall matrix
+    // The columns of q and u (and the rows of r and vt) are only unique up to their sign
+    #[cfg(feature = "lapack")]
+    #[test]
+    fn lapack_qr_svd_t() {
+        let (m, n): (usize, usize) = (4, 3);
+        let a: [f64; 12] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, -1.0, 0.0, 2.0];
+        // flip the signs of `left` (m x n) columns and `right` (n x n) rows such that the first element of every `right` row is positive
+        let canonical =
+            |mut left: Vec<f64>, mut right: Vec<f64>, lead: &dyn Fn(&[f64], usize) -> f64| {
+                for k in 0..n {
+                    if lead(&right, k) < 0.0 {
+                        (0..m).for_each(|i| left[i * n + k] = -left[i * n + k]);
+                        (0..n).for_each(|j| right[k * n + j] = -right[k * n + j]);
+                    }
+                }
+                (left, right)
+            };
+
+        let diagonal = |r: &[f64], k: usize| r[k * n + k];
+        let (q, r) = lapack::qr(&a, m, n).unwrap();
+        let (q, r) = canonical(q, r, &diagonal);
+        let (expected_q, expected_r) = householder_qr(a.to_vec(), m, n);
+        let (expected_q, expected_r) = canonical(expected_q, expected_r, &diagonal);
+        assert_close(&q, &expected_q, 1e-12);
+        assert_close(&r, &expected_r, 1e-12);
+
+        let first = |vt: &[f64], k: usize| vt[k * n];
+        let (u, s, vt) = lapack::svd(&a, m, n).unwrap();
+        let (u, vt) = canonical(u, vt, &first);
+        let (expected_u, expected_s, expected_vt) = jacobi_svd(a.to_vec(), m, n).unwrap();
+        let (expected_u, expected_vt) = canonical(expected_u, expected_vt, &first);
+        assert_close(&s, &expected_s, 1e-12);
+        assert_close(&u, &expected_u, 1e-10);
+        assert_close(&vt, &expected_vt, 1e-10);
+    }
+
+    // Test the LAPACK Cholesky factor and symmetric eigenvalues against the pure-Rust implementations
+    #[cfg(feature = "lapack")]
+    #[test]
+    fn lapack_symmetric_t() {
+        let a: [f64; 9] = [4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0];
+        assert_close(
+            &lapack::cholesky(&a, 3).unwrap(),
+            &cholesky_lower(&a, 3).unwrap(),
+            1e-12,
+        );
+
+        let (w, _) = lapack::eigh(&a, 3).unwrap();
+        let (expected_w, _) = jacobi_eigen(symmetric_from_lower(&a, 3), 3);
+        assert_close(&w, &expected_w, 1e-12);
+
+        // b = I reduces the generalized problem to the standard problem
+        let identity: [f64; 9] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let (w, _) = lapack::eig_generalized(&a, &identity, 3).unwrap();
+        assert_close(&w, &expected_w, 1e-12);
+    }
+
+    // Test the LAPACK real Schur decomposition of a non-symmetric matrix
+    #[cfg(feature = "lapack")]
+    #[test]
+    fn lapack_schur_t() {
+        let values: [f64; 9] = [1.0, 2.0, -1.0, -2.0, 1.0, 0.0, 0.0, 3.0, 2.0];
+        let (t, z) = lapack::schur(&values, 3).unwrap();
+        check_schur(
+            &Array2::<f64>::from(&values, [3, 3]),
+            &Array2::<f64>::from(&t, [3, 3]),
+            &Array2::<f64>::from(&z, [3, 3]),
+        );
+    }
+}
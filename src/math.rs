@@ -0,0 +1,813 @@
+use num_traits::{Float, Signed};
+
+use crate::core::{unravel_index, NdArray, Order, SizedArray};
+
+/// Return the shape of `a` and `b` broadcast together. Axes of equal length are kept, and axes of length 1 are stretched to the length of the other axis, similar to NumPy
+///
+/// ## Panics
+/// If an axis differs in length and neither length is 1, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+pub(crate) fn broadcast_shape<const N: usize>(
+    a: &SizedArray<N>,
+    b: &SizedArray<N>,
+) -> SizedArray<N> {
+    let mut shape: SizedArray<N> = [0; N];
+    for dim in 0..N {
+        shape[dim] = match (a[dim], b[dim]) {
+            (x, y) if x == y => x,
+            (1, y) => y,
+            (x, 1) => x,
+            _ => panic!("Shape({:?}) cannot be broadcast with Shape({:?})", a, b),
+        };
+    }
+    shape
+}
+
+/// Return `shape` with leading axes of length 1 up to dimension `N`, i.e., the shape an array of dimension `M` is broadcast with in dimension `N`
+///
+/// ## Panics
+/// If `M` is greater than `N`, panics; message shown is **Dimension(`N`) must not be less than Dimension(`M`)**
+pub(crate) fn expand_shape<const N: usize, const M: usize>(shape: &SizedArray<M>) -> SizedArray<N> {
+    if M > N {
+        panic!("Dimension({}) must not be less than Dimension({})", N, M);
+    }
+
+    let mut expanded: SizedArray<N> = [1; N];
+    expanded[N - M..].copy_from_slice(shape);
+    expanded
+}
+
+/// Apply `f` to every pair of elements of `a` and `b` broadcast together, and return the results as an NdArray of the broadcast shape. The trailing axes are aligned if `b` has fewer dimensions (`M <= N`)
+///
+/// ## Panics
+/// - If `M` is greater than `N`, panics; message shown is **Dimension(`N`) must not be less than Dimension(`M`)**
+/// - If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+pub(crate) fn broadcast_with<T, U, V, F, const N: usize, const M: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<U, M>,
+    mut f: F,
+) -> NdArray<V, N>
+where
+    T: Copy,
+    U: Copy,
+    F: FnMut(T, U) -> V,
+{
+    let (a_shape, b_shape) = (a.view_shape(), expand_shape::<N, M>(&b.view_shape()));
+    let shape: SizedArray<N> = broadcast_shape(&a_shape, &b_shape);
+    if a_shape == b_shape {
+        let values: Vec<V> = a
+            .as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .map(|(x, y)| f(*x, *y))
+            .collect();
+        return NdArray::from_owned_vec(values, shape);
+    }
+
+    // stretched axes stay on position 0
+    let pin = |index: &SizedArray<N>, of: &SizedArray<N>| -> SizedArray<N> {
+        let mut pinned: SizedArray<N> = *index;
+        for dim in 0..N {
+            if of[dim] == 1 {
+                pinned[dim] = 0;
+            }
+        }
+        pinned
+    };
+    let len: usize = shape.iter().product();
+    let values: Vec<V> = (0..len)
+        .map(|pos| {
+            let index: SizedArray<N> = unravel_index(pos, &shape, Order::C);
+            let b_index: SizedArray<N> = pin(&index, &b_shape);
+            let b_index: SizedArray<M> = std::array::from_fn(|dim| b_index[N - M + dim]);
+            f(a[pin(&index, &a_shape)], b[b_index])
+        })
+        .collect();
+    NdArray::from_owned_vec(values, shape)
+}
+
+/// Return true if `x` is not comparable with itself, i.e. NaN
+fn is_nan<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Clamp `x` to at least `min` and then to at most `max`, where a bound of `None` is not applied. NaN values are propagated
+fn clip_value<T: PartialOrd>(x: T, min: Option<T>, max: Option<T>) -> T {
+    let x: T = match min {
+        Some(min) if x < min => min,
+        _ => x,
+    };
+    match max {
+        Some(max) if x > max => max,
+        _ => x,
+    }
+}
+
+/// Return the element-wise maximum of `a` and `b` broadcast together. NaN values are propagated, similar to `numpy.maximum`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::maximum;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 2>::from(&[1.0, 5.0, f64::NAN, 0.0], [2, 2]);
+/// let b = NdArray::<f64, 2>::from(&[2.0, 3.0], [1, 2]); // broadcast over the rows
+/// let max = maximum(&a, &b);
+/// assert_eq!(max[[0, 0]], 2.0);
+/// assert_eq!(max[[0, 1]], 5.0);
+/// assert!(max[[1, 0]].is_nan());
+/// assert_eq!(max[[1, 1]], 3.0);
+/// # }
+/// ```
+pub fn maximum<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, N>,
+) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| if is_nan(&x) || x >= y { x } else { y })
+}
+
+/// Return the element-wise minimum of `a` and `b` broadcast together. NaN values are propagated, similar to `numpy.minimum`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::minimum;
+/// #
+/// # fn main() {
+/// let a = NdArray::<i32, 1>::from(&[4, -1, 7], [3]);
+/// let b = NdArray::<i32, 1>::from(&[0], [1]);
+/// assert_eq!(minimum(&a, &b).as_slice(), &[0, -1, 0]);
+/// # }
+/// ```
+pub fn minimum<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, N>,
+) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| if is_nan(&x) || x <= y { x } else { y })
+}
+
+/// Return the element-wise maximum of `a` and the scalar `value`, e.g. ReLU with a `value` of zero. NaN values are propagated, similar to `numpy.maximum(a, value)`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::maximum_scalar;
+/// #
+/// # fn main() {
+/// let logits = NdArray::<f32, 2>::from(&[-1.5, 0.5, 2.0, f32::NAN], [2, 2]);
+/// let relu = maximum_scalar(&logits, 0.0);
+/// assert_eq!(relu.as_slice()[..3], [0.0, 0.5, 2.0]);
+/// assert!(relu[[1, 1]].is_nan());
+/// # }
+/// ```
+pub fn maximum_scalar<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    value: T,
+) -> NdArray<T, N> {
+    a.map(|x| if is_nan(x) || *x >= value { *x } else { value })
+}
+
+/// Return the element-wise minimum of `a` and the scalar `value`. NaN values are propagated, similar to `numpy.minimum(a, value)`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::minimum_scalar;
+/// #
+/// # fn main() {
+/// let counts = NdArray::<u32, 1>::from(&[3, 300, 255], [3]);
+/// assert_eq!(minimum_scalar(&counts, 255).as_slice(), &[3, 255, 255]);
+/// # }
+/// ```
+pub fn minimum_scalar<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    value: T,
+) -> NdArray<T, N> {
+    a.map(|x| if is_nan(x) || *x <= value { *x } else { value })
+}
+
+/// Return the element-wise maximum of `a` and `b` broadcast together. NaN values are ignored unless both elements are NaN, similar to `numpy.fmax`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::fmax;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f32, 1>::from(&[1.0, f32::NAN, f32::NAN], [3]);
+/// let b = NdArray::<f32, 1>::from(&[2.0, 3.0, f32::NAN], [3]);
+/// let max = fmax(&a, &b);
+/// assert_eq!(&max.as_slice()[..2], &[2.0, 3.0]);
+/// assert!(max[[2]].is_nan());
+/// # }
+/// ```
+pub fn fmax<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, N>,
+) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| if is_nan(&y) || x >= y { x } else { y })
+}
+
+/// Return the element-wise minimum of `a` and `b` broadcast together. NaN values are ignored unless both elements are NaN, similar to `numpy.fmin`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::fmin;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f64, 1>::from(&[1.0, f64::NAN], [2]);
+/// let b = NdArray::<f64, 1>::from(&[2.0, -3.0], [2]);
+/// assert_eq!(fmin(&a, &b).as_slice(), &[1.0, -3.0]);
+/// # }
+/// ```
+pub fn fmin<T: Copy + PartialOrd, const N: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, N>,
+) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| if is_nan(&y) || x <= y { x } else { y })
+}
+
+/// Return the element-wise length of the hypotenuse `sqrt(a^2 + b^2)` of `a` and `b` broadcast together, without intermediate overflow
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::hypot;
+/// #
+/// # fn main() {
+/// let u = NdArray::<f64, 1>::from(&[3.0, 5.0, 1e300], [3]);
+/// let v = NdArray::<f64, 1>::from(&[4.0, 12.0, 1e300], [3]);
+/// let magnitude = hypot(&u, &v);
+/// assert_eq!(&magnitude.as_slice()[..2], &[5.0, 13.0]);
+/// assert!(magnitude[[2]].is_finite());
+/// # }
+/// ```
+pub fn hypot<T: Float, const N: usize>(a: &NdArray<T, N>, b: &NdArray<T, N>) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| x.hypot(y))
+}
+
+/// Return the element-wise four-quadrant arc tangent of `y / x` broadcast together, in radians within `[-pi, pi]`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`y`) cannot be broadcast with Shape(`x`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::atan2;
+/// # use std::f64::consts::PI;
+/// #
+/// # fn main() {
+/// let y = NdArray::<f64, 1>::from(&[1.0, 1.0, -1.0], [3]);
+/// let x = NdArray::<f64, 1>::from(&[1.0, -1.0, 0.0], [3]);
+/// assert_eq!(atan2(&y, &x).as_slice(), &[PI / 4.0, 3.0 * PI / 4.0, -PI / 2.0]);
+/// # }
+/// ```
+pub fn atan2<T: Float, const N: usize>(y: &NdArray<T, N>, x: &NdArray<T, N>) -> NdArray<T, N> {
+    broadcast_with(y, x, |y, x| y.atan2(x))
+}
+
+/// Return the element-wise magnitude of `a` with the sign of `b`, broadcast together
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::copysign;
+/// #
+/// # fn main() {
+/// let a = NdArray::<f32, 1>::from(&[1.5, -2.0, 3.0], [3]);
+/// let b = NdArray::<f32, 1>::from(&[-0.0], [1]);
+/// assert_eq!(copysign(&a, &b).as_slice(), &[-1.5, -2.0, -3.0]);
+/// # }
+/// ```
+pub fn copysign<T: Float, const N: usize>(a: &NdArray<T, N>, b: &NdArray<T, N>) -> NdArray<T, N> {
+    broadcast_with(a, b, |x, y| {
+        if x.is_sign_negative() == y.is_sign_negative() {
+            x
+        } else {
+            -x
+        }
+    })
+}
+
+/// Return the Heaviside step function of `x` element-wise, which is 0 for negative and 1 for positive values, and `h0` where `x` is zero. Broadcasts `x` and `h0` together, similar to `numpy.heaviside`
+///
+/// ## Panics
+/// If the shapes cannot be broadcast, panics; message shown is **Shape(`x`) cannot be broadcast with Shape(`h0`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::math::heaviside;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[-1.5, 0.0, 2.0], [3]);
+/// let h0 = NdArray::<f64, 1>::from(&[0.5], [1]);
+/// assert_eq!(heaviside(&x, &h0).as_slice(), &[0.0, 0.5, 1.0]);
+/// # }
+/// ```
+pub fn heaviside<T: Float, const N: usize>(x: &NdArray<T, N>, h0: &NdArray<T, N>) -> NdArray<T, N> {
+    broadcast_with(x, h0, |x, h0| {
+        if x.is_nan() {
+            x
+        } else if x == T::zero() {
+            h0
+        } else if x < T::zero() {
+            T::zero()
+        } else {
+            T::one()
+        }
+    })
+}
+
+impl<T: Copy + PartialEq, const N: usize> NdArray<T, N> {
+    /// Compare every element with the element of `other` (equal to), broadcast together, and return the results as a boolean NdArray. Same as `numpy.equal(a, b)`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<i32, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let b = NdArray::<i32, 2>::from(&[1, 4], [1, 2]);
+    /// assert_eq!(a.eq_elem(&b).as_slice(), &[true, false, false, true]);
+    /// # }
+    /// ```
+    pub fn eq_elem(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x == y)
+    }
+
+    /// Compare every element with the element of `other` (not equal to), broadcast together, and return the results as a boolean NdArray. Same as `numpy.not_equal(a, b)`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<i32, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let b = NdArray::<i32, 2>::from(&[1, 4], [1, 2]);
+    /// assert_eq!(a.ne_elem(&b).as_slice(), &[false, true, true, false]);
+    /// # }
+    /// ```
+    pub fn ne_elem(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x != y)
+    }
+}
+
+impl<T: Copy + PartialOrd, const N: usize> NdArray<T, N> {
+    /// Compare every element with the element of `other` (greater than), broadcast together, and return the results as a boolean NdArray. Same as `numpy.greater(a, b)`
+    ///
+    /// ## Note
+    /// Every comparison with NaN is false, also for `ge`, `lt` and `le`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<f32, 2>::from(&[0.5, 2.0, 3.0, -1.0], [2, 2]);
+    /// let b = NdArray::<f32, 2>::from(&[1.0, 0.0], [1, 2]);
+    /// assert_eq!(a.gt(&b).as_slice(), &[false, true, true, false]);
+    /// # }
+    /// ```
+    pub fn gt(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x > y)
+    }
+
+    /// Compare every element with the element of `other` (greater than or equal to), broadcast together, and return the results as a boolean NdArray. Same as `numpy.greater_equal(a, b)`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<f32, 2>::from(&[0.5, 2.0, 1.0, -1.0], [2, 2]);
+    /// let b = NdArray::<f32, 2>::from(&[1.0, 0.0], [1, 2]);
+    /// assert_eq!(a.ge(&b).as_slice(), &[false, true, true, false]);
+    /// # }
+    /// ```
+    pub fn ge(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x >= y)
+    }
+
+    /// Compare every element with the element of `other` (less than), broadcast together, and return the results as a boolean NdArray. Same as `numpy.less(a, b)`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<u8, 2>::from(&[0, 2, 3, 4], [2, 2]);
+    /// let b = NdArray::<u8, 2>::from(&[1, 4], [1, 2]);
+    /// assert_eq!(a.lt(&b).as_slice(), &[true, true, false, false]);
+    /// # }
+    /// ```
+    pub fn lt(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x < y)
+    }
+
+    /// Compare every element with the element of `other` (less than or equal to), broadcast together, and return the results as a boolean NdArray. Same as `numpy.less_equal(a, b)`
+    ///
+    /// ## Panics
+    /// If the shapes cannot be broadcast, panics; message shown is **Shape(`self`) cannot be broadcast with Shape(`other`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let a = NdArray::<u8, 2>::from(&[0, 2, 1, 4], [2, 2]);
+    /// let b = NdArray::<u8, 2>::from(&[1, 4], [1, 2]);
+    /// assert_eq!(a.le(&b).as_slice(), &[true, true, true, true]);
+    /// # }
+    /// ```
+    pub fn le(&self, other: &NdArray<T, N>) -> NdArray<bool, N> {
+        broadcast_with(self, other, |x, y| x <= y)
+    }
+
+    /// Clamp every element into the range `[min, max]` and return the results as a new NdArray, where a bound of `None` is not applied. Same as `numpy.clip(a, min, max)`
+    ///
+    /// ## Note
+    /// NaN values are propagated. If `min` is greater than `max`, every element becomes `max`, similar to NumPy
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let readings = NdArray::<f32, 1>::from(&[-40.0, 21.5, 900.0], [3]);
+    /// assert_eq!(readings.clip(Some(-20.0), Some(60.0)).as_slice(), &[-20.0, 21.5, 60.0]);
+    /// assert_eq!(readings.clip(None, Some(60.0)).as_slice(), &[-40.0, 21.5, 60.0]);
+    /// # }
+    /// ```
+    pub fn clip(&self, min: Option<T>, max: Option<T>) -> NdArray<T, N> {
+        self.map(|x| clip_value(*x, min, max))
+    }
+
+    /// Clamp every element into the range `[min, max]` in-place, where a bound of `None` is not applied. See [`NdArray::<T, N>::clip(...)`](NdArray::clip)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut pixels = NdArray::<i16, 2>::from(&[-3, 128, 300, 255], [2, 2]);
+    /// pixels.clip_inplace(Some(0), Some(255));
+    /// assert_eq!(pixels.as_slice(), &[0, 128, 255, 255]);
+    /// # }
+    /// ```
+    pub fn clip_inplace(&mut self, min: Option<T>, max: Option<T>) {
+        self.map_inplace(|x| *x = clip_value(*x, min, max));
+    }
+}
+
+impl<T: Signed + Copy, const N: usize> NdArray<T, N> {
+    /// Return the sign of every element of a signed integer or float NdArray, i.e. -1 for negative, 0 for zero and 1 for positive values. NaN values are propagated
+    ///
+    /// ## Note
+    /// Unlike `f64::signum`, zero (and negative zero) maps to zero, similar to `numpy.sign`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[-3.5, 0.0, -0.0, 2.0], [4]);
+    /// assert_eq!(arr.signum().as_slice(), &[-1.0, 0.0, 0.0, 1.0]);
+    ///
+    /// let arr = NdArray::<i8, 1>::from(&[-7, 0, 5], [3]);
+    /// assert_eq!(arr.signum().as_slice(), &[-1, 0, 1]);
+    /// # }
+    /// ```
+    pub fn signum(&self) -> NdArray<T, N> {
+        self.map(|x| {
+            if *x == T::zero() {
+                T::zero()
+            } else {
+                x.signum()
+            }
+        })
+    }
+
+    /// Return the absolute value of every element of a signed integer or float NdArray. Same as `numpy.abs(a)`
+    ///
+    /// ## Note
+    /// The absolute value of the minimum of a signed integer type overflows, as in `i32::abs`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[-3, 0, 7, -1], [2, 2]);
+    /// assert_eq!(arr.abs().as_slice(), &[3, 0, 7, 1]);
+    /// # }
+    /// ```
+    pub fn abs(&self) -> NdArray<T, N> {
+        self.map(|x| x.abs())
+    }
+}
+
+impl<T: Float, const N: usize> NdArray<T, N> {
+    /// Return the largest integer less than or equal to every element. Same as `numpy.floor(a)`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[-1.5, -0.2, 0.5, 2.7], [4]);
+    /// assert_eq!(arr.floor().as_slice(), &[-2.0, -1.0, 0.0, 2.0]);
+    /// # }
+    /// ```
+    pub fn floor(&self) -> NdArray<T, N> {
+        self.map(|x| x.floor())
+    }
+
+    /// Return the smallest integer greater than or equal to every element. Same as `numpy.ceil(a)`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[-1.5, -0.2, 0.5, 2.7], [4]);
+    /// assert_eq!(arr.ceil().as_slice(), &[-1.0, -0.0, 1.0, 3.0]);
+    /// # }
+    /// ```
+    pub fn ceil(&self) -> NdArray<T, N> {
+        self.map(|x| x.ceil())
+    }
+
+    /// Return the nearest integer of every element, rounding half-way cases away from zero
+    ///
+    /// ## Note
+    /// Unlike `numpy.round`, which rounds half-way cases to the nearest even integer, `2.5` rounds to `3.0`, similar to `f64::round`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[-1.5, -0.2, 0.5, 2.7], [4]);
+    /// assert_eq!(arr.round().as_slice(), &[-2.0, -0.0, 1.0, 3.0]);
+    /// # }
+    /// ```
+    pub fn round(&self) -> NdArray<T, N> {
+        self.map(|x| x.round())
+    }
+
+    /// Return the integer part, rounded toward zero, of every element. Same as `numpy.trunc(a)`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[-1.5, -0.2, 0.5, 2.7], [4]);
+    /// assert_eq!(arr.trunc().as_slice(), &[-1.0, -0.0, 0.0, 2.0]);
+    /// # }
+    /// ```
+    pub fn trunc(&self) -> NdArray<T, N> {
+        self.map(|x| x.trunc())
+    }
+}
+
+#[cfg(test)]
+mod math_t {
+    use crate::{
+        core::{Array, Array2, Array3},
+        math::{
+            atan2, copysign, fmax, fmin, heaviside, hypot, maximum, maximum_scalar, minimum,
+            minimum_scalar,
+        },
+    };
+
+    // Test the envelope of two 3-D NdArrays broadcast along both sides
+    #[test]
+    fn broadcast_envelope_3dim_t() {
+        let upper = Array3::<i32>::from(&[1, 5, -2, 4], [2, 1, 2]);
+        let lower = Array3::<i32>::from(&[0, 3, 2], [1, 3, 1]);
+
+        let max = maximum(&upper, &lower);
+        assert_eq!(max.shape(), &[2, 3, 2]);
+        assert_eq!(max.as_slice(), &[1, 5, 3, 5, 2, 5, 0, 4, 3, 4, 2, 4]);
+        let min = minimum(&upper, &lower);
+        assert_eq!(min.as_slice(), &[0, 0, 1, 3, 1, 2, -2, 0, -2, 3, -2, 2]);
+    }
+
+    // Test thresholding a 3-D NdArray against per-column limits, and feeding the mask to the masking APIs
+    #[test]
+    fn compare_3dim_t() {
+        let mut data =
+            Array3::<f32>::from_shape_fn([2, 3, 4], |[i, j, k]| (i * 12 + j * 4 + k) as f32);
+        let limits = Array3::<f32>::from(&[4.0, 9.0, 30.0, f32::NAN], [1, 1, 4]);
+
+        let above = data.gt(&limits);
+        assert_eq!(*above.shape(), [2, 3, 4]);
+        assert_eq!(above.as_slice().iter().filter(|x| **x).count(), 7);
+        assert_eq!(data.mask_select(&above).as_slice()[..3], [8.0, 12.0, 13.0]);
+        assert_eq!(
+            data.le(&limits).as_slice().iter().filter(|x| **x).count(),
+            11
+        );
+        assert!(!data.ge(&limits)[[0, 0, 3]] && !data.lt(&limits)[[0, 0, 3]]);
+
+        let eq = data.eq_elem(&limits);
+        assert_eq!(data.mask_select(&eq).as_slice(), &[4.0, 9.0]);
+        assert!(data.ne_elem(&limits)[[0, 0, 3]]);
+        data.mask_assign(&above, 0.0);
+        assert!(!data.gt(&limits).as_slice().contains(&true));
+    }
+
+    // Test clamping a 3-D NdArray against a scalar on either side, and against per-element bounds
+    #[test]
+    fn scalar_envelope_3dim_t() {
+        let data = Array3::<f64>::from_shape_fn([2, 3, 4], |[i, j, k]| {
+            (i as f64 - 0.5) * (j * 4 + k) as f64
+        });
+        let relu = maximum_scalar(&data, 0.0);
+        assert_eq!(relu[[0, 2, 3]], 0.0);
+        assert_eq!(relu[[1, 2, 3]], 5.5);
+        assert_eq!(minimum_scalar(&relu, 2.0)[[1, 2, 3]], 2.0);
+        assert_eq!(minimum_scalar(&relu, 2.0)[[1, 0, 2]], 1.0);
+        assert_eq!(maximum_scalar(&data, f64::NEG_INFINITY), data);
+
+        let bounds = Array3::<f64>::from_shape_fn([1, 3, 1], |[_, j, _]| j as f64);
+        let clamped = minimum(&maximum_scalar(&data, 0.0), &bounds);
+        assert_eq!(clamped[[1, 2, 3]], 2.0);
+        assert_eq!(clamped[[1, 0, 3]], 0.0);
+    }
+
+    // Test clipping a 3-D NdArray of sensor readings with either or both bounds, and in-place
+    #[test]
+    fn clip_3dim_t() {
+        let mut data = Array3::<f64>::from_shape_fn([2, 3, 4], |[i, j, k]| {
+            10.0 * (i * 12 + j * 4 + k) as f64 - 100.0
+        });
+        data[[0, 1, 1]] = f64::NAN;
+
+        let clipped = data.clip(Some(-50.0), Some(50.0));
+        assert_eq!(clipped[[0, 0, 0]], -50.0);
+        assert_eq!(clipped[[0, 2, 3]], 10.0);
+        assert_eq!(clipped[[1, 2, 3]], 50.0);
+        assert!(clipped[[0, 1, 1]].is_nan());
+        assert_eq!(data.clip(None, Some(0.0))[[0, 0, 0]], -100.0);
+        assert_eq!(data.clip(Some(0.0), None)[[1, 2, 3]], 130.0);
+        assert_eq!(data.clip(Some(5.0), Some(-5.0))[[0, 0, 0]], -5.0);
+
+        data.clip_inplace(Some(-50.0), Some(50.0));
+        assert_eq!(data.as_slice()[..4], clipped.as_slice()[..4]);
+        assert_eq!(data[[1, 2, 3]], 50.0);
+    }
+
+    // Test rounding a 3-D NdArray of floats in every direction, and the absolute value of integers and floats
+    #[test]
+    fn rounding_3dim_t() {
+        let data = Array3::<f32>::from_shape_fn([2, 3, 4], |[i, j, k]| {
+            (i as f32 - 1.0) * (j * 4 + k) as f32 * 0.25
+        });
+        assert_eq!(data[[0, 1, 2]], -1.5);
+        assert_eq!(data.floor()[[0, 1, 2]], -2.0);
+        assert_eq!(data.ceil()[[0, 1, 2]], -1.0);
+        assert_eq!(data.round()[[0, 1, 2]], -2.0);
+        assert_eq!(data.trunc()[[0, 1, 2]], -1.0);
+        assert_eq!(data.abs()[[0, 1, 2]], 1.5);
+        assert_eq!(data.trunc().abs(), data.abs().floor());
+
+        let ints =
+            Array3::<i64>::from_shape_fn([2, 3, 4], |[i, j, k]| i as i64 * 12 - (j * 4 + k) as i64);
+        assert_eq!(ints.abs()[[0, 2, 3]], 11);
+        assert_eq!(ints.abs().signum(), ints.signum().abs());
+        assert!(Array3::<f64>::new().round().is_empty());
+    }
+
+    // Test the NaN propagating and NaN ignoring variants on either side
+    #[test]
+    fn nan_variants_t() {
+        let nan = f64::NAN;
+        let a = Array::<f64>::from(&[nan, 1.0, nan], [3]);
+        let b = Array::<f64>::from(&[2.0, nan, nan], [3]);
+
+        assert!(maximum(&a, &b).as_slice().iter().all(|x| x.is_nan()));
+        assert!(minimum(&b, &a).as_slice().iter().all(|x| x.is_nan()));
+        assert_eq!(&fmax(&a, &b).as_slice()[..2], &[2.0, 1.0]);
+        assert_eq!(&fmin(&b, &a).as_slice()[..2], &[2.0, 1.0]);
+        assert!(fmin(&a, &b)[[2]].is_nan());
+    }
+
+    // Test converting a 2-D vector field to magnitude and angle form
+    #[test]
+    fn polar_field_2dim_t() {
+        let u = Array2::<f64>::from(&[3.0, 0.0, -2.0, 0.0], [2, 2]);
+        let v = Array2::<f64>::from(&[4.0, -1.0, 0.0, 0.0], [2, 2]);
+
+        assert_eq!(hypot(&u, &v).as_slice(), &[5.0, 1.0, 2.0, 0.0]);
+        let angle = atan2(&v, &u);
+        assert!((angle[[0, 0]] - (4.0f64).atan2(3.0)).abs() < 1e-15);
+        assert_eq!(angle[[0, 1]], -std::f64::consts::FRAC_PI_2);
+        assert_eq!(angle[[1, 0]], std::f64::consts::PI);
+        assert_eq!(angle[[1, 1]], 0.0);
+    }
+
+    // Test the sign functions with zeros and NaN values
+    #[test]
+    fn sign_t() {
+        let x = Array::<f32>::from(&[-0.0, 0.0, f32::NAN, -4.0], [4]);
+        let ones = Array::<f32>::from(&[1.0], [1]);
+
+        let copied = copysign(&ones, &x);
+        assert_eq!(&copied.as_slice()[..2], &[-1.0, 1.0]);
+        let sign = x.signum();
+        assert_eq!(sign[[0]], 0.0);
+        assert!(sign[[2]].is_nan());
+        assert_eq!(sign[[3]], -1.0);
+        let step = heaviside(&x, &ones);
+        assert_eq!(&step.as_slice()[..2], &[1.0, 1.0]);
+        assert!(step[[2]].is_nan());
+        assert_eq!(step[[3]], 0.0);
+    }
+
+    // Test shapes which cannot be broadcast
+    #[test]
+    #[should_panic(expected = "Shape([3]) cannot be broadcast with Shape([2])")]
+    fn broadcast_mismatch_t() {
+        let a = Array::<u8>::from(&[1, 2, 3], [3]);
+        let b = Array::<u8>::from(&[1, 2], [2]);
+        maximum(&a, &b);
+    }
+
+    // Test broadcasting an empty NdArray created using NdArray::<T, N>::new()
+    // Check if it is broadcast as a shape of [0; N], with a length 1 axis or another empty array
+    #[test]
+    fn broadcast_new_t() {
+        let empty = Array2::<f64>::new();
+        let ones = Array2::<f64>::from(&[1.0], [1, 1]);
+        for other in [&empty, &ones] {
+            assert_eq!(maximum(&empty, other).view_shape(), [0, 0]);
+            assert_eq!(hypot(other, &empty).view_shape(), [0, 0]);
+            assert_eq!(empty.lt(other).view_shape(), [0, 0]);
+            assert_eq!((other + &empty).view_shape(), [0, 0]);
+            assert_eq!((other.clone() - &empty).view_shape(), [0, 0]);
+            assert_eq!((&empty * other.clone()).view_shape(), [0, 0]);
+        }
+    }
+}
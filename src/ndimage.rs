@@ -0,0 +1,405 @@
+use num_traits::ToPrimitive;
+
+use crate::{
+    core::{unravel_index, NdArray, Order, SizedArray},
+    iter::map_lanes,
+};
+
+/// Return the position `pos` reflected into `0..len` about the edges (`d c b a | a b c d | d c b a`), similar to `mode='reflect'` of `scipy.ndimage`
+fn reflect(pos: isize, len: usize) -> usize {
+    let period: isize = 2 * len as isize;
+    let pos: isize = pos.rem_euclid(period);
+    if pos < len as isize {
+        pos as usize
+    } else {
+        (period - 1 - pos) as usize
+    }
+}
+
+/// Offsets of the selected positions of `footprint` from its centre (`shape / 2`). If `mirror` is true, the offsets are negated
+///
+/// ## Panics
+/// If no position is selected, panics; message shown is **Footprint cannot be empty**
+fn footprint_offsets<const N: usize>(
+    footprint: &NdArray<bool, N>,
+    mirror: bool,
+) -> Vec<[isize; N]> {
+    let shape: SizedArray<N> = footprint.view_shape();
+    let offsets: Vec<[isize; N]> = footprint
+        .as_slice()
+        .iter()
+        .enumerate()
+        .filter(|(_, selected)| **selected)
+        .map(|(pos, _)| {
+            let index: SizedArray<N> = unravel_index(pos, &shape, Order::C);
+            let mut offset: [isize; N] = [0; N];
+            for dim in 0..N {
+                offset[dim] = index[dim] as isize - (shape[dim] / 2) as isize;
+                if mirror {
+                    offset[dim] = -offset[dim];
+                }
+            }
+            offset
+        })
+        .collect();
+    if offsets.is_empty() {
+        panic!("Footprint cannot be empty");
+    }
+    offsets
+}
+
+/// Apply `f` to the values at `offsets` around every position of `input`. Positions outside `input` are reflected, or take the value `outside` if given
+fn filter_offsets<T, U, F, const N: usize>(
+    input: &NdArray<T, N>,
+    offsets: &[[isize; N]],
+    outside: Option<T>,
+    mut f: F,
+) -> NdArray<U, N>
+where
+    T: Copy,
+    F: FnMut(&[T]) -> U,
+{
+    let shape: SizedArray<N> = input.view_shape();
+    let mut values: Vec<T> = Vec::with_capacity(offsets.len());
+    let out: Vec<U> = (0..*input.len())
+        .map(|pos| {
+            let index: SizedArray<N> = unravel_index(pos, &shape, Order::C);
+            values.clear();
+            for offset in offsets {
+                let mut neighbour: SizedArray<N> = [0; N];
+                let mut inside: bool = true;
+                for dim in 0..N {
+                    let at: isize = index[dim] as isize + offset[dim];
+                    inside &= at >= 0 && at < shape[dim] as isize;
+                    neighbour[dim] = reflect(at, shape[dim]);
+                }
+                values.push(match outside {
+                    Some(value) if !inside => value,
+                    _ => input[neighbour],
+                });
+            }
+            f(&values)
+        })
+        .collect();
+    NdArray::from_owned_vec(out, shape)
+}
+
+/// Return `f` applied to the values selected by `footprint` around every position of `input`, similar to `scipy.ndimage.generic_filter`. The footprint is centred at `footprint.shape() / 2`, and positions outside `input` are reflected about the edges
+///
+/// ## Panics
+/// If `footprint` selects no position, panics; message shown is **Footprint cannot be empty**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::generic_filter;
+/// #
+/// # fn main() {
+/// let image = NdArray::<u8, 2>::from(&[1, 9, 2, 8, 3, 7, 4, 6, 5], [3, 3]);
+/// let cross = NdArray::<bool, 2>::from(&[false, true, false, true, true, true, false, true, false], [3, 3]);
+/// let range = generic_filter(&image, &cross, |values| {
+///     values.iter().max().unwrap() - values.iter().min().unwrap()
+/// });
+/// assert_eq!(range[[1, 1]], 6); // range of 9, 8, 3, 7 and 6
+/// # }
+/// ```
+pub fn generic_filter<T, U, F, const N: usize>(
+    input: &NdArray<T, N>,
+    footprint: &NdArray<bool, N>,
+    f: F,
+) -> NdArray<U, N>
+where
+    T: Copy,
+    F: FnMut(&[T]) -> U,
+{
+    filter_offsets(input, &footprint_offsets(footprint, false), None, f)
+}
+
+/// Return the grayscale erosion of `input` by the flat structuring element `footprint`, i.e. the minimum of the selected values around every position, similar to `scipy.ndimage.grey_erosion`. Positions outside `input` are reflected about the edges
+///
+/// ## Panics
+/// If `footprint` selects no position, panics; message shown is **Footprint cannot be empty**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::grey_erosion;
+/// #
+/// # fn main() {
+/// let signal = NdArray::<i32, 1>::from(&[5, 3, 8, 8, 1, 9], [6]);
+/// let footprint = NdArray::<bool, 1>::from(&[true; 3], [3]);
+/// assert_eq!(grey_erosion(&signal, &footprint).as_slice(), &[3, 3, 3, 1, 1, 1]);
+/// # }
+/// ```
+pub fn grey_erosion<T: Copy + PartialOrd, const N: usize>(
+    input: &NdArray<T, N>,
+    footprint: &NdArray<bool, N>,
+) -> NdArray<T, N> {
+    generic_filter(input, footprint, |values| {
+        values[1..]
+            .iter()
+            .fold(values[0], |min, x| if *x < min { *x } else { min })
+    })
+}
+
+/// Return the grayscale dilation of `input` by the flat structuring element `footprint`, i.e. the maximum of the values selected by the mirrored footprint around every position, similar to `scipy.ndimage.grey_dilation`. Positions outside `input` are reflected about the edges
+///
+/// ## Panics
+/// If `footprint` selects no position, panics; message shown is **Footprint cannot be empty**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::grey_dilation;
+/// #
+/// # fn main() {
+/// let signal = NdArray::<f32, 1>::from(&[0.0, 2.0, 0.0, 0.0, 1.0], [5]);
+/// let footprint = NdArray::<bool, 1>::from(&[true, true], [2]); // the position and its left neighbour, mirrored
+/// assert_eq!(grey_dilation(&signal, &footprint).as_slice(), &[2.0, 2.0, 0.0, 1.0, 1.0]);
+/// # }
+/// ```
+pub fn grey_dilation<T: Copy + PartialOrd, const N: usize>(
+    input: &NdArray<T, N>,
+    footprint: &NdArray<bool, N>,
+) -> NdArray<T, N> {
+    let offsets: Vec<[isize; N]> = footprint_offsets(footprint, true);
+    filter_offsets(input, &offsets, None, |values| {
+        values[1..]
+            .iter()
+            .fold(values[0], |max, x| if *x > max { *x } else { max })
+    })
+}
+
+/// Return the binary erosion of `input` by `footprint`, which is true where every selected position around it is true, similar to `scipy.ndimage.binary_erosion`. Positions outside `input` are false, hence the foreground touching the edges erodes
+///
+/// ## Panics
+/// If `footprint` selects no position, panics; message shown is **Footprint cannot be empty**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::binary_erosion;
+/// #
+/// # fn main() {
+/// let mask = NdArray::<bool, 1>::from(&[true, true, true, false, true, true, true], [7]);
+/// let footprint = NdArray::<bool, 1>::from(&[true; 3], [3]);
+/// let eroded = binary_erosion(&mask, &footprint);
+/// assert_eq!(eroded.as_slice(), &[false, true, false, false, false, true, false]);
+/// # }
+/// ```
+pub fn binary_erosion<const N: usize>(
+    input: &NdArray<bool, N>,
+    footprint: &NdArray<bool, N>,
+) -> NdArray<bool, N> {
+    filter_offsets(
+        input,
+        &footprint_offsets(footprint, false),
+        Some(false),
+        |values| values.iter().all(|x| *x),
+    )
+}
+
+/// Return the binary dilation of `input` by `footprint`, which is true where any position selected by the mirrored footprint around it is true, similar to `scipy.ndimage.binary_dilation`
+///
+/// ## Panics
+/// If `footprint` selects no position, panics; message shown is **Footprint cannot be empty**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::binary_dilation;
+/// #
+/// # fn main() {
+/// let mask = NdArray::<bool, 2>::from(&[false, false, false, false, true, false, false, false, false], [3, 3]);
+/// let cross = NdArray::<bool, 2>::from(&[false, true, false, true, true, true, false, true, false], [3, 3]);
+/// assert_eq!(binary_dilation(&mask, &cross).as_slice(), cross.as_slice());
+/// # }
+/// ```
+pub fn binary_dilation<const N: usize>(
+    input: &NdArray<bool, N>,
+    footprint: &NdArray<bool, N>,
+) -> NdArray<bool, N> {
+    filter_offsets(
+        input,
+        &footprint_offsets(footprint, true),
+        Some(false),
+        |values| values.iter().any(|x| *x),
+    )
+}
+
+/// Correlate every lane along `axis` of `input` with the 3-point `weights` centred at each position, reflecting the positions outside `input`
+fn correlate3<const N: usize>(
+    input: &NdArray<f64, N>,
+    weights: [f64; 3],
+    axis: usize,
+) -> NdArray<f64, N> {
+    map_lanes(input, axis, |lane| {
+        let len: usize = lane.len();
+        (0..len as isize)
+            .map(|i| {
+                (0..3)
+                    .map(|k| weights[k] * lane[reflect(i + k as isize - 1, len)])
+                    .sum()
+            })
+            .collect()
+    })
+}
+
+/// Derivative `[-1, 0, 1]` along `axis`, smoothed by `smooth` along every other axis
+fn gradient_filter<T: Copy + ToPrimitive, const N: usize>(
+    input: &NdArray<T, N>,
+    axis: usize,
+    smooth: [f64; 3],
+) -> NdArray<f64, N> {
+    if axis >= N {
+        panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+    }
+    let mut out: NdArray<f64, N> = input.map(|x| x.to_f64().unwrap_or(f64::NAN));
+    for dim in 0..N {
+        let weights: [f64; 3] = if dim == axis {
+            [-1.0, 0.0, 1.0]
+        } else {
+            smooth
+        };
+        out = correlate3(&out, weights, dim);
+    }
+    out
+}
+
+/// Return the Sobel gradient of `input` along `axis`, i.e. the derivative `[-1, 0, 1]` along `axis`, smoothed by `[1, 2, 1]` along every other axis, similar to `scipy.ndimage.sobel`. Positions outside `input` are reflected about the edges
+///
+/// ## Panics
+/// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::sobel;
+/// #
+/// # fn main() {
+/// let ramp = NdArray::<u8, 2>::from(&[0, 1, 2, 0, 1, 2, 0, 1, 2], [3, 3]);
+/// let dx = sobel(&ramp, 1);
+/// assert_eq!(dx[[1, 1]], 8.0); // slope 1, scaled by 2 for the derivative and 4 for the smoothing
+/// assert_eq!(sobel(&ramp, 0)[[1, 1]], 0.0);
+/// # }
+/// ```
+pub fn sobel<T: Copy + ToPrimitive, const N: usize>(
+    input: &NdArray<T, N>,
+    axis: usize,
+) -> NdArray<f64, N> {
+    gradient_filter(input, axis, [1.0, 2.0, 1.0])
+}
+
+/// Return the Prewitt gradient of `input` along `axis`, i.e. the derivative `[-1, 0, 1]` along `axis`, smoothed by `[1, 1, 1]` along every other axis, similar to `scipy.ndimage.prewitt`. Positions outside `input` are reflected about the edges
+///
+/// ## Panics
+/// If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::ndimage::prewitt;
+/// #
+/// # fn main() {
+/// let ramp = NdArray::<f64, 2>::from(&[0.0, 0.0, 2.0, 2.0, 4.0, 4.0], [3, 2]);
+/// assert_eq!(prewitt(&ramp, 0)[[1, 0]], 12.0); // slope 2, scaled by 2 for the derivative and 3 for the smoothing
+/// # }
+/// ```
+pub fn prewitt<T: Copy + ToPrimitive, const N: usize>(
+    input: &NdArray<T, N>,
+    axis: usize,
+) -> NdArray<f64, N> {
+    gradient_filter(input, axis, [1.0, 1.0, 1.0])
+}
+
+#[cfg(test)]
+mod ndimage_t {
+    use crate::{
+        core::{Array, Array2, Array3},
+        ndimage::{
+            binary_dilation, binary_erosion, generic_filter, grey_dilation, grey_erosion, reflect,
+            sobel,
+        },
+    };
+
+    // Test reflecting positions about the edges, also beyond a single period
+    #[test]
+    fn reflect_t() {
+        let positions: Vec<usize> = (-4..8).map(|pos| reflect(pos, 3)).collect();
+        assert_eq!(positions, vec![2, 2, 1, 0, 0, 1, 2, 2, 1, 0, 0, 1]);
+        assert_eq!(reflect(-2, 1), 0);
+    }
+
+    // Test the opening (erosion then dilation) of a 2-D binary mask, which removes the isolated pixel
+    #[test]
+    fn binary_opening_2dim_t() {
+        #[rustfmt::skip]
+        let mask = Array2::<bool>::from(&[
+            true, false, false, false, false,
+            false, true, true, true, false,
+            false, true, true, true, false,
+            false, true, true, true, false,
+        ], [4, 5]);
+        let square = Array2::<bool>::from(&[true; 9], [3, 3]);
+
+        let eroded = binary_erosion(&mask, &square);
+        assert_eq!(eroded.as_slice().iter().filter(|x| **x).count(), 1);
+        assert!(eroded[[2, 2]]);
+        let opened = binary_dilation(&eroded, &square);
+        let expected: Vec<bool> = mask
+            .as_slice()
+            .iter()
+            .enumerate()
+            .map(|(pos, x)| *x && pos != 0)
+            .collect();
+        assert_eq!(opened.as_slice(), &expected[..]);
+    }
+
+    // Test the grayscale morphology and a generic median filter of a 3-D NdArray
+    #[test]
+    fn grey_morphology_3dim_t() {
+        let values: Vec<i32> = (0..27).map(|i| if i == 13 { 100 } else { i }).collect();
+        let volume = Array3::<i32>::from(&values, [3, 3, 3]);
+        let cube = Array3::<bool>::from(&[true; 27], [3, 3, 3]);
+
+        assert_eq!(grey_erosion(&volume, &cube)[[1, 1, 1]], 0);
+        assert_eq!(grey_dilation(&volume, &cube).as_slice(), &[100; 27]);
+        let median = generic_filter(&volume, &cube, |values| {
+            let mut sorted: Vec<i32> = values.to_vec();
+            sorted.sort_unstable();
+            sorted[sorted.len() / 2]
+        });
+        assert_eq!(median[[1, 1, 1]], 14); // the outlier at the centre is replaced
+    }
+
+    // Test the Sobel gradient of a 1-D signal, reflected at the edges
+    #[test]
+    fn sobel_1dim_t() {
+        let signal = Array::<f64>::from(&[0.0, 1.0, 4.0, 9.0], [4]);
+        assert_eq!(sobel(&signal, 0).as_slice(), &[1.0, 4.0, 8.0, 5.0]);
+    }
+
+    // Test the filters on an empty NdArray created using NdArray::<T, N>::new()
+    #[test]
+    fn new_input_t() {
+        let footprint = Array2::<bool>::from(&[true; 4], [2, 2]);
+        assert!(grey_erosion(&Array2::<u8>::new(), &footprint).is_empty());
+        assert!(binary_dilation(&Array2::<bool>::new(), &footprint).is_empty());
+        assert!(sobel(&Array2::<f32>::new(), 1).is_empty());
+    }
+
+    // Test a footprint without selected positions
+    #[test]
+    #[should_panic(expected = "Footprint cannot be empty")]
+    fn empty_footprint_t() {
+        let footprint = Array::<bool>::from(&[false, false, false], [3]);
+        grey_erosion(&Array::<u8>::from(&[1, 2], [2]), &footprint);
+    }
+}
@@ -0,0 +1,409 @@
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+
+use crate::{
+    core::{NdArray, SizedArray},
+    math::{broadcast_shape, broadcast_with, expand_shape},
+    zip::Zip,
+};
+
+/// Implement the element-wise arithmetic (or bitwise) operator `$trait::$method` of two NdArrays (with broadcasting, also of a right operand of lower dimension), borrowed or consumed, and of an NdArray and a scalar. A consumed operand's buffer is reused if it has the broadcast shape
+macro_rules! impl_arith_op {
+    ($($trait:ident, $method:ident, $op:tt);* $(;)?) => {
+        $(
+            impl<T: Copy + $trait<Output = T>, const N: usize, const M: usize> $trait<&NdArray<T, M>> for &NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(self, rhs: &NdArray<T, M>) -> Self::Output {
+                    broadcast_with(self, rhs, |x, y| x $op y)
+                }
+            }
+
+            impl<T: Copy + $trait<Output = T>, const N: usize, const M: usize> $trait<&NdArray<T, M>> for NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(mut self, rhs: &NdArray<T, M>) -> Self::Output {
+                    let shape = self.view_shape();
+                    if broadcast_shape(&shape, &expand_shape(&rhs.view_shape())) != shape {
+                        return broadcast_with(&self, rhs, |x, y| x $op y);
+                    }
+
+                    Zip::from(&mut self)
+                        .and_broadcast(rhs)
+                        .for_each(|x, y| *x = *x $op *y);
+                    self
+                }
+            }
+
+            impl<T: Copy + $trait<Output = T>, const N: usize, const M: usize> $trait<NdArray<T, M>> for &NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(self, rhs: NdArray<T, M>) -> Self::Output {
+                    let shape: SizedArray<N> = expand_shape(&rhs.view_shape());
+                    if broadcast_shape(&self.view_shape(), &shape) != shape {
+                        return broadcast_with(self, &rhs, |x, y| x $op y);
+                    }
+
+                    // the buffer of `rhs` in row-major order is also the buffer of the broadcast shape
+                    let mut rhs: NdArray<T, N> = NdArray::from_owned_vec(rhs.into_vec(), shape);
+                    Zip::from(&mut rhs)
+                        .and(self.broadcast_to(shape))
+                        .for_each(|y, x| *y = *x $op *y);
+                    rhs
+                }
+            }
+
+            impl<T: Copy + $trait<Output = T>, const N: usize, const M: usize> $trait<NdArray<T, M>> for NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(self, rhs: NdArray<T, M>) -> Self::Output {
+                    self $op &rhs
+                }
+            }
+
+            impl<T: Copy + $trait<Output = T>, const N: usize> $trait<T> for &NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.map(|x| *x $op rhs)
+                }
+            }
+
+            impl<T: Copy + $trait<Output = T>, const N: usize> $trait<T> for NdArray<T, N> {
+                type Output = NdArray<T, N>;
+
+                fn $method(mut self, rhs: T) -> Self::Output {
+                    self.map_inplace(|x| *x = *x $op rhs);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+// `&a + &b`, `a + &b`, `&a + b`, `a + b` and `a + scalar`, element-wise, for numeric arrays. The operands of two arrays are broadcast together, where `b` may have fewer dimensions than `a`, e.g. `[3, 1, 5] + [4, 5]` is of shape `[3, 4, 5]`
+//
+// ## Panics
+// - If `b` has more dimensions than `a`, panics; message shown is **Dimension(`a`) must not be less than Dimension(`b`)**
+// - If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+impl_arith_op!(
+    Add, add, +;
+    Sub, sub, -;
+    Mul, mul, *;
+    Div, div, /;
+    Rem, rem, %;
+);
+
+/// Implement the element-wise arithmetic operators with a scalar of type `$t` on the left, i.e., `scalar + &a` and `scalar + a`. Implemented for each primitive type, as a generic scalar cannot be on the left of a foreign trait
+macro_rules! impl_scalar_lhs_op {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl_scalar_lhs_op!(@op $t, Add, add, +);
+            impl_scalar_lhs_op!(@op $t, Sub, sub, -);
+            impl_scalar_lhs_op!(@op $t, Mul, mul, *);
+            impl_scalar_lhs_op!(@op $t, Div, div, /);
+            impl_scalar_lhs_op!(@op $t, Rem, rem, %);
+        )*
+    };
+    (@op $t:ty, $trait:ident, $method:ident, $op:tt) => {
+        impl<const N: usize> $trait<&NdArray<$t, N>> for $t {
+            type Output = NdArray<$t, N>;
+
+            fn $method(self, rhs: &NdArray<$t, N>) -> Self::Output {
+                rhs.map(|x| self $op *x)
+            }
+        }
+
+        impl<const N: usize> $trait<NdArray<$t, N>> for $t {
+            type Output = NdArray<$t, N>;
+
+            // the scalar stays on the left for the non-commutative operators
+            #[allow(clippy::assign_op_pattern)]
+            fn $method(self, mut rhs: NdArray<$t, N>) -> Self::Output {
+                rhs.map_inplace(|x| *x = self $op *x);
+                rhs
+            }
+        }
+    };
+}
+
+// `scalar + &a` and `scalar + a`, element-wise, for the primitive numeric types
+impl_scalar_lhs_op!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Implement the compound assignment operator `$trait::$method` of two NdArrays (also of a right operand of lower dimension), i.e., `a += &b` and `a += b`, and of an NdArray and a scalar, i.e., `a += scalar`, in-place
+macro_rules! impl_assign_op {
+    ($($trait:ident, $method:ident, $op:tt);* $(;)?) => {
+        $(
+            impl<T: Copy + $trait, const N: usize, const M: usize> $trait<&NdArray<T, M>> for NdArray<T, N> {
+                fn $method(&mut self, rhs: &NdArray<T, M>) {
+                    Zip::from(self).and_broadcast(rhs).for_each(|x, y| *x $op *y);
+                }
+            }
+
+            impl<T: Copy + $trait, const N: usize, const M: usize> $trait<NdArray<T, M>> for NdArray<T, N> {
+                fn $method(&mut self, rhs: NdArray<T, M>) {
+                    *self $op &rhs;
+                }
+            }
+
+            impl<T: Copy + $trait, const N: usize> $trait<T> for NdArray<T, N> {
+                fn $method(&mut self, rhs: T) {
+                    self.map_inplace(|x| *x $op rhs);
+                }
+            }
+        )*
+    };
+}
+
+// `a += &b`, `a += b` and `a += scalar`, element-wise and in-place, keeping the buffer of `a`. The array `b` is broadcast to the shape of `a`, and may have fewer dimensions
+//
+// ## Panics
+// - If `b` has more dimensions than `a`, panics; message shown is **Dimension(`a`) must not be less than Dimension(`b`)**
+// - If `b` cannot be broadcast to the shape of `a`, panics; message shown is **Shape(`b`) cannot be broadcast to Shape(`a`)**
+impl_assign_op!(
+    AddAssign, add_assign, +=;
+    SubAssign, sub_assign, -=;
+    MulAssign, mul_assign, *=;
+    DivAssign, div_assign, /=;
+    RemAssign, rem_assign, %=;
+);
+
+// `-&a`, element-wise, into a new NdArray
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for &NdArray<T, N> {
+    type Output = NdArray<T, N>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -*x)
+    }
+}
+
+// `-a`, element-wise, reusing the buffer of `a`
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for NdArray<T, N> {
+    type Output = NdArray<T, N>;
+
+    fn neg(mut self) -> Self::Output {
+        self.map_inplace(|x| *x = -*x);
+        self
+    }
+}
+
+// `&a & &b`, `a & &b`, `&a & b`, `a & b` and `a & scalar`, element-wise, for integer (and bool) arrays. The operands of two arrays are broadcast together, where `b` may have fewer dimensions than `a`
+//
+// ## Panics
+// - If `b` has more dimensions than `a`, panics; message shown is **Dimension(`a`) must not be less than Dimension(`b`)**
+// - If the shapes cannot be broadcast, panics; message shown is **Shape(`a`) cannot be broadcast with Shape(`b`)**
+impl_arith_op!(
+    BitAnd, bitand, &;
+    BitOr, bitor, |;
+    BitXor, bitxor, ^;
+    Shl, shl, <<;
+    Shr, shr, >>;
+);
+
+// `a &= &b`, `a &= b` and `a &= scalar`, element-wise and in-place, keeping the buffer of `a`. The array `b` is broadcast to the shape of `a`, and may have fewer dimensions
+//
+// ## Panics
+// - If `b` has more dimensions than `a`, panics; message shown is **Dimension(`a`) must not be less than Dimension(`b`)**
+// - If `b` cannot be broadcast to the shape of `a`, panics; message shown is **Shape(`b`) cannot be broadcast to Shape(`a`)**
+impl_assign_op!(
+    BitAndAssign, bitand_assign, &=;
+    BitOrAssign, bitor_assign, |=;
+    BitXorAssign, bitxor_assign, ^=;
+    ShlAssign, shl_assign, <<=;
+    ShrAssign, shr_assign, >>=;
+);
+
+#[cfg(test)]
+mod ops_t {
+    use crate::core::{Array, Array2, Array3, NdArray};
+
+    // Test negating borrowed, consumed and empty 3-D NdArrays
+    #[test]
+    fn neg_3dim_t() {
+        let a = Array3::<i32>::from_shape_fn([2, 3, 4], |[i, j, k]| (i + j) as i32 - k as i32);
+        let b: Array3<i32> = -&a;
+        assert_eq!(b[[1, 2, 0]], -3);
+        assert_eq!(b[[0, 0, 3]], 3);
+        assert_eq!(-b, a);
+
+        let ptr: *const i32 = a.as_slice().as_ptr();
+        let c: Array3<i32> = -a;
+        assert_eq!(c.as_slice().as_ptr(), ptr);
+        assert_eq!(c.signum()[[0, 0, 0]], 0);
+        assert_eq!(c.signum()[[1, 2, 0]], -1);
+        assert!((-&Array3::<f32>::new()).is_empty());
+    }
+
+    // Test packing and unpacking flag fields of a 2-D NdArray with scalars
+    #[test]
+    fn flags_scalar_2dim_t() {
+        let flags = Array2::<u8>::from(&[0b0001, 0b0110, 0b1011, 0b1000], [2, 2]);
+
+        assert_eq!((&flags & 0b0010).as_slice(), &[0, 2, 2, 0]);
+        assert_eq!((&flags | 0b0001).as_slice(), &[1, 7, 11, 9]);
+        assert_eq!((&flags ^ 0b1111).as_slice(), &[14, 9, 4, 7]);
+        assert_eq!((&flags << 4).as_slice(), &[16, 96, 176, 128]);
+        assert_eq!((&(&flags << 4) >> 4).as_slice(), flags.as_slice());
+    }
+
+    // Test the operators of two NdArrays with broadcasting
+    #[test]
+    fn array_array_t() {
+        let a = Array2::<i32>::from(&[12, 10, -1, 7], [2, 2]);
+        let mask = Array2::<i32>::from(&[0b1000, 0b0010], [1, 2]);
+        let shifts = Array2::<i32>::from(&[1, 2], [2, 1]);
+
+        assert_eq!((&a & &mask).as_slice(), &[8, 2, 8, 2]);
+        assert_eq!((&a ^ &a).as_slice(), &[0; 4]);
+        assert_eq!((&a << &shifts).as_slice(), &[24, 20, -4, 28]);
+        assert_eq!((&a >> &shifts).as_slice(), &[6, 5, -1, 1]);
+
+        let b = Array::<bool>::from(&[true, false], [2]);
+        assert_eq!((&b | false).as_slice(), &[true, false]);
+    }
+
+    // Test the consuming and in-place bitwise operators of 3-D NdArrays, e.g. packing a field into flag words
+    // Check if the buffer on the left is kept
+    #[test]
+    fn bitwise_assign_3dim_t() {
+        let mut flags =
+            Array3::<u16>::from_shape_fn([2, 3, 4], |[i, j, k]| (i * 12 + j * 4 + k) as u16);
+        let low = Array3::<u16>::from_shape_fn([1, 1, 4], |[_, _, k]| 1 << k);
+        let ptr: *const u16 = flags.as_slice().as_ptr();
+
+        flags <<= 4;
+        flags |= &low;
+        flags ^= low.clone();
+        flags &= 0xff0;
+        assert_eq!(flags.as_slice().as_ptr(), ptr);
+        assert_eq!(flags[[1, 2, 3]], 23 << 4);
+        flags >>= &Array3::<u16>::from(&[4], [1, 1, 1]);
+        assert_eq!(flags[[1, 2, 3]], 23);
+
+        let masked = flags & &low;
+        assert_eq!(masked.as_slice().as_ptr(), ptr);
+        assert_eq!(masked[[0, 0, 1]], 0);
+        assert_eq!(masked[[0, 0, 3]], 0);
+        assert_eq!(masked[[0, 1, 2]], 4);
+        assert_eq!((masked ^ 0xffff)[[0, 1, 2]], 0xfffb);
+    }
+
+    // Test the arithmetic operators of borrowed and consumed 3-D NdArrays, with and without broadcasting
+    // Check if the consumed operand keeps its order for the non-commutative operators
+    #[test]
+    fn arith_3dim_t() {
+        let a = Array3::<i64>::from_shape_fn([2, 3, 4], |[i, j, k]| (100 * i + 10 * j + k) as i64);
+        let b = Array3::<i64>::from_shape_fn([2, 3, 4], |[i, j, _]| (i + j + 1) as i64);
+        let row = Array3::<i64>::from_shape_fn([1, 1, 4], |[_, _, k]| k as i64 + 1);
+
+        assert_eq!((&a + &b)[[1, 2, 3]], 127);
+        assert_eq!((&a - &b)[[1, 2, 3]], 119);
+        assert_eq!((&a * &row)[[1, 2, 3]], 492);
+        assert_eq!((&a / &b)[[1, 2, 3]], 30);
+        assert_eq!((&a % &b)[[1, 2, 3]], 3);
+
+        assert_eq!(a.clone() - &b, &a - &b);
+        assert_eq!(&a - b.clone(), &a - &b);
+        assert_eq!(a.clone() / b.clone(), &a / &b);
+        assert_eq!(&row - a.clone(), &row - &a); // the buffer of `a` is reused
+        assert_eq!(row.clone() - &a, &row - &a); // `row` is stretched, so a new buffer is used
+        assert_eq!(*(row.clone() % b.clone()).shape(), [2, 3, 4]);
+
+        assert_eq!((&a * 2)[[1, 2, 3]], 246);
+        assert_eq!((a.clone() - 100)[[1, 2, 3]], 23);
+        let empty = NdArray::<f32, 3>::new();
+        assert!((empty.clone() + &empty).is_empty());
+    }
+
+    // Test the arithmetic operators of scalars on either side of a 2-D NdArray, and in-place
+    #[test]
+    fn scalar_2dim_t() {
+        let a = Array2::<f64>::from(&[1.0, 2.0, 4.0, 8.0], [2, 2]);
+        assert_eq!((2.0 * &a).as_slice(), &[2.0, 4.0, 8.0, 16.0]);
+        assert_eq!((1.0 / &a).as_slice(), &[1.0, 0.5, 0.25, 0.125]);
+        assert_eq!((10.0 - a.clone()).as_slice(), &[9.0, 8.0, 6.0, 2.0]);
+        assert_eq!(&a + 5.0, 5.0 + &a);
+
+        let mut b = Array2::<u8>::from(&[3, 5, 7, 9], [2, 2]);
+        assert_eq!((10 % &b).as_slice(), &[1, 0, 3, 1]);
+        b += 1;
+        b *= 2;
+        b -= 4;
+        b /= 3;
+        b %= 3;
+        assert_eq!(b.as_slice(), &[1, 2, 1, 2]);
+    }
+
+    // Test the compound assignment operators of 3-D NdArrays, e.g. a gradient update
+    // Check if the array on the right is broadcast and the buffer on the left is kept
+    #[test]
+    fn assign_3dim_t() {
+        let mut weights = Array3::<f32>::from_shape_fn([2, 3, 4], |[i, j, k]| (i + j + k) as f32);
+        let grads = Array3::<f32>::ones([2, 3, 4]);
+        let ptr: *const f32 = weights.as_slice().as_ptr();
+        for _ in 0..4 {
+            weights -= &(&grads * 0.5);
+        }
+        assert_eq!(weights[[1, 2, 3]], 4.0);
+        assert_eq!(weights.as_slice().as_ptr(), ptr);
+
+        let scale = Array3::<f32>::from_shape_fn([1, 3, 1], |[_, j, _]| j as f32);
+        weights *= &scale;
+        weights += grads.clone();
+        weights /= grads;
+        assert_eq!(weights[[1, 2, 3]], 9.0);
+        assert_eq!(weights[[1, 0, 3]], 1.0);
+    }
+
+    // Test the operators of a 3-D NdArray and NdArrays of lower dimension, e.g. `[3, 1, 5] + [4, 5]`
+    // Check if the trailing axes are aligned, and a consumed operand's buffer is reused if it has the broadcast shape
+    #[test]
+    fn lower_dim_t() {
+        let a = Array3::<i32>::from_shape_fn([3, 1, 5], |[i, _, k]| (100 * i + k) as i32);
+        let b = Array2::<i32>::from_shape_fn([4, 5], |[j, k]| (10 * j * k) as i32);
+        let sum = &a + &b;
+        assert_eq!(sum.shape(), &[3, 4, 5]);
+        assert_eq!(sum[[2, 3, 4]], 200 + 4 + 120);
+        assert_eq!(a.clone() + &b, sum);
+        assert_eq!(&a + b.clone(), sum);
+        assert_eq!(a.clone() - b.clone(), &a - &b);
+
+        let rows = Array3::<i32>::from_shape_fn([1, 4, 5], |[_, j, k]| (j + k) as i32);
+        let ptr: *const i32 = b.as_slice().as_ptr();
+        let diff = &rows - b;
+        assert_eq!(diff.as_slice().as_ptr(), ptr);
+        assert_eq!(diff[[0, 3, 2]], 5 - 60);
+
+        let mut c = Array3::<i32>::zeros([3, 4, 5]);
+        c += &Array::<i32>::from(&[1, 2, 3, 4, 5], [5]);
+        c *= Array2::<i32>::from(&[1, 2, 3, 4], [4, 1]);
+        assert_eq!(c[[1, 3, 4]], 20);
+        assert_eq!((&c & &Array::<i32>::from(&[1], [1]))[[1, 3, 4]], 0);
+    }
+
+    // Test an operator with an array of higher dimension on the right
+    #[test]
+    #[should_panic(expected = "Dimension(1) must not be less than Dimension(2)")]
+    fn higher_dim_rhs_t() {
+        let _ = &Array::<u8>::zeros([2]) + &Array2::<u8>::zeros([2, 2]);
+    }
+
+    // Test a compound assignment with an array which cannot be broadcast to the left shape
+    #[test]
+    #[should_panic(expected = "Shape([2, 2]) cannot be broadcast to Shape([1, 2])")]
+    fn assign_wrong_shape_t() {
+        let mut a = Array2::<u8>::zeros([1, 2]);
+        a += &Array2::<u8>::zeros([2, 2]);
+    }
+
+    // Test the operators of two NdArrays whose shapes cannot be broadcast
+    #[test]
+    #[should_panic(expected = "Shape([2]) cannot be broadcast with Shape([3])")]
+    fn shape_mismatch_t() {
+        let a = Array::<u16>::from(&[1, 2], [2]);
+        let b = Array::<u16>::from(&[1, 2, 3], [3]);
+        let _ = &a | &b;
+    }
+}
@@ -0,0 +1,410 @@
+use std::fmt::Debug;
+
+use num_traits::Float;
+use rand::{
+    distr::{uniform::SampleUniform, Distribution, StandardUniform},
+    seq::{index, SliceRandom},
+    Rng,
+};
+
+use crate::core::{NdArray, SizedArray};
+
+/// Create an NdArray of the given `shape` with samples of the uniform distribution over `[0, 1)` drawn from `rng`
+fn uniform<T, R: Rng + ?Sized, const N: usize>(shape: SizedArray<N>, rng: &mut R) -> NdArray<T, N>
+where
+    T: Float,
+    StandardUniform: Distribution<T>,
+{
+    NdArray::from_shape_fn(shape, |_| rng.random::<T>())
+}
+
+/// Create an NdArray of the given `shape` with samples of the standard normal distribution drawn from `rng`, using the Box-Muller transform
+fn normal<T, R: Rng + ?Sized, const N: usize>(shape: SizedArray<N>, rng: &mut R) -> NdArray<T, N>
+where
+    T: Float,
+    StandardUniform: Distribution<T>,
+{
+    let tau: T = T::from(std::f64::consts::TAU).unwrap();
+    let mut spare: Option<T> = None;
+    NdArray::from_shape_fn(shape, |_| match spare.take() {
+        Some(value) => value,
+        None => {
+            // 1 - u lies in (0, 1], hence the logarithm is finite
+            let radius: T = (-(T::one() - rng.random::<T>()).ln() * (T::one() + T::one())).sqrt();
+            let angle: T = tau * rng.random::<T>();
+            spare = Some(radius * angle.sin());
+            radius * angle.cos()
+        }
+    })
+}
+
+impl<T: Float, const N: usize> NdArray<T, N>
+where
+    StandardUniform: Distribution<T>,
+{
+    /// Create an NdArray of the given `shape` with samples of the uniform distribution over `[0, 1)`, drawn from the thread-local generator of `rand`
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 2>::random([3, 4]);
+    /// assert!(arr.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+    /// # }
+    /// ```
+    pub fn random(shape: SizedArray<N>) -> Self {
+        uniform(shape, &mut rand::rng())
+    }
+
+    /// Create an NdArray of the given `shape` with samples of the standard normal distribution (mean 0, variance 1), drawn from the thread-local generator of `rand`
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::randn([10_000]);
+    /// let mean: f64 = arr.as_slice().iter().sum::<f64>() / 10_000.0;
+    /// assert!(mean.abs() < 0.1);
+    /// # }
+    /// ```
+    pub fn randn(shape: SizedArray<N>) -> Self {
+        normal(shape, &mut rand::rng())
+    }
+}
+
+impl<T: SampleUniform + PartialOrd + Copy + Debug, const N: usize> NdArray<T, N> {
+    /// Create an NdArray of the given `shape` with integers (or any type supporting uniform sampling) drawn uniformly from `low..high` using `rng`. Use a seeded generator, e.g. `StdRng::seed_from_u64(...)`, for reproducible results
+    ///
+    /// ## Panics
+    /// - If `low` is not less than `high`, panics; message shown is **Low(`low`) must be less than High(`high`)**
+    /// - If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// # fn main() {
+    /// let mut rng = StdRng::seed_from_u64(3);
+    /// let dice = NdArray::<u8, 2>::randint(1, 7, [4, 5], &mut rng);
+    /// assert!(dice.as_slice().iter().all(|x| (1..=6).contains(x)));
+    /// # }
+    /// ```
+    pub fn randint<R: Rng + ?Sized>(low: T, high: T, shape: SizedArray<N>, rng: &mut R) -> Self {
+        if low >= high {
+            panic!("Low({:?}) must be less than High({:?})", low, high);
+        }
+
+        NdArray::from_shape_fn(shape, |_| rng.random_range(low..high))
+    }
+}
+
+impl<T: Clone> NdArray<T, 1> {
+    /// Create a 1-D NdArray of `n` elements chosen at random from `arr` using `rng`. Elements may be chosen more than once if `replace` is true, otherwise every position of `arr` is chosen at most once
+    ///
+    /// ## Panics
+    /// - If `replace` is false and `n` exceeds the length of `arr`, panics; message shown is **Sample Size(`n`) exceeds Length(`len`) without replacement**
+    /// - If `arr` is empty and `n` is not zero, panics; message shown is **Cannot choose from an empty array**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// # fn main() {
+    /// let mut rng = StdRng::seed_from_u64(11);
+    /// let cards = NdArray::<u32, 1>::arange(52);
+    /// let hand = NdArray::choice(&cards, 5, false, &mut rng);
+    /// let mut values = hand.as_slice().to_vec();
+    /// values.sort();
+    /// values.dedup();
+    /// assert_eq!(values.len(), 5);
+    /// # }
+    /// ```
+    pub fn choice<R: Rng + ?Sized>(
+        arr: &NdArray<T, 1>,
+        n: usize,
+        replace: bool,
+        rng: &mut R,
+    ) -> Self {
+        let values: &[T] = arr.as_slice();
+        if values.is_empty() && n > 0 {
+            panic!("Cannot choose from an empty array");
+        }
+        if !replace && n > values.len() {
+            panic!(
+                "Sample Size({}) exceeds Length({}) without replacement",
+                n,
+                values.len()
+            );
+        }
+
+        let chosen: Vec<T> = if replace {
+            (0..n)
+                .map(|_| values[rng.random_range(0..values.len())].clone())
+                .collect()
+        } else {
+            index::sample(rng, values.len(), n)
+                .into_iter()
+                .map(|pos| values[pos].clone())
+                .collect()
+        };
+        NdArray::from_vec(chosen, [n])
+    }
+}
+
+impl NdArray<usize, 1> {
+    /// Create a 1-D NdArray holding a random permutation of `0..n` using `rng`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// # fn main() {
+    /// let mut rng = StdRng::seed_from_u64(5);
+    /// let perm = NdArray::permutation(6, &mut rng);
+    /// let mut values = perm.as_slice().to_vec();
+    /// values.sort();
+    /// assert_eq!(values, vec![0, 1, 2, 3, 4, 5]);
+    /// # }
+    /// ```
+    pub fn permutation<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        let mut values: Vec<usize> = (0..n).collect();
+        values.shuffle(rng);
+
+        NdArray::from_vec(values, [n])
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Create an NdArray of the given `shape` with samples of `dist` drawn from `rng`. Accepts any distribution implementing `rand::distr::Distribution`, including those of the `rand_distr` crate (e.g. Poisson, Exponential, Beta)
+    ///
+    /// ## Panics
+    /// If the size derived from shape, or the number of bytes of the elements overflows `isize::MAX`, panics; message shown is **Shape(`shape`) exceeds the maximum Size(`max`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use rand::{distr::Uniform, rngs::StdRng, SeedableRng};
+    ///
+    /// # fn main() {
+    /// let dist = Uniform::new(-1.0, 1.0).unwrap();
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let arr = NdArray::<f32, 3>::sample([2, 3, 4], &dist, &mut rng);
+    /// assert!(arr.as_slice().iter().all(|x| (-1.0..1.0).contains(x)));
+    /// # }
+    /// ```
+    pub fn sample<D: Distribution<T> + ?Sized, R: Rng + ?Sized>(
+        shape: SizedArray<N>,
+        dist: &D,
+        rng: &mut R,
+    ) -> Self {
+        NdArray::from_shape_fn(shape, |_| dist.sample(rng))
+    }
+
+    /// Shuffle the subarrays along `axis` in place using `rng`, e.g. the rows of a 2-D array with `axis` 0. The order of the elements within each subarray is kept
+    ///
+    /// ## Panics
+    /// If `axis` is out of bounds, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// # fn main() {
+    /// let mut rng = StdRng::seed_from_u64(8);
+    /// let mut arr = NdArray::<i32, 2>::from(&[1, 1, 2, 2, 3, 3], [3, 2]);
+    /// arr.shuffle(0, &mut rng);
+    /// for row in 0..3 {
+    ///     assert_eq!(arr[[row, 0]], arr[[row, 1]]);
+    /// }
+    /// # }
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, axis: usize, rng: &mut R) {
+        if axis >= N {
+            panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+        }
+        if *self.len() == 0 {
+            return;
+        }
+
+        let shape: SizedArray<N> = *self.shape();
+        let len: usize = shape[axis];
+        let outer: usize = shape[..axis].iter().product();
+        let inner: usize = shape[axis + 1..].iter().product();
+        let values: &mut [T] = self.as_mut_slice();
+        // Fisher-Yates over the positions of the axis, swapping whole subarrays
+        for pos in (1..len).rev() {
+            let other: usize = rng.random_range(0..=pos);
+            if other == pos {
+                continue;
+            }
+            for block in 0..outer {
+                let base: usize = block * len * inner;
+                for idx in 0..inner {
+                    values.swap(base + pos * inner + idx, base + other * inner + idx);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod random_t {
+    use rand::{rngs::StdRng, SeedableRng};
+    use rand_distr::{Beta, Exp, Poisson};
+
+    use crate::core::{Array3, NdArray};
+    use crate::random::{normal, uniform};
+
+    // Test the range of uniform samples of a 3-D NdArray
+    #[test]
+    fn random_3dim_t() {
+        let data = NdArray::<f32, 3>::random([4, 5, 6]);
+        assert_eq!(*data.shape(), [4, 5, 6]);
+        assert!(data.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let first: NdArray<f64, 2> = uniform([2, 3], &mut rng);
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(first, uniform([2, 3], &mut rng));
+    }
+
+    // Test the mean and variance of standard normal samples, with an odd number of elements
+    #[test]
+    fn randn_t() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let data: NdArray<f64, 1> = normal([20_001], &mut rng);
+        let len: f64 = 20_001.0;
+        let mean: f64 = data.as_slice().iter().sum::<f64>() / len;
+        let var: f64 = data
+            .as_slice()
+            .iter()
+            .map(|x| (x - mean).powi(2))
+            .sum::<f64>()
+            / len;
+        assert!(mean.abs() < 0.05);
+        assert!((var - 1.0).abs() < 0.05);
+        assert!(data.as_slice().iter().all(|x| x.is_finite()));
+    }
+
+    // Test randint(...), choice(...) and permutation(...) are reproducible with a fixed seed
+    #[test]
+    fn seeded_t() {
+        let sample = |seed: u64| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let ints = NdArray::<i64, 2>::randint(-3, 3, [4, 4], &mut rng);
+            let pool = NdArray::<i64, 1>::arange(10);
+            let with = NdArray::choice(&pool, 20, true, &mut rng);
+            let without = NdArray::choice(&pool, 10, false, &mut rng);
+            (ints, with, without, NdArray::permutation(8, &mut rng))
+        };
+        let (ints, with, without, perm) = sample(2024);
+        assert_eq!(
+            sample(2024),
+            (ints.clone(), with.clone(), without.clone(), perm)
+        );
+        assert!(ints.as_slice().iter().all(|x| (-3..3).contains(x)));
+        assert_eq!(*with.shape(), [20]);
+
+        let mut values: Vec<i64> = without.as_slice().to_vec();
+        values.sort();
+        assert_eq!(values, (0..10).collect::<Vec<i64>>());
+    }
+
+    // Test sample(...) with distributions of the rand_distr crate and a fixed seed
+    // Check the shape, the range of the samples, and the mean of the Poisson samples
+    #[test]
+    fn sample_rand_distr_t() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let counts = NdArray::<f64, 2>::sample([100, 100], &Poisson::new(4.0).unwrap(), &mut rng);
+        assert_eq!(*counts.shape(), [100, 100]);
+        assert!(counts
+            .as_slice()
+            .iter()
+            .all(|x| *x >= 0.0 && x.fract() == 0.0));
+        let mean: f64 = counts.as_slice().iter().sum::<f64>() / 10_000.0;
+        assert!((mean - 4.0).abs() < 0.1);
+
+        let waits = NdArray::<f32, 3>::sample([2, 3, 4], &Exp::new(0.5).unwrap(), &mut rng);
+        assert_eq!(*waits.shape(), [2, 3, 4]);
+        assert!(waits.as_slice().iter().all(|x| *x >= 0.0));
+
+        let ratios = NdArray::<f64, 1>::sample([50], &Beta::new(2.0, 5.0).unwrap(), &mut rng);
+        assert!(ratios.as_slice().iter().all(|x| (0.0..=1.0).contains(x)));
+        let mut rng = StdRng::seed_from_u64(7);
+        let again = NdArray::<f64, 2>::sample([100, 100], &Poisson::new(4.0).unwrap(), &mut rng);
+        assert_eq!(again, counts);
+    }
+
+    // Test shuffling a 3-D NdArray along the middle axis keeps the other axes together
+    #[test]
+    fn shuffle_3dim_t() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut data = Array3::<usize>::from_shape_fn([2, 5, 3], |[i, j, k]| 100 * i + 10 * j + k);
+        data.shuffle(1, &mut rng);
+
+        let mut rows: Vec<usize> = (0..5).map(|j| data[[0, j, 0]] / 10).collect();
+        for j in 0..5 {
+            let row: usize = data[[0, j, 0]] / 10;
+            for i in 0..2 {
+                for k in 0..3 {
+                    assert_eq!(data[[i, j, k]], 100 * i + 10 * row + k);
+                }
+            }
+        }
+        rows.sort();
+        assert_eq!(rows, vec![0, 1, 2, 3, 4]);
+    }
+
+    // Test choosing more elements than the array holds without replacement
+    #[test]
+    #[should_panic(expected = "Sample Size(4) exceeds Length(3) without replacement")]
+    fn choice_without_replace_t() {
+        let mut rng = StdRng::seed_from_u64(0);
+        NdArray::choice(&NdArray::<u8, 1>::arange(3), 4, false, &mut rng);
+    }
+
+    // Test sampling a 2-D NdArray from a seeded generator
+    // Check if equal seeds give equal arrays, and if the mean of the samples is close to the mean of the distribution
+    #[test]
+    fn sample_2dim_t() {
+        let dist = rand::distr::Uniform::new(2.0, 4.0).unwrap();
+        let data = NdArray::<f64, 2>::sample([100, 200], &dist, &mut StdRng::seed_from_u64(3));
+        assert_eq!(*data.shape(), [100, 200]);
+        assert_eq!(
+            data,
+            NdArray::<f64, 2>::sample([100, 200], &dist, &mut StdRng::seed_from_u64(3))
+        );
+        assert_ne!(
+            data,
+            NdArray::<f64, 2>::sample([100, 200], &dist, &mut StdRng::seed_from_u64(4))
+        );
+
+        let mean: f64 = data.as_slice().iter().sum::<f64>() / 20_000.0;
+        assert!((mean - 3.0).abs() < 0.02);
+        assert!(data.as_slice().iter().all(|x| (2.0..4.0).contains(x)));
+
+        let coin = rand::distr::Bernoulli::new(0.25).unwrap();
+        let flips = NdArray::<bool, 2>::sample([100, 200], &coin, &mut StdRng::seed_from_u64(5));
+        let heads: usize = flips.as_slice().iter().filter(|x| **x).count();
+        assert!((heads as f64 / 20_000.0 - 0.25).abs() < 0.02);
+    }
+}
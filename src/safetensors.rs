@@ -0,0 +1,453 @@
+use std::{borrow::Cow, collections::HashMap, fmt, fs::File, io, path::Path};
+
+use memmap2::Mmap;
+use safetensors::{tensor::TensorInfo, Dtype, SafeTensorError, SafeTensors, View};
+
+use crate::{
+    core::{NdArray, SizedArray},
+    view::ArrayView,
+};
+
+/// Error while saving or loading NdArrays in the safetensors format
+#[derive(Debug)]
+pub enum SafetensorsError {
+    /// The file could not be opened or mapped
+    Io(io::Error),
+    /// The file is not a valid safetensors file
+    Format(SafeTensorError),
+    /// No tensor with the given name exists in the file
+    Missing(String),
+    /// The dtype of the tensor does not match the element type of the NdArray
+    Dtype {
+        name: String,
+        expected: Dtype,
+        found: Dtype,
+    },
+    /// The dimension of the tensor does not match the dimension of the NdArray
+    Dimension {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    /// The data of the tensor cannot be borrowed without a copy, as it is not aligned for the element type (or the host is big-endian)
+    Misaligned(String),
+}
+
+impl fmt::Display for SafetensorsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafetensorsError::Io(err) => write!(f, "{}", err),
+            SafetensorsError::Format(err) => write!(f, "{}", err),
+            SafetensorsError::Missing(name) => write!(f, "Tensor({}) not found", name),
+            SafetensorsError::Dtype {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Tensor({}) has Dtype({}), expected Dtype({})",
+                name, found, expected
+            ),
+            SafetensorsError::Dimension {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Tensor({}) has Dimension({}), expected Dimension({})",
+                name, found, expected
+            ),
+            SafetensorsError::Misaligned(name) => {
+                write!(f, "Tensor({}) cannot be borrowed without a copy", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SafetensorsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SafetensorsError::Io(err) => Some(err),
+            SafetensorsError::Format(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SafetensorsError {
+    fn from(err: io::Error) -> Self {
+        SafetensorsError::Io(err)
+    }
+}
+
+impl From<SafeTensorError> for SafetensorsError {
+    fn from(err: SafeTensorError) -> Self {
+        SafetensorsError::Format(err)
+    }
+}
+
+/// Element types which can be stored in the safetensors format. Values are stored in little-endian byte order
+pub trait SafetensorsElement: Copy {
+    /// The safetensors dtype of the element type
+    const DTYPE: Dtype;
+
+    /// Append the little-endian bytes of the value to `bytes`
+    fn write_le(self, bytes: &mut Vec<u8>);
+
+    /// Read a value from its little-endian `bytes`
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+/// Implement `SafetensorsElement` for every primitive `$t` with the dtype `$dtype`
+macro_rules! impl_safetensors_element {
+    ($($t:ty => $dtype:ident),*) => {
+        $(
+            impl SafetensorsElement for $t {
+                const DTYPE: Dtype = Dtype::$dtype;
+
+                fn write_le(self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    <$t>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_safetensors_element!(
+    u8 => U8, i8 => I8, u16 => U16, i16 => I16, u32 => U32, i32 => I32,
+    u64 => U64, i64 => I64, f32 => F32, f64 => F64
+);
+
+/// Little-endian bytes of a tensor, as required by the safetensors serializer
+struct TensorBytes {
+    dtype: Dtype,
+    shape: Vec<usize>,
+    data: Vec<u8>,
+}
+
+impl View for &TensorBytes {
+    fn dtype(&self) -> Dtype {
+        self.dtype
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.data)
+    }
+
+    fn data_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// Collect named NdArrays (of any element type and dimension) and write them into a safetensors file
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::safetensors::{SafetensorsFile, SafetensorsWriter};
+/// #
+/// # fn main() {
+/// let path = std::env::temp_dir().join("ndim_doc_writer.safetensors");
+/// let weight = NdArray::<f32, 2>::ones([2, 3]);
+/// let bias = NdArray::<i64, 1>::arange(3);
+///
+/// let mut writer = SafetensorsWriter::new();
+/// writer.add("weight", &weight).add("bias", &bias);
+/// writer.write(&path).unwrap();
+///
+/// let file = SafetensorsFile::open(&path).unwrap();
+/// assert_eq!(file.names(), vec!["bias", "weight"]);
+/// let loaded = file.load::<f32, 2>("weight").unwrap();
+/// assert_eq!(loaded.shape(), &[2, 3]);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SafetensorsWriter {
+    tensors: Vec<(String, TensorBytes)>,
+    metadata: HashMap<String, String>,
+}
+
+impl SafetensorsWriter {
+    /// Create a writer without any tensor
+    pub fn new() -> Self {
+        SafetensorsWriter::default()
+    }
+
+    /// Add the NdArray `array` with the given `name`. Adding the same name twice replaces the earlier NdArray
+    pub fn add<T: SafetensorsElement, const N: usize>(
+        &mut self,
+        name: &str,
+        array: &NdArray<T, N>,
+    ) -> &mut Self {
+        let mut data: Vec<u8> = Vec::with_capacity(std::mem::size_of_val(array.as_slice()));
+        for value in array.as_slice() {
+            value.write_le(&mut data);
+        }
+        let tensor = TensorBytes {
+            dtype: T::DTYPE,
+            shape: array.view_shape().to_vec(),
+            data,
+        };
+
+        self.tensors.retain(|(other, _)| other != name);
+        self.tensors.push((name.to_string(), tensor));
+        self
+    }
+
+    /// Add a free-form `key`-`value` pair to the `__metadata__` of the file
+    pub fn add_metadata(&mut self, key: &str, value: &str) -> &mut Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Write every added NdArray into the file at `path`
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<(), SafetensorsError> {
+        let metadata: Option<HashMap<String, String>> = if self.metadata.is_empty() {
+            None
+        } else {
+            Some(self.metadata.clone())
+        };
+        let tensors = self
+            .tensors
+            .iter()
+            .map(|(name, tensor)| (name.as_str(), tensor));
+
+        safetensors::serialize_to_file(tensors, metadata, path.as_ref())?;
+        Ok(())
+    }
+}
+
+/// A safetensors file mapped into memory. NdArrays can be loaded with a copy, or borrowed as views without a copy
+///
+/// ## Note
+/// The file must not be modified while it is mapped, as the views would observe the changes
+pub struct SafetensorsFile {
+    mmap: Mmap,
+    start: usize,
+    tensors: HashMap<String, TensorInfo>,
+    metadata: HashMap<String, String>,
+}
+
+impl SafetensorsFile {
+    /// Map the safetensors file at `path` into memory and read its header
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SafetensorsError> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only, and the file is assumed not to be modified while mapped
+        let mmap: Mmap = unsafe { Mmap::map(&file)? };
+
+        // validate the offsets of every tensor
+        SafeTensors::deserialize(&mmap)?;
+        let (header, meta) = SafeTensors::read_metadata(&mmap)?;
+        let tensors: HashMap<String, TensorInfo> = meta
+            .tensors()
+            .into_iter()
+            .map(|(name, info)| (name, info.clone()))
+            .collect();
+        let metadata: HashMap<String, String> = meta.metadata().clone().unwrap_or_default();
+
+        Ok(SafetensorsFile {
+            mmap,
+            start: 8 + header,
+            tensors,
+            metadata,
+        })
+    }
+
+    /// Return the names of every tensor in the file, in sorted order
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tensors.keys().map(|name| name.as_str()).collect();
+        names.sort();
+        names
+    }
+
+    /// Return the dtype and shape of the tensor `name`, if it exists
+    pub fn info(&self, name: &str) -> Option<(Dtype, &[usize])> {
+        self.tensors
+            .get(name)
+            .map(|info| (info.dtype, info.shape.as_slice()))
+    }
+
+    /// Return the free-form `__metadata__` of the file
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Check the dtype and dimension of the tensor `name`, and return its shape and bytes
+    fn tensor<T: SafetensorsElement, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<(SizedArray<N>, &[u8]), SafetensorsError> {
+        let info: &TensorInfo = self
+            .tensors
+            .get(name)
+            .ok_or_else(|| SafetensorsError::Missing(name.to_string()))?;
+        if info.dtype != T::DTYPE {
+            return Err(SafetensorsError::Dtype {
+                name: name.to_string(),
+                expected: T::DTYPE,
+                found: info.dtype,
+            });
+        }
+        let shape: SizedArray<N> =
+            info.shape
+                .clone()
+                .try_into()
+                .map_err(|_| SafetensorsError::Dimension {
+                    name: name.to_string(),
+                    expected: N,
+                    found: info.shape.len(),
+                })?;
+
+        let (begin, end) = info.data_offsets;
+        Ok((shape, &self.mmap[self.start + begin..self.start + end]))
+    }
+
+    /// Load the tensor `name` into a new NdArray. The data is copied from the file
+    ///
+    /// ## Errors
+    /// If the tensor does not exist, or if its dtype or dimension does not match `T` and `N`
+    pub fn load<T: SafetensorsElement, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<NdArray<T, N>, SafetensorsError> {
+        let (shape, bytes) = self.tensor::<T, N>(name)?;
+        let values: Vec<T> = bytes
+            .chunks_exact(std::mem::size_of::<T>())
+            .map(T::read_le)
+            .collect();
+
+        Ok(NdArray::from_owned_vec(values, shape))
+    }
+
+    /// Borrow the tensor `name` from the mapped file as a view, without copying the data
+    ///
+    /// ## Errors
+    /// - If the tensor does not exist, or if its dtype or dimension does not match `T` and `N`
+    /// - If the data is not aligned for `T` in the file, or the host is big-endian. Use [`SafetensorsFile::load(...)`](SafetensorsFile::load) instead
+    pub fn view<T: SafetensorsElement, const N: usize>(
+        &self,
+        name: &str,
+    ) -> Result<ArrayView<'_, T, N>, SafetensorsError> {
+        let (shape, bytes) = self.tensor::<T, N>(name)?;
+        let ptr: *const T = bytes.as_ptr() as *const T;
+        if cfg!(target_endian = "big") || !ptr.is_aligned() {
+            return Err(SafetensorsError::Misaligned(name.to_string()));
+        }
+
+        let size: usize = std::mem::size_of::<T>();
+        let mut strides: SizedArray<N> = [size; N];
+        for dim in (0..N.saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * shape[dim + 1];
+        }
+        // SAFETY: the bytes hold `product(shape)` aligned, little-endian values of T, which live as long as the mapping
+        Ok(unsafe { ArrayView::from_raw_parts(ptr, shape, strides) })
+    }
+}
+
+#[cfg(test)]
+mod safetensors_t {
+    use crate::{
+        core::{Array, Array3, NdArray},
+        safetensors::{SafetensorsError, SafetensorsFile, SafetensorsWriter},
+    };
+
+    // Test saving and loading NdArrays of different types and dimensions
+    // Check if the loaded and borrowed values match the saved values
+    #[test]
+    fn round_trip_t() {
+        let path = std::env::temp_dir().join("ndim_round_trip_t.safetensors");
+        let values: Vec<f64> = (0..24).map(|x| x as f64 * 0.5).collect();
+        let cube: NdArray<f64, 3> = Array3::<f64>::from(&values, [2, 3, 4]);
+        let labels = Array::<u8>::from(&[3, 1, 4], [3]);
+
+        let mut writer = SafetensorsWriter::new();
+        writer
+            .add("cube", &cube)
+            .add("labels", &labels)
+            .add_metadata("format", "pt");
+        writer.write(&path).unwrap();
+
+        let file = SafetensorsFile::open(&path).unwrap();
+        assert_eq!(
+            file.metadata().get("format").map(String::as_str),
+            Some("pt")
+        );
+        let loaded = file.load::<f64, 3>("cube").unwrap();
+        assert_eq!(loaded.shape(), cube.shape());
+        assert_eq!(loaded.as_slice(), cube.as_slice());
+
+        let view = file.view::<f64, 3>("cube").unwrap();
+        assert_eq!(view.as_slice(), Some(cube.as_slice()));
+        assert_eq!(view[[1, 2, 3]], 11.5);
+        assert_eq!(file.load::<u8, 1>("labels").unwrap().as_slice(), &[3, 1, 4]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // Test loading tensors with a wrong name, dtype, or dimension
+    #[test]
+    fn load_mismatch_t() {
+        let path = std::env::temp_dir().join("ndim_load_mismatch_t.safetensors");
+        let mut writer = SafetensorsWriter::new();
+        writer.add("x", &Array::<i32>::zeros([4]));
+        writer.write(&path).unwrap();
+
+        let file = SafetensorsFile::open(&path).unwrap();
+        assert!(matches!(
+            file.load::<i32, 1>("y"),
+            Err(SafetensorsError::Missing(_))
+        ));
+        assert!(matches!(
+            file.load::<f32, 1>("x"),
+            Err(SafetensorsError::Dtype { .. })
+        ));
+        assert!(matches!(
+            file.view::<i32, 2>("x"),
+            Err(SafetensorsError::Dimension {
+                expected: 2,
+                found: 1,
+                ..
+            })
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // Test saving and loading empty NdArrays, including NdArray::new()
+    #[test]
+    fn empty_round_trip_t() {
+        let path = std::env::temp_dir().join("ndim_empty_round_trip_t.safetensors");
+        let mut writer = SafetensorsWriter::new();
+        writer
+            .add("new", &NdArray::<f32, 2>::new())
+            .add("rows", &NdArray::<u16, 2>::zeros([3, 0]));
+        writer.write(&path).unwrap();
+
+        let file = SafetensorsFile::open(&path).unwrap();
+        let loaded = file.load::<f32, 2>("new").unwrap();
+        assert_eq!(loaded.shape(), &[0, 0]);
+        assert!(loaded.is_empty());
+        assert_eq!(file.load::<u16, 2>("rows").unwrap().shape(), &[3, 0]);
+        assert_eq!(file.view::<u16, 2>("rows").unwrap().shape(), &[3, 0]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    // Test the source of an io error
+    #[test]
+    fn source_t() {
+        use std::error::Error;
+
+        let err = SafetensorsFile::open(std::env::temp_dir().join("ndim_missing_t.safetensors"))
+            .err()
+            .unwrap();
+        assert!(matches!(err, SafetensorsError::Io(_)));
+        assert!(err.source().unwrap().is::<std::io::Error>());
+    }
+}
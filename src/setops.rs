@@ -0,0 +1,163 @@
+use crate::core::NdArray;
+
+/// Return the sorted distinct elements of `values`
+fn sorted_unique<T: Copy + Ord>(values: &[T]) -> Vec<T> {
+    let mut values: Vec<T> = values.to_vec();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+/// Return the sorted distinct elements of `arr` as a 1-D NdArray, similar to `numpy.unique`. Arrays with more than one dimension are flattened
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::setops::unique;
+/// #
+/// # fn main() {
+/// let ids = NdArray::<u32, 2>::from(&[4, 1, 4, 9, 1, 1], [2, 3]);
+/// assert_eq!(unique(&ids).as_slice(), &[1, 4, 9]);
+/// # }
+/// ```
+pub fn unique<T: Copy + Ord, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, 1> {
+    let values: Vec<T> = sorted_unique(arr.as_slice());
+    let len: usize = values.len();
+    NdArray::from_owned_vec(values, [len])
+}
+
+/// Return the sorted distinct elements found in both `a` and `b`, similar to `numpy.intersect1d`. Arrays with more than one dimension are flattened
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::setops::intersect1d;
+/// #
+/// # fn main() {
+/// let a = NdArray::<i32, 1>::from(&[5, 1, 3, 3, 7], [5]);
+/// let b = NdArray::<i32, 1>::from(&[3, 7, 8], [3]);
+/// assert_eq!(intersect1d(&a, &b).as_slice(), &[3, 7]);
+/// # }
+/// ```
+pub fn intersect1d<T: Copy + Ord, const N: usize, const M: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, M>,
+) -> NdArray<T, 1> {
+    let b: Vec<T> = sorted_unique(b.as_slice());
+    let values: Vec<T> = sorted_unique(a.as_slice())
+        .into_iter()
+        .filter(|x| b.binary_search(x).is_ok())
+        .collect();
+    let len: usize = values.len();
+    NdArray::from_owned_vec(values, [len])
+}
+
+/// Return the sorted distinct elements found in `a` or `b`, similar to `numpy.union1d`. Arrays with more than one dimension are flattened
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::setops::union1d;
+/// #
+/// # fn main() {
+/// let a = NdArray::<i32, 1>::from(&[5, 1, 3], [3]);
+/// let b = NdArray::<i32, 2>::from(&[3, -2, 5, 0], [2, 2]);
+/// assert_eq!(union1d(&a, &b).as_slice(), &[-2, 0, 1, 3, 5]);
+/// # }
+/// ```
+pub fn union1d<T: Copy + Ord, const N: usize, const M: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, M>,
+) -> NdArray<T, 1> {
+    let mut values: Vec<T> = a.as_slice().to_vec();
+    values.extend_from_slice(b.as_slice());
+    let values: Vec<T> = sorted_unique(&values);
+    let len: usize = values.len();
+    NdArray::from_owned_vec(values, [len])
+}
+
+/// Return the sorted distinct elements of `a` not found in `b`, similar to `numpy.setdiff1d`. Arrays with more than one dimension are flattened
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::setops::setdiff1d;
+/// #
+/// # fn main() {
+/// let a = NdArray::<u8, 1>::from(&[5, 1, 3, 3, 7], [5]);
+/// let b = NdArray::<u8, 1>::from(&[3, 7, 8], [3]);
+/// assert_eq!(setdiff1d(&a, &b).as_slice(), &[1, 5]);
+/// # }
+/// ```
+pub fn setdiff1d<T: Copy + Ord, const N: usize, const M: usize>(
+    a: &NdArray<T, N>,
+    b: &NdArray<T, M>,
+) -> NdArray<T, 1> {
+    let b: Vec<T> = sorted_unique(b.as_slice());
+    let values: Vec<T> = sorted_unique(a.as_slice())
+        .into_iter()
+        .filter(|x| b.binary_search(x).is_err())
+        .collect();
+    let len: usize = values.len();
+    NdArray::from_owned_vec(values, [len])
+}
+
+impl<T: Copy + Ord, const N: usize> NdArray<T, N> {
+    /// Return a mask of the same shape, which is true where the element is found in `test_values`, similar to `numpy.isin`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let ids = NdArray::<u16, 2>::from(&[10, 11, 12, 13], [2, 2]);
+    /// let selected = NdArray::<u16, 1>::from(&[13, 10, 99], [3]);
+    /// let mask = ids.isin(&selected);
+    /// assert_eq!(mask.shape(), &[2, 2]);
+    /// assert_eq!(mask.as_slice(), &[true, false, false, true]);
+    /// # }
+    /// ```
+    pub fn isin<const M: usize>(&self, test_values: &NdArray<T, M>) -> NdArray<bool, N> {
+        let test_values: Vec<T> = sorted_unique(test_values.as_slice());
+        self.map(|x| test_values.binary_search(x).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod setops_t {
+    use crate::{
+        core::{Array, Array2},
+        setops::{intersect1d, setdiff1d, union1d, unique},
+    };
+
+    // Test the set operations on arrays of ids with duplicates
+    #[test]
+    fn set_operations_t() {
+        let a = Array::<i64>::from(&[8, -3, 8, 2, 0, 2], [6]);
+        let b = Array2::<i64>::from(&[2, 9, 9, -3], [2, 2]);
+
+        assert_eq!(unique(&a).as_slice(), &[-3, 0, 2, 8]);
+        assert_eq!(intersect1d(&a, &b).as_slice(), &[-3, 2]);
+        assert_eq!(union1d(&a, &b).as_slice(), &[-3, 0, 2, 8, 9]);
+        assert_eq!(setdiff1d(&a, &b).as_slice(), &[0, 8]);
+        assert_eq!(setdiff1d(&b, &a).as_slice(), &[9]);
+    }
+
+    // Test the set operations and membership with empty arrays
+    #[test]
+    fn empty_t() {
+        let a = Array::<u8>::from(&[3, 1], [2]);
+        let empty = Array::<u8>::from(&[], [0]);
+
+        assert_eq!(intersect1d(&a, &empty).shape(), &[0]);
+        assert_eq!(union1d(&empty, &a).as_slice(), &[1, 3]);
+        assert_eq!(setdiff1d(&a, &empty).as_slice(), &[1, 3]);
+        assert_eq!(a.isin(&empty).as_slice(), &[false, false]);
+        assert!(Array2::<u8>::new().isin(&a).is_empty());
+    }
+}
@@ -0,0 +1,646 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::{core::NdArray, iter::map_lanes, linalg::solve};
+
+/// Discrete Fourier transform of `x` in `O(n log n)`. Lengths which are a power of two are split into halves (radix-2), and other lengths use [`bluestein`]
+fn fft(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n: usize = x.len();
+    if n <= 1 {
+        return x.to_vec();
+    }
+    if !n.is_power_of_two() {
+        return bluestein(x);
+    }
+
+    let even: Vec<Complex<f64>> = fft(&x.iter().step_by(2).copied().collect::<Vec<_>>());
+    let odd: Vec<Complex<f64>> = fft(&x.iter().skip(1).step_by(2).copied().collect::<Vec<_>>());
+    let mut out: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); n];
+    for k in 0..n / 2 {
+        let twiddle: Complex<f64> =
+            Complex::from_polar(1.0, -2.0 * PI * k as f64 / n as f64) * odd[k];
+        out[k] = even[k] + twiddle;
+        out[k + n / 2] = even[k] - twiddle;
+    }
+    out
+}
+
+/// Discrete Fourier transform of `x` of any length `n`, as a circular convolution with the chirp `exp(i pi k^2 / n)` computed by radix-2 transforms of a power of two length of at least `2n - 1` (Bluestein's algorithm)
+fn bluestein(x: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n: usize = x.len();
+    let len: usize = (2 * n - 1).next_power_of_two();
+    // `k^2` is reduced modulo `2n` to keep the phase accurate for long inputs
+    let chirp: Vec<Complex<f64>> = (0..n)
+        .map(|k| Complex::from_polar(1.0, -PI * ((k * k) % (2 * n)) as f64 / n as f64))
+        .collect();
+
+    let mut a: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); len];
+    let mut b: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); len];
+    for k in 0..n {
+        a[k] = x[k] * chirp[k];
+        b[k] = chirp[k].conj();
+        b[(len - k) % len] = chirp[k].conj();
+    }
+
+    // inverse transform of the product, using `ifft(y) = conj(fft(conj(y))) / len`
+    let product: Vec<Complex<f64>> = fft(&a)
+        .iter()
+        .zip(fft(&b))
+        .map(|(x, y)| (x * y).conj())
+        .collect();
+    let conv: Vec<Complex<f64>> = fft(&product);
+    (0..n)
+        .map(|k| conv[k].conj() / len as f64 * chirp[k])
+        .collect()
+}
+
+/// Generalized cosine window `sum((-1)^k * a_k * cos(2 pi k n / (M - 1)))` of `len` points. A periodic window is computed as a symmetric window of `len + 1` points without its last point
+fn cosine_window(len: usize, symmetric: bool, coefficients: &[f64]) -> NdArray<f64, 1> {
+    let period: usize = if symmetric { len } else { len + 1 };
+    let values: Vec<f64> = (0..len)
+        .map(|n| {
+            if period == 1 {
+                return 1.0;
+            }
+            let phase: f64 = 2.0 * PI * n as f64 / (period - 1) as f64;
+            coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, a)| {
+                    let sign: f64 = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+                    sign * a * (k as f64 * phase).cos()
+                })
+                .sum()
+        })
+        .collect();
+    NdArray::from_owned_vec(values, [len])
+}
+
+/// Modified Bessel function of the first kind of order zero
+fn bessel_i0(x: f64) -> f64 {
+    let (mut term, mut sum): (f64, f64) = (1.0, 1.0);
+    let mut k: f64 = 0.0;
+    while term > sum * f64::EPSILON {
+        k += 1.0;
+        term *= (x / (2.0 * k)).powi(2);
+        sum += term;
+    }
+    sum
+}
+
+/// Return the Hann window of `len` points. If `symmetric` is false, the window is periodic, as used for spectral analysis, similar to `scipy.signal.windows.hann(len, sym)`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::signal::hann;
+/// #
+/// # fn main() {
+/// let close = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-15);
+/// assert!(close(hann(5, true).as_slice(), &[0.0, 0.5, 1.0, 0.5, 0.0]));
+/// assert!(close(hann(4, false).as_slice(), &[0.0, 0.5, 1.0, 0.5])); // periodic
+/// # }
+/// ```
+pub fn hann(len: usize, symmetric: bool) -> NdArray<f64, 1> {
+    cosine_window(len, symmetric, &[0.5, 0.5])
+}
+
+/// Return the Hamming window of `len` points. If `symmetric` is false, the window is periodic
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::signal::hamming;
+/// #
+/// # fn main() {
+/// let window = hamming(3, true);
+/// assert!((window[[0]] - 0.08).abs() < 1e-15);
+/// assert_eq!(window[[1]], 1.0);
+/// # }
+/// ```
+pub fn hamming(len: usize, symmetric: bool) -> NdArray<f64, 1> {
+    cosine_window(len, symmetric, &[0.54, 0.46])
+}
+
+/// Return the Blackman window of `len` points. If `symmetric` is false, the window is periodic
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::signal::blackman;
+/// #
+/// # fn main() {
+/// let window = blackman(3, true);
+/// assert!(window[[0]].abs() < 1e-15);
+/// assert!((window[[1]] - 1.0).abs() < 1e-15);
+/// # }
+/// ```
+pub fn blackman(len: usize, symmetric: bool) -> NdArray<f64, 1> {
+    cosine_window(len, symmetric, &[0.42, 0.5, 0.08])
+}
+
+/// Return the Kaiser window of `len` points with shape parameter `beta`, which trades the main lobe width for the side lobe level. If `symmetric` is false, the window is periodic
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::signal::kaiser;
+/// #
+/// # fn main() {
+/// let window = kaiser(5, 0.0, true); // rectangular window
+/// assert_eq!(window.as_slice(), &[1.0; 5]);
+/// let window = kaiser(5, 8.6, true);
+/// assert_eq!(window[[2]], 1.0);
+/// assert!(window[[0]] < 0.01);
+/// # }
+/// ```
+pub fn kaiser(len: usize, beta: f64, symmetric: bool) -> NdArray<f64, 1> {
+    let period: usize = if symmetric { len } else { len + 1 };
+    let values: Vec<f64> = (0..len)
+        .map(|n| {
+            if period == 1 {
+                return 1.0;
+            }
+            let ratio: f64 = 2.0 * n as f64 / (period - 1) as f64 - 1.0;
+            bessel_i0(beta * (1.0 - ratio * ratio).max(0.0).sqrt()) / bessel_i0(beta)
+        })
+        .collect();
+    NdArray::from_owned_vec(values, [len])
+}
+
+/// Return the short-time Fourier transform of `signal`, as a 2-D NdArray of shape `[frames, window.len() / 2 + 1]`, where row `t` holds the one-sided spectrum of the frame starting at `t * hop`, weighted by `window`
+///
+/// ## Note
+/// - Only complete frames are transformed (no padding), hence there are `(signal.len() - window.len()) / hop + 1` frames
+/// - The spectrum is not scaled, i.e. `stft(..)[[t, 0]]` is the weighted sum of the frame
+/// - Every frame is transformed in `O(n log n)` for any window length, though a power of two length is the fastest
+///
+/// ## Panics
+/// - If `window` is empty, panics; message shown is **Window cannot be empty**
+/// - If `hop` is zero, panics; message shown is **Hop cannot be zero**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::signal::{hann, stft};
+/// #
+/// # fn main() {
+/// let values: Vec<f64> = (0..64).map(|n| (std::f64::consts::PI * n as f64 / 4.0).cos()).collect();
+/// let signal = NdArray::<f64, 1>::from(&values, [64]);
+/// let frames = stft(&signal, &hann(16, false), 8);
+/// assert_eq!(frames.shape(), &[7, 9]);
+/// assert!((frames[[0, 2]].norm() - 4.0).abs() < 1e-12); // 1/8 of the sample rate maps to bin 2
+/// # }
+/// ```
+pub fn stft(
+    signal: &NdArray<f64, 1>,
+    window: &NdArray<f64, 1>,
+    hop: usize,
+) -> NdArray<Complex<f64>, 2> {
+    let size: usize = window.view_shape()[0];
+    if size == 0 {
+        panic!("Window cannot be empty");
+    }
+    if hop == 0 {
+        panic!("Hop cannot be zero");
+    }
+
+    let samples: &[f64] = signal.as_slice();
+    let frames: usize = if samples.len() < size {
+        0
+    } else {
+        (samples.len() - size) / hop + 1
+    };
+    let bins: usize = size / 2 + 1;
+    let mut values: Vec<Complex<f64>> = Vec::with_capacity(frames * bins);
+    for frame in 0..frames {
+        let weighted: Vec<Complex<f64>> = samples[frame * hop..frame * hop + size]
+            .iter()
+            .zip(window.as_slice())
+            .map(|(x, w)| Complex::new(x * w, 0.0))
+            .collect();
+        values.extend_from_slice(&fft(&weighted)[..bins]);
+    }
+    NdArray::from_owned_vec(values, [frames, bins])
+}
+
+/// Return the power spectrogram `|stft(signal, window, hop)|^2` of `signal`, as a 2-D NdArray of shape `[frames, window.len() / 2 + 1]`
+///
+/// ## Panics
+/// - If `window` is empty, panics; message shown is **Window cannot be empty**
+/// - If `hop` is zero, panics; message shown is **Hop cannot be zero**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::signal::{hamming, spectrogram};
+/// #
+/// # fn main() {
+/// let signal = NdArray::<f64, 1>::from(&[1.0; 32], [32]);
+/// let power = spectrogram(&signal, &hamming(8, false), 4);
+/// assert_eq!(power.shape(), &[7, 5]);
+/// assert!((power[[3, 0]] - 4.32f64.powi(2)).abs() < 1e-12); // constant signal only has a DC component
+/// # }
+/// ```
+pub fn spectrogram(
+    signal: &NdArray<f64, 1>,
+    window: &NdArray<f64, 1>,
+    hop: usize,
+) -> NdArray<f64, 2> {
+    stft(signal, window, hop).map(|x| x.norm_sqr())
+}
+
+/// Normalize the coefficients `b` and `a` by `a[0]` and pad both to the same length
+///
+/// ## Panics
+/// If `a` is empty or `a[0]` is zero, panics; message shown is **First denominator coefficient cannot be zero**
+fn normalize_coefficients(b: &[f64], a: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    if a.first().is_none_or(|a0| *a0 == 0.0) {
+        panic!("First denominator coefficient cannot be zero");
+    }
+    let len: usize = a.len().max(b.len());
+    let pad = |c: &[f64]| -> Vec<f64> {
+        (0..len)
+            .map(|i| c.get(i).map_or(0.0, |c| c / a[0]))
+            .collect()
+    };
+    (pad(b), pad(a))
+}
+
+/// Filter `x` with the normalized coefficients `b` and `a` of equal length, starting from the state `zi`, using the transposed direct form II
+fn lfilter_lane(b: &[f64], a: &[f64], x: &[f64], zi: &[f64]) -> Vec<f64> {
+    let order: usize = b.len() - 1;
+    let mut z: Vec<f64> = zi.to_vec();
+    x.iter()
+        .map(|x| {
+            let y: f64 = b[0] * x + z.first().copied().unwrap_or(0.0);
+            for i in 0..order {
+                let next: f64 = if i + 1 < order { z[i + 1] } else { 0.0 };
+                z[i] = b[i + 1] * x + next - a[i + 1] * y;
+            }
+            y
+        })
+        .collect()
+}
+
+/// Initial state of the filter for the steady state of a unit step response, similar to `scipy.signal.lfilter_zi`. Zero if the filter has no unit step steady state
+fn lfilter_zi(b: &[f64], a: &[f64]) -> Vec<f64> {
+    let order: usize = b.len() - 1;
+    if order == 0 {
+        return Vec::new();
+    }
+
+    // (I - A^T) zi = b[1..] - a[1..] * b[0], where A is the companion matrix of `a`
+    let mut system: Vec<f64> = vec![0.0; order * order];
+    for i in 0..order {
+        system[i * order + i] = 1.0;
+        system[i * order] += a[i + 1];
+        if i + 1 < order {
+            system[i * order + i + 1] -= 1.0;
+        }
+    }
+    let rhs: Vec<f64> = (0..order).map(|i| b[i + 1] - a[i + 1] * b[0]).collect();
+    let system: NdArray<f64, 2> = NdArray::from_owned_vec(system, [order, order]);
+    let rhs: NdArray<f64, 1> = NdArray::from_owned_vec(rhs, [order]);
+    solve(&system, &rhs).map_or(vec![0.0; order], |zi| zi.as_slice().to_vec())
+}
+
+/// Return `x` filtered along `axis` by the IIR or FIR filter with numerator `b` and denominator `a` coefficients, i.e. `a[0] y[n] = sum(b[k] x[n - k]) - sum(a[k] y[n - k], k >= 1)`, similar to `scipy.signal.lfilter`. The filter starts at rest
+///
+/// ## Panics
+/// - If `a` is empty or `a[0]` is zero, panics; message shown is **First denominator coefficient cannot be zero**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::signal::lfilter;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[1.0, 0.0, 0.0, 0.0], [4]);
+/// let y = lfilter(&[1.0], &[1.0, -0.5], &x, 0); // impulse response of a first order IIR filter
+/// assert_eq!(y.as_slice(), &[1.0, 0.5, 0.25, 0.125]);
+///
+/// let x = NdArray::<f64, 1>::from(&[2.0, 4.0, 6.0], [3]);
+/// let y = lfilter(&[0.5, 0.5], &[1.0], &x, 0); // moving average FIR filter
+/// assert_eq!(y.as_slice(), &[1.0, 3.0, 5.0]);
+/// # }
+/// ```
+pub fn lfilter<const N: usize>(
+    b: &[f64],
+    a: &[f64],
+    x: &NdArray<f64, N>,
+    axis: usize,
+) -> NdArray<f64, N> {
+    let (b, a) = normalize_coefficients(b, a);
+    let zi: Vec<f64> = vec![0.0; b.len() - 1];
+    map_lanes(x, axis, |lane| lfilter_lane(&b, &a, lane, &zi))
+}
+
+/// Return `x` filtered forward and backward along `axis`, which has zero phase and squares the magnitude response of the filter, similar to `scipy.signal.filtfilt`
+///
+/// ## Note
+/// Similar to SciPy, lanes are extended at both ends by `3 * max(a.len(), b.len())` points of odd reflection, and the filter starts in the steady state of the first point to reduce transients
+///
+/// ## Panics
+/// - If `a` is empty or `a[0]` is zero, panics; message shown is **First denominator coefficient cannot be zero**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+/// - If the length of `axis` is not more than the padding, panics; message shown is **Axis Length(`len`) must be more than Padding(`padding`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::signal::filtfilt;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[1.0; 10], [10]);
+/// let y = filtfilt(&[0.2], &[1.0, -0.8], &x, 0); // constant signal passes unchanged
+/// assert!(y.as_slice().iter().all(|y| (y - 1.0).abs() < 1e-12));
+/// # }
+/// ```
+pub fn filtfilt<const N: usize>(
+    b: &[f64],
+    a: &[f64],
+    x: &NdArray<f64, N>,
+    axis: usize,
+) -> NdArray<f64, N> {
+    let padding: usize = 3 * a.len().max(b.len());
+    let (b, a) = normalize_coefficients(b, a);
+    if axis < N && x.shape()[axis] <= padding {
+        panic!(
+            "Axis Length({}) must be more than Padding({})",
+            x.shape()[axis],
+            padding
+        );
+    }
+
+    let zi: Vec<f64> = lfilter_zi(&b, &a);
+    map_lanes(x, axis, |lane| {
+        let (first, last): (f64, f64) = (lane[0], lane[lane.len() - 1]);
+        let mut extended: Vec<f64> = (1..=padding).rev().map(|i| 2.0 * first - lane[i]).collect();
+        extended.extend_from_slice(lane);
+        extended.extend((1..=padding).map(|i| 2.0 * last - lane[lane.len() - 1 - i]));
+
+        let state: Vec<f64> = zi.iter().map(|z| z * extended[0]).collect();
+        let mut y: Vec<f64> = lfilter_lane(&b, &a, &extended, &state);
+        y.reverse();
+        let state: Vec<f64> = zi.iter().map(|z| z * y[0]).collect();
+        let mut y: Vec<f64> = lfilter_lane(&b, &a, &y, &state);
+        y.reverse();
+        y[padding..padding + lane.len()].to_vec()
+    })
+}
+
+/// Return `x` smoothed along `axis` by the Savitzky-Golay filter, which fits a polynomial of degree `order` to each window of `window` points by least squares, similar to `scipy.signal.savgol_filter` (with `mode='interp'`)
+///
+/// ## Note
+/// The first and last `window / 2` points are evaluated from the polynomial fitted to the first and last window, respectively
+///
+/// ## Panics
+/// - If `window` is even, panics; message shown is **Window Length(`window`) must be odd**
+/// - If `order` is not less than `window`, panics; message shown is **Polynomial Order(`order`) must be less than Window Length(`window`)**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+/// - If `window` is longer than `axis`, panics; message shown is **Window Length(`window`) exceeds Axis Length(`len`)**
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::signal::savgol_filter;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[0.0, 1.0, 4.0, 9.0, 16.0, 25.0], [6]);
+/// let y = savgol_filter(&x, 5, 2, 0); // a parabola is preserved by a quadratic fit
+/// assert!(y.as_slice().iter().zip(x.as_slice()).all(|(y, x)| (y - x).abs() < 1e-10));
+///
+/// let x = NdArray::<f64, 1>::from(&[0.0, 3.0, 0.0, 3.0, 0.0], [5]);
+/// let y = savgol_filter(&x, 3, 1, 0); // a linear fit is a moving average
+/// assert!((y[[2]] - 2.0).abs() < 1e-12);
+/// # }
+/// ```
+pub fn savgol_filter<const N: usize>(
+    x: &NdArray<f64, N>,
+    window: usize,
+    order: usize,
+    axis: usize,
+) -> NdArray<f64, N> {
+    if window.is_multiple_of(2) {
+        panic!("Window Length({}) must be odd", window);
+    }
+    if order >= window {
+        panic!(
+            "Polynomial Order({}) must be less than Window Length({})",
+            order, window
+        );
+    }
+    if axis < N && window > x.shape()[axis] {
+        panic!(
+            "Window Length({}) exceeds Axis Length({})",
+            window,
+            x.shape()[axis]
+        );
+    }
+
+    // coefficients[t][j] weighs point j of a window to evaluate its fitted polynomial at point t
+    let half: f64 = (window / 2) as f64;
+    let powers = |t: f64| -> Vec<f64> {
+        (0..=order)
+            .scan(1.0, |p, _| Some(std::mem::replace(p, *p * t)))
+            .collect()
+    };
+    let vandermonde: Vec<Vec<f64>> = (0..window).map(|j| powers(j as f64 - half)).collect();
+    let mut normal: Vec<f64> = vec![0.0; (order + 1) * (order + 1)];
+    for row in vandermonde.iter() {
+        for i in 0..=order {
+            for k in 0..=order {
+                normal[i * (order + 1) + k] += row[i] * row[k];
+            }
+        }
+    }
+    let normal: NdArray<f64, 2> = NdArray::from_owned_vec(normal, [order + 1, order + 1]);
+    let coefficients: Vec<Vec<f64>> = (0..window)
+        .map(|t| {
+            let rhs: NdArray<f64, 1> =
+                NdArray::from_owned_vec(powers(t as f64 - half), [order + 1]);
+            // distinct points make the normal equations regular
+            let p: NdArray<f64, 1> = solve(&normal, &rhs)
+                .unwrap_or_else(|| NdArray::from_owned_vec(vec![0.0; order + 1], [order + 1]));
+            vandermonde
+                .iter()
+                .map(|row| row.iter().zip(p.as_slice()).map(|(v, p)| v * p).sum())
+                .collect()
+        })
+        .collect();
+
+    let centre: usize = window / 2;
+    map_lanes(x, axis, |lane| {
+        let len: usize = lane.len();
+        let weigh = |start: usize, t: usize| -> f64 {
+            coefficients[t]
+                .iter()
+                .zip(&lane[start..start + window])
+                .map(|(c, x)| c * x)
+                .sum()
+        };
+        (0..len)
+            .map(|i| {
+                if i < centre {
+                    weigh(0, i)
+                } else if i + centre >= len {
+                    weigh(len - window, i + window - len)
+                } else {
+                    weigh(i - centre, centre)
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod signal_t {
+    use num_complex::Complex;
+
+    use crate::{
+        core::{Array, Array2},
+        signal::{
+            blackman, fft, filtfilt, hann, kaiser, lfilter, savgol_filter, spectrogram, stft,
+        },
+    };
+
+    // Test the fast transform against a direct transform for lengths which are a power of two, even and odd
+    #[test]
+    fn fft_t() {
+        for n in [1, 6, 8, 12, 7, 15, 97] {
+            let x: Vec<Complex<f64>> = (0..n)
+                .map(|i| Complex::new(i as f64, (i * i) as f64 % 3.0))
+                .collect();
+            for (k, value) in fft(&x).iter().enumerate() {
+                let expected: Complex<f64> = x
+                    .iter()
+                    .enumerate()
+                    .map(|(j, v)| {
+                        v * Complex::from_polar(
+                            1.0,
+                            -2.0 * std::f64::consts::PI * (j * k) as f64 / n as f64,
+                        )
+                    })
+                    .sum();
+                assert!((value - expected).norm() < 1e-9);
+            }
+        }
+    }
+
+    // Test the windows against reference values
+    #[test]
+    fn windows_t() {
+        let expected: [f64; 4] = [0.0, 0.75, 0.75, 0.0];
+        for (w, expected) in hann(4, true).as_slice().iter().zip(expected) {
+            assert!((w - expected).abs() < 1e-15);
+        }
+        assert!((blackman(5, false)[[2]] - 0.8492298567374694).abs() < 1e-15);
+        assert!((kaiser(4, 5.0, true)[[0]] - 0.03671089227128667).abs() < 1e-15);
+        assert_eq!(hann(1, true).as_slice(), &[1.0]);
+        assert_eq!(hann(0, false).shape(), &[0]);
+    }
+
+    // Test the time and frequency of a tone which changes its frequency halfway
+    #[test]
+    fn chirp_spectrogram_t() {
+        let values: Vec<f64> = (0..128)
+            .map(|n| {
+                let bin: f64 = if n < 64 { 2.0 } else { 6.0 };
+                (2.0 * std::f64::consts::PI * bin * n as f64 / 32.0).sin()
+            })
+            .collect();
+        let signal = Array::<f64>::from(&values, [128]);
+        let power = spectrogram(&signal, &hann(32, false), 32);
+        assert_eq!(power.shape(), &[4, 17]);
+
+        let peaks: Vec<usize> = (0..4)
+            .map(|t| {
+                (0..17)
+                    .max_by(|a, b| power[[t, *a]].total_cmp(&power[[t, *b]]))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(peaks, vec![2, 2, 6, 6]);
+        assert_eq!(
+            stft(&Array::<f64>::from(&[1.0], [1]), &hann(4, false), 1).shape(),
+            &[0, 3]
+        );
+    }
+
+    // Test a second order IIR filter along both axes of a 2-D NdArray
+    #[test]
+    fn lfilter_2dim_t() {
+        let x = Array2::<f64>::from(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [2, 3]);
+        // y[n] = x[n] + x[n - 1] + 0.5 y[n - 1] - 0.25 y[n - 2], with unnormalized coefficients
+        let (b, a): ([f64; 2], [f64; 3]) = ([2.0, 2.0], [2.0, -1.0, 0.5]);
+
+        let rows = lfilter(&b, &a, &x, 1);
+        assert_eq!(rows.as_slice(), &[1.0, 3.5, 6.5, 4.0, 11.0, 15.5]);
+        let columns = lfilter(&b, &a, &x, 0);
+        assert_eq!(columns.as_slice(), &[1.0, 2.0, 3.0, 5.5, 8.0, 10.5]);
+        assert!(lfilter(&b, &a, &Array2::<f64>::new(), 1).is_empty());
+    }
+
+    // Test the zero phase of the forward-backward filter on a slow sine
+    #[test]
+    fn filtfilt_zero_phase_t() {
+        let values: Vec<f64> = (0..200)
+            .map(|n| (2.0 * std::f64::consts::PI * n as f64 / 100.0).sin())
+            .collect();
+        let x = Array::<f64>::from(&values, [200]);
+        let y = filtfilt(&[0.1], &[1.0, -0.9], &x, 0);
+        let forward = lfilter(&[0.1], &[1.0, -0.9], &x, 0);
+
+        // away from the ends, the forward filter delays the peak of sin at n = 125, the forward-backward filter does not
+        let peak = |y: &[f64]| (100..200).max_by(|a, b| y[*a].total_cmp(&y[*b])).unwrap();
+        assert!(peak(forward.as_slice()) > 125);
+        assert_eq!(peak(y.as_slice()), 125);
+        // squared magnitude response 0.01 / |1 - 0.9 exp(-iw)|^2 of the filter
+        let gain: f64 = 0.01 / (1.81 - 1.8 * (2.0 * std::f64::consts::PI / 100.0).cos());
+        assert!((y[[125]] - gain).abs() < 1e-3);
+    }
+
+    // Test the Savitzky-Golay filter against reference values, with the polynomial fit at the ends
+    #[test]
+    fn savgol_filter_t() {
+        let x = Array::<f64>::from(&[2.0, 2.0, 5.0, 2.0, 1.0, 0.0, 1.0, 4.0, 9.0], [9]);
+        let y = savgol_filter(&x, 5, 2, 0);
+        let expected: [f64; 9] = [
+            1.65714286, 3.17142857, 3.54285714, 2.85714286, 0.65714286, 0.17142857, 1.0, 4.0, 9.0,
+        ];
+        for (y, expected) in y.as_slice().iter().zip(expected) {
+            assert!((y - expected).abs() < 1e-8, "{} != {}", y, expected);
+        }
+    }
+
+    // Test the Savitzky-Golay filter with an even window
+    #[test]
+    #[should_panic(expected = "Window Length(4) must be odd")]
+    fn savgol_even_window_t() {
+        savgol_filter(&Array::<f64>::from(&[1.0; 8], [8]), 4, 2, 0);
+    }
+
+    // Test the transform with a zero hop
+    #[test]
+    #[should_panic(expected = "Hop cannot be zero")]
+    fn zero_hop_t() {
+        let signal = Array::<f64>::from(&[1.0, 2.0], [2]);
+        stft(&signal, &hann(2, true), 0);
+    }
+
+    // Test the transform with an empty window created using NdArray::<T, N>::new()
+    #[test]
+    #[should_panic(expected = "Window cannot be empty")]
+    fn empty_window_t() {
+        let signal = Array::<f64>::from(&[1.0; 8], [8]);
+        stft(&signal, &Array::<f64>::new(), 1);
+    }
+}
@@ -0,0 +1,330 @@
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// Slicing argument for a single axis of an NdArray
+///
+/// Negative `start`, `end` and `Index` values are counted from the end of the axis, similar to NumPy
+///
+/// ## Example
+///
+/// ```
+/// use ndim::slice::SliceArg;
+///
+/// # fn main() {
+/// let arg = SliceArg::from(1..).step_by(2);
+/// assert_eq!(arg, SliceArg::Range { start: 1, end: None, step: 2 });
+/// assert_eq!(arg.resolve(6), (1, 3, 2)); // selects 1, 3 and 5
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceArg {
+    /// Select `start..end` with a `step`. `end` is the length of the axis when `None`
+    Range {
+        start: isize,
+        end: Option<isize>,
+        step: usize,
+    },
+    /// Select a single position. The axis is dropped from the sliced array
+    Index(isize),
+}
+
+impl SliceArg {
+    /// Set the step of a range argument
+    ///
+    /// ## Panics
+    /// - If `step` is zero, panics; message shown is **Step cannot be zero**
+    /// - If the argument is an index, panics; message shown is **Step cannot be applied to an index**
+    pub fn step_by(self, step: usize) -> Self {
+        if step == 0 {
+            panic!("Step cannot be zero");
+        }
+        match self {
+            SliceArg::Range { start, end, .. } => SliceArg::Range { start, end, step },
+            SliceArg::Index(_) => panic!("Step cannot be applied to an index"),
+        }
+    }
+
+    /// Return true if the argument selects a single position (and drops the axis)
+    pub fn is_index(&self) -> bool {
+        matches!(self, SliceArg::Index(_))
+    }
+
+    /// Resolve the argument for an axis of length `len` and return `(start, count, step)`, where `count` is the number of selected positions
+    ///
+    /// ## Note
+    /// Ranges are clipped to the axis, similar to NumPy. Hence, `count` may be zero
+    ///
+    /// ## Panics
+    /// If an index is not within the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::slice::SliceArg;
+    /// #
+    /// # fn main() {
+    /// assert_eq!(SliceArg::from(-2).resolve(5), (3, 1, 1));
+    /// assert_eq!(SliceArg::from(..10).resolve(5), (0, 5, 1));
+    /// assert_eq!(SliceArg::from(-4..-1).step_by(2).resolve(5), (1, 2, 2));
+    /// # }
+    /// ```
+    pub fn resolve(&self, len: usize) -> (usize, usize, usize) {
+        // negative values count from the end of the axis
+        let absolute = |pos: isize| -> isize {
+            if pos < 0 {
+                pos + len as isize
+            } else {
+                pos
+            }
+        };
+
+        match *self {
+            SliceArg::Index(idx) => {
+                let idx: isize = absolute(idx);
+                if idx < 0 || idx as usize >= len {
+                    panic!("Index out of bounds");
+                }
+                (idx as usize, 1, 1)
+            }
+            SliceArg::Range { start, end, step } => {
+                let start: usize = absolute(start).clamp(0, len as isize) as usize;
+                let end: usize = match end {
+                    Some(end) => absolute(end).clamp(0, len as isize) as usize,
+                    None => len,
+                };
+                let count: usize = end.saturating_sub(start).div_ceil(step);
+                (start, count, step)
+            }
+        }
+    }
+}
+
+impl From<RangeFull> for SliceArg {
+    fn from(_: RangeFull) -> Self {
+        SliceArg::Range {
+            start: 0,
+            end: None,
+            step: 1,
+        }
+    }
+}
+
+/// Implement `From<..>` for every range type and index of the integer types `$t`
+macro_rules! impl_slice_arg_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for SliceArg {
+                fn from(idx: $t) -> Self {
+                    SliceArg::Index(idx as isize)
+                }
+            }
+
+            impl From<Range<$t>> for SliceArg {
+                fn from(range: Range<$t>) -> Self {
+                    SliceArg::Range { start: range.start as isize, end: Some(range.end as isize), step: 1 }
+                }
+            }
+
+            impl From<RangeInclusive<$t>> for SliceArg {
+                fn from(range: RangeInclusive<$t>) -> Self {
+                    let end: isize = *range.end() as isize + 1;
+                    // `..=-1` selects up to the last position
+                    let end: Option<isize> = if end == 0 { None } else { Some(end) };
+                    SliceArg::Range { start: *range.start() as isize, end, step: 1 }
+                }
+            }
+
+            impl From<RangeFrom<$t>> for SliceArg {
+                fn from(range: RangeFrom<$t>) -> Self {
+                    SliceArg::Range { start: range.start as isize, end: None, step: 1 }
+                }
+            }
+
+            impl From<RangeTo<$t>> for SliceArg {
+                fn from(range: RangeTo<$t>) -> Self {
+                    SliceArg::Range { start: 0, end: Some(range.end as isize), step: 1 }
+                }
+            }
+
+            impl From<RangeToInclusive<$t>> for SliceArg {
+                fn from(range: RangeToInclusive<$t>) -> Self {
+                    let end: isize = range.end as isize + 1;
+                    let end: Option<isize> = if end == 0 { None } else { Some(end) };
+                    SliceArg::Range { start: 0, end, step: 1 }
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_arg_from!(usize, isize, i32);
+
+/// Slicing specification of an NdArray with `K` axes. Typically built using the [`s!`](crate::s) macro
+///
+/// ## Example
+///
+/// ```
+/// use ndim::s;
+/// use ndim::slice::{SliceArg, SliceInfo};
+///
+/// # fn main() {
+/// let info: SliceInfo<3> = s![1..3, .., 2];
+/// assert_eq!(info.out_ndim(), 2); // index on the last axis drops it
+/// assert_eq!(info.args()[2], SliceArg::Index(2));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceInfo<const K: usize> {
+    args: [SliceArg; K],
+}
+
+impl<const K: usize> SliceInfo<K> {
+    /// Create a slicing specification from an argument for each axis
+    pub fn new(args: [SliceArg; K]) -> Self {
+        SliceInfo { args }
+    }
+
+    /// Return the slicing argument of each axis
+    pub fn args(&self) -> &[SliceArg; K] {
+        &self.args
+    }
+
+    /// Return the number of dimensions left after slicing, i.e., the count of range arguments
+    pub fn out_ndim(&self) -> usize {
+        self.args.iter().filter(|arg| !arg.is_index()).count()
+    }
+
+    /// Return the shape after slicing an NdArray of shape `shape`. Indexed axes are dropped
+    ///
+    /// ## Panics
+    /// If an index is not within its axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::s;
+    /// #
+    /// # fn main() {
+    /// let info = s![1..3, ..;2, -1];
+    /// assert_eq!(info.out_shape(&[4, 5, 6]), vec![2, 3]);
+    /// # }
+    /// ```
+    pub fn out_shape(&self, shape: &[usize; K]) -> Vec<usize> {
+        self.args
+            .iter()
+            .zip(shape.iter())
+            .filter_map(|(arg, len)| {
+                let (_, count, _) = arg.resolve(*len);
+                if arg.is_index() {
+                    None
+                } else {
+                    Some(count)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Build a [`SliceInfo`](crate::slice::SliceInfo) from a comma separated list of slicing arguments, similar to ndarray's `s!`
+///
+/// Each argument is one of:
+/// + a range (`a..b`, `a..`, `..b`, `a..=b`, `..`), selecting positions along the axis
+/// + a range followed by a step (`a..b;step`), selecting every `step`-th position
+/// + an index (`i`), selecting a single position and dropping the axis
+///
+/// ## Example
+///
+/// ```
+/// use ndim::s;
+/// use ndim::slice::SliceArg;
+///
+/// # fn main() {
+/// let info = s![1..3, ..;2, -1];
+/// assert_eq!(
+///     *info.args(),
+///     [
+///         SliceArg::Range { start: 1, end: Some(3), step: 1 },
+///         SliceArg::Range { start: 0, end: None, step: 2 },
+///         SliceArg::Index(-1),
+///     ]
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! s {
+    ($($arg:expr $(;$step:expr)?),* $(,)?) => {
+        $crate::slice::SliceInfo::new([
+            $($crate::slice::SliceArg::from($arg)$(.step_by($step))?),*
+        ])
+    };
+}
+
+#[cfg(test)]
+mod slice_t {
+    use crate::slice::{SliceArg, SliceInfo};
+
+    // Test the conversion of every range type into a slicing argument
+    #[test]
+    fn slice_arg_from_t() {
+        assert_eq!(
+            SliceArg::from(..),
+            SliceArg::Range {
+                start: 0,
+                end: None,
+                step: 1
+            }
+        );
+        assert_eq!(
+            SliceArg::from(2usize..=4),
+            SliceArg::Range {
+                start: 2,
+                end: Some(5),
+                step: 1
+            }
+        );
+        assert_eq!(
+            SliceArg::from(..=-1),
+            SliceArg::Range {
+                start: 0,
+                end: None,
+                step: 1
+            }
+        );
+        assert_eq!(SliceArg::from(-3isize), SliceArg::Index(-3));
+    }
+
+    // Test resolving ranges with steps, negative values, and out of axis ends
+    #[test]
+    fn resolve_t() {
+        assert_eq!(SliceArg::from(..).step_by(3).resolve(10), (0, 4, 3));
+        assert_eq!(SliceArg::from(-3..).resolve(10), (7, 3, 1));
+        assert_eq!(SliceArg::from(8..20).step_by(4).resolve(10), (8, 1, 4));
+        let reversed = SliceArg::Range {
+            start: 6,
+            end: Some(2),
+            step: 1,
+        };
+        assert_eq!(reversed.resolve(10), (6, 0, 1));
+    }
+
+    // Test the macro with mixed ranges, steps, and indices
+    #[test]
+    fn slice_macro_t() {
+        let info: SliceInfo<4> = s![.., 1..;2, 3, ..-1];
+        assert_eq!(info.out_ndim(), 3);
+        assert_eq!(info.out_shape(&[2, 6, 4, 5]), vec![2, 3, 4]);
+    }
+
+    // Test resolving an index which is not within the axis
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn resolve_index_out_of_bounds_t() {
+        SliceArg::from(-6).resolve(5);
+    }
+
+    // Test applying a step to an index
+    #[test]
+    #[should_panic(expected = "Step cannot be applied to an index")]
+    fn step_on_index_t() {
+        SliceArg::from(1).step_by(2);
+    }
+}
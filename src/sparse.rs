@@ -0,0 +1,405 @@
+use std::{ops::Mul, thread};
+
+use num_traits::Zero;
+
+use crate::core::NdArray;
+
+/// Fill every row of `out` (rows of `row_len` elements) using `fill(row, out_row)`, split across `threads` scoped threads
+fn fill_rows<T, F>(out: &mut [T], row_len: usize, threads: usize, fill: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if row_len == 0 || out.is_empty() {
+        return;
+    }
+    let rows: usize = out.len() / row_len;
+    let per_thread: usize = rows.div_ceil(threads.clamp(1, rows));
+    if per_thread == rows {
+        out.chunks_mut(row_len)
+            .enumerate()
+            .for_each(|(row, out_row)| fill(row, out_row));
+        return;
+    }
+
+    thread::scope(|scope| {
+        for (chunk, block) in out.chunks_mut(per_thread * row_len).enumerate() {
+            let fill = &fill;
+            scope.spawn(move || {
+                for (pos, out_row) in block.chunks_mut(row_len).enumerate() {
+                    fill(chunk * per_thread + pos, out_row);
+                }
+            });
+        }
+    });
+}
+
+/// Sparse matrix in the compressed sparse row (CSR) format. The column indices and values of row `i` are `indices[indptr[i]..indptr[i + 1]]` and `data[indptr[i]..indptr[i + 1]]`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::sparse::CsrMatrix;
+/// #
+/// # fn main() {
+/// // [[1, 0, 2],
+/// //  [0, 0, 3]]
+/// let a = CsrMatrix::from_triplets([2, 3], &[(0, 0, 1), (0, 2, 2), (1, 2, 3)]);
+/// assert_eq!(a.nnz(), 3);
+/// assert_eq!(a.indptr(), &[0, 2, 3]);
+///
+/// let x = NdArray::<i32, 1>::from(&[1, 1, 1], [3]);
+/// assert_eq!(a.matvec(&x).as_slice(), &[3, 3]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    shape: [usize; 2],
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    data: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Create a CSR matrix of `shape` from its row pointers `indptr`, column `indices` and values `data`
+    ///
+    /// ## Panics
+    /// If the arrays do not describe a valid CSR matrix, i.e. `indptr` does not have `rows + 1` non-decreasing elements ending at `data.len()`, `indices` and `data` differ in length, or a column index is not within the shape, panics; message shown is **Invalid CSR matrix of Shape(\[...\])**
+    pub fn new(shape: [usize; 2], indptr: Vec<usize>, indices: Vec<usize>, data: Vec<T>) -> Self {
+        let valid: bool = indptr.len() == shape[0] + 1
+            && indptr[0] == 0
+            && indptr.windows(2).all(|pair| pair[0] <= pair[1])
+            && indptr[shape[0]] == data.len()
+            && indices.len() == data.len()
+            && indices.iter().all(|col| *col < shape[1]);
+        if !valid {
+            panic!("Invalid CSR matrix of Shape({:?})", shape);
+        }
+
+        CsrMatrix {
+            shape,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Return the shape `[rows, cols]` of the matrix
+    pub fn shape(&self) -> &[usize; 2] {
+        &self.shape
+    }
+
+    /// Return the number of stored elements
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Return the row pointers
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// Return the column index of every stored element
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Return the value of every stored element
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Return the column indices and values of the stored elements of `row`
+    fn row(&self, row: usize) -> (&[usize], &[T]) {
+        let range = self.indptr[row]..self.indptr[row + 1];
+        (&self.indices[range.clone()], &self.data[range])
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Copy + Zero + Mul<Output = T> + Send + Sync,
+{
+    /// Create a CSR matrix of `shape` from `(row, col, value)` triplets in any order. Values of duplicate positions are summed
+    ///
+    /// ## Panics
+    /// If a position is not within the shape, panics; message shown is **Index out of bounds**
+    pub fn from_triplets(shape: [usize; 2], triplets: &[(usize, usize, T)]) -> Self {
+        if triplets
+            .iter()
+            .any(|(row, col, _)| *row >= shape[0] || *col >= shape[1])
+        {
+            panic!("Index out of bounds");
+        }
+
+        let mut sorted: Vec<(usize, usize, T)> = triplets.to_vec();
+        sorted.sort_by_key(|(row, col, _)| (*row, *col));
+        let mut indptr: Vec<usize> = vec![0; shape[0] + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(sorted.len());
+        let mut data: Vec<T> = Vec::with_capacity(sorted.len());
+        let mut last: Option<(usize, usize)> = None;
+        for (row, col, value) in sorted {
+            if last == Some((row, col)) {
+                let sum: &mut T = data.last_mut().unwrap();
+                *sum = *sum + value;
+                continue;
+            }
+            last = Some((row, col));
+            indptr[row + 1] += 1;
+            indices.push(col);
+            data.push(value);
+        }
+        for row in 0..shape[0] {
+            indptr[row + 1] += indptr[row];
+        }
+
+        CsrMatrix {
+            shape,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Create a CSR matrix from the non-zero elements of the dense matrix `a`
+    pub fn from_dense(a: &NdArray<T, 2>) -> Self {
+        let [rows, cols] = a.view_shape();
+        let mut indptr: Vec<usize> = Vec::with_capacity(rows + 1);
+        let mut indices: Vec<usize> = Vec::new();
+        let mut data: Vec<T> = Vec::new();
+        indptr.push(0);
+        for row in a.as_slice().chunks(cols.max(1)).take(rows) {
+            for (col, value) in row.iter().enumerate() {
+                if !value.is_zero() {
+                    indices.push(col);
+                    data.push(*value);
+                }
+            }
+            indptr.push(data.len());
+        }
+        indptr.resize(rows + 1, 0);
+
+        CsrMatrix {
+            shape: [rows, cols],
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Return the matrix as a dense 2-D NdArray
+    pub fn to_dense(&self) -> NdArray<T, 2> {
+        let [rows, cols] = self.shape;
+        let mut values: Vec<T> = vec![T::zero(); rows * cols];
+        for row in 0..rows {
+            let (indices, data) = self.row(row);
+            for (col, value) in indices.iter().zip(data) {
+                values[row * cols + col] = values[row * cols + col] + *value;
+            }
+        }
+
+        NdArray::from_owned_vec(values, self.shape)
+    }
+
+    /// Return the product of the matrix and the dense vector `x`
+    ///
+    /// ## Panics
+    /// If `x` does not have `cols` elements, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    pub fn matvec(&self, x: &NdArray<T, 1>) -> NdArray<T, 1> {
+        self.matvec_parallel(x, 1)
+    }
+
+    /// Return the product of the matrix and the dense vector `x`, computing the rows on `threads` threads
+    ///
+    /// ## Panics
+    /// If `x` does not have `cols` elements, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    pub fn matvec_parallel(&self, x: &NdArray<T, 1>, threads: usize) -> NdArray<T, 1> {
+        if x.view_shape()[0] != self.shape[1] {
+            panic!(
+                "Shape({:?}) does not match Shape({:?})",
+                x.view_shape(),
+                self.shape
+            );
+        }
+
+        let x: &[T] = x.as_slice();
+        let mut values: Vec<T> = vec![T::zero(); self.shape[0]];
+        fill_rows(&mut values, 1, threads, |row, out| {
+            let (indices, data) = self.row(row);
+            out[0] = indices
+                .iter()
+                .zip(data)
+                .fold(T::zero(), |sum, (col, value)| sum + *value * x[*col]);
+        });
+
+        NdArray::from_owned_vec(values, [self.shape[0]])
+    }
+
+    /// Return the product of the matrix and the dense matrix `b`
+    ///
+    /// ## Panics
+    /// If `b` does not have `cols` rows, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::sparse::CsrMatrix;
+    /// #
+    /// # fn main() {
+    /// let a = CsrMatrix::from_triplets([2, 2], &[(0, 1, 2.0), (1, 0, 1.0)]);
+    /// let b = NdArray::<f64, 2>::from(&[1.0, 2.0, 3.0, 4.0], [2, 2]);
+    /// assert_eq!(a.matmul(&b).as_slice(), &[6.0, 8.0, 1.0, 2.0]);
+    /// assert_eq!(a.rmatmul(&b).as_slice(), &[2.0, 2.0, 4.0, 6.0]);
+    /// # }
+    /// ```
+    pub fn matmul(&self, b: &NdArray<T, 2>) -> NdArray<T, 2> {
+        self.matmul_parallel(b, 1)
+    }
+
+    /// Return the product of the matrix and the dense matrix `b`, computing the rows on `threads` threads
+    ///
+    /// ## Panics
+    /// If `b` does not have `cols` rows, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    pub fn matmul_parallel(&self, b: &NdArray<T, 2>, threads: usize) -> NdArray<T, 2> {
+        let [inner, cols] = b.view_shape();
+        if inner != self.shape[1] {
+            panic!(
+                "Shape({:?}) does not match Shape({:?})",
+                [inner, cols],
+                self.shape
+            );
+        }
+
+        let b: &[T] = b.as_slice();
+        let mut values: Vec<T> = vec![T::zero(); self.shape[0] * cols];
+        fill_rows(&mut values, cols, threads, |row, out| {
+            let (indices, data) = self.row(row);
+            for (k, value) in indices.iter().zip(data) {
+                let b_row: &[T] = &b[k * cols..(k + 1) * cols];
+                out.iter_mut()
+                    .zip(b_row)
+                    .for_each(|(out, b)| *out = *out + *value * *b);
+            }
+        });
+
+        NdArray::from_owned_vec(values, [self.shape[0], cols])
+    }
+
+    /// Return the product of the dense matrix `a` and the matrix, i.e. `a * self`
+    ///
+    /// ## Panics
+    /// If `a` does not have `rows` columns, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    pub fn rmatmul(&self, a: &NdArray<T, 2>) -> NdArray<T, 2> {
+        self.rmatmul_parallel(a, 1)
+    }
+
+    /// Return the product of the dense matrix `a` and the matrix, i.e. `a * self`, computing the rows on `threads` threads
+    ///
+    /// ## Panics
+    /// If `a` does not have `rows` columns, panics; message shown is **Shape(\[...\]) does not match Shape(\[...\])**
+    pub fn rmatmul_parallel(&self, a: &NdArray<T, 2>, threads: usize) -> NdArray<T, 2> {
+        let [rows, inner] = a.view_shape();
+        if inner != self.shape[0] {
+            panic!(
+                "Shape({:?}) does not match Shape({:?})",
+                [rows, inner],
+                self.shape
+            );
+        }
+
+        let cols: usize = self.shape[1];
+        let a: &[T] = a.as_slice();
+        let mut values: Vec<T> = vec![T::zero(); rows * cols];
+        fill_rows(&mut values, cols, threads, |row, out| {
+            for k in 0..inner {
+                let scale: T = a[row * inner + k];
+                if scale.is_zero() {
+                    continue;
+                }
+                let (indices, data) = self.row(k);
+                for (col, value) in indices.iter().zip(data) {
+                    out[*col] = out[*col] + scale * *value;
+                }
+            }
+        });
+
+        NdArray::from_owned_vec(values, [rows, cols])
+    }
+}
+
+#[cfg(test)]
+mod sparse_t {
+    use crate::{
+        core::{Array, Array2, NdArray},
+        sparse::CsrMatrix,
+    };
+
+    // Dense product of `a` (`n x m`) and `b` (`m x p`)
+    fn dense_matmul(a: &NdArray<i64, 2>, b: &NdArray<i64, 2>) -> Vec<i64> {
+        let ([n, m], p) = (*a.shape(), b.shape()[1]);
+        (0..n * p)
+            .map(|pos| (0..m).map(|k| a[[pos / p, k]] * b[[k, pos % p]]).sum())
+            .collect()
+    }
+
+    // Test the conversions between triplets, dense and CSR matrices
+    // Check if duplicate triplets are summed
+    #[test]
+    fn from_triplets_t() {
+        let a = CsrMatrix::from_triplets([3, 4], &[(2, 1, 5), (0, 3, 1), (2, 1, 2), (0, 0, 4)]);
+        assert_eq!(a.indptr(), &[0, 2, 2, 3]);
+        assert_eq!(a.indices(), &[0, 3, 1]);
+        assert_eq!(a.data(), &[4, 1, 7]);
+
+        let dense = a.to_dense();
+        assert_eq!(dense.as_slice(), &[4, 0, 0, 1, 0, 0, 0, 0, 0, 7, 0, 0]);
+        assert_eq!(CsrMatrix::from_dense(&dense), a);
+    }
+
+    // Test the products of a CSR matrix with dense vectors and matrices, serial and parallel
+    // Check if they match the dense products
+    #[test]
+    fn products_t() {
+        let values: Vec<i64> = (0..30)
+            .map(|x| if x % 3 == 0 { x - 10 } else { 0 })
+            .collect();
+        let dense: NdArray<i64, 2> = Array2::<i64>::from(&values, [5, 6]);
+        let sparse = CsrMatrix::from_dense(&dense);
+        let right_values: Vec<i64> = (0..18).map(|x| x - 4).collect();
+        let right = Array2::<i64>::from(&right_values, [6, 3]);
+        let left_values: Vec<i64> = (0..10).map(|x| 2 * x - 7).collect();
+        let left = Array2::<i64>::from(&left_values, [2, 5]);
+
+        for threads in [1, 2, 4, 16] {
+            assert_eq!(
+                sparse.matmul_parallel(&right, threads).as_slice(),
+                &dense_matmul(&dense, &right)[..]
+            );
+            assert_eq!(
+                sparse.rmatmul_parallel(&left, threads).as_slice(),
+                &dense_matmul(&left, &dense)[..]
+            );
+        }
+
+        let x = Array::<i64>::from(&[1, -1, 2, 0, 3, 1], [6]);
+        let expected: Vec<i64> = (0..5)
+            .map(|i| (0..6).map(|j| dense[[i, j]] * x[[j]]).sum())
+            .collect();
+        assert_eq!(sparse.matvec(&x).as_slice(), &expected[..]);
+        assert_eq!(sparse.matvec_parallel(&x, 3).as_slice(), &expected[..]);
+
+        // an empty NdArray created using NdArray::<T, N>::new() is a 0x0 matrix
+        let empty = CsrMatrix::from_dense(&Array2::<i64>::new());
+        assert!(empty.matmul(&Array2::<i64>::new()).is_empty());
+        assert!(empty.matvec(&Array::<i64>::new()).is_empty());
+    }
+
+    // Test a CSR matrix with an invalid structure
+    #[test]
+    #[should_panic(expected = "Invalid CSR matrix of Shape([2, 2])")]
+    fn invalid_csr_t() {
+        CsrMatrix::new([2, 2], vec![0, 1, 1], vec![2], vec![1.0]);
+    }
+}
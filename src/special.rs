@@ -0,0 +1,274 @@
+use std::f64::consts::PI;
+
+use num_traits::Float;
+
+use crate::core::NdArray;
+
+/// Coefficients of the Lanczos approximation of the gamma function with `g = 7` and 9 terms
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+const LANCZOS_G: f64 = 7.0;
+
+/// Apply `f` to every element of `arr` in `f64` precision
+fn map_f64<T: Float, const N: usize>(arr: &NdArray<T, N>, f: impl Fn(f64) -> f64) -> NdArray<T, N> {
+    arr.map(|x| T::from(f(x.to_f64().unwrap_or(f64::NAN))).unwrap_or(T::nan()))
+}
+
+/// Error function of `x`. A series with positive terms for `|x| < 3`, else the continued fraction of the complementary error function
+fn erf_f64(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    let ax: f64 = x.abs();
+    let value: f64 = if ax < 3.0 {
+        // erf(x) = 2 / sqrt(pi) * exp(-x^2) * sum(2^n x^(2n + 1) / (1 * 3 * .. * (2n + 1)))
+        let (mut term, mut sum): (f64, f64) = (ax, ax);
+        let mut n: f64 = 0.0;
+        while term > sum * f64::EPSILON {
+            n += 1.0;
+            term *= 2.0 * ax * ax / (2.0 * n + 1.0);
+            sum += term;
+        }
+        2.0 / PI.sqrt() * (-ax * ax).exp() * sum
+    } else {
+        // erfc(x) = exp(-x^2) / sqrt(pi) / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ..))))
+        let mut fraction: f64 = ax;
+        for k in (1..=60).rev() {
+            fraction = ax + k as f64 / 2.0 / fraction;
+        }
+        1.0 - (-ax * ax).exp() / PI.sqrt() / fraction
+    };
+
+    value.copysign(x)
+}
+
+/// Lanczos sum and shifted argument `(sum, x + g + 0.5)` of `x + 1`, for `x >= -0.5`
+fn lanczos(x: f64) -> (f64, f64) {
+    let sum: f64 = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    (sum, x + LANCZOS_G + 0.5)
+}
+
+/// Gamma function of `x`, with the reflection formula for `x < 0.5`
+fn gamma_f64(x: f64) -> f64 {
+    if x <= 0.0 && x == x.floor() {
+        // poles at zero and the negative integers
+        return if x == 0.0 {
+            f64::INFINITY.copysign(x)
+        } else {
+            f64::NAN
+        };
+    }
+    if x < 0.5 {
+        return PI / ((PI * x).sin() * gamma_f64(1.0 - x));
+    }
+
+    let (sum, t) = lanczos(x - 1.0);
+    // split `t^(x - 0.5)` to avoid an intermediate overflow
+    let half: f64 = t.powf((x - 0.5) / 2.0);
+    (2.0 * PI).sqrt() * half * (half * (-t).exp()) * sum
+}
+
+/// Natural logarithm of the absolute value of the gamma function of `x`, with the reflection formula for `x < 0.5`
+fn lgamma_f64(x: f64) -> f64 {
+    if x <= 0.0 && x == x.floor() {
+        return f64::INFINITY;
+    }
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - lgamma_f64(1.0 - x);
+    }
+
+    let (sum, t) = lanczos(x - 1.0);
+    0.5 * (2.0 * PI).ln() + (x - 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Return the error function `erf(x) = 2 / sqrt(pi) * integral(exp(-t^2), 0, x)` of every element
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::erf;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[0.0, 1.0, -1.0, 10.0], [4]);
+/// let y = erf(&x);
+/// assert!((y[[1]] - 0.8427007929497149).abs() < 1e-15);
+/// assert_eq!(y[[2]], -y[[1]]);
+/// assert_eq!(y[[3]], 1.0);
+/// # }
+/// ```
+pub fn erf<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    map_f64(arr, erf_f64)
+}
+
+/// Return the gamma function of every element. The poles at zero and at the negative integers are infinity and NaN, respectively
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::gamma;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[5.0, 0.5, -1.0], [3]);
+/// let y = gamma(&x);
+/// assert!((y[[0]] - 24.0).abs() < 1e-12);
+/// assert!((y[[1]] - std::f64::consts::PI.sqrt()).abs() < 1e-14);
+/// assert!(y[[2]].is_nan());
+/// # }
+/// ```
+pub fn gamma<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    map_f64(arr, gamma_f64)
+}
+
+/// Return the natural logarithm of the absolute value of the gamma function of every element, which does not overflow for large values
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::lgamma;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[1.0, 100.0], [2]);
+/// let y = lgamma(&x);
+/// assert!(y[[0]].abs() < 1e-14);
+/// assert!((y[[1]] - 359.1342053695754).abs() < 1e-10);
+/// # }
+/// ```
+pub fn lgamma<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    map_f64(arr, lgamma_f64)
+}
+
+/// Return `exp(x) - 1` of every element, accurate for values close to zero
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::expm1;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[1e-10, 0.0], [2]);
+/// assert_eq!(expm1(&x).as_slice(), &[1.00000000005e-10, 0.0]);
+/// # }
+/// ```
+pub fn expm1<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    arr.map(|x| x.exp_m1())
+}
+
+/// Return `ln(1 + x)` of every element, accurate for values close to zero
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::log1p;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f32, 1>::from(&[1e-8, -1.0], [2]);
+/// let y = log1p(&x);
+/// assert_eq!(y[[0]], 1e-8);
+/// assert_eq!(y[[1]], f32::NEG_INFINITY);
+/// # }
+/// ```
+pub fn log1p<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    arr.map(|x| x.ln_1p())
+}
+
+/// Return the normalized sinc function `sin(pi * x) / (pi * x)` of every element, which is 1 at zero, similar to `numpy.sinc`
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// # use ndim::special::sinc;
+/// #
+/// # fn main() {
+/// let x = NdArray::<f64, 1>::from(&[0.0, 0.5, 1.0], [3]);
+/// let y = sinc(&x);
+/// assert_eq!(y[[0]], 1.0);
+/// assert!((y[[1]] - 2.0 / std::f64::consts::PI).abs() < 1e-15);
+/// assert!(y[[2]].abs() < 1e-15);
+/// # }
+/// ```
+pub fn sinc<T: Float, const N: usize>(arr: &NdArray<T, N>) -> NdArray<T, N> {
+    map_f64(arr, |x| {
+        if x == 0.0 {
+            1.0
+        } else {
+            (PI * x).sin() / (PI * x)
+        }
+    })
+}
+
+#[cfg(test)]
+mod special_t {
+    use crate::{
+        core::{Array, Array2},
+        special::{erf, expm1, gamma, lgamma, log1p, sinc},
+    };
+
+    // Test the error function against reference values on both sides of the series and continued fraction
+    #[test]
+    fn erf_t() {
+        let x = Array2::<f64>::from(&[0.5, 2.0, 2.999, 3.0, 4.5, -0.1], [3, 2]);
+        let expected: [f64; 6] = [
+            0.5204998778130465,
+            0.9953222650189527,
+            0.9999777698314002,
+            0.9999779095030014,
+            0.9999999998033839,
+            -0.1124629160182849,
+        ];
+        for (y, expected) in erf(&x).as_slice().iter().zip(expected) {
+            assert!((y - expected).abs() < 1e-15, "{} != {}", y, expected);
+        }
+    }
+
+    // Test the gamma functions with negative, large and pole arguments
+    #[test]
+    fn gamma_t() {
+        let x = Array::<f64>::from(&[-0.5, 150.0, 0.0, -3.0, 2.5], [5]);
+        let y = gamma(&x);
+        assert!((y[[0]] + 3.5449077018110318).abs() < 1e-14);
+        assert!((y[[1]] / 3.8089226376305703e260 - 1.0).abs() < 1e-12);
+        assert_eq!(y[[2]], f64::INFINITY);
+        assert!(y[[3]].is_nan());
+
+        let ln = lgamma(&x);
+        assert!((ln[[0]] - 1.2655121234846454).abs() < 1e-14);
+        assert!((ln[[1]] - 600.0094705553274).abs() < 1e-10);
+        assert_eq!(ln[[3]], f64::INFINITY);
+        assert!((ln[[4]] - y[[4]].ln()).abs() < 1e-14);
+    }
+
+    // Test the functions on single precision NdArrays
+    #[test]
+    fn single_precision_t() {
+        let x = Array::<f32>::from(&[1.0, 4.0], [2]);
+        assert_eq!(gamma(&x).as_slice(), &[1.0, 6.0]);
+        assert_eq!(erf(&x)[[1]], 1.0);
+    }
+
+    // Test the functions on an empty NdArray created using NdArray::<T, N>::new()
+    #[test]
+    fn new_t() {
+        let x = Array2::<f64>::new();
+        for f in [erf, gamma, lgamma, expm1, log1p, sinc] {
+            assert!(f(&x).is_empty());
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::ops::{Index, IndexMut, Mul};
+
+use num_traits::Zero;
+
+use crate::core::NdArray;
+
+/// Two dimensional (2-D) array of type T with a shape of `[R, C]` known at compile time. The elements are stored inline in row-major (C) order
+///
+/// Shape mismatches of operations such as [`StaticArray::matmul(...)`](StaticArray::matmul) are compile errors. Use a shape of `[R, 1]` or `[1, C]` for vectors
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::static_array::StaticArray;
+/// #
+/// # fn main() {
+/// let a = StaticArray::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]);
+/// let b = StaticArray::<i32, 3, 1>::from_array([[1], [0], [-1]]);
+/// let c: StaticArray<i32, 2, 1> = a.matmul(&b);
+/// assert_eq!(c.as_array(), &[[-2], [-2]]);
+/// # }
+/// ```
+///
+/// The inner dimensions must match:
+///
+/// ```compile_fail
+/// # use ndim::static_array::StaticArray;
+/// #
+/// # fn main() {
+/// let a = StaticArray::<i32, 2, 3>::from_array([[1, 2, 3], [4, 5, 6]]);
+/// let c = a.matmul(&a); // expected `StaticArray<i32, 3, _>`, found `StaticArray<i32, 2, 3>`
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StaticArray<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> StaticArray<T, R, C> {
+    /// Create a static array from its rows
+    pub const fn from_array(data: [[T; C]; R]) -> Self {
+        StaticArray { data }
+    }
+
+    /// Return the shape `[R, C]` of the static array
+    pub const fn shape(&self) -> [usize; 2] {
+        [R, C]
+    }
+
+    /// Return the rows of the static array
+    pub fn as_array(&self) -> &[[T; C]; R] {
+        &self.data
+    }
+
+    /// Consume the static array and return its rows
+    pub fn into_array(self) -> [[T; C]; R] {
+        self.data
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> StaticArray<T, R, C> {
+    /// Create a static array with every element set to `value`
+    pub fn full(value: T) -> Self {
+        StaticArray {
+            data: [[value; C]; R],
+        }
+    }
+
+    /// Return the transpose of the static array, of shape `[C, R]`
+    pub fn transpose(&self) -> StaticArray<T, C, R> {
+        StaticArray {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| self.data[j][i])),
+        }
+    }
+
+    /// Stack the rows of `other` below the rows of `self`. The output number of rows `OUT` is usually inferred
+    ///
+    /// ## Note
+    /// If `OUT` is not `R + R2`, compilation fails (when the function is instantiated)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::static_array::StaticArray;
+    /// #
+    /// # fn main() {
+    /// let top = StaticArray::<u8, 1, 2>::from_array([[1, 2]]);
+    /// let bottom = StaticArray::<u8, 2, 2>::from_array([[3, 4], [5, 6]]);
+    /// let stacked: StaticArray<u8, 3, 2> = top.concat_rows(&bottom);
+    /// assert_eq!(stacked.as_array(), &[[1, 2], [3, 4], [5, 6]]);
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use ndim::static_array::StaticArray;
+    /// #
+    /// # fn main() {
+    /// let top = StaticArray::<u8, 1, 2>::from_array([[1, 2]]);
+    /// let stacked: StaticArray<u8, 4, 2> = top.concat_rows(&top);
+    /// # }
+    /// ```
+    pub fn concat_rows<const R2: usize, const OUT: usize>(
+        &self,
+        other: &StaticArray<T, R2, C>,
+    ) -> StaticArray<T, OUT, C> {
+        const {
+            assert!(
+                R + R2 == OUT,
+                "Output rows must be the sum of the input rows"
+            )
+        };
+        StaticArray {
+            data: std::array::from_fn(|i| {
+                if i < R {
+                    self.data[i]
+                } else {
+                    other.data[i - R]
+                }
+            }),
+        }
+    }
+
+    /// Place the columns of `other` to the right of the columns of `self`. The output number of columns `OUT` is usually inferred
+    ///
+    /// ## Note
+    /// If `OUT` is not `C + C2`, compilation fails (when the function is instantiated)
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::static_array::StaticArray;
+    /// #
+    /// # fn main() {
+    /// let left = StaticArray::<u8, 2, 1>::from_array([[1], [2]]);
+    /// let right = StaticArray::<u8, 2, 2>::from_array([[3, 4], [5, 6]]);
+    /// let joined: StaticArray<u8, 2, 3> = left.concat_cols(&right);
+    /// assert_eq!(joined.as_array(), &[[1, 3, 4], [2, 5, 6]]);
+    /// # }
+    /// ```
+    pub fn concat_cols<const C2: usize, const OUT: usize>(
+        &self,
+        other: &StaticArray<T, R, C2>,
+    ) -> StaticArray<T, R, OUT> {
+        const {
+            assert!(
+                C + C2 == OUT,
+                "Output columns must be the sum of the input columns"
+            )
+        };
+        StaticArray {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    if j < C {
+                        self.data[i][j]
+                    } else {
+                        other.data[i][j - C]
+                    }
+                })
+            }),
+        }
+    }
+
+    /// Return the static array as a runtime-shaped NdArray of shape `[R, C]`
+    pub fn to_ndarray(&self) -> NdArray<T, 2> {
+        let values: Vec<T> = self.data.iter().flatten().copied().collect();
+        NdArray::from_owned_vec(values, [R, C])
+    }
+
+    /// Create a static array from a runtime-shaped NdArray. Returns `None` if the shape of `array` is not `[R, C]`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::static_array::StaticArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 2>::ones([2, 2]);
+    /// let fixed = StaticArray::<f32, 2, 2>::from_ndarray(&arr).unwrap();
+    /// assert_eq!(fixed.to_ndarray().as_slice(), arr.as_slice());
+    /// assert!(StaticArray::<f32, 3, 2>::from_ndarray(&arr).is_none());
+    /// # }
+    /// ```
+    pub fn from_ndarray(array: &NdArray<T, 2>) -> Option<Self> {
+        if array.view_shape() != [R, C] {
+            return None;
+        }
+
+        let values: &[T] = array.as_slice();
+        Some(StaticArray {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| values[i * C + j])),
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> StaticArray<T, R, C>
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    /// Create a static array filled with zeros
+    pub fn zeros() -> Self {
+        Self::full(T::zero())
+    }
+
+    /// Return the matrix product of `self` (`[R, C]`) and `other` (`[C, K]`), of shape `[R, K]`
+    pub fn matmul<const K: usize>(&self, other: &StaticArray<T, C, K>) -> StaticArray<T, R, K> {
+        StaticArray {
+            data: std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    (0..C).fold(T::zero(), |sum, k| sum + self.data[i][k] * other.data[k][j])
+                })
+            }),
+        }
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> From<StaticArray<T, R, C>> for NdArray<T, 2> {
+    fn from(array: StaticArray<T, R, C>) -> Self {
+        array.to_ndarray()
+    }
+}
+
+/// Use for indexing a static array
+///
+/// ## Note
+/// `Panics` if the index of any axis is not within the shape
+impl<T, const R: usize, const C: usize> Index<[usize; 2]> for StaticArray<T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        &self.data[index[0]][index[1]]
+    }
+}
+
+/// Use for mutating a static array
+///
+/// ## Note
+/// `Panics` if the index of any axis is not within the shape
+impl<T, const R: usize, const C: usize> IndexMut<[usize; 2]> for StaticArray<T, R, C> {
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        &mut self.data[index[0]][index[1]]
+    }
+}
+
+#[cfg(test)]
+mod static_array_t {
+    use crate::{
+        core::{Array2, NdArray},
+        static_array::StaticArray,
+    };
+
+    // Test the matrix product and transpose of static arrays
+    // Check if (a b)^T = b^T a^T
+    #[test]
+    fn matmul_transpose_t() {
+        let a = StaticArray::<i64, 2, 3>::from_array([[1, -2, 3], [0, 4, -1]]);
+        let b = StaticArray::<i64, 3, 4>::from_array([[2, 0, 1, 1], [1, 3, 0, -2], [0, 1, 5, 2]]);
+        let ab: StaticArray<i64, 2, 4> = a.matmul(&b);
+        assert_eq!(ab.as_array(), &[[0, -3, 16, 11], [4, 11, -5, -10]]);
+        assert_eq!(ab.transpose(), b.transpose().matmul(&a.transpose()));
+        assert_eq!(ab.shape(), [2, 4]);
+    }
+
+    // Test the conversions between static arrays and NdArray
+    #[test]
+    fn ndarray_conversion_t() {
+        let values: Vec<u16> = (0..6).collect();
+        let arr: NdArray<u16, 2> = Array2::<u16>::from(&values, [3, 2]);
+        let mut fixed = StaticArray::<u16, 3, 2>::from_ndarray(&arr).unwrap();
+        assert_eq!(fixed[[2, 1]], 5);
+        fixed[[0, 0]] = 9;
+
+        let back: NdArray<u16, 2> = fixed.into();
+        assert_eq!(back.shape(), &[3, 2]);
+        assert_eq!(back.as_slice(), &[9, 1, 2, 3, 4, 5]);
+        assert!(StaticArray::<u16, 2, 3>::from_ndarray(&arr).is_none());
+        assert!(StaticArray::<u16, 1, 1>::from_ndarray(&NdArray::new()).is_none());
+        assert!(StaticArray::<u16, 0, 0>::from_ndarray(&NdArray::new()).is_some());
+    }
+
+    // Test stacking static arrays along both axes
+    #[test]
+    fn concat_t() {
+        let a = StaticArray::<f32, 2, 2>::zeros();
+        let b = StaticArray::<f32, 2, 2>::full(1.0);
+        let rows: StaticArray<f32, 4, 2> = a.concat_rows(&b);
+        let cols: StaticArray<f32, 2, 4> = a.concat_cols(&b);
+        assert_eq!(rows.transpose(), cols);
+    }
+}
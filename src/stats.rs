@@ -0,0 +1,520 @@
+use num_traits::{ToPrimitive, Zero};
+
+use crate::{core::NdArray, view::ArrayView};
+
+/// Reduce every 1-D lane along `axis` with `reduce`, returning an NdArray of one lower dimension (`M = N - 1`). The lanes are passed as contiguous slices
+///
+/// ## Panics
+/// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+pub(crate) fn reduce_lanes<T, U, F, const N: usize, const M: usize>(
+    array: &NdArray<T, N>,
+    axis: usize,
+    mut reduce: F,
+) -> NdArray<U, M>
+where
+    T: Copy,
+    F: FnMut(&[T]) -> U,
+{
+    if M + 1 != N {
+        panic!("Dimension({}) must be one less than Dimension({})", M, N);
+    }
+
+    let mut lane: Vec<T> = Vec::new();
+    let values: Vec<U> = array
+        .lanes(axis)
+        .map(|view: ArrayView<'_, T, 1>| {
+            lane.clear();
+            lane.extend((0..view.len()).map(|i| view[[i]]));
+            reduce(&lane)
+        })
+        .collect();
+
+    let full: [usize; N] = array.view_shape();
+    let mut shape: [usize; M] = [0; M];
+    for (pos, dim) in (0..N).filter(|dim| *dim != axis).enumerate() {
+        shape[pos] = full[dim];
+    }
+    NdArray::from_owned_vec(values, shape)
+}
+
+/// Count, mean and central moments `(n, mean, m2, m3, m4)` of `values`, where `mk = sum((x - mean)^k) / n`
+fn moments<T: ToPrimitive>(values: &[T]) -> (f64, f64, f64, f64, f64) {
+    let values: Vec<f64> = values
+        .iter()
+        .map(|x| x.to_f64().unwrap_or(f64::NAN))
+        .collect();
+    let n: f64 = values.len() as f64;
+    let mean: f64 = values.iter().sum::<f64>() / n;
+
+    let (mut m2, mut m3, mut m4) = (0.0, 0.0, 0.0);
+    for x in values {
+        let d: f64 = x - mean;
+        m2 += d * d;
+        m3 += d * d * d;
+        m4 += d * d * d * d;
+    }
+    (n, mean, m2 / n, m3 / n, m4 / n)
+}
+
+/// Aggregate the lanes along `axis` into one output position per distinct label with `reduce`, where `labels` holds the label of every position along `axis`. Returns the sorted distinct labels and the NdArray whose `axis` has one position per label
+///
+/// ## Panics
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+/// - If the length of `labels` is not the length of `axis`, panics; message shown is **Shape(`labels.shape()`) does not match Shape([`axis length`])**
+fn group_lanes<T, U, L, F, const N: usize>(
+    array: &NdArray<T, N>,
+    labels: &NdArray<L, 1>,
+    axis: usize,
+    mut reduce: F,
+) -> (NdArray<L, 1>, NdArray<U, N>)
+where
+    T: Copy,
+    L: Copy + Ord,
+    F: FnMut(&[T]) -> U,
+{
+    if axis >= N {
+        panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+    }
+    let mut shape: [usize; N] = array.view_shape();
+    if labels.view_shape()[0] != shape[axis] {
+        panic!(
+            "Shape({:?}) does not match Shape({:?})",
+            labels.view_shape(),
+            [shape[axis]]
+        );
+    }
+
+    let mut keys: Vec<L> = labels.as_slice().to_vec();
+    keys.sort_unstable();
+    keys.dedup();
+    let groups: Vec<usize> = labels
+        .as_slice()
+        .iter()
+        .map(|label| keys.binary_search(label).unwrap_or_default())
+        .collect();
+
+    // lanes are in row-major order of the other axes, i.e. `lane = outer * inner + pos` for the axes before and after `axis`
+    let inner: usize = shape[axis + 1..].iter().product();
+    shape[axis] = keys.len();
+    let mut values: Vec<Option<U>> = (0..shape.iter().product()).map(|_| None).collect();
+    let mut members: Vec<Vec<T>> = vec![Vec::new(); keys.len()];
+    for (lane, view) in array.lanes(axis).enumerate() {
+        members.iter_mut().for_each(|group| group.clear());
+        for (pos, group) in groups.iter().enumerate() {
+            members[*group].push(view[[pos]]);
+        }
+
+        let (outer, pos): (usize, usize) = (lane / inner, lane % inner);
+        for (group, group_values) in members.iter().enumerate() {
+            values[(outer * keys.len() + group) * inner + pos] = Some(reduce(group_values));
+        }
+    }
+
+    let values: Vec<U> = values.into_iter().flatten().collect();
+    let len: usize = keys.len();
+    (
+        NdArray::from_owned_vec(keys, [len]),
+        NdArray::from_owned_vec(values, shape),
+    )
+}
+
+impl<T: Copy + ToPrimitive, const N: usize> NdArray<T, N> {
+    /// Return the arithmetic mean along `axis`, as an NdArray of one lower dimension (`M = N - 1`). Lanes without elements have a NaN mean
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// assert_eq!(arr.mean::<1>(0).as_slice(), &[2.5, 3.5, 4.5]);
+    /// assert_eq!(arr.mean::<1>(1).as_slice(), &[2.0, 5.0]);
+    /// # }
+    /// ```
+    pub fn mean<const M: usize>(&self, axis: usize) -> NdArray<f64, M> {
+        reduce_lanes(self, axis, |lane| moments(lane).1)
+    }
+
+    /// Return the variance along `axis`, with `ddof` delta degrees of freedom, i.e. `sum((x - mean)^2) / (n - ddof)`. Use `ddof = 1` for the unbiased sample variance
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[1.0, 2.0, 3.0, 4.0], [4]);
+    /// assert_eq!(arr.var::<0>(0, 0)[[]], 1.25);
+    /// assert_eq!(arr.var::<0>(0, 1)[[]], 5.0 / 3.0);
+    /// # }
+    /// ```
+    pub fn var<const M: usize>(&self, axis: usize, ddof: usize) -> NdArray<f64, M> {
+        reduce_lanes(self, axis, |lane| {
+            let (n, _, m2, _, _) = moments(lane);
+            m2 * n / (n - ddof as f64)
+        })
+    }
+
+    /// Return the sample skewness along `axis`, i.e. `m3 / m2^1.5` of the central moments. If `bias` is false, the skewness is corrected for the statistical bias (requires more than 2 elements, else NaN)
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 1>::from(&[1, 2, 3, 4, 10], [5]);
+    /// let skew = arr.skew::<0>(0, true)[[]];
+    /// assert!((skew - 1.1384199576606167).abs() < 1e-12); // skewed to the right
+    /// # }
+    /// ```
+    pub fn skew<const M: usize>(&self, axis: usize, bias: bool) -> NdArray<f64, M> {
+        reduce_lanes(self, axis, |lane| {
+            let (n, _, m2, m3, _) = moments(lane);
+            let skew: f64 = m3 / m2.powf(1.5);
+            if bias {
+                skew
+            } else if n > 2.0 {
+                skew * (n * (n - 1.0)).sqrt() / (n - 2.0)
+            } else {
+                f64::NAN
+            }
+        })
+    }
+
+    /// Return the sample kurtosis along `axis`, i.e. `m4 / m2^2` of the central moments. If `fisher` is true, 3 is subtracted so that a normal distribution has a kurtosis of 0 (excess kurtosis). If `bias` is false, the kurtosis is corrected for the statistical bias (requires more than 3 elements, else NaN)
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 1>::from(&[1, 2, 3, 4, 10], [5]);
+    /// let pearson = arr.kurtosis::<0>(0, false, true)[[]];
+    /// let fisher = arr.kurtosis::<0>(0, true, true)[[]];
+    /// assert!((pearson - 2.788).abs() < 1e-12);
+    /// assert!((fisher - pearson + 3.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn kurtosis<const M: usize>(
+        &self,
+        axis: usize,
+        fisher: bool,
+        bias: bool,
+    ) -> NdArray<f64, M> {
+        reduce_lanes(self, axis, |lane| {
+            let (n, _, m2, _, m4) = moments(lane);
+            let mut kurtosis: f64 = m4 / (m2 * m2);
+            if !bias {
+                kurtosis = if n > 3.0 {
+                    ((n * n - 1.0) * kurtosis - 3.0 * (n - 1.0) * (n - 1.0))
+                        / ((n - 2.0) * (n - 3.0))
+                        + 3.0
+                } else {
+                    f64::NAN
+                };
+            }
+            if fisher {
+                kurtosis - 3.0
+            } else {
+                kurtosis
+            }
+        })
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> NdArray<T, N> {
+    /// Return the most frequent value along `axis` and the number of its occurrences, as NdArrays of one lower dimension (`M = N - 1`). If several values are equally frequent, the smallest one is returned, similar to `scipy.stats.mode`
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If the length of `axis` is zero, panics; message shown is **Mode of an empty Axis(`axis`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let labels = NdArray::<u8, 2>::from(&[3, 1, 3, 2, 4, 1], [2, 3]);
+    /// let (mode, count) = labels.mode::<1>(1);
+    /// assert_eq!(mode.as_slice(), &[3, 1]); // tie of 2, 4 and 1 returns the smallest label
+    /// assert_eq!(count.as_slice(), &[2, 1]);
+    /// # }
+    /// ```
+    pub fn mode<const M: usize>(&self, axis: usize) -> (NdArray<T, M>, NdArray<usize, M>) {
+        if axis < N && self.view_shape()[axis] == 0 {
+            panic!("Mode of an empty Axis({})", axis);
+        }
+
+        let modes: NdArray<(T, usize), M> = reduce_lanes(self, axis, |lane| {
+            let mut sorted: Vec<T> = lane.to_vec();
+            sorted.sort_unstable();
+            let mut best: (T, usize) = (sorted[0], 0);
+            for run in sorted.chunk_by(|a, b| a == b) {
+                if run.len() > best.1 {
+                    best = (run[0], run.len());
+                }
+            }
+            best
+        });
+
+        let shape: [usize; M] = *modes.shape();
+        let (values, counts): (Vec<T>, Vec<usize>) = modes.as_slice().iter().copied().unzip();
+        (
+            NdArray::from_owned_vec(values, shape),
+            NdArray::from_owned_vec(counts, shape),
+        )
+    }
+}
+
+impl<T: Copy, const N: usize> NdArray<T, N> {
+    /// Sum the positions along `axis` sharing the same label of `labels` (segment reduction). Returns the sorted distinct labels and an NdArray whose `axis` has one position per label, in the order of the labels
+    ///
+    /// ## Note
+    /// For per-class statistics over a label image, flatten the image and the labels and group along axis 0
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If the length of `labels` is not the length of `axis`, panics; message shown is **Shape(`labels.shape()`) does not match Shape([`axis length`])**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let data = NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6, 7, 8], [2, 4]);
+    /// let labels = NdArray::<u8, 1>::from(&[7, 0, 7, 0], [4]);
+    /// let (keys, sums) = data.groupby_sum(&labels, 1);
+    /// assert_eq!(keys.as_slice(), &[0, 7]);
+    /// assert_eq!(sums.shape(), &[2, 2]);
+    /// assert_eq!(sums.as_slice(), &[6, 4, 14, 12]);
+    /// # }
+    /// ```
+    pub fn groupby_sum<L: Copy + Ord>(
+        &self,
+        labels: &NdArray<L, 1>,
+        axis: usize,
+    ) -> (NdArray<L, 1>, NdArray<T, N>)
+    where
+        T: Zero,
+    {
+        group_lanes(self, labels, axis, |group| {
+            group.iter().fold(T::zero(), |sum, x| sum + *x)
+        })
+    }
+
+    /// Average the positions along `axis` sharing the same label of `labels`. Returns the sorted distinct labels and an NdArray whose `axis` has one position per label
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If the length of `labels` is not the length of `axis`, panics; message shown is **Shape(`labels.shape()`) does not match Shape([`axis length`])**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let data = NdArray::<u8, 1>::from(&[1, 2, 3, 5], [4]);
+    /// let labels = NdArray::<char, 1>::from(&['b', 'a', 'b', 'a'], [4]);
+    /// let (keys, means) = data.groupby_mean(&labels, 0);
+    /// assert_eq!(keys.as_slice(), &['a', 'b']);
+    /// assert_eq!(means.as_slice(), &[3.5, 2.0]);
+    /// # }
+    /// ```
+    pub fn groupby_mean<L: Copy + Ord>(
+        &self,
+        labels: &NdArray<L, 1>,
+        axis: usize,
+    ) -> (NdArray<L, 1>, NdArray<f64, N>)
+    where
+        T: ToPrimitive,
+    {
+        group_lanes(self, labels, axis, |group| moments(group).1)
+    }
+
+    /// Return the maximum of the positions along `axis` sharing the same label of `labels`. Returns the sorted distinct labels and an NdArray whose `axis` has one position per label
+    ///
+    /// ## Note
+    /// NaN values are ignored unless a group only holds NaN values
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If the length of `labels` is not the length of `axis`, panics; message shown is **Shape(`labels.shape()`) does not match Shape([`axis length`])**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let data = NdArray::<f32, 1>::from(&[1.5, -2.0, 0.5, 4.0], [4]);
+    /// let labels = NdArray::<i32, 1>::from(&[1, 1, 2, 1], [4]);
+    /// let (keys, max) = data.groupby_max(&labels, 0);
+    /// assert_eq!(keys.as_slice(), &[1, 2]);
+    /// assert_eq!(max.as_slice(), &[4.0, 0.5]);
+    /// # }
+    /// ```
+    pub fn groupby_max<L: Copy + Ord>(
+        &self,
+        labels: &NdArray<L, 1>,
+        axis: usize,
+    ) -> (NdArray<L, 1>, NdArray<T, N>)
+    where
+        T: PartialOrd,
+    {
+        group_lanes(self, labels, axis, |group| {
+            // every group holds at least one position. A maximum not comparable with itself is NaN
+            group[1..].iter().fold(group[0], |max, x| {
+                if *x > max || max.partial_cmp(&max).is_none() {
+                    *x
+                } else {
+                    max
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod stats_t {
+    use crate::core::{Array, Array2, Array3, NdArray};
+
+    // Test the moment statistics along both axes of a 2-D NdArray
+    // Check if every lane matches the statistics of the 1-D lane
+    #[test]
+    fn moments_2dim_t() {
+        let values: Vec<i32> = vec![1, 2, 3, 4, 10, 5, -1, 0, 7, 2];
+        let data: NdArray<i32, 2> = Array2::<i32>::from(&values, [2, 5]);
+        let row = Array::<i32>::from(&values[..5], [5]);
+
+        assert_eq!(data.mean::<1>(1)[[0]], 4.0);
+        assert_eq!(data.var::<1>(1, 0)[[0]], 10.0);
+        assert!((data.skew::<1>(1, false)[[0]] - 1.6970562748477143).abs() < 1e-12);
+        assert!((data.kurtosis::<1>(1, false, false)[[0]] - 6.152000000000001).abs() < 1e-12);
+        assert!((data.kurtosis::<1>(1, true, false)[[0]] - 3.152000000000001).abs() < 1e-12);
+        assert_eq!(data.skew::<1>(1, true)[[0]], row.skew::<0>(0, true)[[]]);
+
+        let columns = data.var::<1>(0, 1);
+        assert_eq!(columns.shape(), &[5]);
+        assert_eq!(columns.as_slice(), &[8.0, 4.5, 4.5, 4.5, 32.0]);
+        assert_eq!(*Array2::<f64>::new().mean::<1>(0).shape(), [0]);
+    }
+
+    // Test the bias correction with too few elements
+    #[test]
+    fn small_lanes_t() {
+        let data = Array2::<f64>::from(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], [3, 2]);
+        assert!(data
+            .skew::<1>(1, false)
+            .as_slice()
+            .iter()
+            .all(|x| x.is_nan()));
+        assert!(data
+            .kurtosis::<1>(0, true, false)
+            .as_slice()
+            .iter()
+            .all(|x| x.is_nan()));
+        assert_eq!(data.skew::<1>(0, true).as_slice(), &[0.0, 0.0]);
+    }
+
+    // Test the mode along both axes of a 3-D label grid
+    #[test]
+    fn mode_3dim_t() {
+        let labels = Array3::<i64>::from(&[0, 1, 1, 2, 2, 2, 5, 1, -1, 2, 5, -1], [2, 2, 3]);
+        let (mode, count) = labels.mode::<2>(2);
+        assert_eq!(mode.as_slice(), &[1, 2, -1, -1]);
+        assert_eq!(count.as_slice(), &[2, 3, 1, 1]);
+
+        let (mode, count) = labels.mode::<2>(0);
+        assert_eq!(mode.shape(), &[2, 3]);
+        assert_eq!(mode.as_slice(), &[0, 1, -1, 2, 2, -1]);
+        assert_eq!(count.as_slice(), &[1, 2, 1, 2, 1, 1]);
+    }
+
+    // Test the mode of an empty axis
+    #[test]
+    #[should_panic(expected = "Mode of an empty Axis(1)")]
+    fn mode_empty_t() {
+        Array2::<u8>::zeros([2, 0]).mode::<1>(1);
+    }
+
+    // Test the mode of an empty NdArray created using NdArray::<T, N>::new(), whose axes are empty
+    #[test]
+    #[should_panic(expected = "Mode of an empty Axis(0)")]
+    fn mode_new_t() {
+        Array2::<u8>::new().mode::<1>(0);
+    }
+
+    // Test the group-by reductions along the middle axis of a 3-D NdArray
+    #[test]
+    fn groupby_3dim_t() {
+        let values: Vec<i32> = (0..24).collect();
+        let data = Array3::<i32>::from(&values, [2, 3, 4]);
+        let labels = Array::<i32>::from(&[5, -1, 5], [3]);
+
+        let (keys, sums) = data.groupby_sum(&labels, 1);
+        assert_eq!(keys.as_slice(), &[-1, 5]);
+        assert_eq!(sums.shape(), &[2, 2, 4]);
+        assert_eq!(
+            sums.as_slice(),
+            &[4, 5, 6, 7, 8, 10, 12, 14, 16, 17, 18, 19, 32, 34, 36, 38]
+        );
+
+        let (_, max) = data.groupby_max(&labels, 1);
+        assert_eq!(&max.as_slice()[4..8], &[8, 9, 10, 11]);
+        let (_, means) = data.groupby_mean(&labels, 1);
+        assert_eq!(&means.as_slice()[12..], &[16.0, 17.0, 18.0, 19.0]);
+
+        let (keys, sums) = Array2::<i32>::new().groupby_sum(&Array::<u8>::new(), 0);
+        assert!(keys.is_empty() && sums.is_empty());
+    }
+
+    // Test the group-by maximum with NaN values
+    #[test]
+    fn groupby_max_nan_t() {
+        let data = Array::<f64>::from(&[f64::NAN, 1.0, f64::NAN, 2.0], [4]);
+        let labels = Array::<u8>::from(&[0, 0, 1, 2], [4]);
+        let (_, max) = data.groupby_max(&labels, 0);
+        assert_eq!(max[[0]], 1.0);
+        assert!(max[[1]].is_nan());
+        assert_eq!(max[[2]], 2.0);
+    }
+
+    // Test the group-by reductions with labels of a wrong length
+    #[test]
+    #[should_panic(expected = "Shape([3]) does not match Shape([2])")]
+    fn groupby_wrong_labels_t() {
+        let labels = Array::<u8>::from(&[0, 1, 0], [3]);
+        Array2::<u8>::zeros([2, 3]).groupby_sum(&labels, 0);
+    }
+
+    // Test a reduction with a wrong output dimension
+    #[test]
+    #[should_panic(expected = "Dimension(2) must be one less than Dimension(2)")]
+    fn wrong_dimension_t() {
+        Array2::<u8>::zeros([2, 2]).mean::<2>(0);
+    }
+}
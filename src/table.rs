@@ -0,0 +1,430 @@
+use std::fmt::Display;
+
+use crate::core::NdArray;
+
+/// Horizontal alignment of the cells in a rendered table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// Cells are aligned to the left
+    Left,
+    /// Cells are centered
+    Center,
+    /// Cells are aligned to the right (default, suitable for numerals)
+    #[default]
+    Right,
+}
+
+/// Options used while rendering a 1-D or 2-D NdArray as a Markdown or LaTeX table
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+/// use ndim::table::{Align, TableOptions};
+///
+/// # fn main() {
+/// let arr = NdArray::<f32, 2>::from(&[1.0, 2.5, -3.25, 4.0], [2, 2]);
+/// let options = TableOptions {
+///     align: Align::Left,
+///     precision: Some(1),
+///     ..TableOptions::default()
+/// };
+/// assert_eq!(arr.to_markdown_with(&options), "| 0    | 1   |\n| :--- | :-- |\n| 1.0  | 2.5 |\n| -3.2 | 4.0 |\n");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TableOptions {
+    /// Alignment of every column
+    pub align: Align,
+    /// Number of digits after the decimal point of numeric cells. Uses the `Display` output of type T when `None`, and for cells which `Display` would truncate instead, e.g. strings, `char` and `bool`
+    pub precision: Option<usize>,
+    /// Header of the table. Column indices are used when `None`
+    pub header: Option<Vec<String>>,
+}
+
+/// Format each value of the table (in row-major order) along with the column header
+///
+/// ## Panics
+/// If the header length does not match the column count, panics; message shown is **Header length(`len`) don't match with Column count(`cols`)**
+fn cells<T: Display>(
+    rows: usize,
+    cols: usize,
+    value: impl Fn(usize, usize) -> T,
+    options: &TableOptions,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let header: Vec<String> = match &options.header {
+        Some(header) => {
+            if header.len() != cols {
+                panic!(
+                    "Header length({}) don't match with Column count({})",
+                    header.len(),
+                    cols
+                );
+            }
+            header.clone()
+        }
+        None => (0..cols).map(|col| col.to_string()).collect(),
+    };
+
+    // strings (and `char`, `bool`) are truncated to the precision by `Display`, i.e., render empty with a precision of 0, while numerals never do
+    let format = |value: T| -> String {
+        match options.precision {
+            Some(precision) if !format!("{:.0}", value).is_empty() => {
+                format!("{:.*}", precision, value)
+            }
+            _ => value.to_string(),
+        }
+    };
+    let body: Vec<Vec<String>> = (0..rows)
+        .map(|row| (0..cols).map(|col| format(value(row, col))).collect())
+        .collect();
+
+    (header, body)
+}
+
+/// Pad `cell` up to `width` characters as per the alignment
+fn pad(cell: &str, width: usize, align: Align) -> String {
+    match align {
+        Align::Left => format!("{:<width$}", cell, width = width),
+        Align::Center => format!("{:^width$}", cell, width = width),
+        Align::Right => format!("{:>width$}", cell, width = width),
+    }
+}
+
+/// Render a table in GitHub flavoured Markdown. Pipes present in a cell are escaped
+fn markdown<T: Display>(
+    rows: usize,
+    cols: usize,
+    value: impl Fn(usize, usize) -> T,
+    options: &TableOptions,
+) -> String {
+    let escape = |cell: &String| cell.replace('|', "\\|");
+    let (mut header, mut body) = cells(rows, cols, value, options);
+    if header.is_empty() {
+        // a Markdown table has at least one column, hence an array without columns is a single empty column without rows
+        header.push(String::new());
+        body.clear();
+    }
+    let header: Vec<String> = header.iter().map(escape).collect();
+    let body: Vec<Vec<String>> = body
+        .iter()
+        .map(|row| row.iter().map(escape).collect())
+        .collect();
+
+    // each column is as wide as its widest cell, and a separator needs at least 3 characters
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            body.iter()
+                .map(|row| row[col].chars().count())
+                .chain(std::iter::once(header[col].chars().count()))
+                .fold(3, usize::max)
+        })
+        .collect();
+
+    let line = |row: &[String]| -> String {
+        let padded: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| pad(cell, *width, options.align))
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+    let separator: Vec<String> = widths
+        .iter()
+        .map(|width| match options.align {
+            Align::Left => format!(":{}", "-".repeat(width - 1)),
+            Align::Center => format!(":{}:", "-".repeat(width - 2)),
+            Align::Right => format!("{}:", "-".repeat(width - 1)),
+        })
+        .collect();
+
+    let mut table: String = line(&header);
+    table.push_str(&format!("| {} |\n", separator.join(" | ")));
+    for row in body.iter() {
+        table.push_str(&line(row));
+    }
+
+    table
+}
+
+/// Render a table as a LaTeX `tabular` environment. LaTeX special characters present in a cell are escaped
+fn latex<T: Display>(
+    rows: usize,
+    cols: usize,
+    value: impl Fn(usize, usize) -> T,
+    options: &TableOptions,
+) -> String {
+    let escape = |cell: &String| -> String {
+        let mut escaped: String = String::with_capacity(cell.len());
+        for ch in cell.chars() {
+            match ch {
+                '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                    escaped.push('\\');
+                    escaped.push(ch);
+                }
+                '\\' => escaped.push_str("\\textbackslash{}"),
+                '~' => escaped.push_str("\\textasciitilde{}"),
+                '^' => escaped.push_str("\\textasciicircum{}"),
+                _ => escaped.push(ch),
+            }
+        }
+        escaped
+    };
+    let (header, body) = cells(rows, cols, value, options);
+    let column: &str = match options.align {
+        Align::Left => "l",
+        Align::Center => "c",
+        Align::Right => "r",
+    };
+
+    let mut table: String = format!("\\begin{{tabular}}{{{}}}\n\\hline\n", column.repeat(cols));
+    let row = |row: &[String]| -> String {
+        let escaped: Vec<String> = row.iter().map(escape).collect();
+        format!("{} \\\\\n", escaped.join(" & "))
+    };
+    // header is only rendered when provided explicitly, as column indices add little to a report
+    if options.header.is_some() {
+        table.push_str(&row(&header));
+        table.push_str("\\hline\n");
+    }
+    for cells in body.iter() {
+        table.push_str(&row(cells));
+    }
+    table.push_str("\\hline\n\\end{tabular}\n");
+
+    table
+}
+
+/// Render a one dimensional (1-D) or two dimensional (2-D) NdArray as a table, where a 1-D NdArray is a single row
+impl<T: Display, const N: usize> NdArray<T, N>
+where
+    [(); N]: OneOrTwoDims,
+{
+    /// Render the NdArray as a GitHub flavoured Markdown table using the default [`TableOptions`]
+    ///
+    /// ## Note
+    /// - Only available for one dimensional (1-D) and two dimensional (2-D) arrays
+    /// - A 1-D NdArray is rendered as a single row
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::arange(4);
+    /// assert_eq!(arr.to_markdown(), "|   0 |   1 |   2 |   3 |\n| --: | --: | --: | --: |\n|   0 |   1 |   2 |   3 |\n");
+    /// # }
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with(&TableOptions::default())
+    }
+
+    /// Render the NdArray as a GitHub flavoured Markdown table using the given [`TableOptions`]
+    ///
+    /// ## Panics
+    /// If the header in `options` does not match the column count, panics; message shown is **Header length(`len`) don't match with Column count(`cols`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::table::{Align, TableOptions};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f64, 1>::from(&[0.5, 0.25], [2]);
+    /// let options = TableOptions {
+    ///     align: Align::Center,
+    ///     precision: Some(2),
+    ///     header: Some(vec![String::from("x"), String::from("y")]),
+    /// };
+    /// assert_eq!(arr.to_markdown_with(&options), "|  x   |  y   |\n| :--: | :--: |\n| 0.50 | 0.25 |\n");
+    /// # }
+    /// ```
+    pub fn to_markdown_with(&self, options: &TableOptions) -> String {
+        let (rows, cols) = self.table_dims();
+        markdown(rows, cols, |row, col| self.table_value(row, col), options)
+    }
+
+    /// Render the NdArray as a LaTeX `tabular` environment using the default [`TableOptions`]
+    ///
+    /// ## Note
+    /// - Only available for one dimensional (1-D) and two dimensional (2-D) arrays
+    /// - Header is only rendered if it is set in [`TableOptions`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, -2, 3, -4], [2, 2]);
+    /// assert_eq!(arr.to_latex(), "\\begin{tabular}{rr}\n\\hline\n1 & -2 \\\\\n3 & -4 \\\\\n\\hline\n\\end{tabular}\n");
+    /// # }
+    /// ```
+    pub fn to_latex(&self) -> String {
+        self.to_latex_with(&TableOptions::default())
+    }
+
+    /// Render the NdArray as a LaTeX `tabular` environment using the given [`TableOptions`]
+    ///
+    /// ## Panics
+    /// If the header in `options` does not match the column count, panics; message shown is **Header length(`len`) don't match with Column count(`cols`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::table::{Align, TableOptions};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<f32, 1>::from(&[1.0, 2.0], [2]);
+    /// let options = TableOptions {
+    ///     align: Align::Left,
+    ///     precision: Some(1),
+    ///     header: Some(vec![String::from("a_0"), String::from("a_1")]),
+    /// };
+    /// assert_eq!(arr.to_latex_with(&options), "\\begin{tabular}{ll}\n\\hline\na\\_0 & a\\_1 \\\\\n\\hline\n1.0 & 2.0 \\\\\n\\hline\n\\end{tabular}\n");
+    /// # }
+    /// ```
+    pub fn to_latex_with(&self, options: &TableOptions) -> String {
+        let (rows, cols) = self.table_dims();
+        latex(rows, cols, |row, col| self.table_value(row, col), options)
+    }
+
+    /// Number of rows and columns of the rendered table. An empty NdArray (e.g. created using `NdArray::<T, N>::new()`) has neither
+    fn table_dims(&self) -> (usize, usize) {
+        let shape: [usize; N] = self.view_shape();
+        match shape.as_slice() {
+            [0] => (0, 0),
+            [cols] => (1, *cols),
+            [rows, cols] => (*rows, *cols),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Value present in the cell `(row, col)` of the rendered table
+    fn table_value(&self, row: usize, col: usize) -> &T {
+        let mut index: [usize; N] = [0; N];
+        index[N - 1] = col;
+        if N == 2 {
+            index[0] = row;
+        }
+        &self[index]
+    }
+}
+
+mod dims {
+    /// Supertrait of [`OneOrTwoDims`](super::OneOrTwoDims), which cannot be named outside the crate
+    pub trait Sealed {}
+
+    impl Sealed for [(); 1] {}
+    impl Sealed for [(); 2] {}
+}
+
+/// Marker trait implemented for `[(); 1]` and `[(); 2]`, restricting table rendering to 1-D and 2-D arrays. The trait is sealed, and cannot be implemented outside the crate
+pub trait OneOrTwoDims: dims::Sealed {}
+
+impl OneOrTwoDims for [(); 1] {}
+impl OneOrTwoDims for [(); 2] {}
+
+#[cfg(test)]
+mod table_t {
+    use crate::core::{Array, Array2};
+    use crate::table::{Align, TableOptions};
+
+    // Test Markdown rendering of a 2-D NdArray with right aligned columns
+    // Check if the columns are padded to the widest cell
+    #[test]
+    fn markdown_2dim_t() {
+        let data = Array2::<i32>::from(&[1, -200, 30, 4], [2, 2]);
+        let table: String = data.to_markdown();
+        assert_eq!(
+            table,
+            "|   0 |    1 |\n| --: | ---: |\n|   1 | -200 |\n|  30 |    4 |\n"
+        );
+    }
+
+    // Test Markdown rendering of a 1-D NdArray with a header and escaped pipes
+    #[test]
+    fn markdown_1dim_header_t() {
+        let data = Array::<u8>::from(&[7, 8], [2]);
+        let options = TableOptions {
+            align: Align::Left,
+            precision: None,
+            header: Some(vec![String::from("a|b"), String::from("c")]),
+        };
+        assert_eq!(
+            data.to_markdown_with(&options),
+            "| a\\|b | c   |\n| :--- | :-- |\n| 7    | 8   |\n"
+        );
+    }
+
+    // Test LaTeX rendering of a 2-D NdArray with precision and centered columns
+    #[test]
+    fn latex_2dim_precision_t() {
+        let data = Array2::<f64>::from(&[1.0, 2.0 / 3.0, -0.5, 10.0], [2, 2]);
+        let options = TableOptions {
+            align: Align::Center,
+            precision: Some(3),
+            header: None,
+        };
+        assert_eq!(
+            data.to_latex_with(&options),
+            "\\begin{tabular}{cc}\n\\hline\n1.000 & 0.667 \\\\\n-0.500 & 10.000 \\\\\n\\hline\n\\end{tabular}\n"
+        );
+    }
+
+    // Test the precision applied to numeric cells only, e.g. of strings
+    #[test]
+    fn precision_strings_t() {
+        let options = TableOptions {
+            precision: Some(1),
+            ..TableOptions::default()
+        };
+        let words = Array::<&str>::from(&["alpha", "NaN", "2.25"], [3]);
+        assert_eq!(
+            words.to_markdown_with(&options),
+            "|     0 |   1 |    2 |\n| ----: | --: | ---: |\n| alpha | NaN | 2.25 |\n"
+        );
+        let flags = Array::<bool>::from(&[true, false], [2]);
+        assert_eq!(
+            flags.to_markdown_with(&options),
+            "|    0 |     1 |\n| ---: | ----: |\n| true | false |\n"
+        );
+        let values = Array::<f64>::from(&[f64::NAN, 0.25], [2]);
+        assert_eq!(
+            values.to_latex_with(&options),
+            "\\begin{tabular}{rr}\n\\hline\nNaN & 0.2 \\\\\n\\hline\n\\end{tabular}\n"
+        );
+    }
+
+    // Test rendering with a header which does not match the column count
+    #[test]
+    #[should_panic(expected = "Header length(1) don't match with Column count(2)")]
+    fn header_mismatch_t() {
+        let data = Array2::<u8>::zeros([2, 2]);
+        let options = TableOptions {
+            header: Some(vec![String::from("x")]),
+            ..TableOptions::default()
+        };
+        data.to_markdown_with(&options);
+    }
+
+    // Test rendering an empty NdArray, e.g. created using NdArray::<T, N>::new()
+    // Check if the Markdown table has a single empty column, as it needs a separator with dashes
+    #[test]
+    fn empty_table_t() {
+        for table in [
+            Array::<u8>::new().to_markdown(),
+            Array2::<u8>::new().to_markdown(),
+            Array2::<u8>::zeros([3, 0]).to_markdown(),
+        ] {
+            assert_eq!(table, "|     |\n| --: |\n");
+        }
+        assert_eq!(
+            Array2::<f64>::new().to_latex(),
+            "\\begin{tabular}{}\n\\hline\n\\hline\n\\end{tabular}\n"
+        );
+    }
+}
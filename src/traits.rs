@@ -0,0 +1,137 @@
+use crate::core::{checked_size, NdArray, ShapeError, SizedArray};
+
+/// Conversion of an array-like value, i.e., a slice, a Vec or an array, into an NdArray of a given shape
+///
+/// ## Note
+/// Implemented for slices, arrays and Vecs. `vec.into_array(shape)` takes the ownership of the Vec without copying, like [`NdArray::<T, N>::from_vec(...)`](NdArray::from_vec). The elements are copied otherwise
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::ShapeError;
+/// use ndim::traits::ArrayLike;
+///
+/// # fn main() {
+/// let values = vec![1, 2, 3, 4, 5, 6];
+/// let ptr = values.as_ptr();
+/// let arr = values.into_array([2, 3]).unwrap();
+/// assert_eq!(arr[[1, 0]], 4);
+/// assert_eq!(arr.as_slice().as_ptr(), ptr); // the buffer is reused
+///
+/// let err = [1, 2, 3].array([2, 2]).unwrap_err();
+/// assert_eq!(err, ShapeError::TooShort { shape: vec![2, 2], len: 3, size: 4 });
+/// # }
+/// ```
+pub trait ArrayLike<T, const N: usize> {
+    /// Convert into an NdArray of the given `shape`. Copies the elements using [`ArrayLike::array`] unless the implementation can take the ownership of the buffer
+    ///
+    /// Returns [`ShapeError::TooLong`] or [`ShapeError::TooShort`] if the number of elements is not the size derived from `shape`
+    fn into_array(self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError>
+    where
+        Self: Sized,
+    {
+        self.array(shape)
+    }
+
+    /// Create an NdArray of the given `shape` from a reference to the value
+    ///
+    /// Returns [`ShapeError::TooLong`] or [`ShapeError::TooShort`] if the number of elements is not the size derived from `shape`
+    fn array(&self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError>;
+}
+
+/// Return a [`ShapeError`] if `len` elements do not fill `shape`
+fn check_len<T, const N: usize>(len: usize, shape: &SizedArray<N>) -> Result<(), ShapeError> {
+    match checked_size::<T, N>(shape) {
+        Some(size) if len > size => Err(ShapeError::TooLong {
+            shape: shape.to_vec(),
+            len,
+            size,
+        }),
+        Some(size) if len < size => Err(ShapeError::TooShort {
+            shape: shape.to_vec(),
+            len,
+            size,
+        }),
+        Some(_) => Ok(()),
+        None => Err(ShapeError::SizeMismatch {
+            shape: shape.to_vec(),
+            size: len,
+        }),
+    }
+}
+
+impl<T: Clone, const N: usize> ArrayLike<T, N> for [T] {
+    fn array(&self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        check_len::<T, N>(self.len(), &shape)?;
+        Ok(NdArray::from_owned_vec(self.to_vec(), shape))
+    }
+}
+
+impl<T: Clone, const N: usize> ArrayLike<T, N> for &[T] {
+    fn array(&self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        (**self).array(shape)
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> ArrayLike<T, N> for [T; M] {
+    fn into_array(self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        check_len::<T, N>(M, &shape)?;
+        Ok(NdArray::from_owned_vec(Vec::from(self), shape))
+    }
+
+    fn array(&self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        self[..].array(shape)
+    }
+}
+
+impl<T: Clone, const N: usize> ArrayLike<T, N> for Vec<T> {
+    fn into_array(self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        check_len::<T, N>(self.len(), &shape)?;
+        Ok(NdArray::from_owned_vec(self, shape))
+    }
+
+    fn array(&self, shape: SizedArray<N>) -> Result<NdArray<T, N>, ShapeError> {
+        self[..].array(shape)
+    }
+}
+
+#[cfg(test)]
+mod traits_t {
+    use crate::core::{NdArray, ShapeError};
+    use crate::traits::ArrayLike;
+
+    // Test converting slices, Vecs and arrays with matching, longer and shorter lengths
+    #[test]
+    fn array_like_t() {
+        let data: NdArray<f32, 3> = [1.0_f32; 8].into_array([2, 2, 2]).unwrap();
+        assert_eq!(*data.shape(), [2, 2, 2]);
+
+        let values: Vec<u8> = (0..6).collect();
+        let data: NdArray<u8, 2> = values[..].array([3, 2]).unwrap();
+        assert_eq!(data[[2, 1]], 5);
+
+        let err = ArrayLike::<u8, 2>::array(&values, [2, 2]).unwrap_err();
+        assert_eq!(
+            err,
+            ShapeError::TooLong {
+                shape: vec![2, 2],
+                len: 6,
+                size: 4
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Length(6) is too long for Shape([2, 2]) of Size(4)"
+        );
+        assert!(matches!(
+            ArrayLike::<u8, 1>::into_array(values.clone(), [7]),
+            Err(ShapeError::TooShort { .. })
+        ));
+
+        // an owned Vec is moved, not copied
+        let ptr: *const u8 = values.as_ptr();
+        let data: NdArray<u8, 3> = values.into_array([1, 2, 3]).unwrap();
+        assert_eq!(data.as_slice().as_ptr(), ptr);
+        assert_eq!(data[[0, 1, 2]], 5);
+    }
+}
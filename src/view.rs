@@ -0,0 +1,2116 @@
+use std::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    core::{unravel_index, NdArray, Order, SizedArray},
+    error::NdimError,
+    slice::SliceInfo,
+};
+
+/// Size of type T used to convert the strides (in bytes) to an element offset. Zero sized types are treated as a single byte
+pub(crate) fn item_size<T>() -> usize {
+    std::mem::size_of::<T>().max(1)
+}
+
+/// Calculate the element offset from the first element using the `strides` (in bytes) and the given `index`
+pub(crate) fn offset<T, const N: usize>(index: &SizedArray<N>, strides: &SizedArray<N>) -> usize {
+    let bytes: usize = index
+        .iter()
+        .zip(strides.iter())
+        .map(|(idx, stride)| idx * stride)
+        .sum();
+
+    bytes / item_size::<T>()
+}
+
+/// Return true if every axis of `index` is within the `shape`
+pub(crate) fn in_bounds<const N: usize>(index: &SizedArray<N>, shape: &SizedArray<N>) -> bool {
+    index.iter().zip(shape.iter()).all(|(idx, len)| idx < len)
+}
+
+/// Return true if the `strides` (in bytes) describe a row-major (C) contiguous layout of `shape`. Strides of axes with a single element are ignored
+pub(crate) fn is_c_contiguous<T, const N: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+) -> bool {
+    if shape.contains(&0) {
+        return true;
+    }
+
+    let mut expected: usize = std::mem::size_of::<T>();
+    for dim in (0..N).rev() {
+        if shape[dim] != 1 && strides[dim] != expected {
+            return false;
+        }
+        expected *= shape[dim];
+    }
+
+    true
+}
+
+/// Split the `shape` at `index` along `axis`. Returns the shape of both halves and the element offset of the second half
+///
+/// ## Panics
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+/// - If `index` is greater than the length of the axis, panics; message shown is **Index out of bounds**
+fn split_shape<T, const N: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    axis: usize,
+    index: usize,
+) -> (SizedArray<N>, SizedArray<N>, usize) {
+    if axis >= N {
+        panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+    }
+    if index > shape[axis] {
+        panic!("Index out of bounds");
+    }
+
+    let mut left: SizedArray<N> = *shape;
+    left[axis] = index;
+    let mut right: SizedArray<N> = *shape;
+    right[axis] = shape[axis] - index;
+
+    let mut start: SizedArray<N> = [0; N];
+    start[axis] = index;
+
+    (left, right, offset::<T, N>(&start, strides))
+}
+
+/// Shape and strides after removing `axis`, and the element offset of `index` along `axis`
+///
+/// ## Panics
+/// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+/// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+/// - If `index` is not less than the length of the axis, panics; message shown is **Index out of bounds**
+fn remove_axis<T, const N: usize, const M: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    axis: usize,
+    index: usize,
+) -> (SizedArray<M>, SizedArray<M>, usize) {
+    if M + 1 != N {
+        panic!("Dimension({}) must be one less than Dimension({})", M, N);
+    }
+    if axis >= N {
+        panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+    }
+    if index >= shape[axis] {
+        panic!("Index out of bounds");
+    }
+
+    let mut sub_shape: SizedArray<M> = [0; M];
+    let mut sub_strides: SizedArray<M> = [0; M];
+    for (pos, dim) in (0..N).filter(|dim| *dim != axis).enumerate() {
+        sub_shape[pos] = shape[dim];
+        sub_strides[pos] = strides[dim];
+    }
+
+    let mut start: SizedArray<N> = [0; N];
+    start[axis] = index;
+
+    (sub_shape, sub_strides, offset::<T, N>(&start, strides))
+}
+
+/// Shape and strides after slicing with `info`, and the element offset of the first selected element. Indexed axes are dropped and the strides of ranges are multiplied by their step
+///
+/// ## Panics
+/// - If `M` is not the number of ranges in `info`, panics; message shown is **Dimension(`M`) must match with the sliced Dimension(`out_ndim`)**
+/// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+fn slice_shape<T, const N: usize, const M: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    info: &SliceInfo<N>,
+) -> (SizedArray<M>, SizedArray<M>, usize) {
+    if M != info.out_ndim() {
+        panic!(
+            "Dimension({}) must match with the sliced Dimension({})",
+            M,
+            info.out_ndim()
+        );
+    }
+
+    let mut sub_shape: SizedArray<M> = [0; M];
+    let mut sub_strides: SizedArray<M> = [0; M];
+    let mut start: SizedArray<N> = [0; N];
+    let mut pos: usize = 0;
+    for (dim, arg) in info.args().iter().enumerate() {
+        let (first, count, step) = arg.resolve(shape[dim]);
+        start[dim] = first;
+        if !arg.is_index() {
+            sub_shape[pos] = count;
+            sub_strides[pos] = strides[dim] * step;
+            pos += 1;
+        }
+    }
+
+    // an empty slice may start past the end of the memory, hence it starts at the first element
+    if sub_shape.contains(&0) {
+        return (sub_shape, sub_strides, 0);
+    }
+    (sub_shape, sub_strides, offset::<T, N>(&start, strides))
+}
+
+/// Shape and strides of the trailing `M` axes after fixing the leading `K` axes at `index`, and the element offset of the sub-view
+///
+/// ## Panics
+/// - If `K + M` is not `N`, panics; message shown is **Dimension(`K`) + Dimension(`M`) must be equal to Dimension(`N`)**
+/// - If the index of any leading axis is not less than the length of the axis, panics; message shown is **Index out of bounds**
+fn fix_leading_axes<T, const N: usize, const K: usize, const M: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    index: &[usize; K],
+) -> (SizedArray<M>, SizedArray<M>, usize) {
+    if K + M != N {
+        panic!(
+            "Dimension({}) + Dimension({}) must be equal to Dimension({})",
+            K, M, N
+        );
+    }
+    if index.iter().zip(shape.iter()).any(|(idx, len)| idx >= len) {
+        panic!("Index out of bounds");
+    }
+
+    let sub_shape: SizedArray<M> = std::array::from_fn(|dim| shape[K + dim]);
+    let sub_strides: SizedArray<M> = std::array::from_fn(|dim| strides[K + dim]);
+    let mut start: SizedArray<N> = [0; N];
+    start[..K].copy_from_slice(index);
+
+    (sub_shape, sub_strides, offset::<T, N>(&start, strides))
+}
+
+/// Strides (in bytes) to view `shape` with `strides` as the larger `target`, aligning the trailing axes similar to NumPy. Stretched and new axes have a stride of zero
+///
+/// ## Panics
+/// - If `M` is less than `N`, panics; message shown is **Dimension(`M`) must not be less than Dimension(`N`)**
+/// - If an axis differs in length and its length is not 1, panics; message shown is **Shape(`shape`) cannot be broadcast to Shape(`target`)**
+fn broadcast_strides<const N: usize, const M: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    target: &SizedArray<M>,
+) -> SizedArray<M> {
+    if M < N {
+        panic!("Dimension({}) must not be less than Dimension({})", M, N);
+    }
+
+    let mut new_strides: SizedArray<M> = [0; M];
+    for dim in 0..N {
+        let new_dim: usize = M - N + dim;
+        new_strides[new_dim] = match (shape[dim], target[new_dim]) {
+            (len, new_len) if len == new_len => strides[dim],
+            (1, _) => 0,
+            _ => panic!(
+                "Shape({:?}) cannot be broadcast to Shape({:?})",
+                shape, target
+            ),
+        };
+    }
+    new_strides
+}
+
+/// Shape and strides (in bytes) of the sliding `window`s over `shape` with `strides`. The leading `N` axes select the window, and the trailing `N` axes index within it
+///
+/// ## Panics
+/// - If `M` is not twice `N`, panics; message shown is **Dimension(`M`) must be twice Dimension(`N`)**
+/// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+fn window_strides<const N: usize, const M: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    window: &SizedArray<N>,
+) -> (SizedArray<M>, SizedArray<M>) {
+    if M != 2 * N {
+        panic!("Dimension({}) must be twice Dimension({})", M, N);
+    }
+    if window.iter().zip(shape.iter()).any(|(w, len)| w > len) {
+        panic!("Window({:?}) exceeds Shape({:?})", window, shape);
+    }
+
+    let mut new_shape: SizedArray<M> = [0; M];
+    let mut new_strides: SizedArray<M> = [0; M];
+    for dim in 0..N {
+        new_shape[dim] = shape[dim] - window[dim] + 1;
+        new_shape[N + dim] = window[dim];
+        new_strides[dim] = strides[dim];
+        new_strides[N + dim] = strides[dim];
+    }
+    (new_shape, new_strides)
+}
+
+/// Shape and strides with the axes reordered, where axis `dim` of the result is axis `axes[dim]` of the input
+///
+/// ## Panics
+/// If `axes` is not a permutation of `0..N`, panics; message shown is **Axes(`axes`) must be a permutation of 0..`N`**
+fn permute_axes<const N: usize>(
+    shape: &SizedArray<N>,
+    strides: &SizedArray<N>,
+    axes: &SizedArray<N>,
+) -> (SizedArray<N>, SizedArray<N>) {
+    let mut seen: [bool; N] = [false; N];
+    for axis in axes {
+        if *axis >= N || seen[*axis] {
+            panic!("Axes({:?}) must be a permutation of 0..{}", axes, N);
+        }
+        seen[*axis] = true;
+    }
+
+    (axes.map(|axis| shape[axis]), axes.map(|axis| strides[axis]))
+}
+
+/// A read-only view of an N-dimensional array of type T. Shares the memory of the array it borrows from, and hence creating a view does not copy any data
+///
+/// Similar to NdArray, the strides of a view are in bytes. A view need not be contiguous in the memory
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+/// use ndim::view::ArrayView;
+///
+/// # fn main() {
+/// let arr = NdArray::<u16, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+/// let view: ArrayView<u16, 2> = arr.view();
+/// assert_eq!(*view.shape(), [2, 3]);
+/// assert_eq!(view[[1, 2]], 5);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ArrayView<'a, T, const N: usize> {
+    ptr: *const T,
+    shape: SizedArray<N>,
+    strides: SizedArray<N>,
+    marker: PhantomData<&'a T>,
+}
+
+/// A mutable view of an N-dimensional array of type T. Shares the memory of the array it borrows from, and hence creating a view does not copy any data
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+///
+/// # fn main() {
+/// let mut arr = NdArray::<u16, 2>::zeros([2, 3]);
+/// let mut view = arr.view_mut();
+/// view[[1, 2]] = 7;
+/// assert_eq!(arr[[1, 2]], 7);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ArrayViewMut<'a, T, const N: usize> {
+    ptr: *mut T,
+    shape: SizedArray<N>,
+    strides: SizedArray<N>,
+    marker: PhantomData<&'a mut T>,
+}
+
+// A view behaves like `&'a T`, hence it can be copied freely
+impl<T, const N: usize> Clone for ArrayView<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ArrayView<'_, T, N> {}
+
+// SAFETY: `ArrayView` behaves like `&'a T` and `ArrayViewMut` like `&'a mut T`
+unsafe impl<T: Sync, const N: usize> Send for ArrayView<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for ArrayView<'_, T, N> {}
+unsafe impl<T: Send, const N: usize> Send for ArrayViewMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for ArrayViewMut<'_, T, N> {}
+
+impl<'a, T, const N: usize> ArrayView<'a, T, N> {
+    /// Create a view from a pointer, shape and strides (in bytes)
+    ///
+    /// ## Safety
+    /// Every index within `shape` must point to a valid and initialized `T` for the lifetime `'a`, and must not be mutated for the lifetime `'a`
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *const T,
+        shape: SizedArray<N>,
+        strides: SizedArray<N>,
+    ) -> Self {
+        ArrayView {
+            ptr,
+            shape,
+            strides,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the pointer to the first element of the view
+    pub(crate) fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Return the shape of the view
+    pub fn shape(&self) -> &SizedArray<N> {
+        &self.shape
+    }
+
+    /// Return the strides (in bytes) of the view
+    pub fn strides(&self) -> &SizedArray<N> {
+        &self.strides
+    }
+
+    /// Return the number of elements in the view
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Return true if the view has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Create a view of the memory of this view with the given `shape` and `strides` (in bytes), starting from its first element. The returned view may have a different dimension `M`, and may visit the same element more than once
+    ///
+    /// ## Safety
+    /// - Every index within `shape` must point to an element of this view, i.e. the strides can only step over elements which are visited by this view
+    /// - Every stride must be a multiple of the size of type T
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// let view = arr.view();
+    /// // repeat the first row twice, by stepping 0 bytes along the first axis
+    /// let repeated = unsafe { view.as_strided([2, 3], [0, 2]) };
+    /// assert_eq!(repeated[[1, 2]], 2);
+    /// # }
+    /// ```
+    pub unsafe fn as_strided<const M: usize>(
+        &self,
+        shape: SizedArray<M>,
+        strides: SizedArray<M>,
+    ) -> ArrayView<'a, T, M> {
+        ArrayView::from_raw_parts(self.ptr, shape, strides)
+    }
+
+    /// Copy the elements of the view, in row-major (C) order, into a new NdArray with the shape of the view
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let (_, right) = arr.split_at(1, 1);
+    /// let right: NdArray<u8, 2> = right.to_owned();
+    /// assert_eq!(*right.shape(), [3, 1]);
+    /// assert_eq!(right.as_slice(), &[1, 3, 5]);
+    /// # }
+    /// ```
+    pub fn to_owned(&self) -> NdArray<T, N>
+    where
+        T: Clone,
+    {
+        let values: Vec<T> = (0..self.len())
+            .map(|pos| self[unravel_index(pos, &self.shape, Order::C)].clone())
+            .collect();
+        NdArray::from_owned_vec(values, self.shape)
+    }
+
+    /// Return a new NdArray of the shape of the view with `f` applied to a reference of every element, in row-major (C) order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3], [2, 2]);
+    /// let doubled = arr.t().map(|x| 2 * x);
+    /// assert_eq!(doubled.as_slice(), &[0, 4, 2, 6]);
+    /// # }
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> NdArray<U, N>
+    where
+        F: FnMut(&'a T) -> U,
+    {
+        let values: Vec<U> = self.iter().map(f).collect();
+        NdArray::from_owned_vec(values, self.shape)
+    }
+
+    /// Return the elements of the view as a slice if the view is contiguous in row-major (C) order, else `None`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let (top, _) = arr.split_at(0, 2);
+    /// assert_eq!(top.as_slice(), Some(&[0, 1, 2, 3][..]));
+    ///
+    /// let (left, _) = arr.split_at(1, 1);
+    /// assert_eq!(left.as_slice(), None); // skips every other element
+    /// # }
+    /// ```
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        if !is_c_contiguous::<T, N>(&self.shape, &self.strides) {
+            return None;
+        }
+        // SAFETY: the view is contiguous, hence its elements are `len` consecutive values
+        Some(unsafe { std::slice::from_raw_parts(self.ptr, self.len()) })
+    }
+
+    /// Return the elements of the view in row-major (C) order as a one dimensional (1-D) array. Borrows the memory if the view is contiguous in row-major order, else copies the elements
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// assert!(arr.view().ravel().is_view());
+    /// let flat = arr.t().ravel(); // copies, as the transpose is not contiguous
+    /// assert!(flat.is_owned());
+    /// assert_eq!(flat.as_slice(), Some(&[0, 3, 1, 4, 2, 5][..]));
+    /// # }
+    /// ```
+    pub fn ravel(self) -> CowArray<'a, T, 1>
+    where
+        T: Clone,
+    {
+        match self.as_slice() {
+            // SAFETY: the view is contiguous, hence its elements are `len` consecutive values
+            Some(values) => CowArray::View(unsafe {
+                ArrayView::from_raw_parts(values.as_ptr(), [values.len()], [item_size::<T>()])
+            }),
+            None => CowArray::Owned(self.flatten()),
+        }
+    }
+
+    /// Copy the elements of the view in row-major (C) order into a one dimensional (1-D) NdArray
+    pub fn flatten(&self) -> NdArray<T, 1>
+    where
+        T: Clone,
+    {
+        let len: usize = self.len();
+        self.to_owned().into_shape([len])
+    }
+
+    /// Copy the elements of the view in the given `order` into a one dimensional (1-D) NdArray
+    pub fn flatten_with_order(&self, order: Order) -> NdArray<T, 1>
+    where
+        T: Clone,
+    {
+        self.iter_with_order(order).cloned().collect()
+    }
+
+    /// Return the view itself if it is contiguous in row-major (C) order, also known as the standard layout. Else, copies the elements into an NdArray which is contiguous
+    ///
+    /// Use this when a slice of the elements is required after splitting or transposing an array, as `as_slice()` never fails on the returned array
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let (_, right) = arr.split_at(1, 1);
+    /// let right = right.as_standard_layout();
+    /// assert!(right.is_owned());
+    /// assert_eq!(right.as_slice(), Some(&[1, 3, 5][..]));
+    /// # }
+    /// ```
+    pub fn as_standard_layout(self) -> CowArray<'a, T, N>
+    where
+        T: Copy,
+    {
+        if is_c_contiguous::<T, N>(&self.shape, &self.strides) {
+            CowArray::View(self)
+        } else {
+            CowArray::Owned(CowArray::View(self).into_owned())
+        }
+    }
+
+    /// Split the view into two non-overlapping views at `index` along `axis`. The first view holds `0..index` and the second holds `index..` of the axis
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is greater than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 2>::from(&[0, 1, 2, 3, 4, 5, 6, 7], [2, 4]);
+    /// let (left, right) = arr.view().split_at(1, 3);
+    /// assert_eq!(*left.shape(), [2, 3]);
+    /// assert_eq!(*right.shape(), [2, 1]);
+    /// assert_eq!(right[[1, 0]], 7);
+    /// # }
+    /// ```
+    pub fn split_at(self, axis: usize, index: usize) -> (Self, Self) {
+        let (left, right, start) = split_shape::<T, N>(&self.shape, &self.strides, axis, index);
+        // SAFETY: both halves are within the memory borrowed by `self`
+        unsafe {
+            (
+                ArrayView::from_raw_parts(self.ptr, left, self.strides),
+                ArrayView::from_raw_parts(self.ptr.wrapping_add(start), right, self.strides),
+            )
+        }
+    }
+
+    /// Select `index` along `axis` and return a view of one lower dimension (`M = N - 1`), e.g. one image out of a batch of images
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::view::ArrayView;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// let column: ArrayView<u16, 1> = arr.view().index_axis(1, 2);
+    /// assert_eq!(*column.shape(), [2]);
+    /// assert_eq!(column[[1]], 5);
+    /// # }
+    /// ```
+    pub fn index_axis<const M: usize>(self, axis: usize, index: usize) -> ArrayView<'a, T, M> {
+        let (shape, strides, start) =
+            remove_axis::<T, N, M>(&self.shape, &self.strides, axis, index);
+        // SAFETY: the sub-view is within the memory borrowed by `self`
+        unsafe { ArrayView::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Slice the view with `info`, typically built using [`s!`](crate::s), and return a view of dimension `M`, which is the number of ranges in `info`
+    ///
+    /// ## Panics
+    /// - If `M` is not the number of ranges in `info`, panics; message shown is **Dimension(`M`) must match with the sliced Dimension(`out_ndim`)**
+    /// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+    pub fn slice<const M: usize>(self, info: SliceInfo<N>) -> ArrayView<'a, T, M> {
+        let (shape, strides, start) = slice_shape::<T, N, M>(&self.shape, &self.strides, &info);
+        // SAFETY: the selected elements are within the memory borrowed by `self`
+        unsafe { ArrayView::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Broadcast the view to `shape` of dimension `M >= N`, without copying. The trailing axes are aligned, and axes of length 1 (and new leading axes) are repeated using a stride of zero, similar to `numpy.broadcast_to`
+    ///
+    /// ## Panics
+    /// - If `M` is less than `N`, panics; message shown is **Dimension(`M`) must not be less than Dimension(`N`)**
+    /// - If an axis differs in length and its length is not 1, panics; message shown is **Shape(`shape`) cannot be broadcast to Shape(`target`)**
+    pub fn broadcast_to<const M: usize>(self, shape: SizedArray<M>) -> ArrayView<'a, T, M> {
+        let strides: SizedArray<M> = broadcast_strides(&self.shape, &self.strides, &shape);
+        // SAFETY: every index within `shape` maps to an index within the shape of `self`, and the view is read-only
+        unsafe { ArrayView::from_raw_parts(self.ptr, shape, strides) }
+    }
+
+    /// Return a view of every (overlapping) `window` of the view, with a step of one along each axis, without copying. The leading `N` axes of the returned view select the window, and the trailing `N` axes index within it, similar to `numpy.lib.stride_tricks.sliding_window_view`
+    ///
+    /// ## Panics
+    /// - If `M` is not twice `N`, panics; message shown is **Dimension(`M`) must be twice Dimension(`N`)**
+    /// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+    pub fn sliding_window_view<const M: usize>(self, window: SizedArray<N>) -> ArrayView<'a, T, M> {
+        let (shape, strides) = window_strides::<N, M>(&self.shape, &self.strides, &window);
+        // SAFETY: the last element of the last window is the last element of the view, and the strides are those of the view
+        unsafe { self.as_strided(shape, strides) }
+    }
+
+    /// Fix the leading `K` axes at `index` and return a view of the remaining `M = N - K` axes, e.g. `view.subview([i])` is row `i` of a 2-D view
+    ///
+    /// ## Panics
+    /// - If `K + M` is not `N`, panics; message shown is **Dimension(`K`) + Dimension(`M`) must be equal to Dimension(`N`)**
+    /// - If the index of any leading axis is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    pub fn subview<const K: usize, const M: usize>(self, index: [usize; K]) -> ArrayView<'a, T, M> {
+        let (shape, strides, start) =
+            fix_leading_axes::<T, N, K, M>(&self.shape, &self.strides, &index);
+        // SAFETY: the sub-view is within the memory borrowed by `self`
+        unsafe { ArrayView::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Reorder the axes without copying, where axis `dim` of the returned view is axis `axes[dim]` of this view, similar to `numpy.transpose(a, axes)`
+    ///
+    /// ## Panics
+    /// If `axes` is not a permutation of `0..N`, panics; message shown is **Axes(`axes`) must be a permutation of 0..`N`**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 3>::zeros([2, 3, 4]);
+    /// let view = arr.view().permuted_axes([2, 0, 1]);
+    /// assert_eq!(*view.shape(), [4, 2, 3]);
+    /// assert_eq!(*view.strides(), [1, 12, 4]);
+    /// # }
+    /// ```
+    pub fn permuted_axes(self, axes: SizedArray<N>) -> Self {
+        let (shape, strides) = permute_axes(&self.shape, &self.strides, &axes);
+        ArrayView {
+            shape,
+            strides,
+            ..self
+        }
+    }
+
+    /// Reverse the order of the axes without copying, i.e., the transpose of a 2-D view
+    pub fn reversed_axes(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+        self
+    }
+
+    /// Swap the axes `a` and `b` in place, without copying
+    ///
+    /// ## Panics
+    /// If `a` or `b` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    pub fn swap_axes(&mut self, a: usize, b: usize) {
+        for axis in [a, b] {
+            if axis >= N {
+                panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+            }
+        }
+        self.shape.swap(a, b);
+        self.strides.swap(a, b);
+    }
+}
+
+impl<'a, T> ArrayView<'a, T, 2> {
+    /// Return the transpose of the 2-D view without copying. Same as `reversed_axes()`
+    pub fn t(self) -> Self {
+        self.reversed_axes()
+    }
+}
+
+impl<'a, T, const N: usize> ArrayViewMut<'a, T, N> {
+    /// Create a mutable view from a pointer, shape and strides (in bytes)
+    ///
+    /// ## Safety
+    /// Every index within `shape` must point to a valid and initialized `T` for the lifetime `'a`, must not be accessed through any other pointer for the lifetime `'a`, and distinct indices must not point to the same element
+    pub(crate) unsafe fn from_raw_parts(
+        ptr: *mut T,
+        shape: SizedArray<N>,
+        strides: SizedArray<N>,
+    ) -> Self {
+        ArrayViewMut {
+            ptr,
+            shape,
+            strides,
+            marker: PhantomData,
+        }
+    }
+
+    /// Return the mutable pointer to the first element of the view
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    /// Consume the mutable view and return its pointer, shape and strides (in bytes)
+    pub(crate) fn into_raw_parts(self) -> (*mut T, SizedArray<N>, SizedArray<N>) {
+        (self.ptr, self.shape, self.strides)
+    }
+
+    /// Return the shape of the view
+    pub fn shape(&self) -> &SizedArray<N> {
+        &self.shape
+    }
+
+    /// Return the strides (in bytes) of the view
+    pub fn strides(&self) -> &SizedArray<N> {
+        &self.strides
+    }
+
+    /// Return the number of elements in the view
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Return true if the view has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return a read-only view borrowing from this mutable view
+    pub fn view(&self) -> ArrayView<'_, T, N> {
+        // SAFETY: the memory is borrowed immutably from `self`
+        unsafe { ArrayView::from_raw_parts(self.ptr, self.shape, self.strides) }
+    }
+
+    /// Return a mutable view with a shorter lifetime, so that `self` can be used again after the returned view is dropped
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, N> {
+        // SAFETY: the memory is borrowed mutably from `self`
+        unsafe { ArrayViewMut::from_raw_parts(self.ptr, self.shape, self.strides) }
+    }
+
+    /// Copy the elements of the view, in row-major (C) order, into a new NdArray with the shape of the view
+    pub fn to_owned(&self) -> NdArray<T, N>
+    where
+        T: Clone,
+    {
+        self.view().to_owned()
+    }
+
+    /// Return the elements of the view as a mutable slice if the view is contiguous in row-major (C) order, else `None`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([3, 2]);
+    /// let (_, mut bottom) = arr.split_at_mut(0, 1);
+    /// bottom.as_mut_slice().unwrap().fill(4);
+    /// assert_eq!(arr.as_slice(), &[0, 0, 4, 4, 4, 4]);
+    /// # }
+    /// ```
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        if !is_c_contiguous::<T, N>(&self.shape, &self.strides) {
+            return None;
+        }
+        // SAFETY: the view is contiguous and borrowed mutably from `self`
+        Some(unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len()) })
+    }
+
+    /// Overwrite every element of the view with `value`
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        let shape: SizedArray<N> = self.shape;
+        for pos in 0..self.len() {
+            self[unravel_index(pos, &shape, Order::C)] = value.clone();
+        }
+    }
+
+    /// Split the mutable view into two non-overlapping mutable views at `index` along `axis`. The first view holds `0..index` and the second holds `index..` of the axis
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is greater than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u16, 1>::zeros([6]);
+    /// let (mut left, mut right) = arr.view_mut().split_at(0, 2);
+    /// left[[1]] = 1;
+    /// right[[0]] = 2;
+    /// assert_eq!(arr[[1]], 1);
+    /// assert_eq!(arr[[2]], 2);
+    /// # }
+    /// ```
+    pub fn split_at(self, axis: usize, index: usize) -> (Self, Self) {
+        let (left, right, start) = split_shape::<T, N>(&self.shape, &self.strides, axis, index);
+        // SAFETY: both halves are within the memory borrowed by `self` and do not overlap
+        unsafe {
+            (
+                ArrayViewMut::from_raw_parts(self.ptr, left, self.strides),
+                ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), right, self.strides),
+            )
+        }
+    }
+
+    /// Select `index` along `axis` and return a mutable view of one lower dimension (`M = N - 1`)
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    pub fn index_axis<const M: usize>(self, axis: usize, index: usize) -> ArrayViewMut<'a, T, M> {
+        let (shape, strides, start) =
+            remove_axis::<T, N, M>(&self.shape, &self.strides, axis, index);
+        // SAFETY: the sub-view is within the memory borrowed by `self`, which is consumed
+        unsafe { ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Slice the mutable view with `info`, typically built using [`s!`](crate::s), and return a mutable view of dimension `M`, which is the number of ranges in `info`
+    ///
+    /// ## Panics
+    /// - If `M` is not the number of ranges in `info`, panics; message shown is **Dimension(`M`) must match with the sliced Dimension(`out_ndim`)**
+    /// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+    pub fn slice<const M: usize>(self, info: SliceInfo<N>) -> ArrayViewMut<'a, T, M> {
+        let (shape, strides, start) = slice_shape::<T, N, M>(&self.shape, &self.strides, &info);
+        // SAFETY: the selected elements are within the memory borrowed by `self`, which is consumed, and the steps are non-zero
+        unsafe { ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Fix the leading `K` axes at `index` and return a mutable view of the remaining `M = N - K` axes
+    ///
+    /// ## Panics
+    /// - If `K + M` is not `N`, panics; message shown is **Dimension(`K`) + Dimension(`M`) must be equal to Dimension(`N`)**
+    /// - If the index of any leading axis is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    pub fn subview<const K: usize, const M: usize>(
+        self,
+        index: [usize; K],
+    ) -> ArrayViewMut<'a, T, M> {
+        let (shape, strides, start) =
+            fix_leading_axes::<T, N, K, M>(&self.shape, &self.strides, &index);
+        // SAFETY: the sub-view is within the memory borrowed by `self`, which is consumed
+        unsafe { ArrayViewMut::from_raw_parts(self.ptr.wrapping_add(start), shape, strides) }
+    }
+
+    /// Reorder the axes without copying, where axis `dim` of the returned view is axis `axes[dim]` of this view, similar to `numpy.transpose(a, axes)`
+    ///
+    /// ## Panics
+    /// If `axes` is not a permutation of `0..N`, panics; message shown is **Axes(`axes`) must be a permutation of 0..`N`**
+    pub fn permuted_axes(self, axes: SizedArray<N>) -> Self {
+        let (shape, strides) = permute_axes(&self.shape, &self.strides, &axes);
+        ArrayViewMut {
+            shape,
+            strides,
+            ..self
+        }
+    }
+
+    /// Reverse the order of the axes without copying, i.e., the transpose of a 2-D view
+    pub fn reversed_axes(mut self) -> Self {
+        self.shape.reverse();
+        self.strides.reverse();
+        self
+    }
+
+    /// Swap the axes `a` and `b` in place, without copying
+    ///
+    /// ## Panics
+    /// If `a` or `b` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    pub fn swap_axes(&mut self, a: usize, b: usize) {
+        for axis in [a, b] {
+            if axis >= N {
+                panic!("Axis({}) out of bounds for Dimension({})", axis, N);
+            }
+        }
+        self.shape.swap(a, b);
+        self.strides.swap(a, b);
+    }
+}
+
+/// Use for indexing a view
+///
+/// ## Note
+/// `Panics` if the index of any axis is not within the shape of the view
+impl<T, const N: usize> Index<SizedArray<N>> for ArrayView<'_, T, N> {
+    type Output = T;
+
+    fn index(&self, index: SizedArray<N>) -> &Self::Output {
+        if !in_bounds(&index, &self.shape) {
+            panic!("Index out of bounds")
+        }
+        unsafe { &*self.ptr.add(offset::<T, N>(&index, &self.strides)) }
+    }
+}
+
+/// Use for indexing a mutable view
+///
+/// ## Note
+/// `Panics` if the index of any axis is not within the shape of the view
+impl<T, const N: usize> Index<SizedArray<N>> for ArrayViewMut<'_, T, N> {
+    type Output = T;
+
+    fn index(&self, index: SizedArray<N>) -> &Self::Output {
+        if !in_bounds(&index, &self.shape) {
+            panic!("Index out of bounds")
+        }
+        unsafe { &*self.ptr.add(offset::<T, N>(&index, &self.strides)) }
+    }
+}
+
+/// Use for mutating a value through a mutable view
+impl<T, const N: usize> IndexMut<SizedArray<N>> for ArrayViewMut<'_, T, N> {
+    fn index_mut(&mut self, index: SizedArray<N>) -> &mut Self::Output {
+        if !in_bounds(&index, &self.shape) {
+            panic!("Index out of bounds")
+        }
+        unsafe { &mut *self.ptr.add(offset::<T, N>(&index, &self.strides)) }
+    }
+}
+
+/// Use for comparing two views. Views are equal if their shapes are equal, and the elements at every index are equal, irrespective of their strides
+///
+/// ## Example
+///
+/// ```
+/// # use ndim::core::NdArray;
+/// #
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[0, 1, 0, 1, 0, 1], [3, 2]);
+/// let (left, right) = arr.split_at(1, 1);
+/// let zeros = NdArray::<u8, 2>::zeros([3, 1]);
+/// assert_eq!(left, zeros.view());
+/// assert_ne!(right, zeros.view());
+/// # }
+/// ```
+impl<T: PartialEq, const N: usize> PartialEq for ArrayView<'_, T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.shape == other.shape
+            && (0..self.len()).all(|pos| {
+                let index: SizedArray<N> = unravel_index(pos, &self.shape, Order::C);
+                self[index] == other[index]
+            })
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayView<'_, T, N> {}
+
+/// Borrow an NdArray as a read-only view, so that functions can accept `impl Into<ArrayView<'a, T, N>>` for both arrays and views
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+/// use ndim::view::ArrayView;
+///
+/// fn total<'a>(arr: impl Into<ArrayView<'a, i32, 2>>) -> i32 {
+///     arr.into().to_owned().as_slice().iter().sum()
+/// }
+///
+/// # fn main() {
+/// let arr = NdArray::<i32, 2>::ones([2, 3]);
+/// assert_eq!(total(&arr), 6);
+/// let (top, _) = arr.split_at(0, 1);
+/// assert_eq!(total(top), 3);
+/// # }
+/// ```
+impl<'a, T, const N: usize> From<&'a NdArray<T, N>> for ArrayView<'a, T, N> {
+    fn from(arr: &'a NdArray<T, N>) -> Self {
+        arr.view()
+    }
+}
+
+/// Borrow an NdArray as a mutable view
+impl<'a, T, const N: usize> From<&'a mut NdArray<T, N>> for ArrayViewMut<'a, T, N> {
+    fn from(arr: &'a mut NdArray<T, N>) -> Self {
+        arr.view_mut()
+    }
+}
+
+/// Convert a mutable view into a read-only view of the same lifetime
+impl<'a, T, const N: usize> From<ArrayViewMut<'a, T, N>> for ArrayView<'a, T, N> {
+    fn from(view: ArrayViewMut<'a, T, N>) -> Self {
+        let (ptr, shape, strides) = view.into_raw_parts();
+        // SAFETY: the mutable borrow of `view` is consumed, hence the memory is only read for the lifetime `'a`
+        unsafe { ArrayView::from_raw_parts(ptr, shape, strides) }
+    }
+}
+
+/// Either a view borrowing an array, or an owned NdArray. Returned by methods which avoid copying whenever the memory layout allows it, similar to `std::borrow::Cow`
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::{NdArray, Order};
+/// use ndim::view::CowArray;
+///
+/// # fn main() {
+/// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3], [2, 2]);
+/// let flat: CowArray<u8, 1> = arr.ravel_with_order(Order::C);
+/// assert!(flat.is_view());
+/// assert_eq!(flat[[3]], 3);
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum CowArray<'a, T, const N: usize> {
+    /// Borrowed view of an array
+    View(ArrayView<'a, T, N>),
+    /// Owned NdArray
+    Owned(NdArray<T, N>),
+}
+
+impl<T, const N: usize> CowArray<'_, T, N> {
+    /// Return true if the array is borrowed
+    pub fn is_view(&self) -> bool {
+        matches!(self, CowArray::View(_))
+    }
+
+    /// Return true if the array is owned
+    pub fn is_owned(&self) -> bool {
+        matches!(self, CowArray::Owned(_))
+    }
+
+    /// Return a read-only view of the array
+    pub fn view(&self) -> ArrayView<'_, T, N> {
+        match self {
+            CowArray::View(view) => *view,
+            CowArray::Owned(arr) => arr.view(),
+        }
+    }
+
+    /// Return the shape of the array
+    pub fn shape(&self) -> &SizedArray<N> {
+        match self {
+            CowArray::View(view) => view.shape(),
+            CowArray::Owned(arr) => arr.shape(),
+        }
+    }
+
+    /// Return the elements as a slice if the array is contiguous in row-major (C) order, else `None`. Always returns a slice for an owned array
+    pub fn as_slice(&self) -> Option<&[T]> {
+        match self {
+            CowArray::View(view) => view.as_slice(),
+            CowArray::Owned(arr) => Some(arr.as_slice()),
+        }
+    }
+
+    /// Convert into an owned NdArray. Copies the elements if the array is borrowed
+    pub fn into_owned(self) -> NdArray<T, N>
+    where
+        T: Copy,
+    {
+        match self {
+            CowArray::View(view) => view.to_owned(),
+            CowArray::Owned(arr) => arr,
+        }
+    }
+}
+
+/// Use for indexing a borrowed or an owned array
+impl<T, const N: usize> Index<SizedArray<N>> for CowArray<'_, T, N> {
+    type Output = T;
+
+    fn index(&self, index: SizedArray<N>) -> &Self::Output {
+        match self {
+            CowArray::View(view) => &view[index],
+            CowArray::Owned(arr) => &arr[index],
+        }
+    }
+}
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Shape used by the views of the NdArray. An empty NdArray created using `NdArray::<T, N>::new()` has a shape of `[1; N]`, which is not valid for a view
+    pub(crate) fn view_shape(&self) -> SizedArray<N> {
+        if *self.len() != self.shape().iter().product::<usize>() {
+            [0; N]
+        } else {
+            *self.shape()
+        }
+    }
+
+    /// Pointer used by the read-only views of the NdArray. Never null, as the elements are owned by a `Vec<T>`
+    ///
+    /// ## Note
+    /// The pointer is derived from a shared borrow and must never be written through. Use `view_mut_ptr` for the mutable views
+    fn view_ptr(&self) -> *mut T {
+        self.as_ptr() as *mut T
+    }
+
+    /// Pointer used by the mutable views of the NdArray, derived from a mutable borrow of the elements. Never null, as the elements are owned by a `Vec<T>`
+    fn view_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_slice().as_mut_ptr()
+    }
+
+    /// Return a read-only view of the whole NdArray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i8, 3>::ones([2, 1, 3]);
+    /// let view = arr.view();
+    /// assert_eq!(view.len(), 6);
+    /// assert_eq!(view[[1, 0, 2]], 1);
+    /// # }
+    /// ```
+    pub fn view(&self) -> ArrayView<'_, T, N> {
+        // SAFETY: the memory is borrowed immutably from `self`
+        unsafe { ArrayView::from_raw_parts(self.view_ptr(), self.view_shape(), *self.strides()) }
+    }
+
+    /// Return a mutable view of the whole NdArray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i8, 2>::zeros([2, 2]);
+    /// arr.view_mut()[[0, 1]] = -1;
+    /// assert_eq!(arr[[0, 1]], -1);
+    /// # }
+    /// ```
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, N> {
+        let (ptr, shape, strides) = (self.view_mut_ptr(), self.view_shape(), *self.strides());
+        // SAFETY: the memory is borrowed mutably from `self`
+        unsafe { ArrayViewMut::from_raw_parts(ptr, shape, strides) }
+    }
+
+    /// Return the NdArray as a borrowed `CowArray`. An NdArray is always contiguous in row-major (C) order, hence no elements are copied
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i16, 2>::ones([2, 2]);
+    /// assert!(arr.as_standard_layout().is_view());
+    /// # }
+    /// ```
+    pub fn as_standard_layout(&self) -> CowArray<'_, T, N>
+    where
+        T: Copy,
+    {
+        CowArray::View(self.view())
+    }
+
+    /// Create a view of the sized array with the given `shape` and `strides` (in bytes), starting from the first element. Useful to build overlapping windows and broadcast views without copying
+    ///
+    /// ## Note
+    /// - The returned view may have a different dimension `M`, and may visit the same element more than once. Hence, no mutable counterpart exists
+    /// - Use [`NdArray::<T, N>::try_as_strided(...)`](NdArray::try_as_strided) to check the shape and strides
+    ///
+    /// ## Safety
+    /// - Every index within `shape` must point within the sized array, i.e. `sum((shape[i] - 1) * strides[i]) / size_of::<T>()` must be less than the length (if `shape` has no zeros)
+    /// - Every stride must be a multiple of the size of type T
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let signal = NdArray::<f32, 1>::arange(6);
+    /// // 4 overlapping windows of length 3: [0, 1, 2], [1, 2, 3], ...
+    /// let windows = unsafe { signal.as_strided([4, 3], [4, 4]) };
+    /// assert_eq!(windows[[2, 0]], 2.0);
+    /// assert_eq!(windows[[3, 2]], 5.0);
+    /// # }
+    /// ```
+    pub unsafe fn as_strided<const M: usize>(
+        &self,
+        shape: SizedArray<M>,
+        strides: SizedArray<M>,
+    ) -> ArrayView<'_, T, M> {
+        self.view().as_strided(shape, strides)
+    }
+
+    /// Create a view of the sized array with the given `shape` and `strides` (in bytes), similar to [`NdArray::<T, N>::as_strided(...)`](NdArray::as_strided). Returns `None` if any index within `shape` points outside the sized array, or if a stride is not a multiple of the size of type T
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 1>::arange(4);
+    /// let pairs = arr.try_as_strided([3, 2], [1, 1]).unwrap(); // [0, 1], [1, 2], [2, 3]
+    /// assert_eq!(pairs[[2, 1]], 3);
+    /// assert!(arr.try_as_strided([4, 2], [1, 1]).is_none()); // [3, 4] is out of bounds
+    /// # }
+    /// ```
+    pub fn try_as_strided<const M: usize>(
+        &self,
+        shape: SizedArray<M>,
+        strides: SizedArray<M>,
+    ) -> Option<ArrayView<'_, T, M>> {
+        let size: usize = item_size::<T>();
+        if strides.iter().any(|stride| stride % size != 0) {
+            return None;
+        }
+
+        if !shape.contains(&0) {
+            let mut last: usize = 0;
+            for (len, stride) in shape.iter().zip(strides.iter()) {
+                last = last.checked_add((len - 1).checked_mul(stride / size)?)?;
+            }
+            if last >= *self.len() {
+                return None;
+            }
+        }
+
+        // SAFETY: every index within `shape` points to an element of the sized array
+        Some(unsafe { self.as_strided(shape, strides) })
+    }
+
+    /// Return the elements of the NdArray in the given `order` as a one dimensional (1-D) array. Borrows the NdArray if its memory is already in that order, else copies the elements
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::{NdArray, Order};
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let flat = arr.ravel_with_order(Order::F); // copies, as the memory is in row-major order
+    /// assert!(flat.is_owned());
+    /// assert_eq!(flat[[1]], 2);
+    ///
+    /// let column = NdArray::<u8, 2>::ones([3, 1]);
+    /// assert!(column.ravel_with_order(Order::F).is_view()); // same elements in either order
+    /// # }
+    /// ```
+    pub fn ravel_with_order(&self, order: Order) -> CowArray<'_, T, 1>
+    where
+        T: Copy,
+    {
+        // both orders visit the elements in the same sequence when at most one axis is longer than 1
+        let same: bool = self.shape().iter().filter(|len| **len > 1).count() <= 1;
+        if order == Order::C || same {
+            let shape: [usize; 1] = [self.view().len()];
+            let strides: [usize; 1] = [item_size::<T>()];
+            // SAFETY: the sized array of an NdArray is contiguous in row-major order
+            return CowArray::View(unsafe {
+                ArrayView::from_raw_parts(self.view_ptr(), shape, strides)
+            });
+        }
+
+        let values: Vec<T> = (0..self.view().len())
+            .map(|pos| self[unravel_index(pos, self.shape(), order)])
+            .collect();
+        let len: usize = values.len();
+        CowArray::Owned(NdArray::from_owned_vec(values, [len]))
+    }
+
+    /// Return the elements of the NdArray as a one dimensional (1-D) view in row-major (C) order, without copying. An NdArray is always contiguous, hence a view is always returned
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 3>::ones([2, 2, 3]);
+    /// let flat = arr.ravel();
+    /// assert_eq!(*flat.shape(), [12]);
+    /// # }
+    /// ```
+    pub fn ravel(&self) -> ArrayView<'_, T, 1> {
+        let shape: [usize; 1] = [self.view().len()];
+        // SAFETY: the sized array of an NdArray is contiguous in row-major order
+        unsafe { ArrayView::from_raw_parts(self.view_ptr(), shape, [item_size::<T>()]) }
+    }
+
+    /// Split the NdArray into two non-overlapping views at `index` along `axis`. The first view holds `0..index` and the second holds `index..` of the axis
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is greater than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [3, 2]);
+    /// let (top, bottom) = arr.split_at(0, 1);
+    /// assert_eq!(*top.shape(), [1, 2]);
+    /// assert_eq!(*bottom.shape(), [2, 2]);
+    /// assert_eq!(bottom[[0, 1]], 3);
+    /// # }
+    /// ```
+    pub fn split_at(
+        &self,
+        axis: usize,
+        index: usize,
+    ) -> (ArrayView<'_, T, N>, ArrayView<'_, T, N>) {
+        self.view().split_at(axis, index)
+    }
+
+    /// Split the NdArray into two non-overlapping mutable views at `index` along `axis`. The first view holds `0..index` and the second holds `index..` of the axis
+    ///
+    /// ## Panics
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is greater than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<u8, 2>::zeros([2, 2]);
+    /// let (mut left, mut right) = arr.split_at_mut(1, 1);
+    /// left[[1, 0]] = 1;
+    /// right[[1, 0]] = 2;
+    /// assert_eq!(arr[[1, 0]], 1);
+    /// assert_eq!(arr[[1, 1]], 2);
+    /// # }
+    /// ```
+    pub fn split_at_mut(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> (ArrayViewMut<'_, T, N>, ArrayViewMut<'_, T, N>) {
+        self.view_mut().split_at(axis, index)
+    }
+
+    /// Select `index` along `axis` and return a view of one lower dimension (`M = N - 1`), without copying
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // a batch of two 2x2 images
+    /// let batch = NdArray::<u8, 3>::from(&[0, 1, 2, 3, 4, 5, 6, 7], [2, 2, 2]);
+    /// let image = batch.index_axis::<2>(0, 1);
+    /// assert_eq!(*image.shape(), [2, 2]);
+    /// assert_eq!(image[[1, 0]], 6);
+    /// # }
+    /// ```
+    pub fn index_axis<const M: usize>(&self, axis: usize, index: usize) -> ArrayView<'_, T, M> {
+        self.view().index_axis(axis, index)
+    }
+
+    /// Select `index` along `axis` and return a view of one lower dimension (`M = N - 1`), or return [`NdimError::AxisOutOfRange`] or [`NdimError::IndexOutOfBounds`] instead of panicking
+    ///
+    /// ## Panics
+    /// If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::error::NdimError;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5], [2, 3]);
+    /// assert_eq!(arr.try_index_axis::<1>(1, 2).unwrap()[[1]], 5);
+    /// assert_eq!(
+    ///     arr.try_index_axis::<1>(2, 0).unwrap_err(),
+    ///     NdimError::AxisOutOfRange { axis: 2, ndim: 2 }
+    /// );
+    /// assert!(arr.try_index_axis::<1>(0, 2).is_err());
+    /// # }
+    /// ```
+    pub fn try_index_axis<const M: usize>(
+        &self,
+        axis: usize,
+        index: usize,
+    ) -> Result<ArrayView<'_, T, M>, NdimError> {
+        if M + 1 == N {
+            let shape: SizedArray<N> = self.view_shape();
+            if axis >= N {
+                return Err(NdimError::AxisOutOfRange { axis, ndim: N });
+            }
+            if index >= shape[axis] {
+                return Err(NdimError::IndexOutOfBounds {
+                    index: vec![index],
+                    shape: vec![shape[axis]],
+                });
+            }
+        }
+
+        Ok(self.index_axis(axis, index))
+    }
+
+    /// Select `index` along `axis` and return a mutable view of one lower dimension (`M = N - 1`), without copying
+    ///
+    /// ## Panics
+    /// - If `M` is not one less than `N`, panics; message shown is **Dimension(`M`) must be one less than Dimension(`N`)**
+    /// - If `axis` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    /// - If `index` is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut arr = NdArray::<i32, 2>::zeros([3, 2]);
+    /// let mut row = arr.index_axis_mut::<1>(0, 1);
+    /// row[[0]] = 7;
+    /// assert_eq!(arr[[1, 0]], 7);
+    /// # }
+    /// ```
+    pub fn index_axis_mut<const M: usize>(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> ArrayViewMut<'_, T, M> {
+        self.view_mut().index_axis(axis, index)
+    }
+
+    /// Slice the NdArray with `info`, typically built using [`s!`](crate::s), and return a view of dimension `M` without copying. Ranges keep their axis (with a step, if any), while indices drop it
+    ///
+    /// ## Note
+    /// `M` is the number of ranges in `info`, and may need to be annotated, e.g. `arr.slice::<2>(...)`
+    ///
+    /// ## Panics
+    /// - If `M` is not the number of ranges in `info`, panics; message shown is **Dimension(`M`) must match with the sliced Dimension(`out_ndim`)**
+    /// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use ndim::s;
+    ///
+    /// # fn main() {
+    /// let arr = NdArray::<u32, 3>::from_shape_fn([5, 4, 3], |[i, j, k]| (100 * i + 10 * j + k) as u32);
+    /// let view = arr.slice::<2>(s![1..4, ..;2, 2]);
+    /// assert_eq!(*view.shape(), [3, 2]);
+    /// assert_eq!(view[[2, 1]], 322);
+    /// # }
+    /// ```
+    pub fn slice<const M: usize>(&self, info: SliceInfo<N>) -> ArrayView<'_, T, M> {
+        self.view().slice(info)
+    }
+
+    /// Slice the NdArray with `info`, typically built using [`s!`](crate::s), and return a mutable view of dimension `M` without copying
+    ///
+    /// ## Panics
+    /// - If `M` is not the number of ranges in `info`, panics; message shown is **Dimension(`M`) must match with the sliced Dimension(`out_ndim`)**
+    /// - If an index is not within its axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ndim::core::NdArray;
+    /// use ndim::s;
+    ///
+    /// # fn main() {
+    /// let mut arr = NdArray::<i8, 2>::zeros([3, 4]);
+    /// let mut even = arr.slice_mut::<2>(s![.., ..;2]);
+    /// even[[2, 1]] = 5;
+    /// assert_eq!(arr[[2, 2]], 5);
+    /// # }
+    /// ```
+    pub fn slice_mut<const M: usize>(&mut self, info: SliceInfo<N>) -> ArrayViewMut<'_, T, M> {
+        self.view_mut().slice(info)
+    }
+
+    /// Return a read-only view of the NdArray broadcast to `shape` of dimension `M >= N`, without copying the repeated elements. The trailing axes are aligned, and axes of length 1 (and new leading axes) are repeated using a stride of zero, similar to `numpy.broadcast_to`
+    ///
+    /// ## Panics
+    /// - If `M` is less than `N`, panics; message shown is **Dimension(`M`) must not be less than Dimension(`N`)**
+    /// - If an axis differs in length and its length is not 1, panics; message shown is **Shape(`shape`) cannot be broadcast to Shape(`target`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let row = NdArray::<i32, 1>::from(&[1, 2, 3], [3]);
+    /// let rows = row.broadcast_to([4, 3]);
+    /// assert_eq!(*rows.strides(), [0, 4]);
+    /// assert_eq!(rows[[3, 2]], 3);
+    ///
+    /// let column = NdArray::<i32, 2>::from(&[10, 20], [2, 1]);
+    /// assert_eq!(column.broadcast_to([2, 3]).to_owned().as_slice(), &[10, 10, 10, 20, 20, 20]);
+    /// # }
+    /// ```
+    pub fn broadcast_to<const M: usize>(&self, shape: SizedArray<M>) -> ArrayView<'_, T, M> {
+        self.view().broadcast_to(shape)
+    }
+
+    /// Return a read-only view of every (overlapping) `window` of the NdArray, with a step of one along each axis, without copying. The leading `N` axes of the returned view select the window, and the trailing `N` axes index within it, similar to `numpy.lib.stride_tricks.sliding_window_view`. A safe alternative to [`NdArray::<T, N>::as_strided(...)`](NdArray::as_strided) for overlapping blocks
+    ///
+    /// ## Note
+    /// `M` must be `2 * N`, and may need to be annotated, e.g. `arr.sliding_window_view::<4>([2, 2])`
+    ///
+    /// ## Panics
+    /// - If `M` is not twice `N`, panics; message shown is **Dimension(`M`) must be twice Dimension(`N`)**
+    /// - If the window is longer than an axis, panics; message shown is **Window(`window`) exceeds Shape(`shape`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u8, 1>::arange(5);
+    /// let pairs = arr.sliding_window_view::<2>([2]); // [0, 1], [1, 2], [2, 3], [3, 4]
+    /// assert_eq!(*pairs.shape(), [4, 2]);
+    /// assert_eq!(pairs[[3, 0]], 3);
+    ///
+    /// // 2x2 blocks of a 3x3 matrix
+    /// let mat = NdArray::<u8, 2>::from(&[0, 1, 2, 3, 4, 5, 6, 7, 8], [3, 3]);
+    /// let blocks = mat.sliding_window_view::<4>([2, 2]);
+    /// assert_eq!(*blocks.shape(), [2, 2, 2, 2]);
+    /// assert_eq!(blocks.subview::<2, 2>([1, 1]).to_owned().as_slice(), &[4, 5, 7, 8]);
+    /// # }
+    /// ```
+    pub fn sliding_window_view<const M: usize>(
+        &self,
+        window: SizedArray<N>,
+    ) -> ArrayView<'_, T, M> {
+        self.view().sliding_window_view(window)
+    }
+
+    /// Index the NdArray with fewer indices than its dimension and return a view of the remaining axes without copying. The `K` indices fix the leading axes, and the view has the trailing `M = N - K` axes, e.g. one image out of a batch of images
+    ///
+    /// ## Note
+    /// `M` may need to be annotated, e.g. `arr.subview::<1, 2>([i])`. See [`NdArray::<T, N>::index_axis(...)`](NdArray::index_axis) to fix any single axis
+    ///
+    /// ## Panics
+    /// - If `K + M` is not `N`, panics; message shown is **Dimension(`K`) + Dimension(`M`) must be equal to Dimension(`N`)**
+    /// - If the index of any leading axis is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// # use ndim::view::ArrayView;
+    /// #
+    /// # fn main() {
+    /// let batch = NdArray::<u8, 4>::from_shape_fn([2, 3, 4, 4], |[n, c, _, _]| (n * 3 + c) as u8);
+    /// for n in 0..2 {
+    ///     let image: ArrayView<u8, 3> = batch.subview([n]);
+    ///     assert_eq!(*image.shape(), [3, 4, 4]);
+    /// }
+    /// let channel: ArrayView<u8, 2> = batch.subview([1, 2]);
+    /// assert_eq!(channel[[3, 3]], 5);
+    /// # }
+    /// ```
+    pub fn subview<const K: usize, const M: usize>(
+        &self,
+        index: [usize; K],
+    ) -> ArrayView<'_, T, M> {
+        self.view().subview(index)
+    }
+
+    /// Index the NdArray with fewer indices than its dimension and return a mutable view of the remaining axes without copying
+    ///
+    /// ## Panics
+    /// - If `K + M` is not `N`, panics; message shown is **Dimension(`K`) + Dimension(`M`) must be equal to Dimension(`N`)**
+    /// - If the index of any leading axis is not less than the length of the axis, panics; message shown is **Index out of bounds**
+    pub fn subview_mut<const K: usize, const M: usize>(
+        &mut self,
+        index: [usize; K],
+    ) -> ArrayViewMut<'_, T, M> {
+        self.view_mut().subview(index)
+    }
+
+    /// Return a view with the axes reordered, where axis `dim` of the view is axis `axes[dim]` of the NdArray, without copying. Use `to_owned()` on the view for a contiguous copy
+    ///
+    /// ## Panics
+    /// If `axes` is not a permutation of `0..N`, panics; message shown is **Axes(`axes`) must be a permutation of 0..`N`**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<u16, 3>::from_shape_fn([2, 3, 4], |[i, j, k]| (100 * i + 10 * j + k) as u16);
+    /// let view = arr.permuted_axes([1, 2, 0]);
+    /// assert_eq!(*view.shape(), [3, 4, 2]);
+    /// assert_eq!(view[[2, 3, 1]], 123);
+    /// # }
+    /// ```
+    pub fn permuted_axes(&self, axes: SizedArray<N>) -> ArrayView<'_, T, N> {
+        self.view().permuted_axes(axes)
+    }
+
+    /// Return a view with the order of the axes reversed, without copying
+    pub fn reversed_axes(&self) -> ArrayView<'_, T, N> {
+        self.view().reversed_axes()
+    }
+
+    /// Return a view with the axes `a` and `b` swapped, without copying
+    ///
+    /// ## Panics
+    /// If `a` or `b` is not less than `N`, panics; message shown is **Axis(`axis`) out of bounds for Dimension(`N`)**
+    pub fn swap_axes(&self, a: usize, b: usize) -> ArrayView<'_, T, N> {
+        let mut view: ArrayView<'_, T, N> = self.view();
+        view.swap_axes(a, b);
+        view
+    }
+}
+
+impl<T> NdArray<T, 2> {
+    /// Return the transpose of the 2-D NdArray as a view, without copying
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let t = arr.t();
+    /// assert_eq!(*t.shape(), [3, 2]);
+    /// assert_eq!(t.to_owned().as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// # }
+    /// ```
+    pub fn t(&self) -> ArrayView<'_, T, 2> {
+        self.view().t()
+    }
+
+    /// Return the main diagonal of the 2-D NdArray as a strided 1-D view, without copying. See [`NdArray::<T, 2>::diagonal(...)`](NdArray::diagonal) for a copy of any diagonal
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let arr = NdArray::<i32, 2>::from(&[1, 2, 3, 4, 5, 6], [2, 3]);
+    /// let diag = arr.diag();
+    /// assert_eq!(*diag.shape(), [2]);
+    /// assert_eq!(diag[[1]], 5);
+    /// # }
+    /// ```
+    pub fn diag(&self) -> ArrayView<'_, T, 1> {
+        let (shape, strides) = diag_shape(&self.view_shape(), self.strides());
+        // SAFETY: the diagonal is within the memory borrowed by `self`
+        unsafe { ArrayView::from_raw_parts(self.view_ptr(), shape, strides) }
+    }
+
+    /// Return the main diagonal of the 2-D NdArray as a strided 1-D mutable view, without copying
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// // regularize a matrix in place
+    /// let mut arr = NdArray::<f64, 2>::zeros([3, 3]);
+    /// arr.diag_mut().fill(1e-3);
+    /// assert_eq!(arr[[2, 2]], 1e-3);
+    /// assert_eq!(arr[[2, 1]], 0.0);
+    /// # }
+    /// ```
+    pub fn diag_mut(&mut self) -> ArrayViewMut<'_, T, 1> {
+        let (shape, strides) = diag_shape(&self.view_shape(), self.strides());
+        // SAFETY: the diagonal is within the memory borrowed mutably by `self`, and its elements are distinct
+        unsafe { ArrayViewMut::from_raw_parts(self.view_mut_ptr(), shape, strides) }
+    }
+}
+
+/// Shape and stride (in bytes) of the main diagonal of a 2-D array
+fn diag_shape(shape: &SizedArray<2>, strides: &SizedArray<2>) -> ([usize; 1], [usize; 1]) {
+    ([shape[0].min(shape[1])], [strides[0] + strides[1]])
+}
+
+#[cfg(test)]
+mod view_t {
+    use crate::core::{Array2, Array3, NdArray, Order};
+    use crate::s;
+    use crate::view::{ArrayView, ArrayViewMut};
+
+    // Test the views of a 2-D NdArray
+    // Check if the values accessed through the view are correct
+    #[test]
+    fn view_2dim_t() {
+        let arr: Vec<i32> = (0..12).collect();
+        let data: NdArray<i32, 2> = Array2::<i32>::from(&arr, [3, 4]);
+        let view = data.view();
+        assert_eq!(*view.shape(), [3, 4]);
+        assert_eq!(*view.strides(), [16, 4]);
+
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(view[[i, j]], arr[i * 4 + j]);
+            }
+        }
+    }
+
+    // Test the view of an empty NdArray
+    #[test]
+    fn view_empty_t() {
+        let data = NdArray::<u8, 2>::new();
+        let view = data.view();
+        assert!(view.is_empty());
+        assert_eq!(*view.shape(), [0, 0]);
+    }
+
+    // Test split_at(...) along every axis of a 3-D NdArray
+    // Check if both halves hold the right values
+    #[test]
+    fn split_at_3dim_t() {
+        let data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        let mut data = data;
+        data.reshape([2, 3, 4]);
+
+        for axis in 0..3 {
+            let len: usize = data.shape()[axis];
+            for index in 0..=len {
+                let (left, right) = data.split_at(axis, index);
+                assert_eq!(left.len() + right.len(), 24);
+                for i in 0..right.shape()[0] {
+                    for j in 0..right.shape()[1] {
+                        for k in 0..right.shape()[2] {
+                            let mut idx: [usize; 3] = [i, j, k];
+                            idx[axis] += index;
+                            assert_eq!(right[[i, j, k]], data[idx]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Test split_at_mut(...) and mutate both halves from separate threads
+    #[test]
+    fn split_at_mut_threads_t() {
+        let mut data = Array2::<u32>::zeros([4, 2]);
+        let (mut top, mut bottom) = data.split_at_mut(0, 2);
+        std::thread::scope(|scope| {
+            scope.spawn(move || top[[1, 1]] = 1);
+            scope.spawn(move || bottom[[1, 1]] = 2);
+        });
+        assert_eq!(data[[1, 1]], 1);
+        assert_eq!(data[[3, 1]], 2);
+    }
+
+    // Test ravel_with_order(...) for a 3-D NdArray in both orders
+    // Check if the elements are visited in the right order and copied only when required
+    #[test]
+    fn ravel_with_order_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        data.reshape([2, 3, 4]);
+
+        let flat = data.ravel_with_order(Order::C);
+        assert!(flat.is_view());
+        for pos in 0..24 {
+            assert_eq!(flat[[pos]], pos as u16);
+        }
+
+        let flat = data.ravel_with_order(Order::F);
+        assert!(flat.is_owned());
+        let mut pos: usize = 0;
+        for k in 0..4 {
+            for j in 0..3 {
+                for i in 0..2 {
+                    assert_eq!(flat[[pos]], data[[i, j, k]]);
+                    pos += 1;
+                }
+            }
+        }
+    }
+
+    // Test as_standard_layout(...) for the halves of a 3-D NdArray split along every axis
+    // Check if only non-contiguous halves are copied, and the copies hold the right values
+    #[test]
+    fn as_standard_layout_3dim_t() {
+        let mut data: NdArray<u16, 3> = Array3::<u16>::arange(24);
+        data.reshape([2, 3, 4]);
+
+        for axis in 0..3 {
+            let (left, _) = data.split_at(axis, 1);
+            let layout = left.as_standard_layout();
+            assert_eq!(layout.is_view(), axis == 0);
+
+            let slice: &[u16] = layout.as_slice().unwrap();
+            let mut pos: usize = 0;
+            for i in 0..left.shape()[0] {
+                for j in 0..left.shape()[1] {
+                    for k in 0..left.shape()[2] {
+                        assert_eq!(slice[pos], data[[i, j, k]]);
+                        pos += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Test as_slice(...) for a view whose axes have a single element
+    #[test]
+    fn as_slice_single_element_axes_t() {
+        let data: NdArray<u8, 3> = Array3::<u8>::zeros([2, 1, 3]);
+        let (_, right) = data.split_at(0, 1);
+        assert_eq!(right.as_slice(), Some(&[0u8; 3][..]));
+    }
+
+    // Test try_as_strided(...) for overlapping windows of a 2-D NdArray
+    // Check if the windows hold the right values
+    #[test]
+    fn try_as_strided_windows_t() {
+        let data: NdArray<u32, 2> = Array2::<u32>::from(&(0..12).collect::<Vec<u32>>(), [3, 4]);
+        // 2x2 windows sliding over every row and column
+        let windows = data.try_as_strided([2, 3, 2, 2], [16, 4, 16, 4]).unwrap();
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..2 {
+                    for l in 0..2 {
+                        assert_eq!(windows[[i, j, k, l]], data[[i + k, j + l]]);
+                    }
+                }
+            }
+        }
+    }
+
+    // Test try_as_strided(...) with strides which are out of bounds or unaligned
+    #[test]
+    fn try_as_strided_invalid_t() {
+        let data = Array2::<u32>::zeros([3, 4]);
+        assert!(data.try_as_strided([2, 4], [16, 4]).is_some());
+        assert!(data.try_as_strided([4, 4], [16, 4]).is_none());
+        assert!(data.try_as_strided([2, 2], [6, 4]).is_none());
+        assert!(data.try_as_strided([usize::MAX, 2], [4, 4]).is_none());
+        assert!(data.try_as_strided([0, 100], [400, 4]).is_some());
+    }
+
+    // Test index_axis(...) along every axis of a 3-D NdArray
+    // Check if the sub-views hold the right values
+    #[test]
+    fn index_axis_3dim_t() {
+        let arr: Vec<u32> = (0..24).collect();
+        let data: NdArray<u32, 3> = Array3::<u32>::from(&arr, [2, 3, 4]);
+        for axis in 0..3 {
+            let index: usize = data.shape()[axis] - 1;
+            let sub = data.index_axis::<2>(axis, index);
+            for i in 0..sub.shape()[0] {
+                for j in 0..sub.shape()[1] {
+                    let full: [usize; 3] = match axis {
+                        0 => [index, i, j],
+                        1 => [i, index, j],
+                        _ => [i, j, index],
+                    };
+                    assert_eq!(sub[[i, j]], data[full]);
+                }
+            }
+        }
+    }
+
+    // Test index_axis_mut(...) of a 2-D NdArray
+    // Check if the writes are visible in the NdArray
+    #[test]
+    fn index_axis_mut_2dim_t() {
+        let mut data = Array2::<i8>::zeros([3, 3]);
+        let mut column = data.index_axis_mut::<1>(1, 2);
+        for i in 0..3 {
+            column[[i]] = i as i8 + 1;
+        }
+        assert_eq!(data.as_slice(), &[0, 0, 1, 0, 0, 2, 0, 0, 3]);
+    }
+
+    // Test to_owned(...) of the views of a 3-D NdArray, for a type which is not Copy
+    #[test]
+    fn to_owned_3dim_t() {
+        let names: Vec<String> = (0..12).map(|idx| idx.to_string()).collect();
+        let data = NdArray::<String, 3>::from_vec(names, [2, 3, 2]);
+        let (_, middle) = data.split_at(1, 1);
+        let middle: NdArray<String, 3> = middle.to_owned();
+        assert_eq!(*middle.shape(), [2, 2, 2]);
+        assert_eq!(
+            middle.as_slice(),
+            &["2", "3", "4", "5", "8", "9", "10", "11"]
+        );
+
+        let mut copy = data.clone();
+        let row: NdArray<String, 2> = copy.index_axis_mut::<2>(0, 1).to_owned();
+        assert_eq!(row.as_slice(), &data.as_slice()[6..]);
+    }
+
+    // Test index_axis(...) with a dimension which is not one less
+    #[test]
+    #[should_panic(expected = "Dimension(2) must be one less than Dimension(2)")]
+    fn index_axis_wrong_dimension_t() {
+        let data = Array2::<u8>::zeros([2, 2]);
+        data.index_axis::<2>(0, 0);
+    }
+
+    // Test split_at(...) with an index larger than the axis
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn split_at_out_of_bounds_t() {
+        let data = Array2::<u8>::zeros([2, 2]);
+        data.split_at(1, 3);
+    }
+
+    // Test indexing a view out of its shape, but within the memory of the NdArray
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn view_index_out_of_bounds_t() {
+        let data = Array2::<u8>::zeros([2, 4]);
+        let (left, _) = data.split_at(1, 2);
+        let _ = left[[0, 2]];
+    }
+
+    // Test slicing a 3-D NdArray with steps, negative values and indices, then slicing the view again
+    #[test]
+    fn slice_3dim_t() {
+        let data = Array3::<usize>::from_shape_fn([4, 6, 5], |[i, j, k]| 100 * i + 10 * j + k);
+        let view = data.slice::<3>(s![1.., ..;2, -2..]);
+        assert_eq!(*view.shape(), [3, 3, 2]);
+        assert_eq!(view[[2, 1, 0]], 323);
+
+        let column = view.slice::<1>(s![0, 1..;1, -1]);
+        assert_eq!(column.to_owned().as_slice(), &[124, 144]);
+
+        let empty = data.slice::<2>(s![4.., 1, ..]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.to_owned().as_slice(), &[] as &[usize]);
+    }
+
+    // Test writing through a mutable slice with a step
+    #[test]
+    fn slice_mut_2dim_t() {
+        let mut data = Array2::<i32>::zeros([4, 4]);
+        let mut odd = data.slice_mut::<2>(s![1..;2, ..]);
+        odd[[1, 3]] = 9;
+        assert_eq!(*odd.shape(), [2, 4]);
+        assert_eq!(data[[3, 3]], 9);
+    }
+
+    // Test slicing with a dimension which does not match the ranges of the slice
+    #[test]
+    #[should_panic(expected = "Dimension(2) must match with the sliced Dimension(1)")]
+    fn slice_wrong_dimension_t() {
+        let data = Array2::<u8>::zeros([2, 2]);
+        data.slice::<2>(s![.., 0]);
+    }
+
+    // Test the conversions of a 3-D NdArray and its mutable view into views with the same elements
+    #[test]
+    fn view_from_3dim_t() {
+        let mut data =
+            Array3::<u8>::from_shape_fn([2, 3, 2], |[i, j, k]| (i * 6 + j * 2 + k) as u8);
+        let expected = data.clone();
+        let view: ArrayView<u8, 3> = (&data).into();
+        assert_eq!(view, expected.view());
+
+        let mut view_mut: ArrayViewMut<u8, 3> = (&mut data).into();
+        view_mut[[1, 2, 1]] = 0;
+        let view: ArrayView<u8, 3> = view_mut.into();
+        assert_eq!(view[[1, 2, 1]], 0);
+        assert_eq!(view[[1, 2, 0]], 10);
+    }
+
+    // Test reordering the axes of a 3-D NdArray, and writing through a swapped mutable view
+    #[test]
+    fn permuted_axes_3dim_t() {
+        let mut data = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        let view = data.permuted_axes([2, 0, 1]);
+        assert_eq!(view[[3, 1, 2]], 123);
+        assert_eq!(data.reversed_axes().permuted_axes([2, 1, 0]), data.view());
+        assert!(!data.swap_axes(0, 2).is_standard_layout());
+        assert_eq!(data.swap_axes(1, 1), data.view());
+
+        let mut view_mut = data.view_mut();
+        view_mut.swap_axes(0, 2);
+        view_mut[[3, 2, 1]] = 0;
+        assert_eq!(data[[1, 2, 3]], 0);
+    }
+
+    // Test reordering the axes with an axis repeated
+    #[test]
+    #[should_panic(expected = "Axes([0, 0]) must be a permutation of 0..2")]
+    fn permuted_axes_repeated_t() {
+        let data = Array2::<u8>::zeros([2, 3]);
+        data.permuted_axes([0, 0]);
+    }
+
+    // Test ravel(...) and flatten(...) of contiguous and strided views of a 3-D NdArray
+    #[test]
+    fn ravel_flatten_3dim_t() {
+        let data = Array3::<u16>::from_shape_fn([2, 3, 2], |[i, j, k]| (i * 6 + j * 2 + k) as u16);
+        assert_eq!(data.ravel().len(), 12);
+        assert_eq!(data.ravel()[[7]], 7);
+
+        let (_, bottom) = data.split_at(0, 1);
+        let flat = bottom.ravel();
+        assert!(flat.is_view());
+        assert_eq!(flat[[0]], 6);
+
+        let swapped = data.swap_axes(0, 2);
+        let flat = swapped.ravel();
+        assert!(flat.is_owned());
+        assert_eq!(
+            flat.as_slice(),
+            Some(&[0, 6, 2, 8, 4, 10, 1, 7, 3, 9, 5, 11][..])
+        );
+        assert_eq!(swapped.flatten(), flat.into_owned());
+        assert_eq!(NdArray::<u16, 3>::new().ravel().len(), 0);
+    }
+
+    // Test partial indexing of a 3-D NdArray matches index_axis(...) along the first axis
+    #[test]
+    fn subview_3dim_t() {
+        let mut data =
+            Array3::<i32>::from_shape_fn([3, 2, 4], |[i, j, k]| (i * 8 + j * 4 + k) as i32);
+        for i in 0..3 {
+            let sub: ArrayView<i32, 2> = data.subview([i]);
+            assert_eq!(sub, data.index_axis::<2>(0, i));
+        }
+        let row: ArrayView<i32, 1> = data.subview([2, 1]);
+        assert_eq!(row.to_owned().as_slice(), &[20, 21, 22, 23]);
+        let all: ArrayView<i32, 3> = data.subview([]);
+        assert_eq!(all, data.view());
+
+        let mut row: ArrayViewMut<i32, 1> = data.subview_mut([1, 0]);
+        row[[3]] = -1;
+        assert_eq!(data[[1, 0, 3]], -1);
+    }
+
+    // Test partial indexing with a dimension which does not add up
+    #[test]
+    #[should_panic(expected = "Dimension(1) + Dimension(1) must be equal to Dimension(3)")]
+    fn subview_wrong_dimension_t() {
+        let data = Array3::<u8>::zeros([2, 2, 2]);
+        data.subview::<1, 1>([0]);
+    }
+
+    // Test reading and writing the diagonal of rectangular and empty 2-D NdArrays
+    #[test]
+    fn diag_2dim_t() {
+        let mut data = Array2::<i32>::from_shape_fn([3, 5], |[i, j]| (i * 5 + j) as i32);
+        assert_eq!(data.diag().to_owned(), data.diagonal(0));
+        data.diag_mut().fill(-1);
+        assert_eq!(data.diagonal(0).as_slice(), &[-1, -1, -1]);
+        assert_eq!(data[[0, 1]], 1);
+
+        assert!(Array2::<i32>::new().diag().is_empty());
+        assert_eq!(data.t().to_owned().diag().len(), 3);
+    }
+
+    // Test broadcasting a 3-D NdArray to a higher dimension, and an empty axis
+    #[test]
+    fn broadcast_to_3dim_t() {
+        let data = Array3::<u8>::from_shape_fn([2, 1, 3], |[i, _, k]| (i * 3 + k) as u8);
+        let view: ArrayView<u8, 4> = data.broadcast_to([2, 2, 4, 3]);
+        assert_eq!(*view.strides(), [0, 3, 0, 1]);
+        for (i, j, k) in [(0, 0, 0), (1, 3, 2), (1, 2, 1)] {
+            assert_eq!(view[[1, i, j, k]], data[[i, 0, k]]);
+        }
+        assert!(!view.is_contiguous());
+        assert!(data.broadcast_to([2, 0, 3]).is_empty());
+    }
+
+    // Test broadcasting an axis which is neither equal nor of length 1
+    #[test]
+    #[should_panic(expected = "Shape([2, 3]) cannot be broadcast to Shape([2, 4])")]
+    fn broadcast_to_wrong_shape_t() {
+        let data = Array2::<u8>::zeros([2, 3]);
+        data.broadcast_to([2, 4]);
+    }
+
+    // Test the sliding windows of a 3-D NdArray and of a strided view
+    #[test]
+    fn sliding_window_view_3dim_t() {
+        let data = Array3::<usize>::from_shape_fn([3, 4, 2], |[i, j, k]| 100 * i + 10 * j + k);
+        let windows = data.sliding_window_view::<6>([2, 3, 2]);
+        assert_eq!(*windows.shape(), [2, 2, 1, 2, 3, 2]);
+        assert_eq!(windows[[1, 1, 0, 1, 2, 1]], 231);
+        assert_eq!(windows[[0, 1, 0, 0, 0, 0]], 10);
+
+        let column = data.index_axis::<2>(2, 1);
+        let pairs = column.sliding_window_view::<4>([3, 1]);
+        assert_eq!(*pairs.shape(), [1, 4, 3, 1]);
+        assert_eq!(pairs[[0, 3, 2, 0]], 231);
+        assert!(data.sliding_window_view::<6>([3, 4, 2]).len() == 24);
+    }
+
+    // Test a window longer than its axis
+    #[test]
+    #[should_panic(expected = "Window([2, 4]) exceeds Shape([2, 3])")]
+    fn sliding_window_view_too_long_t() {
+        let data = Array2::<u8>::zeros([2, 3]);
+        data.sliding_window_view::<4>([2, 4]);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{
+    core::{NdArray, SizedArray},
+    view::{offset, ArrayView, ArrayViewMut},
+};
+
+/// An array which yields one item per index, i.e., a reference to its element for a view and a mutable reference for a mutable view. Traversed in lock-step with other producers by [`Zip`]
+pub trait NdProducer<const N: usize> {
+    /// Item yielded for an index
+    type Item;
+
+    /// Return the shape of the producer
+    fn shape(&self) -> &SizedArray<N>;
+
+    /// Return the item at `index` without checking the bounds
+    ///
+    /// ## Safety
+    /// - `index` must be within the shape of the producer
+    /// - A producer of mutable references must not be asked for the same `index` twice
+    unsafe fn uget(&mut self, index: &SizedArray<N>) -> Self::Item;
+}
+
+impl<'a, T, const N: usize> NdProducer<N> for ArrayView<'a, T, N> {
+    type Item = &'a T;
+
+    fn shape(&self) -> &SizedArray<N> {
+        ArrayView::shape(self)
+    }
+
+    unsafe fn uget(&mut self, index: &SizedArray<N>) -> Self::Item {
+        &*self
+            .as_ptr()
+            .wrapping_add(offset::<T, N>(index, self.strides()))
+    }
+}
+
+impl<'a, T, const N: usize> NdProducer<N> for ArrayViewMut<'a, T, N> {
+    type Item = &'a mut T;
+
+    fn shape(&self) -> &SizedArray<N> {
+        ArrayViewMut::shape(self)
+    }
+
+    unsafe fn uget(&mut self, index: &SizedArray<N>) -> Self::Item {
+        let start: usize = offset::<T, N>(index, self.strides());
+        &mut *self.as_mut_ptr().wrapping_add(start)
+    }
+}
+
+/// Conversion into an [`NdProducer`], implemented for `&NdArray`, `&mut NdArray` and the views
+pub trait IntoNdProducer<const N: usize> {
+    /// Item yielded for an index
+    type Item;
+    /// Producer to convert into
+    type Producer: NdProducer<N, Item = Self::Item>;
+
+    /// Convert into a producer
+    fn into_producer(self) -> Self::Producer;
+}
+
+impl<'a, T, const N: usize> IntoNdProducer<N> for ArrayView<'a, T, N> {
+    type Item = &'a T;
+    type Producer = ArrayView<'a, T, N>;
+
+    fn into_producer(self) -> Self::Producer {
+        self
+    }
+}
+
+impl<'a, T, const N: usize> IntoNdProducer<N> for ArrayViewMut<'a, T, N> {
+    type Item = &'a mut T;
+    type Producer = ArrayViewMut<'a, T, N>;
+
+    fn into_producer(self) -> Self::Producer {
+        self
+    }
+}
+
+impl<'a, T, const N: usize> IntoNdProducer<N> for &'a NdArray<T, N> {
+    type Item = &'a T;
+    type Producer = ArrayView<'a, T, N>;
+
+    fn into_producer(self) -> Self::Producer {
+        self.view()
+    }
+}
+
+impl<'a, T, const N: usize> IntoNdProducer<N> for &'a mut NdArray<T, N> {
+    type Item = &'a mut T;
+    type Producer = ArrayViewMut<'a, T, N>;
+
+    fn into_producer(self) -> Self::Producer {
+        self.view_mut()
+    }
+}
+
+/// Advance `index` to the next index within `shape` in row-major (C) order
+fn next_index<const N: usize>(index: &mut SizedArray<N>, shape: &SizedArray<N>) {
+    for dim in (0..N).rev() {
+        index[dim] += 1;
+        if index[dim] < shape[dim] {
+            return;
+        }
+        index[dim] = 0;
+    }
+}
+
+/// Lock-step traversal of up to 4 arrays of the same shape, to write fused element-wise kernels without temporary arrays or index loops. The elements are visited in row-major (C) order of the logical index, following the strides of each array
+///
+/// ## Panics
+/// If an added array does not have the shape of the first array, panics; message shown is **Shape(`added`) don't match with Shape(`shape`)**
+///
+/// ## Example
+///
+/// ```
+/// use ndim::core::NdArray;
+/// use ndim::zip::Zip;
+///
+/// # fn main() {
+/// let a = NdArray::<f32, 2>::from(&[1.0, 2.0, 3.0, 4.0], [2, 2]);
+/// let b = NdArray::<f32, 2>::from(&[0.5, 0.5, 2.0, 2.0], [2, 2]);
+/// let mut out = NdArray::<f32, 2>::zeros([2, 2]);
+/// Zip::from(&mut out)
+///     .and(&a)
+///     .and(&b)
+///     .for_each(|o, x, y| *o = x * y + 1.0);
+/// assert_eq!(out.as_slice(), &[1.5, 2.0, 7.0, 9.0]);
+///
+/// // a transposed view is traversed in its logical order
+/// let sums = Zip::from(a.t()).and(&b).map_collect(|x, y| x + y);
+/// assert_eq!(sums.as_slice(), &[1.5, 3.5, 4.0, 6.0]);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Zip<P, const N: usize> {
+    parts: P,
+    shape: SizedArray<N>,
+}
+
+impl<P: NdProducer<N>, const N: usize> Zip<(P,), N> {
+    /// Start the traversal with `producer`, whose shape is the shape of the traversal
+    pub fn from<I: IntoNdProducer<N, Producer = P>>(producer: I) -> Self {
+        let part: P = producer.into_producer();
+        Zip {
+            shape: *part.shape(),
+            parts: (part,),
+        }
+    }
+}
+
+/// Implement `and(...)` adding the producer `$next` to a Zip of the producers `$p`
+macro_rules! impl_zip_and {
+    ($([$($p:ident $v:ident),+] $next:ident);* $(;)?) => {
+        $(
+            impl<$($p: NdProducer<N>,)+ const N: usize> Zip<($($p,)+), N> {
+                /// Add `producer` to the traversal
+                ///
+                /// ## Panics
+                /// If `producer` does not have the shape of the traversal, panics; message shown is **Shape(`producer`) don't match with Shape(`shape`)**
+                pub fn and<I: IntoNdProducer<N>>(self, producer: I) -> Zip<($($p,)+ I::Producer,), N> {
+                    let part: I::Producer = producer.into_producer();
+                    if *part.shape() != self.shape {
+                        panic!(
+                            "Shape({:?}) don't match with Shape({:?})",
+                            part.shape(),
+                            self.shape
+                        );
+                    }
+
+                    let ($($v,)+) = self.parts;
+                    Zip {
+                        parts: ($($v,)+ part,),
+                        shape: self.shape,
+                    }
+                }
+
+                /// Add the read-only `producer` of dimension `M <= N` to the traversal, broadcast to the shape of the traversal without copying, e.g. a `[4, 5]` array with a `[3, 4, 5]` traversal. See [`ArrayView::<T, N>::broadcast_to(...)`](ArrayView::broadcast_to)
+                ///
+                /// ## Panics
+                /// - If `M` is greater than `N`, panics; message shown is **Dimension(`N`) must not be less than Dimension(`M`)**
+                /// - If `producer` cannot be broadcast to the shape of the traversal, panics; message shown is **Shape(`producer`) cannot be broadcast to Shape(`shape`)**
+                ///
+                /// ## Example
+                ///
+                /// ```
+                /// use ndim::core::NdArray;
+                /// use ndim::zip::Zip;
+                ///
+                /// # fn main() {
+                /// // subtract the mean of every column
+                /// let mut data = NdArray::<f32, 2>::from(&[1.0, 10.0, 3.0, 30.0], [2, 2]);
+                /// let mean = NdArray::<f32, 1>::from(&[2.0, 20.0], [2]);
+                /// Zip::from(&mut data).and_broadcast(&mean).for_each(|x, m| *x -= m);
+                /// assert_eq!(data.as_slice(), &[-1.0, -10.0, 1.0, 10.0]);
+                /// # }
+                /// ```
+                pub fn and_broadcast<'b, U: 'b, I, const M: usize>(
+                    self,
+                    producer: I,
+                ) -> Zip<($($p,)+ ArrayView<'b, U, N>,), N>
+                where
+                    I: IntoNdProducer<M, Producer = ArrayView<'b, U, M>>,
+                {
+                    let part: ArrayView<'b, U, N> = producer.into_producer().broadcast_to(self.shape);
+                    let ($($v,)+) = self.parts;
+                    Zip {
+                        parts: ($($v,)+ part,),
+                        shape: self.shape,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_zip_and!(
+    [P1 p1] P2;
+    [P1 p1, P2 p2] P3;
+    [P1 p1, P2 p2, P3 p3] P4;
+);
+
+/// Implement `for_each(...)` and `map_collect(...)` for a Zip of the producers `$p`
+macro_rules! impl_zip_apply {
+    ($([$($p:ident $v:ident),+]);* $(;)?) => {
+        $(
+            impl<$($p: NdProducer<N>,)+ const N: usize> Zip<($($p,)+), N> {
+                /// Call `f` with the items of every producer at each index
+                pub fn for_each<F>(self, mut f: F)
+                where
+                    F: FnMut($($p::Item),+),
+                {
+                    let len: usize = self.shape.iter().product();
+                    let ($(mut $v,)+) = self.parts;
+                    let mut index: SizedArray<N> = [0; N];
+                    for _ in 0..len {
+                        // SAFETY: `index` is within the shape shared by every producer, and takes each value once
+                        unsafe { f($($v.uget(&index)),+) };
+                        next_index(&mut index, &self.shape);
+                    }
+                }
+
+                /// Call `f` with the items of every producer at each index, and collect the results into an NdArray of the shape of the traversal
+                pub fn map_collect<R, F>(self, mut f: F) -> NdArray<R, N>
+                where
+                    F: FnMut($($p::Item),+) -> R,
+                {
+                    let shape: SizedArray<N> = self.shape;
+                    let mut values: Vec<R> = Vec::with_capacity(shape.iter().product());
+                    self.for_each(|$($v),+| values.push(f($($v),+)));
+                    NdArray::from_owned_vec(values, shape)
+                }
+            }
+        )*
+    };
+}
+
+impl_zip_apply!(
+    [P1 p1];
+    [P1 p1, P2 p2];
+    [P1 p1, P2 p2, P3 p3];
+    [P1 p1, P2 p2, P3 p3, P4 p4];
+);
+
+impl<T, const N: usize> NdArray<T, N> {
+    /// Call `f` with every element and the element of `rhs` at the same index, to combine `rhs` into the NdArray in-place without a temporary array. `rhs` is broadcast to the shape of the NdArray, e.g. a row can be added to every row of a matrix
+    ///
+    /// ## Panics
+    /// If `rhs` cannot be broadcast to the shape of the NdArray, panics; message shown is **Shape(`rhs`) cannot be broadcast to Shape(`shape`)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use ndim::core::NdArray;
+    /// #
+    /// # fn main() {
+    /// let mut a = NdArray::<i32, 2>::from(&[1, 2, 3, 4], [2, 2]);
+    /// let b = NdArray::<i32, 2>::from(&[10, 20, 30, 40], [2, 2]);
+    /// a.zip_mut_with(&b, |x, y| *x += *y);
+    /// assert_eq!(a.as_slice(), &[11, 22, 33, 44]);
+    ///
+    /// let row = NdArray::<i32, 2>::from(&[1, -1], [1, 2]);
+    /// a.zip_mut_with(&row, |x, y| *x *= *y);
+    /// assert_eq!(a.as_slice(), &[11, -22, 33, -44]);
+    /// # }
+    /// ```
+    pub fn zip_mut_with<U, F>(&mut self, rhs: &NdArray<U, N>, f: F)
+    where
+        F: FnMut(&mut T, &U),
+    {
+        let shape: SizedArray<N> = self.view_shape();
+        Zip::from(self).and(rhs.broadcast_to(shape)).for_each(f);
+    }
+}
+
+#[cfg(test)]
+mod zip_t {
+    use crate::{
+        core::{Array, Array2, Array3, NdArray},
+        zip::Zip,
+    };
+
+    // Test a fused kernel over 4 3-D arrays, one of them a permuted view
+    // Check if every element is combined with the elements at the same logical index
+    #[test]
+    fn zip_3dim_t() {
+        let a = Array3::<usize>::from_shape_fn([2, 3, 4], |[i, j, k]| 100 * i + 10 * j + k);
+        let b = Array3::<usize>::from_shape_fn([4, 2, 3], |[k, i, j]| 100 * i + 10 * j + k);
+        let c = Array3::<usize>::ones([2, 3, 4]);
+        let mut out = Array3::<usize>::zeros([2, 3, 4]);
+        Zip::from(&mut out)
+            .and(&a)
+            .and(b.permuted_axes([1, 2, 0]))
+            .and(&c)
+            .for_each(|o, x, y, z| *o = x + y + z);
+        for (index, elem) in out.indexed_iter() {
+            assert_eq!(*elem, 2 * a[index] + 1);
+        }
+
+        let (_, right) = a.split_at(2, 2);
+        let doubled: NdArray<usize, 3> = Zip::from(right).map_collect(|x| 2 * x);
+        assert_eq!(*doubled.shape(), [2, 3, 2]);
+        assert_eq!(doubled[[1, 2, 1]], 246);
+        Zip::from(&NdArray::<u8, 3>::new()).for_each(|_| panic!("visited an element"));
+    }
+
+    // Test broadcasting a 2-D and a 3-D NdArray to a 3-D traversal, e.g. `[3, 1, 5]` with `[4, 5]`
+    #[test]
+    fn zip_broadcast_3dim_t() {
+        let a = Array3::<i32>::from_shape_fn([3, 1, 5], |[i, _, k]| 10 * i as i32 + k as i32);
+        let b = Array2::<i32>::from_shape_fn([4, 5], |[j, k]| 100 * j as i32 - k as i32);
+        let mut out = Array3::<i32>::zeros([3, 4, 5]);
+        Zip::from(&mut out)
+            .and_broadcast(&a)
+            .and_broadcast(b.view())
+            .for_each(|o, x, y| *o = x + y);
+        for ([i, j, _], elem) in out.indexed_iter() {
+            assert_eq!(*elem, 10 * i as i32 + 100 * j as i32);
+        }
+    }
+
+    // Test broadcasting an NdArray whose axis of length 2 must be stretched
+    #[test]
+    #[should_panic(expected = "Shape([2]) cannot be broadcast to Shape([3, 3])")]
+    fn zip_broadcast_wrong_shape_t() {
+        let mut out = Array2::<u8>::zeros([3, 3]);
+        Zip::from(&mut out)
+            .and_broadcast(&Array::<u8>::zeros([2]))
+            .for_each(|o, x| *o = *x);
+    }
+
+    // Test combining 3-D NdArrays in-place, with and without broadcasting
+    #[test]
+    fn zip_mut_with_3dim_t() {
+        let mut a =
+            Array3::<i64>::from_shape_fn([2, 3, 4], |[i, j, k]| (100 * i + 10 * j + k) as i64);
+        let b = Array3::<i64>::from_shape_fn([2, 3, 4], |[i, j, _]| -((100 * i + 10 * j) as i64));
+        a.zip_mut_with(&b, |x, y| *x += *y);
+        assert_eq!(a[[1, 2, 3]], 3);
+
+        let scale = Array3::<i64>::from_shape_fn([1, 3, 1], |[_, j, _]| j as i64);
+        a.zip_mut_with(&scale, |x, y| *x *= *y);
+        assert_eq!(a[[1, 0, 3]], 0);
+        assert_eq!(a[[0, 2, 3]], 6);
+    }
+
+    // Test combining with an array which cannot be broadcast
+    #[test]
+    #[should_panic(expected = "Shape([3]) cannot be broadcast to Shape([2])")]
+    fn zip_mut_with_wrong_shape_t() {
+        let mut a = NdArray::<u8, 1>::zeros([2]);
+        a.zip_mut_with(&NdArray::<u8, 1>::zeros([3]), |x, y| *x += y);
+    }
+
+    // Test adding an array of a different shape
+    #[test]
+    #[should_panic(expected = "Shape([3, 2]) don't match with Shape([2, 3])")]
+    fn zip_wrong_shape_t() {
+        let a = NdArray::<u8, 2>::zeros([2, 3]);
+        let b = NdArray::<u8, 2>::zeros([3, 2]);
+        Zip::from(&a).and(&b);
+    }
+}